@@ -66,3 +66,57 @@ def test_zizmor_offline_cpython_48f88310044c(cpython: Path):
         ],
         check=True,
     )
+
+
+@pytest.fixture(scope="session")
+def large_run_block(tmp_path_factory) -> Path:
+    # A single workflow with a very large `run:` block, with template
+    # injections scattered throughout. Locating each injection's subfeature
+    # scans the rest of the `run:` block.
+    #
+    # NOTE: This times the whole CLI, so it guards against regressions in
+    # subfeature location on large inputs rather than measuring any single
+    # optimization within it (such as skipping too-short features).
+    lines = []
+    for i in range(5000):
+        lines.append(f"          echo 'line {i}'")
+        if i % 100 == 0:
+            lines.append("          echo ${{ github.event.issue.title }}")
+            lines.append("          echo ${{ github.event.pull_request.head.ref\n            }}")
+
+    script = "\n".join(lines)
+    workflow = f"""\
+on: issues
+
+permissions: {{}}
+
+jobs:
+  large:
+    runs-on: ubuntu-latest
+    steps:
+      - run: |
+{script}
+"""
+
+    path = tmp_path_factory.mktemp("large-run-block") / "large-run-block.yml"
+    path.write_text(workflow)
+
+    return path
+
+
+@pytest.mark.benchmark
+def test_zizmor_offline_large_run_block(large_run_block: Path):
+    """
+    Runs `zizmor --offline --format=plain --no-exit-codes --no-config <path-to-large-run-block>`
+    """
+
+    zizmor(
+        [
+            "--offline",
+            "--format=plain",
+            "--no-exit-codes",
+            "--no-config",
+            str(large_run_block),
+        ],
+        check=True,
+    )
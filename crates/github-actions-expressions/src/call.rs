@@ -138,6 +138,13 @@ impl<'src> Call<'src> {
 
     /// Constant-evaluates a `format(fmtspec, args...)` call.
     ///
+    /// Actions fails the expression at runtime if the format string is
+    /// invalid, including if it references an argument that wasn't
+    /// supplied (e.g. `format('{0} {1}', 'a')`). We return `None` in these
+    /// cases, since the call has no constant value, rather than producing
+    /// a partially formatted string. Extra arguments are ignored, as
+    /// they are by Actions.
+    ///
     /// See: <https://github.com/actions/languageservices/blob/1f3436c3cacc0f99d5d79e7120a5a9270cf13a72/expressions/src/funcs/format.ts>
     fn consteval_format(args: &[Evaluation]) -> Option<Evaluation> {
         if args.is_empty() {
@@ -730,6 +737,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_consteval_format_arg_count() -> Result<(), Error> {
+        use crate::Evaluation;
+
+        // Too few arguments: Actions errors, so there's no constant value,
+        // even when an earlier placeholder could be filled in.
+        for expr_str in [
+            "format('{0}')",
+            "format('{0} {1}', 'a')",
+            "format('{1} {0}', 'a')",
+        ] {
+            let expr = Expr::parse(expr_str)?;
+            assert_eq!(expr.consteval(), None, "for expression: {expr_str}");
+        }
+
+        // Too many arguments: the extras are ignored.
+        for (expr_str, expected) in [
+            ("format('{0}', 'a', 'b')", "a"),
+            ("format('{1}', 'a', 'b', 'c')", "b"),
+            ("format('no placeholders', 'a')", "no placeholders"),
+        ] {
+            let expr = Expr::parse(expr_str)?;
+            assert_eq!(
+                expr.consteval(),
+                Some(Evaluation::String(expected.into())),
+                "for expression: {expr_str}"
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_consteval_contains() -> Result<(), Error> {
         use crate::Evaluation;
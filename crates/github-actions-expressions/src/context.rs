@@ -10,18 +10,89 @@ use super::{Expr, SpannedExpr};
 #[error("invalid context pattern")]
 pub struct InvalidContextPattern;
 
+/// The well-known "root" namespaces of GitHub Actions contexts.
+///
+/// See: <https://docs.github.com/en/actions/reference/workflows-and-actions/contexts>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextRoot<'src> {
+    /// `github.*`
+    Github,
+    /// `env.*`
+    Env,
+    /// `vars.*`
+    Vars,
+    /// `job.*`
+    Job,
+    /// `jobs.*`, in reusable workflow outputs.
+    Jobs,
+    /// `steps.*`
+    Steps,
+    /// `runner.*`
+    Runner,
+    /// `secrets.*`
+    Secrets,
+    /// `strategy.*`
+    Strategy,
+    /// `matrix.*`
+    Matrix,
+    /// `needs.*`
+    Needs,
+    /// `inputs.*`
+    Inputs,
+    /// Any other root identifier, as it appears in the expression.
+    Other(&'src str),
+}
+
+impl<'src> From<&'src str> for ContextRoot<'src> {
+    /// Classify a root identifier. Like identifiers themselves,
+    /// this is case-insensitive.
+    fn from(root: &'src str) -> Self {
+        match root.to_ascii_lowercase().as_str() {
+            "github" => Self::Github,
+            "env" => Self::Env,
+            "vars" => Self::Vars,
+            "job" => Self::Job,
+            "jobs" => Self::Jobs,
+            "steps" => Self::Steps,
+            "runner" => Self::Runner,
+            "secrets" => Self::Secrets,
+            "strategy" => Self::Strategy,
+            "matrix" => Self::Matrix,
+            "needs" => Self::Needs,
+            "inputs" => Self::Inputs,
+            _ => Self::Other(root),
+        }
+    }
+}
+
 /// Represents a context in a GitHub Actions expression.
 ///
 /// These typically look something like `github.actor` or `inputs.foo`,
 /// although they can also be a "call" context like `fromJSON(...).foo.bar`,
 /// i.e. where the head of the context is a function call rather than an
 /// identifier.
-#[derive(Debug, PartialEq)]
+///
+/// Like identifiers, contexts compare case-insensitively: `GitHub.Actor`,
+/// `github.actor`, and `github['ACTOR']` are all equal.
+#[derive(Debug)]
 pub struct Context<'src> {
     /// The individual parts of the context.
     pub parts: Vec<SpannedExpr<'src>>,
 }
 
+impl PartialEq for Context<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.parts.len() == other.parts.len()
+            && self.parts.iter().zip(other.parts.iter()).all(|(lhs, rhs)| {
+                match (Self::part_key(lhs), Self::part_key(rhs)) {
+                    (Some(lhs), Some(rhs)) => lhs.eq_ignore_ascii_case(rhs),
+                    (None, None) => lhs == rhs,
+                    _ => false,
+                }
+            })
+    }
+}
+
 impl<'src> Context<'src> {
     pub(crate) fn new(parts: impl Into<Vec<SpannedExpr<'src>>>) -> Self {
         Self {
@@ -62,6 +133,27 @@ impl<'src> Context<'src> {
         parent.parent_of(self)
     }
 
+    /// Returns this context's root, i.e. its leading identifier,
+    /// as it appears in the expression.
+    ///
+    /// For example, the root of `github.event.issue.title` is `github`.
+    ///
+    /// Returns `None` if the context's head is not an identifier,
+    /// e.g. `fromJSON(...).foo`.
+    pub fn root(&self) -> Option<&'src str> {
+        match &self.parts.first()?.inner {
+            Expr::Identifier(ident) => Some(ident.0),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`ContextRoot`] classification of this context's root.
+    ///
+    /// Returns `None` under the same conditions as [`Context::root`].
+    pub fn root_kind(&self) -> Option<ContextRoot<'src>> {
+        self.root().map(ContextRoot::from)
+    }
+
     /// Return this context's "single tail," if it has one.
     ///
     /// This is useful primarily for contexts under `env` and `inputs`,
@@ -85,6 +177,46 @@ impl<'src> Context<'src> {
         }
     }
 
+    /// Returns the key that a context part names, if it names one
+    /// statically: the identifier itself (`bar` in `foo.bar`), or a
+    /// string index (`bar` in `foo['bar']`).
+    fn part_key<'a>(part: &'a Expr<'src>) -> Option<&'a str> {
+        match part {
+            Expr::Identifier(ident) => Some(ident.0),
+            Expr::Index(idx) => match &idx.inner {
+                Expr::Literal(Literal::String(idx)) => Some(idx),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns a normalized form of this context, suitable for
+    /// case-insensitive comparison and lookup.
+    ///
+    /// The normalized form is dotted and ASCII-lowercased, with string
+    /// indices rewritten as dotted parts, e.g. `GitHub['Actor']` becomes
+    /// `github.actor`. Unlike [`Context::as_pattern`], computed indices
+    /// are not collapsed into wildcards, so two contexts with equal
+    /// normalized forms are always equal.
+    ///
+    /// Returns `None` if the context contains a part that can't be
+    /// normalized, e.g. a call or a computed index.
+    pub fn normalized(&self) -> Option<String> {
+        let mut normalized = self
+            .parts
+            .iter()
+            .map(|part| match &part.inner {
+                Expr::Star => Some("*"),
+                part => Self::part_key(part),
+            })
+            .collect::<Option<Vec<_>>>()?
+            .join(".");
+
+        normalized.make_ascii_lowercase();
+        Some(normalized)
+    }
+
     /// Returns the "pattern equivalent" of this context.
     ///
     /// This is a string that can be used to efficiently match the context,
@@ -291,7 +423,7 @@ impl<'src> ContextPattern<'src> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Context, ContextPattern};
+    use super::{Context, ContextPattern, ContextRoot};
 
     #[test]
     fn test_context_child_of() {
@@ -322,6 +454,141 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_context_root() {
+        for (case, root, kind) in &[
+            (
+                "github.event.issue.title",
+                Some("github"),
+                Some(ContextRoot::Github),
+            ),
+            ("env.FOO", Some("env"), Some(ContextRoot::Env)),
+            ("vars.FOO", Some("vars"), Some(ContextRoot::Vars)),
+            ("job.status", Some("job"), Some(ContextRoot::Job)),
+            (
+                "jobs.build.outputs.foo",
+                Some("jobs"),
+                Some(ContextRoot::Jobs),
+            ),
+            (
+                "steps.foo.outputs.bar",
+                Some("steps"),
+                Some(ContextRoot::Steps),
+            ),
+            ("runner.os", Some("runner"), Some(ContextRoot::Runner)),
+            (
+                "secrets.GITHUB_TOKEN",
+                Some("secrets"),
+                Some(ContextRoot::Secrets),
+            ),
+            (
+                "strategy.job-index",
+                Some("strategy"),
+                Some(ContextRoot::Strategy),
+            ),
+            ("matrix.os", Some("matrix"), Some(ContextRoot::Matrix)),
+            (
+                "needs.build.result",
+                Some("needs"),
+                Some(ContextRoot::Needs),
+            ),
+            ("inputs['foo']", Some("inputs"), Some(ContextRoot::Inputs)),
+            // Roots are case-insensitive, but returned as written.
+            ("GitHub.actor", Some("GitHub"), Some(ContextRoot::Github)),
+            ("SECRETS.foo", Some("SECRETS"), Some(ContextRoot::Secrets)),
+            // Unknown roots.
+            ("foo.bar", Some("foo"), Some(ContextRoot::Other("foo"))),
+            // Heads that aren't identifiers have no root.
+            ("fromJSON(inputs.foo).bar", None, None),
+            ("fromJSON('{}')['bar']", None, None),
+            ("(github || env).foo", None, None),
+        ] {
+            let ctx = Context::parse(case).unwrap();
+            assert_eq!(ctx.root(), *root, "{case}");
+            assert_eq!(ctx.root_kind(), *kind, "{case}");
+        }
+    }
+
+    #[test]
+    fn test_context_eq_case_insensitive() {
+        for (lhs, rhs, expected) in &[
+            // Mixed-case roots and identifiers, across well-known roots.
+            ("github.actor", "GitHub.Actor", true),
+            (
+                "github.event.pull_request.title",
+                "GITHUB.EVENT.Pull_Request.TITLE",
+                true,
+            ),
+            ("secrets.GITHUB_TOKEN", "Secrets.github_token", true),
+            ("env.FOO", "ENV.foo", true),
+            ("inputs.some-input", "Inputs.Some-Input", true),
+            ("matrix.os", "MATRIX.OS", true),
+            ("steps.foo.outputs.bar", "Steps.FOO.Outputs.BAR", true),
+            ("needs.build.result", "NEEDS.Build.Result", true),
+            ("vars.FOO", "Vars.Foo", true),
+            // String indices are equivalent to identifiers.
+            ("github.actor", "github['Actor']", true),
+            ("secrets['GITHUB_TOKEN']", "SECRETS.github_token", true),
+            ("inputs['foo']", "Inputs['FOO']", true),
+            // Other indices and stars compare structurally.
+            ("foo.bar[0]", "FOO.BAR[0]", true),
+            ("foo.*.baz", "Foo.*.Baz", true),
+            ("foo.bar[0]", "foo.bar[1]", false),
+            ("foo.bar[0]", "foo.bar.*", false),
+            // Different contexts.
+            ("github.actor", "github.actors", false),
+            ("github.actor", "github.event.actor", false),
+            ("secrets.foo", "env.foo", false),
+        ] {
+            let lhs_ctx = Context::parse(lhs).unwrap();
+            let rhs_ctx = Context::parse(rhs).unwrap();
+            assert_eq!(lhs_ctx == rhs_ctx, *expected, "{lhs} == {rhs}");
+            assert_eq!(rhs_ctx == lhs_ctx, *expected, "{rhs} == {lhs}");
+        }
+    }
+
+    #[test]
+    fn test_context_matches_mixed_case() {
+        for (ctx, pattern) in &[
+            ("GitHub.Actor", "github.actor"),
+            ("GITHUB.event.Issue.Title", "github.event.issue.title"),
+            (
+                "github.EVENT.pull_request.HEAD.ref",
+                "github.event.pull_request.head.ref",
+            ),
+            ("Secrets.GITHUB_TOKEN", "secrets.github_token"),
+            ("github.Token", "github.token"),
+            ("Inputs['Foo']", "inputs.foo"),
+            ("Steps.Foo.Outputs.Bar", "steps.*.outputs.*"),
+            ("Needs.Build.Result", "needs.*.result"),
+        ] {
+            let ctx = Context::parse(ctx).unwrap();
+            assert!(ctx.matches(*pattern), "{ctx:?} should match {pattern}");
+            assert!(
+                ctx.child_of(*pattern),
+                "{ctx:?} should be a child of {pattern}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_context_normalized() {
+        for (case, expected) in &[
+            ("github.actor", Some("github.actor")),
+            ("GitHub.Actor", Some("github.actor")),
+            ("GitHub['Actor']", Some("github.actor")),
+            ("Secrets['GITHUB_TOKEN']", Some("secrets.github_token")),
+            ("foo.*.Bar", Some("foo.*.bar")),
+            // Parts that can't be normalized.
+            ("foo.bar[0]", None),
+            ("foo[bar]", None),
+            ("fromJSON('{}').bar", None),
+        ] {
+            let ctx = Context::parse(case).unwrap();
+            assert_eq!(ctx.normalized().as_deref(), *expected, "{case}");
+        }
+    }
+
     #[test]
     fn test_single_tail() {
         for (case, expected) in &[
@@ -473,6 +473,16 @@ pub enum Evaluation {
     /// A string value (includes both string literals and stringified other types).
     String(String),
     /// A numeric value.
+    ///
+    /// Like GitHub Actions itself, numbers are always IEEE 754 doubles:
+    /// there's no separate integer type, so `Number(1.0)` is *the*
+    /// integer one and compares equal to it under both `PartialEq` and
+    /// [`EvaluationSema`]. Integral numbers display without a decimal
+    /// point (e.g. `42`), while fractional numbers keep it (e.g. `1.5`).
+    ///
+    /// Integers beyond ±2^53 can't be represented exactly, so e.g.
+    /// `fromJSON('9007199254740993')` evaluates to `9007199254740992`,
+    /// exactly as it would on GitHub's runners.
     Number(f64),
     /// A boolean value.
     Boolean(bool),
@@ -528,7 +538,9 @@ impl TryInto<serde_json::Value> for Evaluation {
                 // NOTE: serde_json has different internal representations
                 // for integers and floats, so we need to handle both cases
                 // to ensure we serialize integers without a decimal point.
-                if n.fract() == 0.0 {
+                // Integral values outside of `i64`'s range would be saturated
+                // by the cast, so we fall back to a float for them.
+                if n.fract() == 0.0 && n.abs() < I64_BOUND {
                     Ok(serde_json::Value::Number(serde_json::Number::from(
                         n as i64,
                     )))
@@ -600,6 +612,35 @@ impl Evaluation {
     pub fn sema(&self) -> EvaluationSema<'_> {
         EvaluationSema(self)
     }
+
+    /// Compares two evaluations structurally, recursing into arrays and
+    /// objects.
+    ///
+    /// Scalars are compared with GitHub Actions semantics (see
+    /// [`EvaluationSema`]), and object keys are compared
+    /// case-insensitively.
+    ///
+    /// This is **not** how `==` behaves in GitHub Actions: arrays and
+    /// objects compare by reference there, so two separately constructed
+    /// values are never equal. Use this only when a structural comparison
+    /// is explicitly wanted.
+    pub fn deep_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Evaluation::Array(a), Evaluation::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.deep_eq(b))
+            }
+            (Evaluation::Object(a), Evaluation::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(key, a)| {
+                        let key = EvaluationSema::upper_special(key);
+                        b.iter().any(|(other_key, b)| {
+                            EvaluationSema::upper_special(other_key) == key && a.deep_eq(b)
+                        })
+                    })
+            }
+            (a, b) => a.sema() == b.sema(),
+        }
+    }
 }
 
 /// Parse a string into a number following GitHub Actions coercion rules.
@@ -652,6 +693,10 @@ fn parse_number(s: &str) -> f64 {
     f64::NAN
 }
 
+/// The (exclusive) magnitude bound below which an integral `f64` can be
+/// cast to `i64` without saturating, i.e. 2^63.
+const I64_BOUND: f64 = 9_223_372_036_854_775_808.0;
+
 /// A wrapper around `Evaluation` that implements GitHub Actions
 /// various evaluation semantics (comparison, stringification, etc.).
 pub struct EvaluationSema<'a>(&'a Evaluation);
@@ -677,6 +722,14 @@ impl EvaluationSema<'_> {
     }
 }
 
+/// Equality under GitHub Actions semantics, i.e. the `==` operator.
+///
+/// Arrays and objects compare by reference in GitHub Actions, which
+/// can't be observed during constant evaluation: every array or object
+/// here comes from a separate `fromJSON` call and so is a distinct
+/// value. They therefore never compare equal, not even to themselves;
+/// this falls out of both sides coercing to NaN below. See
+/// [`Evaluation::deep_eq`] for a structural comparison.
 impl PartialEq for EvaluationSema<'_> {
     fn eq(&self, other: &Self) -> bool {
         match (self.0, other.0) {
@@ -726,9 +779,22 @@ impl std::fmt::Display for EvaluationSema<'_> {
                     let rounded: f64 = format!("{:.15}", n)
                         .parse()
                         .expect("impossible f64 round-trip error");
-                    if rounded.fract() == 0.0 {
-                        write!(f, "{}", rounded as i64)
+                    if rounded == 0.0 {
+                        // Normalizes `-0` to `0`.
+                        write!(f, "0")
+                    } else if !(1e-6..1e21).contains(&rounded.abs()) {
+                        // Like JavaScript, use exponential notation for very
+                        // large or small magnitudes, e.g. `1e+21` and `1e-7`.
+                        let exp = format!("{:e}", rounded);
+                        match exp.split_once('e') {
+                            Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+                                write!(f, "{mantissa}e+{exponent}")
+                            }
+                            _ => write!(f, "{exp}"),
+                        }
                     } else {
+                        // NOTE: `f64`'s `Display` doesn't add a trailing `.0`
+                        // to integral values, e.g. `42.0` displays as `42`.
                         write!(f, "{}", rounded)
                     }
                 }
@@ -2192,6 +2258,84 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_mixed_type_comparisons() -> Result<(), Error> {
+        use crate::Evaluation;
+
+        // Mixed-type comparisons coerce both sides to numbers, per
+        // <https://docs.github.com/en/actions/reference/workflows-and-actions/expressions#operators>.
+        let test_cases = &[
+            // String vs. number: the string is parsed as a number.
+            ("'20' >= 20", true),
+            ("'20' > 20", false),
+            ("'20' <= 20", true),
+            ("'20' == 20", true),
+            ("'20' != 20", false),
+            ("'21' > 20", true),
+            ("20 < '21'", true),
+            ("' 20 ' == 20", true),
+            ("'020' == 20", true),
+            ("'20.0' == 20", true),
+            ("'0x14' == 20", true),
+            ("'2e1' == 20", true),
+            // Non-numeric strings become NaN, so every relational
+            // comparison is false and only `!=` holds.
+            ("'abc' == 0", false),
+            ("'abc' != 0", true),
+            ("'abc' < 1", false),
+            ("'abc' >= 1", false),
+            ("'abc' <= 1", false),
+            ("'inf' > 0", false),
+            // The empty string becomes zero.
+            ("'' == 0", true),
+            ("'' < 1", true),
+            // Null becomes zero when compared to other types.
+            ("null == 0", true),
+            ("null < 1", true),
+            ("null > -1", true),
+            ("null == ''", true),
+            ("null == false", true),
+            ("null == 'null'", false),
+            ("null >= 0", true),
+            // Null vs. null.
+            ("null == null", true),
+            ("null != null", false),
+            ("null >= null", true),
+            ("null <= null", true),
+            ("null < null", false),
+            ("null > null", false),
+            // Booleans become zero or one.
+            ("true == 1", true),
+            ("false == 0", true),
+            ("true > false", true),
+            ("false < true", true),
+            ("true >= 1", true),
+            ("'true' == true", false),
+            ("'1' == true", true),
+            ("'0' == false", true),
+            // Arrays and objects become NaN.
+            ("fromJSON('[]') == 0", false),
+            ("fromJSON('[]') != 0", true),
+            ("fromJSON('[]') < 1", false),
+            ("fromJSON('{}') >= 0", false),
+            ("fromJSON('{}') == fromJSON('{}')", false),
+            ("fromJSON('[]') == null", false),
+        ];
+
+        for (expr_str, expected) in test_cases {
+            let expr = Expr::parse(expr_str)?;
+            let result = expr.consteval().unwrap();
+            assert_eq!(
+                result,
+                Evaluation::Boolean(*expected),
+                "Failed for expression: {}",
+                expr_str
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_evaluation_sema_display() {
         use crate::Evaluation;
@@ -2200,6 +2344,19 @@ mod tests {
             (Evaluation::String("hello".to_string()), "hello"),
             (Evaluation::Number(42.0), "42"),
             (Evaluation::Number(3.14), "3.14"),
+            (Evaluation::Number(1.5), "1.5"),
+            (Evaluation::Number(-0.0), "0"),
+            (Evaluation::Number(-42.0), "-42"),
+            // Large integers keep all of their digits, rather than
+            // saturating at `i64::MAX`.
+            (Evaluation::Number(9007199254740992.0), "9007199254740992"),
+            (Evaluation::Number(1e20), "100000000000000000000"),
+            (Evaluation::Number(-1e20), "-100000000000000000000"),
+            // Exponential notation kicks in at the same bounds as JavaScript.
+            (Evaluation::Number(1e21), "1e+21"),
+            (Evaluation::Number(1.5e300), "1.5e+300"),
+            (Evaluation::Number(0.000001), "0.000001"),
+            (Evaluation::Number(1e-7), "1e-7"),
             (Evaluation::Boolean(true), "true"),
             (Evaluation::Boolean(false), "false"),
             (Evaluation::Null, ""),
@@ -2210,6 +2367,119 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_evaluation_number_semantics() -> Result<(), Error> {
+        use crate::Evaluation;
+
+        // JSON integers evaluate to integer-displaying numbers, and
+        // fractional values keep their decimals.
+        for (expr, expected) in [
+            ("fromJSON('42')", "42"),
+            ("fromJSON('42.0')", "42"),
+            ("fromJSON('-7')", "-7"),
+            ("fromJSON('1.5')", "1.5"),
+            ("fromJSON('9007199254740992')", "9007199254740992"),
+            // Beyond 2^53, integers lose precision exactly as they do on
+            // GitHub's (JavaScript-based) runners.
+            ("fromJSON('9007199254740993')", "9007199254740992"),
+            ("fromJSON('18446744073709551615')", "18446744073709552000"),
+            (
+                "format('{0}', fromJSON('100000000000000000000'))",
+                "100000000000000000000",
+            ),
+        ] {
+            let evaluation = Expr::parse(expr)?.consteval().unwrap();
+            assert_eq!(evaluation.sema().to_string(), expected, "{expr}");
+        }
+
+        // There's no integer/float distinction under comparison.
+        for (expr, expected) in [
+            ("1.0 == 1", true),
+            ("fromJSON('1.0') == fromJSON('1')", true),
+            ("1.5 == 1", false),
+            ("'1.0' == 1", true),
+            ("fromJSON('9007199254740993') == 9007199254740992", true),
+        ] {
+            let evaluation = Expr::parse(expr)?.consteval().unwrap();
+            assert_eq!(evaluation, Evaluation::Boolean(expected), "{expr}");
+        }
+        assert_eq!(Evaluation::Number(1.0), Evaluation::Number(1.0));
+        assert!(Evaluation::Number(1.0).sema() == Evaluation::Number(1.0).sema());
+
+        // JSON serialization keeps integral values as integers, without
+        // saturating ones outside of `i64`'s range.
+        for (evaluation, expected) in [
+            (Evaluation::Number(42.0), "42"),
+            (Evaluation::Number(1.5), "1.5"),
+            (Evaluation::Number(-1e20), "-1e+20"),
+            (Evaluation::Number(1e20), "1e+20"),
+        ] {
+            let value: serde_json::Value = evaluation.try_into().unwrap();
+            assert_eq!(value.to_string(), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_object_equality() -> Result<(), Error> {
+        use crate::Evaluation;
+
+        // GitHub Actions compares arrays and objects by reference, so
+        // separately constructed values are never equal, even when
+        // structurally identical.
+        for (expr, expected) in [
+            ("fromJSON('[1, 2]') == fromJSON('[1, 2]')", false),
+            ("fromJSON('[1, 2]') != fromJSON('[1, 2]')", true),
+            ("fromJSON('[]') == fromJSON('[]')", false),
+            ("fromJSON('{\"a\": 1}') == fromJSON('{\"a\": 1}')", false),
+            ("fromJSON('{\"a\": 1}') != fromJSON('{\"a\": 1}')", true),
+            ("fromJSON('[1]') == fromJSON('{}')", false),
+            ("fromJSON('[1]') == 1", false),
+            ("fromJSON('[\"a\"]') == 'a'", false),
+            // The same holds for the element comparisons in `contains`,
+            // so nested arrays are never found.
+            ("contains(fromJSON('[[1, 2]]'), fromJSON('[1, 2]'))", false),
+            ("contains(fromJSON('[{}]'), fromJSON('{}'))", false),
+            ("contains(fromJSON('[1, 2]'), 2)", true),
+            ("contains(fromJSON('[1, 2]'), '2')", true),
+        ] {
+            let evaluation = Expr::parse(expr)?.consteval().unwrap();
+            assert_eq!(evaluation, Evaluation::Boolean(expected), "{expr}");
+        }
+
+        // Structural comparison is available explicitly.
+        let eval = |expr: &str| Expr::parse(expr).unwrap().consteval().unwrap();
+        for (lhs, rhs, expected) in [
+            ("fromJSON('[1, 2]')", "fromJSON('[1, 2]')", true),
+            ("fromJSON('[1, 2]')", "fromJSON('[2, 1]')", false),
+            ("fromJSON('[1, 2]')", "fromJSON('[1]')", false),
+            (
+                "fromJSON('[\"A\", [1]]')",
+                "fromJSON('[\"a\", [\"1\"]]')",
+                true,
+            ),
+            ("fromJSON('{\"a\": [1]}')", "fromJSON('{\"A\": [1]}')", true),
+            (
+                "fromJSON('{\"a\": 1}')",
+                "fromJSON('{\"a\": 1, \"b\": 2}')",
+                false,
+            ),
+            ("fromJSON('{\"a\": 1}')", "fromJSON('{\"b\": 1}')", false),
+            ("fromJSON('[]')", "fromJSON('{}')", false),
+            ("fromJSON('[]')", "0", false),
+            ("'abc'", "'ABC'", true),
+        ] {
+            assert_eq!(
+                eval(lhs).deep_eq(&eval(rhs)),
+                expected,
+                "{lhs} deep_eq {rhs}"
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_evaluation_result_to_boolean() {
         use crate::Evaluation;
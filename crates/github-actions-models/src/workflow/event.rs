@@ -13,7 +13,7 @@ use crate::common::EnvValue;
 /// ```yaml
 /// on: push
 /// ```
-#[derive(Deserialize, Debug, PartialEq, Eq, Hash)]
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum BareEvent {
     BranchProtectionRule,
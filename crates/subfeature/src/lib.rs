@@ -2,15 +2,19 @@
 
 #![deny(missing_docs)]
 
-use std::sync::LazyLock;
+use std::{ops::Deref, sync::LazyLock};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Represent's a subfeature's fragment.
 ///
 /// This is used to locate a subfeature's exact location within a surrounding
 /// feature.
-#[derive(Serialize, Clone, Debug)]
+///
+/// Fragments round-trip through serde: raw fragments are (de)serialized
+/// as borrowed strings, and regex fragments are (de)serialized as their
+/// pattern, which is recompiled on deserialization.
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum Fragment<'a> {
     /// A raw subfeature fragment.
     ///
@@ -20,25 +24,71 @@ pub enum Fragment<'a> {
     /// It *shouldn't* be used to match things like expressions, since they
     /// might contain whitespace that won't exactly match the surrounding
     /// feature. For that, [`Fragment::Regex`] is appropriate.
-    Raw(&'a str),
+    Raw(#[serde(borrow)] &'a str),
     /// A regular expression for matching a subfeature.
     ///
     /// This is useful primarily for matching any kind of subfeature that
     /// might contain multiple lines, e.g. a multi-line GitHub Actions
     /// expression, since the subfeature's indentation won't necessarily match
     /// the surrounding feature's YAML-level indentation.
-    Regex(#[serde(serialize_with = "Fragment::serialize_regex")] regex::bytes::Regex),
+    Regex(FragmentRegex),
 }
 
-impl<'a> Fragment<'a> {
-    fn serialize_regex<S>(regex: &regex::bytes::Regex, serializer: S) -> Result<S::Ok, S::Error>
+/// A compiled regular expression for a [`Fragment::Regex`].
+///
+/// This dereferences to the underlying [`regex::bytes::Regex`], and also
+/// records the minimum length of any match for it, which is computed once
+/// when the pattern is compiled.
+#[derive(Clone, Debug)]
+pub struct FragmentRegex {
+    regex: regex::bytes::Regex,
+    min_len: usize,
+}
+
+impl FragmentRegex {
+    fn new(pattern: &str) -> Result<Self, regex::Error> {
+        let regex = regex::bytes::Regex::new(pattern)?;
+
+        // The pattern is already known to be valid, but we fall back to
+        // a minimum length of zero (i.e. no bound) if it somehow can't be
+        // analyzed, or can't match anything at all.
+        let min_len = regex_syntax::parse(pattern)
+            .ok()
+            .and_then(|hir| hir.properties().minimum_len())
+            .unwrap_or(0);
+
+        Ok(Self { regex, min_len })
+    }
+}
+
+impl Deref for FragmentRegex {
+    type Target = regex::bytes::Regex;
+
+    fn deref(&self) -> &Self::Target {
+        &self.regex
+    }
+}
+
+impl Serialize for FragmentRegex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let pattern = regex.as_str();
-        serializer.serialize_str(pattern)
+        serializer.serialize_str(self.regex.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for FragmentRegex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(serde::de::Error::custom)
     }
+}
 
+impl<'a> Fragment<'a> {
     /// Create a new [`Fragment`] from the given string.
     ///
     /// The created fragment's behavior depends on whether the input
@@ -76,10 +126,37 @@ impl<'a> Fragment<'a> {
                 LazyLock::new(|| regex::Regex::new(r"\s+").unwrap());
             let regex = WHITESPACE.replace_all(&escaped, "\\s+");
 
-            Fragment::Regex(
-                regex::bytes::Regex::new(&regex)
-                    .expect("internal error: failed to compile fragment regex"),
-            )
+            // NOTE: Pathologically large inputs can produce a pattern that
+            // exceeds the regex engine's size limit. In that case we fall back
+            // to matching the fragment verbatim, which is correct whenever
+            // the feature's whitespace matches the fragment's exactly.
+            match FragmentRegex::new(&regex) {
+                Ok(regex) => Fragment::Regex(regex),
+                Err(_) => Fragment::Raw(fragment),
+            }
+        }
+    }
+}
+
+impl Fragment<'_> {
+    /// Create a new [`Fragment::Regex`] from the given user-supplied pattern.
+    ///
+    /// Unlike [`Fragment::new`], the pattern is used as-is rather than
+    /// being derived from literal text. Returns an error if the pattern
+    /// isn't a valid regular expression.
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        FragmentRegex::new(pattern).map(Fragment::Regex)
+    }
+
+    /// Returns the minimum length, in bytes, of any match for this fragment.
+    ///
+    /// For [`Fragment::Raw`] this is the fragment's exact length. For
+    /// [`Fragment::Regex`] this is derived from the pattern when it's
+    /// compiled, and is zero if the pattern can't be analyzed.
+    pub fn min_len(&self) -> usize {
+        match self {
+            Fragment::Raw(fragment) => fragment.len(),
+            Fragment::Regex(regex) => regex.min_len,
         }
     }
 }
@@ -125,7 +202,7 @@ impl From<std::ops::Range<usize>> for Span {
 
 /// Represents a "subfeature" of a symbolic location, such as a substring
 /// within a YAML string.
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Subfeature<'a> {
     /// A byte index after which the subfeature starts.
     ///
@@ -134,6 +211,7 @@ pub struct Subfeature<'a> {
     /// in the original feature due to parsed whitespace.
     pub after: usize,
     /// The fragment of the subfeature.
+    #[serde(borrow)]
     pub fragment: Fragment<'a>,
 }
 
@@ -165,6 +243,12 @@ impl<'a> Subfeature<'a> {
         let bias = self.after;
         let focus = &feature[bias..];
 
+        // Nothing to scan if the remaining feature is too short to
+        // contain even the shortest possible match.
+        if focus.len() < self.fragment.min_len() {
+            return None;
+        }
+
         match &self.fragment {
             Fragment::Raw(fragment) => {
                 memchr::memmem::find(focus, fragment.as_bytes()).map(|start| {
@@ -181,7 +265,7 @@ impl<'a> Subfeature<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Fragment;
+    use crate::{Fragment, Span, Subfeature};
 
     #[test]
     fn test_fragment_from_context() {
@@ -197,4 +281,118 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_fragment_regex() {
+        let Ok(Fragment::Regex(regex)) = Fragment::regex(r"uses:\s+\S+@v\d+") else {
+            panic!("expected a regex fragment");
+        };
+        assert_eq!(regex.as_str(), r"uses:\s+\S+@v\d+");
+
+        let Ok(fragment) = Fragment::regex(r"@v\d+") else {
+            panic!("expected a valid pattern");
+        };
+        let subfeature = Subfeature { after: 0, fragment };
+        assert_eq!(
+            subfeature.locate_within("uses: actions/checkout@v4"),
+            Some(Span { start: 22, end: 25 })
+        );
+
+        assert!(Fragment::regex(r"foo(bar").is_err());
+        assert!(Fragment::regex(r"[z-a]").is_err());
+    }
+
+    #[test]
+    fn test_fragment_pathological() {
+        // Each run of whitespace becomes a `\s+`, so enough of them
+        // produce a pattern that exceeds the default regex size limit.
+        let fragment = "a ".repeat(500_000);
+        assert!(regex::bytes::Regex::new(&fragment.replace(' ', r"\s+")).is_err());
+
+        match Fragment::new(&fragment) {
+            Fragment::Raw(actual) => assert_eq!(actual, fragment),
+            Fragment::Regex(_) => panic!("expected a raw fragment"),
+        }
+    }
+
+    #[test]
+    fn test_fragment_serde_roundtrip() -> Result<(), serde_json::Error> {
+        for fragment in ["foo.bar", "foo [\n'bar'\n]"] {
+            let original = Subfeature::new(3, fragment);
+            let serialized = serde_json::to_string(&original)?;
+            let deserialized: Subfeature = serde_json::from_str(&serialized)?;
+
+            assert_eq!(deserialized.after, original.after);
+            match (&original.fragment, &deserialized.fragment) {
+                (Fragment::Raw(a), Fragment::Raw(b)) => assert_eq!(a, b),
+                (Fragment::Regex(a), Fragment::Regex(b)) => assert_eq!(a.as_str(), b.as_str()),
+                _ => panic!("fragment kind changed across round-trip: {serialized}"),
+            }
+            assert_eq!(serde_json::to_string(&deserialized)?, serialized);
+        }
+
+        // The recompiled regex still matches.
+        let serialized = r#"{"after":0,"fragment":{"Regex":"@v\\d+"}}"#;
+        let subfeature: Subfeature = serde_json::from_str(serialized)?;
+        assert_eq!(
+            subfeature.locate_within("uses: actions/checkout@v4"),
+            Some(Span { start: 22, end: 25 })
+        );
+
+        // Invalid patterns are rejected.
+        assert!(
+            serde_json::from_str::<Subfeature>(r#"{"after":0,"fragment":{"Regex":"foo(bar"}}"#)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fragment_min_len() {
+        for (fragment, expected) in &[
+            ("", 0),
+            ("foo.bar", 7),
+            ("foo . bar", 9),
+            ("foo [\n'bar'\n]", 13),
+            ("foo     bar", 7),
+            ("ünïcode  ẅhitespace", 22),
+        ] {
+            assert_eq!(Fragment::from(*fragment).min_len(), *expected, "{fragment}");
+        }
+
+        for (pattern, expected) in &[(r"@v\d+", 3), (r"uses:\s+\S+@v\d+", 10), (r"a*", 0)] {
+            let Ok(fragment) = Fragment::regex(pattern) else {
+                panic!("expected a valid pattern");
+            };
+            assert_eq!(fragment.min_len(), *expected, "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_locate_within() {
+        let feature = "echo ${{ foo.bar }}\necho ${{ foo [\n    'bar'\n  ] }}\n";
+
+        for (after, fragment, expected) in &[
+            // Raw fragments.
+            (0, "foo.bar", Some(Span::from(9..16))),
+            (9, "foo.bar", Some(Span::from(9..16))),
+            (10, "foo.bar", None),
+            (0, "nope", None),
+            // Regex fragments.
+            (0, "foo [ 'bar' ]", Some(Span::from(29..48))),
+            (29, "foo [ 'bar' ]", Some(Span::from(29..48))),
+            (30, "foo [ 'bar' ]", None),
+            // The remaining feature is shorter than the fragment.
+            (feature.len() - 3, "foo.bar", None),
+            (feature.len() - 3, "foo [ 'bar' ]", None),
+            (feature.len(), "foo.bar", None),
+        ] {
+            assert_eq!(
+                Subfeature::new(*after, *fragment).locate_within(feature),
+                *expected,
+                "{after} {fragment}"
+            );
+        }
+    }
 }
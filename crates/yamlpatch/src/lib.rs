@@ -102,6 +102,188 @@ pub struct Patch<'doc> {
     pub operation: Op<'doc>,
 }
 
+impl Patch<'_> {
+    /// Returns the byte spans of `document` that this patch would rewrite.
+    ///
+    /// Two patches whose target spans overlap generally can't be safely
+    /// applied together, since the second may corrupt the first's changes;
+    /// see [`Patch::conflicts_with`].
+    ///
+    /// Additive operations (`Add`, `Insert`, `EnsureKey`, `MergeInto`, and
+    /// `Append`) only target existing content when they would overwrite it,
+    /// e.g. a `MergeInto` that updates an already-present key. Patches that
+    /// fail to resolve against `document` have no target spans, since
+    /// they'll fail to apply regardless.
+    pub fn target_spans(&self, document: &yamlpath::Document) -> Vec<core::ops::Range<usize>> {
+        let pretty_span = |route: &yamlpath::Route<'_>| {
+            document
+                .query_pretty(route)
+                .ok()
+                .map(|feature| feature.location.byte_span.0..feature.location.byte_span.1)
+        };
+
+        match &self.operation {
+            Op::RewriteFragment { from, .. } => {
+                let (extracted, start) = if self.route.is_empty() {
+                    (document.source(), 0)
+                } else {
+                    let Ok(Some(feature)) = route_to_feature_exact(&self.route, document) else {
+                        return vec![];
+                    };
+                    (document.extract(&feature), feature.location.byte_span.0)
+                };
+
+                from.locate_within(extracted)
+                    .map(|span| start + span.start..start + span.end)
+                    .into_iter()
+                    .collect()
+            }
+            Op::ReplaceComment { .. } | Op::EmplaceComment { .. } => {
+                let Ok(feature) = route_to_feature_pretty(&self.route, document) else {
+                    return vec![];
+                };
+
+                document
+                    .feature_comments(&feature)
+                    .iter()
+                    .map(|comment| comment.location.byte_span.0..comment.location.byte_span.1)
+                    .collect()
+            }
+            Op::Replace(_) => pretty_span(&self.route).into_iter().collect(),
+            Op::Remove | Op::CommentOut => document.removal_span(&self.route).into_iter().collect(),
+            Op::Add { key, .. } | Op::Insert { key, .. } | Op::EnsureKey { key, .. } => {
+                pretty_span(&self.route.with_key(key.as_str()))
+                    .into_iter()
+                    .collect()
+            }
+            Op::MergeInto { key, updates } => {
+                let route = self.route.with_key(key.as_str());
+                updates
+                    .keys()
+                    .filter_map(|update| pretty_span(&route.with_key(update.as_str())))
+                    .collect()
+            }
+            Op::Append { .. } => vec![],
+        }
+    }
+
+    /// Returns the byte span of the mapping in `document` that this patch
+    /// would create a new key in, along with that key.
+    ///
+    /// Only `Add`, `EnsureKey` and `MergeInto` create keys, and only when
+    /// the key isn't already present.
+    fn new_key(&self, document: &yamlpath::Document) -> Option<(core::ops::Range<usize>, &str)> {
+        let key = match &self.operation {
+            Op::Add { key, .. } | Op::EnsureKey { key, .. } | Op::MergeInto { key, .. } => key,
+            _ => return None,
+        };
+
+        if document.query_exists(&self.route.with_key(key.as_str())) {
+            return None;
+        }
+
+        let mapping = document.query_pretty(&self.route).ok()?;
+        Some((
+            mapping.location.byte_span.0..mapping.location.byte_span.1,
+            key.as_str(),
+        ))
+    }
+
+    /// Returns whether this patch and `other` can't be safely applied
+    /// to `document` together, in either order.
+    ///
+    /// Patches conflict when their [target spans](Patch::target_spans)
+    /// overlap, or when both create the same key in the same mapping.
+    /// There are two exceptions:
+    ///
+    /// - `MergeInto` patches into the same mapping are compatible unless
+    ///   they give the same key different values.
+    /// - `RewriteFragment` patches of the same fragment within the same
+    ///   feature are compatible, since each rewrites the first remaining
+    ///   match rather than a fixed span.
+    pub fn conflicts_with(&self, other: &Patch<'_>, document: &yamlpath::Document) -> bool {
+        let same_feature = || {
+            let span = |route| {
+                document
+                    .query_pretty(route)
+                    .ok()
+                    .map(|feature| feature.location.byte_span)
+            };
+            span(&self.route).is_some_and(|ours| Some(ours) == span(&other.route))
+        };
+
+        match (&self.operation, &other.operation) {
+            (
+                Op::MergeInto { key, updates },
+                Op::MergeInto {
+                    key: other_key,
+                    updates: other_updates,
+                },
+            ) if key == other_key && same_feature() => {
+                return updates.iter().any(|(update, value)| {
+                    other_updates
+                        .get(update)
+                        .is_some_and(|other_value| other_value != value)
+                });
+            }
+            (
+                Op::RewriteFragment { from, .. },
+                Op::RewriteFragment {
+                    from: other_from, ..
+                },
+            ) if from.after == other_from.after
+                && same_fragment(&from.fragment, &other_from.fragment)
+                && same_feature() =>
+            {
+                return false;
+            }
+            _ => {}
+        }
+
+        if let (Some(ours), Some(theirs)) = (self.new_key(document), other.new_key(document))
+            && ours == theirs
+        {
+            return true;
+        }
+
+        let theirs = other.target_spans(document);
+        self.target_spans(document).iter().any(|span| {
+            theirs
+                .iter()
+                .any(|other| span.start < other.end && other.start < span.end)
+        })
+    }
+}
+
+/// Returns whether two fragments are the same, i.e. match the same text.
+fn same_fragment(a: &subfeature::Fragment<'_>, b: &subfeature::Fragment<'_>) -> bool {
+    match (a, b) {
+        (subfeature::Fragment::Raw(a), subfeature::Fragment::Raw(b)) => a == b,
+        (subfeature::Fragment::Regex(a), subfeature::Fragment::Regex(b)) => {
+            a.as_str() == b.as_str()
+        }
+        _ => false,
+    }
+}
+
+/// Where to insert a new key-value pair, relative to an existing key
+/// in the same mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AddPosition {
+    /// Insert immediately before the given key.
+    Before(String),
+    /// Insert immediately after the given key (and its value).
+    After(String),
+}
+
+impl AddPosition {
+    fn key(&self) -> &str {
+        match self {
+            Self::Before(key) | Self::After(key) => key,
+        }
+    }
+}
+
 /// Represents a YAML patch operation.
 #[derive(Debug, Clone)]
 pub enum Op<'doc> {
@@ -171,6 +353,28 @@ pub enum Op<'doc> {
         key: String,
         value: yaml_serde::Value,
     },
+    /// Insert a new key-value pair at the given path, immediately
+    /// before or after an existing key.
+    ///
+    /// This is like `Add`, with the same limitations, except that the
+    /// `position`'s key must also already exist in the targeted mapping.
+    Insert {
+        key: String,
+        value: yaml_serde::Value,
+        position: AddPosition,
+    },
+    /// Ensure that a key has the given value in the mapping at the given path.
+    ///
+    /// The route should point to a mapping. If `key` is already present
+    /// in the mapping, its value is replaced (like `Replace`); otherwise,
+    /// the pair is appended to the mapping (like `Add`).
+    ///
+    /// This is idempotent: applying it to a document that already
+    /// has `key: value` produces the same document.
+    EnsureKey {
+        key: String,
+        value: yaml_serde::Value,
+    },
     /// Update a mapping at the given path.
     ///
     /// If the mapping does not already exist, it will be created.
@@ -185,6 +389,16 @@ pub enum Op<'doc> {
     ///
     /// The sequence must be a block sequence; flow sequences are not supported.
     Append { value: yaml_serde::Value },
+    /// Comment out the sequence item or mapping pair at the given path.
+    ///
+    /// Each line of the element is prefixed with `# ` at the element's
+    /// own indentation, leaving its siblings untouched. Like `Remove`,
+    /// commenting out the sole member of a block container comments out
+    /// the enclosing element instead.
+    ///
+    /// The element must be in a block container; flow elements can't
+    /// be commented out without disturbing their neighbors.
+    CommentOut,
 }
 
 /// Apply a sequence of YAML patch operations to a YAML document.
@@ -218,10 +432,44 @@ pub fn apply_yaml_patches(
     Ok(next_document)
 }
 
+/// The result of applying a single [`Patch`] with [`apply_yaml_patch`].
+pub struct Applied {
+    /// The patched document.
+    pub document: yamlpath::Document,
+    /// The span of the replacement text within [`Applied::document`].
+    ///
+    /// This is only present for operations that write a single contiguous
+    /// replacement, i.e. [`Op::RewriteFragment`]. Callers can use it to
+    /// target the just-written content with a subsequent patch.
+    pub replacement: Option<subfeature::Span>,
+}
+
+/// Apply a single YAML patch operation to a YAML document.
+///
+/// Unlike [`apply_yaml_patches`], this also returns the span of the
+/// replacement text in the new document, when the operation has one.
+pub fn apply_yaml_patch(document: &yamlpath::Document, patch: &Patch) -> Result<Applied, Error> {
+    let mut replacement = None;
+    let document = apply_patch_inner(document, patch, &mut replacement)?;
+
+    Ok(Applied {
+        document,
+        replacement,
+    })
+}
+
 /// Apply a single YAML patch operation
 fn apply_single_patch(
     document: &yamlpath::Document,
     patch: &Patch,
+) -> Result<yamlpath::Document, Error> {
+    apply_patch_inner(document, patch, &mut None)
+}
+
+fn apply_patch_inner(
+    document: &yamlpath::Document,
+    patch: &Patch,
+    replacement: &mut Option<subfeature::Span>,
 ) -> Result<yamlpath::Document, Error> {
     let content = document.source();
     let mut patched_content = match &patch.operation {
@@ -265,6 +513,9 @@ fn apply_single_patch(
             let mut patched_feature = extracted_feature.to_string();
             patched_feature.replace_range(span.as_range(), to);
 
+            let start = range.start + span.start;
+            *replacement = Some(subfeature::Span::from(start..start + to.len()));
+
             // Finally, put our patch back into the overall content.
             let mut patched_content = content.to_string();
             patched_content.replace_range(range, &patched_feature);
@@ -385,55 +636,31 @@ fn apply_single_patch(
 
             result
         }
-        Op::Add { key, value } => {
-            // Check to see whether `key` is already present within the route.
-            // NOTE: Safe unwrap, since `with_keys` ensures we always have at
-            // least one component.
-            let key_query = patch.route.with_key(key.as_str());
-
-            if document.query_exists(&key_query) {
-                return Err(Error::InvalidOperation(format!(
-                    "key '{key}' already exists at {route:?}",
-                    key = key,
-                    route = patch.route
-                )));
-            }
-
-            let feature = if patch.route.is_empty() {
-                document.top_feature()?
+        Op::Add { key, value } => add_pair(document, patch, key, value, None)?,
+        Op::Insert {
+            key,
+            value,
+            position,
+        } => add_pair(document, patch, key, value, Some(position))?,
+        Op::EnsureKey { key, value } => {
+            let key_route = patch.route.with_key(key.as_str());
+
+            let patch = if document.query_exists(&key_route) {
+                Patch {
+                    route: key_route,
+                    operation: Op::Replace(value.clone()),
+                }
             } else {
-                route_to_feature_exact(&patch.route, document)?.ok_or_else(|| {
-                    Error::InvalidOperation(format!(
-                        "no existing mapping at {route:?}",
-                        route = patch.route
-                    ))
-                })?
-            };
-
-            let style = Style::from_feature(&feature, document);
-            let feature_content = document.extract(&feature);
-
-            let updated_feature = match style {
-                Style::BlockMapping => {
-                    handle_block_mapping_addition(feature_content, document, &feature, key, value)
+                Patch {
+                    route: patch.route.clone(),
+                    operation: Op::Add {
+                        key: key.clone(),
+                        value: value.clone(),
+                    },
                 }
-                Style::FlowMapping => handle_flow_mapping_addition(feature_content, key, value),
-                // TODO: Remove this limitation.
-                Style::MultilineFlowMapping => Err(Error::InvalidOperation(format!(
-                    "add operation is not permitted against multiline flow mapping route: {:?}",
-                    patch.route
-                ))),
-                _ => Err(Error::InvalidOperation(format!(
-                    "add operation is not permitted against non-mapping route: {:?}",
-                    patch.route
-                ))),
-            }?;
-
-            // Replace the content in the document
-            let mut result = content.to_string();
-            result.replace_range(&feature, &updated_feature);
+            };
 
-            result
+            return apply_single_patch(document, &patch);
         }
         Op::MergeInto { key, updates } => {
             let existing_key_route = patch.route.with_key(key.as_str());
@@ -541,6 +768,50 @@ fn apply_single_patch(
 
             result
         }
+        Op::CommentOut => {
+            if patch.route.is_empty() {
+                return Err(Error::InvalidOperation(
+                    "Cannot comment out root document".to_string(),
+                ));
+            }
+
+            // The removal span for a block element covers exactly the
+            // element's lines, so it's also the span we need to comment out.
+            let span = document.removal_span(&patch.route)?;
+
+            let is_whole_lines = line_span(document, span.start).start == span.start
+                && (span.end == content.len() || content[..span.end].ends_with('\n'));
+            if span.is_empty() || !is_whole_lines {
+                return Err(Error::InvalidOperation(format!(
+                    "can't comment out non-block element at {route:?}",
+                    route = patch.route
+                )));
+            }
+
+            let lines = &content[span.clone()];
+            let indent = lines
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.len() - line.trim_start_matches(' ').len())
+                .min()
+                .unwrap_or(0);
+
+            let mut commented = String::with_capacity(lines.len());
+            for line in lines.split_inclusive('\n') {
+                if line.trim().is_empty() {
+                    commented.push_str(line);
+                } else {
+                    commented.push_str(&line[..indent]);
+                    commented.push_str("# ");
+                    commented.push_str(&line[indent..]);
+                }
+            }
+
+            let mut result = content.to_string();
+            result.replace_range(span, &commented);
+
+            result
+        }
         Op::Append { value } => {
             let feature = route_to_feature_exact(&patch.route, document)?.ok_or_else(|| {
                 Error::InvalidOperation(format!(
@@ -581,7 +852,7 @@ fn apply_single_patch(
         patched_content.push('\n');
     }
 
-    yamlpath::Document::new(patched_content).map_err(Error::from)
+    document.with_source(patched_content).map_err(Error::from)
 }
 
 pub fn route_to_feature_pretty<'a>(
@@ -809,8 +1080,10 @@ fn indent_multiline_yaml(content: &str, base_indent: &str) -> String {
     result
 }
 
-fn handle_block_mapping_addition(
-    feature_content: &str,
+/// Formats a new `key: value` entry for insertion into the given block mapping.
+///
+/// The returned entry begins with a newline and the mapping's indentation.
+fn format_block_mapping_entry(
     doc: &yamlpath::Document,
     feature: &yamlpath::Feature,
     key: &str,
@@ -829,7 +1102,7 @@ fn handle_block_mapping_addition(
     let indent = " ".repeat(extract_leading_indentation_for_block_item(doc, feature));
 
     // Format the new entry
-    let mut final_entry = if let yaml_serde::Value::Mapping(mapping) = &value {
+    let final_entry = if let yaml_serde::Value::Mapping(mapping) = &value {
         if mapping.is_empty() {
             // For empty mappings, format inline
             format!("\n{indent}{key}: {new_value_str}")
@@ -855,6 +1128,156 @@ fn handle_block_mapping_addition(
         format!("\n{indent}{key}: {new_value_str}")
     };
 
+    Ok(final_entry)
+}
+
+/// Adds a new `key: value` pair to the mapping at the patch's route,
+/// optionally relative to an existing key, returning the patched content.
+fn add_pair(
+    document: &yamlpath::Document,
+    patch: &Patch,
+    key: &str,
+    value: &yaml_serde::Value,
+    position: Option<&AddPosition>,
+) -> Result<String, Error> {
+    let content = document.source();
+
+    // Check to see whether `key` is already present within the route.
+    // NOTE: Safe unwrap, since `with_keys` ensures we always have at
+    // least one component.
+    let key_query = patch.route.with_key(key);
+
+    if document.query_exists(&key_query) {
+        return Err(Error::InvalidOperation(format!(
+            "key '{key}' already exists at {route:?}",
+            key = key,
+            route = patch.route
+        )));
+    }
+
+    let feature = if patch.route.is_empty() {
+        document.top_feature()?
+    } else {
+        route_to_feature_exact(&patch.route, document)?.ok_or_else(|| {
+            Error::InvalidOperation(format!(
+                "no existing mapping at {route:?}",
+                route = patch.route
+            ))
+        })?
+    };
+
+    let style = Style::from_feature(&feature, document);
+    let feature_content = document.extract(&feature);
+
+    if let Some(position) = position {
+        let position_query = patch.route.with_key(position.key());
+        if !document.query_exists(&position_query) {
+            return Err(Error::InvalidOperation(format!(
+                "key '{key}' does not exist at {route:?}",
+                key = position.key(),
+                route = patch.route
+            )));
+        }
+    }
+
+    let patched = match (style, position) {
+        // Positioned insertions into block mappings can land outside
+        // of the mapping's own span (e.g. at the start of the line
+        // following its last pair), so we insert into the document
+        // directly rather than rewriting the mapping's feature.
+        (Style::BlockMapping, Some(position)) => {
+            let pair = route_to_feature_pretty(&patch.route.with_key(position.key()), document)?;
+            let (insertion_point, entry) =
+                handle_block_mapping_insertion(document, &feature, key, value, position, &pair)?;
+
+            let mut result = content.to_string();
+            result.insert_str(insertion_point, &entry);
+
+            result
+        }
+        _ => {
+            let updated_feature = match style {
+                Style::BlockMapping => {
+                    handle_block_mapping_addition(feature_content, document, &feature, key, value)
+                }
+                Style::FlowMapping => {
+                    handle_flow_mapping_addition(feature_content, key, value, position)
+                }
+                // TODO: Remove this limitation.
+                Style::MultilineFlowMapping => Err(Error::InvalidOperation(format!(
+                    "add operation is not permitted against multiline flow mapping route: {:?}",
+                    patch.route
+                ))),
+                _ => Err(Error::InvalidOperation(format!(
+                    "add operation is not permitted against non-mapping route: {:?}",
+                    patch.route
+                ))),
+            }?;
+
+            // Replace the content in the document
+            let mut result = content.to_string();
+            result.replace_range(&feature, &updated_feature);
+
+            result
+        }
+    };
+
+    Ok(patched)
+}
+
+/// Like [`handle_block_mapping_addition`], but inserts the new entry immediately
+/// before or after the existing `pair` within the mapping.
+///
+/// Returns the absolute insertion point within the document, and the text to
+/// insert there.
+fn handle_block_mapping_insertion(
+    doc: &yamlpath::Document,
+    feature: &yamlpath::Feature,
+    key: &str,
+    value: &yaml_serde::Value,
+    position: &AddPosition,
+    pair: &yamlpath::Feature,
+) -> Result<(usize, String), Error> {
+    let final_entry = format_block_mapping_entry(doc, feature, key, value)?;
+
+    match position {
+        AddPosition::Before(_) => {
+            // The existing pair's line is already indented, so we insert
+            // the new entry at the pair's start, followed by a newline
+            // and indentation for the existing pair.
+            let indent = " ".repeat(extract_leading_indentation_for_block_item(doc, feature));
+            let entry = final_entry
+                .strip_prefix('\n')
+                .and_then(|entry| entry.strip_prefix(indent.as_str()))
+                .unwrap_or(&final_entry);
+
+            Ok((pair.location.byte_span.0, format!("{entry}\n{indent}")))
+        }
+        AddPosition::After(_) => {
+            // We insert after the entire line that the pair's content ends on,
+            // so that any trailing comment on that line stays with the pair.
+            let content_end = find_content_end(pair, doc);
+            let line_end = line_span(doc, content_end.saturating_sub(1)).end;
+
+            if doc.source()[..line_end].ends_with('\n') {
+                let entry = final_entry.strip_prefix('\n').unwrap_or(&final_entry);
+                Ok((line_end, format!("{entry}\n")))
+            } else {
+                Ok((line_end, final_entry))
+            }
+        }
+    }
+}
+
+fn handle_block_mapping_addition(
+    feature_content: &str,
+    doc: &yamlpath::Document,
+    feature: &yamlpath::Feature,
+    key: &str,
+    value: &yaml_serde::Value,
+) -> Result<String, Error> {
+    let mut final_entry = format_block_mapping_entry(doc, feature, key, value)?;
+
     // Figure out the insertion point.
     // To do this, we find the end of the feature's content, i.e.
     // the last non-empty, non-comment line in the feature.
@@ -955,6 +1378,7 @@ fn handle_flow_mapping_addition(
     feature_content: &str,
     key: &str,
     value: &yaml_serde::Value,
+    position: Option<&AddPosition>,
 ) -> Result<String, Error> {
     // Our strategy for flow mappings is to deserialize the existing feature,
     // add the new key-value pair, and then serialize it back.
@@ -969,9 +1393,29 @@ fn handle_flow_mapping_addition(
     let mut existing_mapping = yaml_serde::from_str::<yaml_serde::Mapping>(feature_content)
         .map_err(Error::Serialization)?;
 
-    existing_mapping.insert(key.into(), value.clone());
+    let updated_mapping = match position {
+        None => {
+            existing_mapping.insert(key.into(), value.clone());
+            existing_mapping
+        }
+        Some(position) => {
+            let mut updated_mapping = yaml_serde::Mapping::new();
+            for (existing_key, existing_value) in existing_mapping {
+                let is_relative = existing_key.as_str() == Some(position.key());
+
+                if is_relative && matches!(position, AddPosition::Before(_)) {
+                    updated_mapping.insert(key.into(), value.clone());
+                }
+                updated_mapping.insert(existing_key, existing_value);
+                if is_relative && matches!(position, AddPosition::After(_)) {
+                    updated_mapping.insert(key.into(), value.clone());
+                }
+            }
+            updated_mapping
+        }
+    };
 
-    let updated_content = serialize_flow(&yaml_serde::Value::Mapping(existing_mapping))?;
+    let updated_content = serialize_flow(&yaml_serde::Value::Mapping(updated_mapping))?;
 
     Ok(updated_content)
 }
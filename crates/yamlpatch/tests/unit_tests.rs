@@ -350,6 +350,75 @@ foo:
     "#);
 }
 
+/// `apply_yaml_patch` reports where a `RewriteFragment`'s replacement
+/// landed, so that a subsequent patch can target the written content.
+#[test]
+fn test_apply_yaml_patch_replacement_span() {
+    let original = r#"
+foo:
+  bar: |
+    echo "foo: ${{ foo }}"
+    echo "bar: ${{ bar }}"
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+
+    let applied = apply_yaml_patch(
+        &document,
+        &Patch {
+            route: route!("foo", "bar"),
+            operation: Op::RewriteFragment {
+                from: subfeature::Subfeature::new(0, "${{ bar }}"),
+                to: "${BAR}".into(),
+            },
+        },
+    )
+    .unwrap();
+
+    let span = applied.replacement.unwrap();
+    assert_eq!(&applied.document.source()[span.as_range()], "${BAR}");
+
+    // Chain a second rewrite that targets the just-written content.
+    // The span is relative to the whole document, so we rewrite against
+    // the empty route.
+    let applied = apply_yaml_patch(
+        &applied.document,
+        &Patch {
+            route: route!(),
+            operation: Op::RewriteFragment {
+                from: subfeature::Subfeature::new(span.start, "BAR"),
+                to: "BAR_VALUE".into(),
+            },
+        },
+    )
+    .unwrap();
+
+    let span = applied.replacement.unwrap();
+    assert_eq!(&applied.document.source()[span.as_range()], "BAR_VALUE");
+
+    insta::assert_snapshot!(format_patch(applied.document.source()), @r#"
+    --- PATCH ---
+
+    foo:
+      bar: |
+        echo "foo: ${{ foo }}"
+        echo "bar: ${BAR_VALUE}"
+
+    --- END PATCH ---
+    "#);
+
+    // Operations without a single contiguous replacement don't report one.
+    let applied = apply_yaml_patch(
+        &document,
+        &Patch {
+            route: route!("foo", "bar"),
+            operation: Op::Replace("baz".into()),
+        },
+    )
+    .unwrap();
+    assert!(applied.replacement.is_none());
+}
+
 #[test]
 fn test_rewrite_fragment_multi_line_in_list() {
     let original = r#"
@@ -821,6 +890,34 @@ foo:
     ");
 }
 
+#[test]
+fn test_patch_preserves_bom() {
+    let document = yamlpath::Document::new("\u{feff}foo:\n  bar: abc\n").unwrap();
+
+    let operations = vec![
+        Patch {
+            route: route!("foo", "bar"),
+            operation: Op::Replace("def".into()),
+        },
+        Patch {
+            route: route!("foo"),
+            operation: Op::Add {
+                key: "baz".into(),
+                value: false.into(),
+            },
+        },
+    ];
+
+    let result = apply_yaml_patches(&document, &operations).unwrap();
+
+    assert!(result.has_bom());
+    assert_eq!(result.source(), "foo:\n  bar: def\n  baz: false\n");
+    assert_eq!(
+        result.source_with_bom(),
+        "\u{feff}foo:\n  bar: def\n  baz: false\n"
+    );
+}
+
 #[test]
 fn test_replace_empty_block_value() {
     let original = r#"
@@ -1064,6 +1161,207 @@ foo: { bar: abc }
     ");
 }
 
+#[test]
+fn test_add_with_position() {
+    let original = r#"
+on: push  # trigger
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    env:
+      FOO: foo
+      BAR: bar
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+
+    let add = |route, key: &str, position| Patch {
+        route,
+        operation: Op::Insert {
+            key: key.to_string(),
+            value: yaml_serde::Value::String("new".to_string()),
+            position,
+        },
+    };
+
+    // Before the first key.
+    let result = apply_yaml_patches(
+        &document,
+        &[add(route!(), "name", AddPosition::Before("on".into()))],
+    )
+    .unwrap();
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    name: new
+    on: push  # trigger
+
+    jobs:
+      test:
+        runs-on: ubuntu-latest
+        env:
+          FOO: foo
+          BAR: bar
+
+    --- END PATCH ---
+    ");
+
+    // Between two keys, both at the top level and nested.
+    let result = apply_yaml_patches(
+        &document,
+        &[
+            add(route!(), "permissions", AddPosition::After("on".into())),
+            add(
+                route!("jobs", "test", "env"),
+                "BAZ",
+                AddPosition::Before("BAR".into()),
+            ),
+        ],
+    )
+    .unwrap();
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    on: push  # trigger
+    permissions: new
+
+    jobs:
+      test:
+        runs-on: ubuntu-latest
+        env:
+          FOO: foo
+          BAZ: new
+          BAR: bar
+
+    --- END PATCH ---
+    ");
+
+    // After the last key.
+    let result = apply_yaml_patches(
+        &document,
+        &[add(
+            route!("jobs", "test", "env"),
+            "BAZ",
+            AddPosition::After("BAR".into()),
+        )],
+    )
+    .unwrap();
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    on: push  # trigger
+
+    jobs:
+      test:
+        runs-on: ubuntu-latest
+        env:
+          FOO: foo
+          BAR: bar
+          BAZ: new
+
+    --- END PATCH ---
+    ");
+}
+
+#[test]
+fn test_add_with_position_mapping_value() {
+    let original = r#"
+on: push
+jobs: {}
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+
+    let operations = vec![Patch {
+        route: route!(),
+        operation: Op::Insert {
+            key: "permissions".to_string(),
+            value: yaml_serde::from_str("contents: read\nissues: write").unwrap(),
+            position: AddPosition::After("on".into()),
+        },
+    }];
+
+    let result = apply_yaml_patches(&document, &operations).unwrap();
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    on: push
+    permissions:
+      contents: read
+      issues: write
+    jobs: {}
+
+    --- END PATCH ---
+    ");
+}
+
+#[test]
+fn test_add_with_position_flow_mapping() {
+    let original = r#"
+foo: { bar: abc, baz: def }
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+
+    let results = [
+        AddPosition::Before("bar".into()),
+        AddPosition::After("bar".into()),
+        AddPosition::After("baz".into()),
+    ]
+    .into_iter()
+    .map(|position| {
+        let operations = vec![Patch {
+            route: route!("foo"),
+            operation: Op::Insert {
+                key: "new".to_string(),
+                value: yaml_serde::Value::String("xyz".to_string()),
+                position,
+            },
+        }];
+
+        apply_yaml_patches(&document, &operations)
+            .unwrap()
+            .source()
+            .trim()
+            .to_string()
+    })
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+        results,
+        [
+            "foo: { new: xyz, bar: abc, baz: def }",
+            "foo: { bar: abc, new: xyz, baz: def }",
+            "foo: { bar: abc, baz: def, new: xyz }",
+        ]
+    );
+}
+
+#[test]
+fn test_add_with_position_rejects_missing_key() {
+    let original = r#"
+foo:
+  bar: abc
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+
+    let operations = vec![Patch {
+        route: route!("foo"),
+        operation: Op::Insert {
+            key: "baz".to_string(),
+            value: yaml_serde::Value::String("def".to_string()),
+            position: AddPosition::After("missing".into()),
+        },
+    }];
+
+    let Err(err) = apply_yaml_patches(&document, &operations) else {
+        panic!("expected an error");
+    };
+    assert!(err.to_string().contains("key 'missing' does not exist at"));
+}
+
 #[test]
 fn test_remove_preserves_structure() {
     let original = r#"
@@ -2102,6 +2400,61 @@ fn test_step_content_end_detection() {
     "#);
 }
 
+#[test]
+fn test_ensure_key() {
+    let absent = r#"jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+        with:
+          fetch-depth: 0"#;
+
+    let present = r#"jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+        with:
+          fetch-depth: 0
+          persist-credentials: true"#;
+
+    let operations = vec![Patch {
+        route: route!("jobs", "test", "steps", 0, "with"),
+        operation: Op::EnsureKey {
+            key: "persist-credentials".to_string(),
+            value: yaml_serde::Value::Bool(false),
+        },
+    }];
+
+    // The add path: the key is missing, so it's appended.
+    let added = apply_yaml_patches(&yamlpath::Document::new(absent).unwrap(), &operations).unwrap();
+
+    // The replace path: the key is present, so its value is replaced.
+    let replaced =
+        apply_yaml_patches(&yamlpath::Document::new(present).unwrap(), &operations).unwrap();
+
+    assert_eq!(added.source(), replaced.source());
+
+    insta::assert_snapshot!(format_patch(added.source()), @"
+    --- PATCH ---
+    jobs:
+      test:
+        runs-on: ubuntu-latest
+        steps:
+          - uses: actions/checkout@v4
+            with:
+              fetch-depth: 0
+              persist-credentials: false
+
+    --- END PATCH ---
+    ");
+
+    // Applying the operation again is a no-op.
+    let reapplied = apply_yaml_patches(&added, &operations).unwrap();
+    assert_eq!(reapplied.source(), added.source());
+}
+
 #[test]
 fn test_merge_into_new_key() {
     // Test MergeInto when the key doesn't exist yet
@@ -3975,3 +4328,311 @@ foo:
       - [def, ghi]
     ");
 }
+
+/// Apply a single `Op::CommentOut` at `route` to `yaml`, returning the
+/// resulting document.
+fn comment_out(yaml: &str, route: yamlpath::Route) -> yamlpath::Document {
+    let document = yamlpath::Document::new(yaml).unwrap();
+    apply_yaml_patches(
+        &document,
+        &[Patch {
+            route,
+            operation: Op::CommentOut,
+        }],
+    )
+    .unwrap()
+}
+
+/// Parse `yaml` and return the steps of its `test` job.
+fn steps(yaml: &str) -> Vec<yaml_serde::Value> {
+    let value: yaml_serde::Value = yaml_serde::from_str(yaml).unwrap();
+    value["jobs"]["test"]["steps"]
+        .as_sequence()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn test_comment_out_single_line_step() {
+    let original = r#"
+jobs:
+  test:
+    steps:
+      - run: echo before
+      - run: true
+      - run: echo after
+"#;
+
+    let result = comment_out(original, route!("jobs", "test", "steps", 1));
+
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    jobs:
+      test:
+        steps:
+          - run: echo before
+          # - run: true
+          - run: echo after
+
+    --- END PATCH ---
+    ");
+
+    let steps = steps(result.source());
+    assert_eq!(steps.len(), 2);
+    assert!(steps.iter().all(|step| step["run"] != "true"));
+}
+
+#[test]
+fn test_comment_out_multi_line_step() {
+    let original = r#"
+jobs:
+  test:
+    steps:
+      - name: checkout
+        uses: actions/checkout@v4
+        with:
+          persist-credentials: false # keep this
+
+      - run: echo after
+"#;
+
+    let result = comment_out(original, route!("jobs", "test", "steps", 0));
+
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    jobs:
+      test:
+        steps:
+          # - name: checkout
+          #   uses: actions/checkout@v4
+          #   with:
+          #     persist-credentials: false # keep this
+
+          - run: echo after
+
+    --- END PATCH ---
+    ");
+
+    let steps = steps(result.source());
+    assert_eq!(steps.len(), 1);
+    assert_eq!(steps[0]["run"], "echo after");
+}
+
+#[test]
+fn test_comment_out_mapping_pair() {
+    let original = r#"
+permissions:
+  contents: read
+  actions: write
+"#;
+
+    let result = comment_out(original, route!("permissions", "actions"));
+
+    insta::assert_snapshot!(format_patch(result.source()), @"
+    --- PATCH ---
+
+    permissions:
+      contents: read
+      # actions: write
+
+    --- END PATCH ---
+    ");
+}
+
+#[test]
+fn test_comment_out_flow_member_rejected() {
+    let document = yamlpath::Document::new("s: [a, b, c]\n").unwrap();
+    let result = apply_yaml_patches(
+        &document,
+        &[Patch {
+            route: route!("s", 1),
+            operation: Op::CommentOut,
+        }],
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_target_spans() {
+    let original = r#"
+steps:
+  - uses: actions/checkout@v4 # v4
+    with:
+      persist-credentials: true
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+    let spans = |route, operation| {
+        Patch { route, operation }
+            .target_spans(&document)
+            .into_iter()
+            .map(|span| &original[span])
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        spans(
+            route!("steps", 0, "uses"),
+            Op::Replace("actions/checkout@v5".into())
+        ),
+        ["uses: actions/checkout@v4"]
+    );
+    assert_eq!(
+        spans(
+            route!("steps", 0, "uses"),
+            Op::RewriteFragment {
+                from: subfeature::Subfeature::new(0, "@v4"),
+                to: "@v5".into(),
+            }
+        ),
+        ["@v4"]
+    );
+    assert_eq!(
+        spans(
+            route!("steps", 0, "uses"),
+            Op::ReplaceComment { new: "# v5".into() }
+        ),
+        ["# v4"]
+    );
+
+    // Merging only targets the keys it would overwrite.
+    assert_eq!(
+        spans(
+            route!("steps", 0),
+            Op::MergeInto {
+                key: "with".into(),
+                updates: indexmap::IndexMap::from_iter([
+                    ("persist-credentials".into(), false.into()),
+                    ("fetch-depth".into(), 0.into()),
+                ]),
+            }
+        ),
+        ["persist-credentials: true"]
+    );
+    assert!(
+        spans(
+            route!("steps", 0),
+            Op::Add {
+                key: "name".into(),
+                value: "checkout".into(),
+            }
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn test_conflicts_with() {
+    let original = r#"
+steps:
+  - uses: actions/checkout@v4
+    with:
+      persist-credentials: true
+  - run: |
+      echo "${{ foo }}"
+      echo "${{ foo }}"
+"#;
+
+    let document = yamlpath::Document::new(original).unwrap();
+    let conflicts = |a: Patch, b: Patch| {
+        let conflicts = a.conflicts_with(&b, &document);
+        assert_eq!(conflicts, b.conflicts_with(&a, &document));
+        conflicts
+    };
+    let merge = |route, key: &str, updates: &[(&str, yaml_serde::Value)]| Patch {
+        route,
+        operation: Op::MergeInto {
+            key: key.into(),
+            updates: updates
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+        },
+    };
+    let add = |route, key: &str| Patch {
+        route,
+        operation: Op::Add {
+            key: key.into(),
+            value: "bar".into(),
+        },
+    };
+    let rewrite = |from| Patch {
+        route: route!("steps", 1, "run"),
+        operation: Op::RewriteFragment {
+            from: subfeature::Subfeature::new(0, from),
+            to: "${FOO}".into(),
+        },
+    };
+
+    // Overlapping target spans conflict.
+    assert!(conflicts(
+        Patch {
+            route: route!("steps", 0, "uses"),
+            operation: Op::Replace("actions/checkout@v5".into()),
+        },
+        Patch {
+            route: route!("steps", 0, "uses"),
+            operation: Op::RewriteFragment {
+                from: subfeature::Subfeature::new(0, "@v4"),
+                to: "@v5".into(),
+            },
+        },
+    ));
+
+    // Merging the same key into the same mapping is only a conflict
+    // if the values differ.
+    assert!(!conflicts(
+        merge(
+            route!("steps", 0),
+            "with",
+            &[("persist-credentials", false.into())]
+        ),
+        merge(
+            route!("steps", 0),
+            "with",
+            &[("persist-credentials", false.into())]
+        ),
+    ));
+    assert!(conflicts(
+        merge(
+            route!("steps", 0),
+            "with",
+            &[("persist-credentials", false.into())]
+        ),
+        merge(
+            route!("steps", 0),
+            "with",
+            &[("persist-credentials", true.into())]
+        ),
+    ));
+    assert!(!conflicts(
+        merge(route!("steps", 1), "env", &[("FOO", "foo".into())]),
+        merge(route!("steps", 1), "env", &[("BAR", "bar".into())]),
+    ));
+
+    // Creating the same key in the same mapping conflicts, while creating
+    // different keys doesn't.
+    assert!(conflicts(
+        add(route!("steps", 1), "with"),
+        merge(route!("steps", 1), "with", &[("foo", "bar".into())]),
+    ));
+    assert!(conflicts(
+        add(route!("steps", 1), "name"),
+        add(route!("steps", 1), "name")
+    ));
+    assert!(!conflicts(
+        add(route!("steps", 1), "name"),
+        add(route!("steps", 1), "id")
+    ));
+    assert!(!conflicts(
+        add(route!("steps", 0), "name"),
+        add(route!("steps", 1), "name")
+    ));
+
+    // Rewrites of the same fragment each apply to the next remaining match.
+    assert!(!conflicts(rewrite("${{ foo }}"), rewrite("${{ foo }}")));
+    assert!(conflicts(rewrite("${{ foo }}"), rewrite("{{ foo")));
+}
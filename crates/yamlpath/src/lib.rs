@@ -15,7 +15,7 @@
 use std::{
     borrow::Cow,
     collections::{BTreeMap, HashMap},
-    ops::{Deref, RangeBounds},
+    ops::{Deref, Range, RangeBounds},
 };
 
 use line_index::{LineIndex, TextSize};
@@ -213,6 +213,10 @@ pub enum QueryError {
     /// The route expects the given list index, but the list isn't the right size.
     #[error("index `[{0}]` exceeds list size ({1})")]
     ExhaustedList(usize, usize),
+    /// The route expects the given list index, counting from the end of the
+    /// list, but the list isn't the right size.
+    #[error("index `[-{index}]` exceeds list size ({1})", index = .0 + 1)]
+    ExhaustedListFromEnd(usize, usize),
     /// The YAML syntax tree wasn't structured the way we expect.
     #[error("unexpected node: `{0}`")]
     UnexpectedNode(String),
@@ -223,11 +227,36 @@ pub enum QueryError {
     /// the given field name.
     #[error("syntax node `{0}` is missing child field `{1}`")]
     MissingChildField(String, &'static str),
+    /// A feature's span doesn't lie within the document, e.g. because
+    /// the feature was extracted before the document was edited.
+    #[error("span `{0}..{1}` is not valid within the document")]
+    InvalidSpan(usize, usize),
     /// Any other route error that doesn't fit cleanly above.
     #[error("route error: {0}")]
     Other(String),
 }
 
+/// Possible errors when parsing a [`Route`] from a string.
+#[derive(Error, Debug, PartialEq)]
+pub enum RouteParseError {
+    /// The route doesn't start with `.` or `[`, or has trailing garbage
+    /// after a component.
+    #[error("expected `.` or `[` at offset {0}")]
+    ExpectedComponent(usize),
+    /// A `.` isn't followed by a key.
+    #[error("expected key at offset {0}")]
+    EmptyKey(usize),
+    /// A quoted key is missing its closing quote.
+    #[error("unterminated quoted key starting at offset {0}")]
+    UnterminatedKey(usize),
+    /// A bracketed component isn't a quoted key or a valid index.
+    #[error("invalid index `{1}` at offset {0}")]
+    InvalidIndex(usize, String),
+    /// A bracketed component is missing its closing `]`.
+    #[error("expected `]` at offset {0}")]
+    ExpectedBracket(usize),
+}
+
 /// A route into some YAML document.
 ///
 /// Internally, a route is zero or more "component" selectors, each of which
@@ -287,6 +316,150 @@ impl<'a> Route<'a> {
             Some(Self::from(route))
         }
     }
+
+    /// Parses a route from a jq-ish string, e.g. `.jobs.build.steps[0]`.
+    ///
+    /// Each component is either `.key`, `[index]`, `[-index]` (counting
+    /// from the end of the list, so `[-1]` is the last element), or a quoted
+    /// key in brackets (`["key"]` or `['key']`, optionally preceded by `.`).
+    /// Quoted keys can contain dots and other special characters,
+    /// e.g. `.["on.push"]` is the single key `on.push`. Within a quoted
+    /// key, `\` escapes the quote character and itself.
+    ///
+    /// An empty string or a lone `.` parses as the empty route.
+    ///
+    /// This is the inverse of [`Route`]'s [`Display`](std::fmt::Display)
+    /// implementation.
+    pub fn parse(route: &'a str) -> Result<Self, RouteParseError> {
+        let mut components = vec![];
+
+        if route == "." {
+            return Ok(Self::default());
+        }
+
+        let bytes = route.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'.' if bytes.get(pos + 1) == Some(&b'[') => {
+                    // jq-style `.["key"]`; the bracket is handled next.
+                    pos += 1;
+                }
+                b'.' => {
+                    let start = pos + 1;
+                    let end = route[start..]
+                        .find(['.', '['])
+                        .map_or(route.len(), |idx| start + idx);
+
+                    if start == end {
+                        return Err(RouteParseError::EmptyKey(start));
+                    }
+
+                    components.push(Component::Key(Cow::Borrowed(&route[start..end])));
+                    pos = end;
+                }
+                b'[' => {
+                    let (component, end) = Self::parse_bracketed(route, pos)?;
+                    components.push(component);
+                    pos = end;
+                }
+                _ => return Err(RouteParseError::ExpectedComponent(pos)),
+            }
+        }
+
+        Ok(Self::from(components))
+    }
+
+    /// Parses a bracketed component starting at `open` (the `[`),
+    /// returning the component and the offset just past the closing `]`.
+    fn parse_bracketed(
+        route: &'a str,
+        open: usize,
+    ) -> Result<(Component<'a>, usize), RouteParseError> {
+        let start = open + 1;
+
+        let (component, close) = match route[start..].chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let mut key = String::new();
+                let mut escaped = false;
+                let mut end = None;
+                for (idx, c) in route[start + 1..].char_indices() {
+                    match c {
+                        _ if escaped => {
+                            key.push(c);
+                            escaped = false;
+                        }
+                        '\\' => escaped = true,
+                        _ if c == quote => {
+                            end = Some(start + 1 + idx + 1);
+                            break;
+                        }
+                        _ => key.push(c),
+                    }
+                }
+
+                let Some(end) = end else {
+                    return Err(RouteParseError::UnterminatedKey(start));
+                };
+
+                (Component::Key(key.into()), end)
+            }
+            _ => {
+                let end = route[start..]
+                    .find(']')
+                    .map_or(route.len(), |idx| start + idx);
+                let index = &route[start..end];
+                let invalid = || RouteParseError::InvalidIndex(start, index.into());
+
+                // A leading `-` counts from the end of the list, so `[-1]`
+                // is the last element.
+                let component = match index.strip_prefix('-') {
+                    Some(index) => match index.parse::<usize>() {
+                        Ok(index) if index > 0 => Component::FromEnd(index - 1),
+                        _ => return Err(invalid()),
+                    },
+                    None => Component::Index(index.parse().map_err(|_| invalid())?),
+                };
+
+                (component, end)
+            }
+        };
+
+        if route.as_bytes().get(close) != Some(&b']') {
+            return Err(RouteParseError::ExpectedBracket(close));
+        }
+
+        Ok((component, close + 1))
+    }
+}
+
+impl std::fmt::Display for Route<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.route.is_empty() {
+            return write!(f, ".");
+        }
+
+        for component in &self.route {
+            match component {
+                Component::Key(key)
+                    if !key.is_empty()
+                        && key
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+                {
+                    write!(f, ".{key}")?
+                }
+                Component::Key(key) => {
+                    let escaped = key.replace('\\', "\\\\").replace('"', "\\\"");
+                    write!(f, "[\"{escaped}\"]")?
+                }
+                Component::Index(idx) => write!(f, "[{idx}]")?,
+                Component::FromEnd(idx) => write!(f, "[-{}]", idx + 1)?,
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Convenience builder for constructing a `Route`.
@@ -316,6 +489,43 @@ pub enum Component<'a> {
 
     /// An index into a YAML array.
     Index(usize),
+
+    /// An index into a YAML array, counting backwards from its end.
+    ///
+    /// `FromEnd(0)` is the last element, `FromEnd(1)` the one before it,
+    /// and so on. This is written as `[-1]`, `[-2]`, etc. in a route's
+    /// string form.
+    FromEnd(usize),
+}
+
+impl Component<'_> {
+    /// Resolves an index component against a list of `len` items,
+    /// returning the absolute index into the list.
+    ///
+    /// Returns `None` if this component is a key.
+    fn resolve_index(&self, len: usize) -> Option<Result<usize, QueryError>> {
+        match self {
+            Component::Key(_) => None,
+            Component::Index(idx) if *idx < len => Some(Ok(*idx)),
+            Component::Index(idx) => Some(Err(QueryError::ExhaustedList(*idx, len))),
+            Component::FromEnd(idx) => Some(
+                len.checked_sub(idx + 1)
+                    .ok_or(QueryError::ExhaustedListFromEnd(*idx, len)),
+            ),
+        }
+    }
+
+    /// Returns an error for this component being used to index into
+    /// something other than a list.
+    fn expected_list(&self) -> QueryError {
+        match self {
+            Component::FromEnd(idx) => {
+                QueryError::Other(format!("expected list for index `[-{}]`", idx + 1))
+            }
+            Component::Index(idx) => QueryError::ExpectedList(*idx),
+            Component::Key(key) => QueryError::ExpectedMapping(key.to_string()),
+        }
+    }
 }
 
 impl From<usize> for Component<'_> {
@@ -557,17 +767,66 @@ impl Deref for Tree {
     }
 }
 
+/// Maps byte offsets from before a [`Document::with_edit`] to byte offsets
+/// after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// The start of the edited range, which is the same before and after the edit.
+    start: usize,
+    /// The end of the edited range, before the edit.
+    old_end: usize,
+    /// The end of the edited range, after the edit.
+    new_end: usize,
+}
+
+impl OffsetMap {
+    /// Map a pre-edit byte offset to its post-edit byte offset.
+    ///
+    /// Offsets before the edited range are unchanged, and offsets at or
+    /// after its end are shifted by the edit's change in length.
+    /// Offsets inside the edited range keep their position relative to
+    /// the start of the range, clamped to the end of the replacement.
+    pub fn map(&self, offset: usize) -> usize {
+        if offset <= self.start {
+            offset
+        } else if offset >= self.old_end {
+            offset - self.old_end + self.new_end
+        } else {
+            offset.min(self.new_end)
+        }
+    }
+
+    /// Map a pre-edit byte span to its post-edit byte span.
+    pub fn map_span(&self, span: (usize, usize)) -> (usize, usize) {
+        (self.map(span.0), self.map(span.1))
+    }
+}
+
 /// Represents a queryable YAML document.
 #[derive(Clone)]
 pub struct Document {
     tree: Tree,
     line_index: LineIndex,
+    follow_merge_keys: bool,
+    has_bom: bool,
 }
 
 impl Document {
     /// Construct a new `Document` from the given YAML.
+    ///
+    /// A leading UTF-8 byte order mark, if present, is stripped from the
+    /// source before parsing, so that spans and columns on the first line
+    /// aren't skewed by it. Its presence is recorded, and
+    /// [`Document::source_with_bom`] restores it. CRLF line endings are
+    /// preserved as-is: all byte spans and line/column positions account
+    /// for them correctly.
     pub fn new(source: impl Into<String>) -> Result<Self, QueryError> {
-        let source = source.into();
+        let mut source = source.into();
+
+        let has_bom = source.starts_with('\u{feff}');
+        if has_bom {
+            source.drain(..'\u{feff}'.len_utf8());
+        }
 
         let mut parser = Parser::new();
         let language: Language = tree_sitter_yaml::LANGUAGE.into();
@@ -605,9 +864,37 @@ impl Document {
         Ok(Self {
             tree: Tree::build(source_tree)?,
             line_index,
+            follow_merge_keys: false,
+            has_bom,
         })
     }
 
+    /// Construct a new `Document` from the given YAML, keeping this
+    /// document's configuration: whether it follows merge keys, and
+    /// whether its source had a byte order mark.
+    ///
+    /// This is intended for producing modified copies of a document,
+    /// e.g. after patching its [source](Self::source).
+    pub fn with_source(&self, source: impl Into<String>) -> Result<Self, QueryError> {
+        let mut document = Self::new(source)?.follow_merge_keys(self.follow_merge_keys);
+        document.has_bom |= self.has_bom;
+        Ok(document)
+    }
+
+    /// Configure whether queries follow YAML merge keys (`<<: *anchor`)
+    /// when descending through mappings.
+    ///
+    /// When enabled, a key that isn't present in a mapping is looked up
+    /// in the mapping(s) merged into it, in order. Keys that are present
+    /// in the mapping itself always take precedence over merged keys, and
+    /// features for merged keys point to the anchored mapping.
+    ///
+    /// This is disabled by default.
+    pub fn follow_merge_keys(mut self, follow: bool) -> Self {
+        self.follow_merge_keys = follow;
+        self
+    }
+
     /// Returns a [`LineIndex`] for this document, which can be used
     /// to efficiently map between byte offsets and line coordinates.
     pub fn line_index(&self) -> &LineIndex {
@@ -615,11 +902,70 @@ impl Document {
     }
 
     /// Return a view of the original YAML source that this document was
-    /// loaded from.
+    /// loaded from, minus any leading byte order mark.
     pub fn source(&self) -> &str {
         &self.tree.borrow_owner().source
     }
 
+    /// Returns whether the original YAML source began with a byte order mark.
+    pub fn has_bom(&self) -> bool {
+        self.has_bom
+    }
+
+    /// Return this document's [source](Self::source), with its byte order
+    /// mark restored if the original YAML source had one.
+    ///
+    /// This is the form to write back to disk.
+    pub fn source_with_bom(&self) -> Cow<'_, str> {
+        if self.has_bom {
+            Cow::Owned(format!("\u{feff}{source}", source = self.source()))
+        } else {
+            Cow::Borrowed(self.source())
+        }
+    }
+
+    /// Returns a 64-bit hash of this document's [source](Self::source).
+    ///
+    /// The hash is FNV-1a over the source's bytes, so it's stable across
+    /// runs, platforms, and releases, and is suitable for cache keys and
+    /// change detection. It isn't cryptographically secure.
+    pub fn source_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        self.source().bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+
+    /// Replace the given byte range of this document's source with
+    /// `replacement`, returning the resulting [`Document`] along with an
+    /// [`OffsetMap`] that maps byte offsets in this document to byte
+    /// offsets in the new one.
+    ///
+    /// Returns an error if the edited source is not valid YAML.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or doesn't lie on `char`
+    /// boundaries, like [`String::replace_range`].
+    pub fn with_edit(
+        &self,
+        range: Range<usize>,
+        replacement: &str,
+    ) -> Result<(Document, OffsetMap), QueryError> {
+        let mut source = self.source().to_string();
+        source.replace_range(range.clone(), replacement);
+
+        let offsets = OffsetMap {
+            start: range.start,
+            old_end: range.end,
+            new_end: range.start + replacement.len(),
+        };
+
+        Ok((self.with_source(source)?, offsets))
+    }
+
     /// Resolve an anchor by name, returning the target node that was active
     /// at the given position. For duplicate anchors, this returns the most
     /// recent definition that appears before `position`.
@@ -641,6 +987,28 @@ impl Document {
         Ok(top_node.into())
     }
 
+    /// Returns whether this document is a multi-document YAML stream,
+    /// i.e. contains more than one `---`-separated document.
+    ///
+    /// Queries against a multi-document stream only consider the first
+    /// document; use [`Document::documents`] to reach the others.
+    pub fn is_yaml_stream_multi_doc(&self) -> bool {
+        self.document_nodes().nth(1).is_some()
+    }
+
+    /// Returns a [`Feature`] for the topmost semantic object in each
+    /// of this stream's documents, in order.
+    ///
+    /// For a single-document stream this is equivalent to
+    /// [`Document::top_feature`]. Empty documents (e.g. `---` with no
+    /// content) are skipped.
+    pub fn documents(&self) -> Vec<Feature<'_>> {
+        self.document_nodes()
+            .filter_map(|document| Self::document_top_node(document).ok())
+            .map(Into::into)
+            .collect()
+    }
+
     /// Returns whether the given range is spanned by a comment node.
     ///
     /// The comment node must fully span the range; a range that ends
@@ -681,6 +1049,73 @@ impl Document {
         self.query_node(route, QueryMode::Pretty).map(|n| n.into())
     }
 
+    /// Like [`Self::query_pretty`], but extends the feature's span to
+    /// include any trailing comments adjacent to it.
+    ///
+    /// A trailing comment is either a comment on the same line as the
+    /// end of the feature, or a run of comment-only lines immediately
+    /// following the feature that are indented at least as deeply as the
+    /// feature itself. Comments indented less deeply are assumed to
+    /// belong to the surrounding context rather than to the feature.
+    ///
+    /// For example, querying `[1]` in the following:
+    ///
+    /// ```yaml
+    /// - a
+    /// - b # same line
+    ///   # next line
+    /// # not captured
+    /// - c
+    /// ```
+    ///
+    /// will return `b # same line\n  # next line`.
+    pub fn query_pretty_with_comments(&self, route: &Route) -> Result<Feature<'_>, QueryError> {
+        let node = self.query_node(route, QueryMode::Pretty)?;
+        let mut feature = Feature::from(node);
+
+        let source = self.source();
+        let column = feature.location.point_span.0.1;
+        // NOTE: tree-sitter-yaml sometimes attaches comments that follow
+        // a block node to the node itself, regardless of their indentation.
+        // We start from the end of the node's actual content instead, so
+        // that only comments that meet the rules below are included.
+        let mut end = Self::content_end(&node);
+
+        // A comment on the same line as the feature's end.
+        let line_end = source[end..]
+            .find('\n')
+            .map_or(source.len(), |idx| end + idx);
+        if let Some(offset) = source[end..line_end].find('#')
+            && source[end..end + offset].trim().is_empty()
+            && let Some(comment) = self.comment_at(end + offset)
+        {
+            end = comment.end_byte();
+        }
+
+        // Any comment-only lines immediately following the feature.
+        let mut line_start = source[end..].find('\n').map(|idx| end + idx + 1);
+        while let Some(start) = line_start {
+            let line = &source[start..];
+            let indent = line.len() - line.trim_start_matches(' ').len();
+            if indent < column || !line[indent..].starts_with('#') {
+                break;
+            }
+
+            let Some(comment) = self.comment_at(start + indent) else {
+                break;
+            };
+
+            end = comment.end_byte();
+            line_start = source[end..].find('\n').map(|idx| end + idx + 1);
+        }
+
+        let end_point = self.line_index.line_col(TextSize::new(end as u32));
+        feature.location.byte_span.1 = end;
+        feature.location.point_span.1 = (end_point.line as usize, end_point.col as usize);
+
+        Ok(feature)
+    }
+
     /// Perform a route on the current document, returning a `Feature`
     /// if the route succeeds. Returns `None` if the route
     /// succeeds, but matches an absent value (e.g. `foo:`).
@@ -722,6 +1157,45 @@ impl Document {
         self.query_node(route, QueryMode::KeyOnly).map(|n| n.into())
     }
 
+    /// Perform a route on the current document, returning a `Feature`
+    /// for the nearest block or flow mapping or sequence that encloses
+    /// the route result.
+    ///
+    /// For example, querying `jobs.build.steps` will return the
+    /// mapping that makes up the body of `jobs.build`, while querying
+    /// `jobs.build.steps[0]` will return the `steps` sequence.
+    ///
+    /// Returns an error if the route result has no enclosing mapping
+    /// or sequence, e.g. if the route is empty.
+    pub fn query_context(&self, route: &Route) -> Result<Feature<'_>, QueryError> {
+        let node = self.query_node(route, QueryMode::Pretty)?;
+
+        // NOTE: In "pretty" mode, a key within a flow mapping can resolve
+        // to the flow mapping itself rather than its pair, in which case
+        // we're already on the enclosing mapping.
+        let mut parent =
+            if matches!(route.route.last(), Some(Component::Key(_))) && node.is_flow_mapping() {
+                Some(node)
+            } else {
+                node.parent()
+            };
+        while let Some(node) = parent {
+            if node.is_block_mapping()
+                || node.is_block_sequence()
+                || node.is_flow_mapping()
+                || node.is_flow_sequence()
+            {
+                return Ok(node.into());
+            }
+
+            parent = node.parent();
+        }
+
+        Err(QueryError::Other(
+            "route result has no enclosing mapping or sequence".into(),
+        ))
+    }
+
     /// Computes the byte range that should be deleted from the document's
     /// [`Self::source`] in order to remove the value at `route`, together
     /// with the structural "affixes" appropriate to the value's container.
@@ -772,7 +1246,7 @@ impl Document {
             // For an index, select the item node directly from the parent
             // sequence (via `flatten_sequence`, which keeps aliased items
             // as-is) so an aliased element isn't resolved away.
-            Component::Index(idx) => {
+            Component::Index(_) | Component::FromEnd(_) => {
                 let parent_route = route
                     .parent()
                     .expect("non-empty route always has a parent route");
@@ -785,13 +1259,14 @@ impl Document {
                     parent
                         .named_children(&mut cursor)
                         .find(|child| child.is_sequence())
-                        .ok_or(QueryError::ExpectedList(*idx))?
+                        .ok_or_else(|| last.expected_list())?
                 };
 
                 let items = self.flatten_sequence(&sequence)?;
-                *items
-                    .get(*idx)
-                    .ok_or(QueryError::ExhaustedList(*idx, items.len()))?
+                let idx = last
+                    .resolve_index(items.len())
+                    .expect("impossible: index component")?;
+                items[idx]
             }
         };
 
@@ -1022,11 +1497,23 @@ impl Document {
     /// necessarily captured. See [`Self::extract_with_leading_whitespace`]
     /// for feature extraction with rudimentary whitespace handling.
     ///
-    /// Panics if the feature's span is invalid.
+    /// Panics if the feature's span is invalid. See [`Self::try_extract`]
+    /// for a non-panicking variant.
     pub fn extract(&self, feature: &Feature) -> &str {
         &self.source()[feature.location.byte_span.0..feature.location.byte_span.1]
     }
 
+    /// Like [`Self::extract`], but returns an error instead of panicking
+    /// if the feature's span is out of bounds or doesn't fall on
+    /// UTF-8 character boundaries.
+    pub fn try_extract(&self, feature: &Feature) -> Result<&str, QueryError> {
+        let (start, end) = feature.location.byte_span;
+
+        self.source()
+            .get(start..end)
+            .ok_or(QueryError::InvalidSpan(start, end))
+    }
+
     /// Returns a string slice of the original document corresponding to the given
     /// [`Feature`], along with any leading (indentation-semantic) whitespace.
     ///
@@ -1054,6 +1541,129 @@ impl Document {
         &self.source()[start_idx..feature.location.byte_span.1]
     }
 
+    /// Returns a string slice of the original document corresponding to the given
+    /// [`Feature`], without any leading or trailing whitespace-only lines.
+    ///
+    /// Unlike [`str::trim`], this preserves the indentation of the first
+    /// non-blank line and any trailing whitespace on the last non-blank line,
+    /// as well as any interior blank lines.
+    ///
+    /// Panics if the feature's span is invalid.
+    pub fn extract_trimmed<'a>(&'a self, feature: &Feature) -> &'a str {
+        let extracted = self.extract(feature);
+
+        let Some(first) = extracted.find(|c: char| !c.is_whitespace()) else {
+            return &extracted[..0];
+        };
+        let start = extracted[..first]
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+
+        let last = extracted.trim_end().len();
+        let end = extracted[last..]
+            .find(['\r', '\n'])
+            .map_or(extracted.len(), |eol| last + eol);
+
+        &extracted[start..end]
+    }
+
+    /// Returns the absolute byte offset of the given `line` and `column`
+    /// within a block scalar (`|` or `>`) [`Feature`]'s content.
+    ///
+    /// `line` is the zero-indexed line within the scalar's content, i.e.
+    /// line 0 is the line immediately after the scalar's header.
+    /// Lines are counted in the source, so a folded (`>`) scalar's lines
+    /// are not joined. `column` is a byte offset from the scalar's
+    /// content indentation, as given by the header's indentation indicator
+    /// or detected from the first non-blank line.
+    ///
+    /// Returns `Ok(None)` if the position lies outside of the scalar's content.
+    /// Blank lines only have column 0.
+    ///
+    /// Returns an error if the [`Feature`] is not a block scalar.
+    pub fn block_scalar_offset(
+        &self,
+        feature: &Feature,
+        line: usize,
+        column: usize,
+    ) -> Result<Option<usize>, QueryError> {
+        let node = if feature._node.is_block_or_flow_node() {
+            feature
+                ._node
+                .child(0)
+                .expect("internal error: expected child of block_node/flow_node")
+        } else {
+            feature._node
+        };
+
+        if !node.is_block_scalar() {
+            return Err(QueryError::UnexpectedNode(node.kind().into()));
+        }
+
+        let scalar = &self.source()[node.start_byte()..node.end_byte()];
+        let Some(header_end) = scalar.find('\n') else {
+            // A header without any content.
+            return Ok(None);
+        };
+
+        // The header is the block indicator (`|` or `>`), followed by an
+        // optional indentation indicator and chomping indicator in either order,
+        // e.g. `|2-` or `>+1`.
+        let indicator = scalar[1..header_end]
+            .chars()
+            .take_while(|c| !c.is_whitespace())
+            .find_map(|c| c.to_digit(10));
+
+        let content_start = node.start_byte() + header_end + 1;
+        let lines = self.source()[content_start..node.end_byte()]
+            .split_inclusive('\n')
+            .scan(content_start, |start, line| {
+                let line_start = *start;
+                *start += line.len();
+                Some((line_start, line.trim_end_matches(['\r', '\n'])))
+            })
+            .collect::<Vec<_>>();
+
+        let indent = match indicator {
+            // An explicit indentation indicator is relative to the indentation
+            // of the node that contains the scalar, e.g. its mapping pair.
+            Some(indicator) => {
+                let parent = node
+                    .parent()
+                    .filter(|parent| parent.is_block_or_flow_node())
+                    .and_then(|parent| parent.parent());
+                let parent_indent = match parent {
+                    Some(parent)
+                        if matches!(
+                            parent.kind(),
+                            "block_mapping_pair" | "block_sequence_item"
+                        ) =>
+                    {
+                        parent.start_position().column
+                    }
+                    _ => 0,
+                };
+
+                parent_indent + indicator as usize
+            }
+            None => lines
+                .iter()
+                .find(|(_, line)| !line.trim().is_empty())
+                .map(|(_, line)| line.len() - line.trim_start_matches(' ').len())
+                .unwrap_or_default(),
+        };
+
+        let Some((line_start, line)) = lines.get(line) else {
+            return Ok(None);
+        };
+
+        if line.trim().is_empty() {
+            return Ok((column == 0).then_some(line_start + line.len().min(indent)));
+        }
+
+        Ok((indent + column <= line.len()).then_some(line_start + indent + column))
+    }
+
     /// Given a [`Feature`], return all comments that span the same range
     /// as the feature does.
     pub fn feature_comments<'tree>(&'tree self, feature: &Feature<'tree>) -> Vec<Feature<'tree>> {
@@ -1116,35 +1726,102 @@ impl Document {
         trawl(&self.tree.root_node(), start_line, end_line)
     }
 
+    /// Returns the end of `node`'s last non-comment descendant.
+    fn content_end(node: &Node) -> usize {
+        let mut cursor = node.walk();
+        let last = node
+            .children(&mut cursor)
+            .filter(|child| !child.is_comment())
+            .last();
+
+        match last {
+            Some(child) => Self::content_end(&child),
+            None => node.end_byte(),
+        }
+    }
+
+    /// Returns the comment node starting at the given offset, if any.
+    fn comment_at(&self, offset: usize) -> Option<Node<'_>> {
+        self.tree
+            .root_node()
+            .named_descendant_for_byte_range(offset, offset)
+            .filter(|node| node.is_comment() && node.start_byte() == offset)
+    }
+
     /// Returns whether this document contains any YAML anchors.
     pub fn has_anchors(&self) -> bool {
         !self.tree.borrow_dependent().is_empty()
     }
 
+    /// Renders this document's underlying concrete syntax tree as a string,
+    /// one node per line.
+    ///
+    /// Each line contains the node's kind and byte span, indented by its
+    /// depth in the tree. Leaf nodes also include their source text.
+    ///
+    /// This is a debugging aid: its format is not stable, and should not
+    /// be parsed or shown to end users.
+    pub fn debug_tree(&self) -> String {
+        let mut out = String::new();
+
+        for node in TreeIter::new(&self.tree) {
+            let depth = std::iter::successors(node.parent(), |n| n.parent()).count();
+            let indent = "  ".repeat(depth);
+
+            out.push_str(&format!(
+                "{indent}{kind} [{start}..{end}]",
+                kind = node.kind(),
+                start = node.start_byte(),
+                end = node.end_byte(),
+            ));
+
+            if node.child_count() == 0 {
+                out.push_str(&format!(" {:?}", &self.source()[node.byte_range()]));
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
     /// Returns the topmost semantic object in the YAML document,
     /// i.e. the node corresponding to the first block or flow feature.
     fn top_object(&self) -> Result<Node<'_>, QueryError> {
+        // The `document` child is the "body" of the YAML document; it
+        // might not be the first node in the `stream` if there are comments.
+        let document = self.document_nodes().next().ok_or_else(|| {
+            QueryError::MissingChild(self.tree.root_node().kind().into(), "document".into())
+        })?;
+
+        Self::document_top_node(document)
+    }
+
+    /// Returns an iterator over the `document` nodes in this YAML stream.
+    fn document_nodes(&self) -> impl Iterator<Item = Node<'_>> {
         // All tree-sitter-yaml trees start with a `stream` node.
         let stream = self.tree.root_node();
 
-        // The `document` child is the "body" of the YAML document; it
-        // might not be the first node in the `stream` if there are comments.
         let mut cur = stream.walk();
-        let document = stream
+        stream
             .named_children(&mut cur)
-            .find(|c| c.is_document())
-            .ok_or_else(|| QueryError::MissingChild(stream.kind().into(), "document".into()))?;
+            .filter(|c| c.is_document())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
 
+    /// Returns the top-level `block_node` or `flow_node` of the given
+    /// `document` node.
+    fn document_top_node(document: Node<'_>) -> Result<Node<'_>, QueryError> {
         // The document might have a directives section, which we need to
         // skip over. We do this by finding the top-level `block_node`
         // or `flow_node`, of which one will be present depending on how
         // the top-level document value is expressed.
-        let top_node = document
+        let mut cur = document.walk();
+        document
             .named_children(&mut cur)
             .find(|c| c.is_block_or_flow_node())
-            .ok_or_else(|| QueryError::Other("document has no block_node or flow_node".into()))?;
-
-        Ok(top_node)
+            .ok_or_else(|| QueryError::Other("document has no block_node or flow_node".into()))
     }
 
     fn query_node(&self, route: &Route, mode: QueryMode) -> Result<Node<'_>, QueryError> {
@@ -1334,11 +2011,13 @@ impl Document {
         if child.is_mapping() {
             match component {
                 Component::Key(key) => self.descend_mapping(&child, key),
-                Component::Index(idx) => Err(QueryError::ExpectedList(*idx)),
+                Component::Index(_) | Component::FromEnd(_) => Err(component.expected_list()),
             }
         } else if child.is_sequence() {
             match component {
-                Component::Index(idx) => self.descend_sequence(&child, *idx),
+                Component::Index(_) | Component::FromEnd(_) => {
+                    self.descend_sequence(&child, component)
+                }
                 Component::Key(key) => Err(QueryError::ExpectedMapping(key.to_string())),
             }
         } else {
@@ -1346,7 +2025,13 @@ impl Document {
         }
     }
 
-    fn descend_mapping<'b>(&self, node: &Node<'b>, expected: &str) -> Result<Node<'b>, QueryError> {
+    fn descend_mapping<'b>(
+        &'b self,
+        node: &Node<'b>,
+        expected: &str,
+    ) -> Result<Node<'b>, QueryError> {
+        let mut merges = vec![];
+
         let mut cur = node.walk();
         for child in node.named_children(&mut cur) {
             let key = if child.is_pair() {
@@ -1405,12 +2090,103 @@ impl Document {
                 // in a way that matters (since an empty value is terminal anyways).
                 return Ok(child.child_by_field_name("value").unwrap_or(child));
             }
+
+            if key_value == "<<"
+                && let Some(value) = child.child_by_field_name("value")
+            {
+                merges.push(value);
+            }
+        }
+
+        // None of the keys in the mapping matched, but the mapping might
+        // still inherit the key via a merge key.
+        if self.follow_merge_keys {
+            let mut merged_mappings = vec![];
+            for value in &merges {
+                merged_mappings.extend(self.merged_mappings(value)?);
+            }
+
+            for merged in merged_mappings {
+                // An anchored mapping can merge an alias to itself
+                // (e.g. `&a { <<: *a }`), which we don't follow.
+                if merged.byte_range().contains(&node.start_byte()) {
+                    continue;
+                }
+
+                match self.descend_mapping(&merged, expected) {
+                    Err(QueryError::ExhaustedMapping(_)) => continue,
+                    result => return result,
+                }
+            }
         }
 
         // None of the keys in the mapping matched.
         Err(QueryError::ExhaustedMapping(expected.into()))
     }
 
+    /// Given the value node of a merge key (`<<`), return the mappings
+    /// it merges, in order.
+    ///
+    /// The value is either a single alias (`<<: *a`) or a sequence of
+    /// aliases (`<<: [*a, *b]`). Anything else isn't a valid merge, and
+    /// is ignored.
+    fn merged_mappings<'b>(&'b self, value: &Node<'b>) -> Result<Vec<Node<'b>>, QueryError> {
+        let resolve = |node: Node<'b>| -> Result<Option<Node<'b>>, QueryError> {
+            let mut cursor = node.walk();
+            let Some(alias) = node.named_children(&mut cursor).find(|n| n.is_alias()) else {
+                return Ok(None);
+            };
+
+            let alias_name = alias
+                .utf8_text(self.source().as_bytes())
+                .expect("impossible: alias name should be UTF-8 by construction");
+
+            let target = self
+                .resolve_anchor(&alias_name[1..], alias.start_byte())
+                .ok_or_else(|| QueryError::Other(format!("unknown alias: {}", alias_name)))?;
+
+            Ok(target.is_mapping().then_some(target))
+        };
+
+        let mut cursor = value.walk();
+        let Some(inner) = value.named_children(&mut cursor).find(|n| !n.is_anchor()) else {
+            return Ok(vec![]);
+        };
+
+        if inner.is_alias() {
+            return Ok(resolve(*value)?.into_iter().collect());
+        }
+
+        if !inner.is_sequence() {
+            return Ok(vec![]);
+        }
+
+        let mut mappings = vec![];
+        let mut cursor = inner.walk();
+        for item in inner.named_children(&mut cursor) {
+            // Block sequence items wrap their value in a `block_node`
+            // or `flow_node`; flow sequence items are `flow_node`s already.
+            let item = if item.is_block_sequence_item() {
+                let mut cursor = item.walk();
+                match item
+                    .named_children(&mut cursor)
+                    .find(|n| n.is_block_or_flow_node())
+                {
+                    Some(item) => item,
+                    None => continue,
+                }
+            } else if item.is_flow_node() {
+                item
+            } else {
+                continue;
+            };
+
+            mappings.extend(resolve(item)?);
+        }
+
+        Ok(mappings)
+    }
+
     /// Given a `block_sequence` or `flow_sequence` node, return
     /// a full list of child nodes after expanding any aliases present.
     ///
@@ -1453,11 +2229,16 @@ impl Document {
         Ok(children)
     }
 
-    fn descend_sequence<'b>(&'b self, node: &Node<'b>, idx: usize) -> Result<Node<'b>, QueryError> {
+    fn descend_sequence<'b>(
+        &'b self,
+        node: &Node<'b>,
+        component: &Component,
+    ) -> Result<Node<'b>, QueryError> {
         let children = self.flatten_sequence(node)?;
-        let Some(child) = children.get(idx) else {
-            return Err(QueryError::ExhaustedList(idx, children.len()));
-        };
+        let idx = component
+            .resolve_index(children.len())
+            .expect("impossible: index component")?;
+        let child = &children[idx];
 
         if child.is_flow_pair() {
             // Similarly, if our index happens to be a `flow_pair`, we need to
@@ -1475,7 +2256,9 @@ impl Document {
 mod tests {
     use std::vec;
 
-    use crate::{Component, Document, FeatureKind, QueryError, Route};
+    use line_index::TextSize;
+
+    use crate::{Component, Document, FeatureKind, QueryError, Route, RouteParseError};
 
     #[test]
     fn test_document_preserves_leading_trailing_whitespace() {
@@ -1484,6 +2267,218 @@ mod tests {
         assert_eq!(doc.source(), source);
     }
 
+    #[test]
+    fn test_document_strips_bom() {
+        let source = "\u{feff}foo: bar\nbaz:\n  - a\n  - b\n";
+        let doc = Document::new(source).unwrap();
+        assert_eq!(doc.source(), "foo: bar\nbaz:\n  - a\n  - b\n");
+
+        let foo = doc.query_pretty(&route!("foo")).unwrap();
+        assert_eq!(doc.extract(&foo), "foo: bar");
+        assert_eq!(foo.location.byte_span, (0, 8));
+        assert_eq!(foo.location.point_span, ((0, 0), (0, 8)));
+
+        assert!(doc.has_bom());
+        assert_eq!(doc.source_with_bom(), source);
+
+        let b = doc.query_exact(&route!("baz", 1)).unwrap().unwrap();
+        assert_eq!(doc.extract(&b), "b");
+        assert_eq!(b.location.point_span, ((3, 4), (3, 5)));
+
+        let pos = doc
+            .line_index()
+            .line_col(TextSize::new(b.location.byte_span.0 as u32));
+        assert_eq!((pos.line, pos.col), (3, 4));
+
+        // Documents derived from this one keep the BOM.
+        let (edited, _) = doc.with_edit(5..8, "baz").unwrap();
+        assert!(edited.has_bom());
+        assert_eq!(
+            edited.source_with_bom(),
+            "\u{feff}foo: baz\nbaz:\n  - a\n  - b\n"
+        );
+
+        let plain = Document::new("foo: bar\n").unwrap();
+        assert!(!plain.has_bom());
+        assert_eq!(plain.source_with_bom(), "foo: bar\n");
+        assert!(!plain.with_source("foo: baz\n").unwrap().has_bom());
+    }
+
+    #[test]
+    fn test_document_crlf() {
+        let source = "foo: bar\r\nbaz:\r\n  - a\r\n  - b\r\nquux: |\r\n  x\r\n  y\r\n";
+        let doc = Document::new(source).unwrap();
+        assert_eq!(doc.source(), source);
+
+        let foo = doc.query_pretty(&route!("foo")).unwrap();
+        assert_eq!(doc.extract(&foo), "foo: bar");
+        assert_eq!(foo.location.point_span, ((0, 0), (0, 8)));
+
+        let b = doc.query_exact(&route!("baz", 1)).unwrap().unwrap();
+        assert_eq!(doc.extract(&b), "b");
+        assert_eq!(b.location.byte_span, (27, 28));
+        assert_eq!(b.location.point_span, ((3, 4), (3, 5)));
+
+        let pos = doc
+            .line_index()
+            .line_col(TextSize::new(b.location.byte_span.0 as u32));
+        assert_eq!((pos.line, pos.col), (3, 4));
+
+        let quux = doc.query_pretty(&route!("quux")).unwrap();
+        assert_eq!(quux.location.point_span.0, (4, 0));
+        assert!(doc.extract(&quux).starts_with("quux: |\r\n  x\r\n  y"));
+    }
+
+    #[test]
+    fn test_document_source_hash() {
+        let doc = Document::new("foo: bar\n").unwrap();
+        let same = Document::new("foo: bar\n").unwrap();
+        let changed = Document::new("foo: baz\n").unwrap();
+
+        assert_eq!(doc.source_hash(), same.source_hash());
+        assert_ne!(doc.source_hash(), changed.source_hash());
+
+        // Known FNV-1a values, to catch accidental algorithm changes.
+        assert_eq!(Document::new("").unwrap().source_hash(), 0xcbf29ce484222325);
+        assert_eq!(
+            Document::new("a").unwrap().source_hash(),
+            0xaf63dc4c8601ec8c
+        );
+
+        // The BOM is stripped before hashing.
+        let bom = Document::new("\u{feff}foo: bar\n").unwrap();
+        assert_eq!(doc.source_hash(), bom.source_hash());
+    }
+
+    #[test]
+    fn test_document_with_edit() {
+        let source = "foo: bar\nbaz: quux\n";
+        let doc = Document::new(source).unwrap();
+
+        // Grow `bar` into `barbarbar`.
+        let (new_doc, offsets) = doc.with_edit(5..8, "barbarbar").unwrap();
+        assert_eq!(new_doc.source(), "foo: barbarbar\nbaz: quux\n");
+
+        // Before the edit.
+        assert_eq!(offsets.map(0), 0);
+        assert_eq!(offsets.map(5), 5);
+        // Inside the edit.
+        assert_eq!(offsets.map(6), 6);
+        assert_eq!(offsets.map(7), 7);
+        // After the edit.
+        assert_eq!(offsets.map(8), 14);
+        assert_eq!(offsets.map(source.len()), new_doc.source().len());
+
+        // The `quux` value is relocated to the same value in the new document.
+        let route = route!("baz");
+        let old = doc.query_exact(&route).unwrap().unwrap();
+        let new = new_doc.query_exact(&route).unwrap().unwrap();
+        assert_eq!(
+            offsets.map_span(old.location.byte_span),
+            new.location.byte_span
+        );
+
+        // Shrink `quux` into `q`.
+        let (new_doc, offsets) = doc.with_edit(14..18, "q").unwrap();
+        assert_eq!(new_doc.source(), "foo: bar\nbaz: q\n");
+
+        // Before the edit.
+        assert_eq!(offsets.map(5), 5);
+        // Inside the edit, clamped to the end of the replacement.
+        assert_eq!(offsets.map(15), 15);
+        assert_eq!(offsets.map(17), 15);
+        // After the edit.
+        assert_eq!(offsets.map(18), 15);
+        assert_eq!(offsets.map(19), 16);
+
+        // Edits that produce invalid YAML are rejected.
+        assert!(matches!(
+            doc.with_edit(5..8, "[bar"),
+            Err(QueryError::InvalidInput(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_route_parse() {
+        for (input, expected) in [
+            ("", route!()),
+            (".", route!()),
+            (".jobs", route!("jobs")),
+            (".jobs.build.steps[0]", route!("jobs", "build", "steps", 0)),
+            ("[0][1]", route!(0, 1)),
+            (".foo[10].bar", route!("foo", 10, "bar")),
+            // Negative indices count from the end.
+            (
+                ".steps[-1]",
+                Route::from(vec![Component::from("steps"), Component::FromEnd(0)]),
+            ),
+            ("[-3]", Route::from(vec![Component::FromEnd(2)])),
+            // Dot-form components are always keys, even when numeric.
+            (".foo.0", route!("foo", "0")),
+            // Quoted keys, with and without a leading `.`.
+            (r#".on["push"]"#, route!("on", "push")),
+            (".on.['push']", route!("on", "push")),
+            // Quoted keys can contain dots and brackets.
+            (r#".["on.push"]"#, route!("on.push")),
+            (
+                r#".jobs["a.b"].steps[0]"#,
+                route!("jobs", "a.b", "steps", 0),
+            ),
+            (r#"["[0]"]"#, route!("[0]")),
+            (r#"[""]"#, route!("")),
+            // Escapes within quoted keys.
+            (r#"["a\"b"]"#, route!("a\"b")),
+            (r#"['a\'b']"#, route!("a'b")),
+            (r#"["a\\b"]"#, route!("a\\b")),
+        ] {
+            assert_eq!(
+                Route::parse(input).unwrap().route,
+                expected.route,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_route_parse_invalid() {
+        for (input, expected) in [
+            ("jobs", RouteParseError::ExpectedComponent(0)),
+            (".jobs.", RouteParseError::EmptyKey(6)),
+            ("..jobs", RouteParseError::EmptyKey(1)),
+            (".a[", RouteParseError::InvalidIndex(3, "".into())),
+            (".a[x]", RouteParseError::InvalidIndex(3, "x".into())),
+            (".a[-0]", RouteParseError::InvalidIndex(3, "-0".into())),
+            (".a[-]", RouteParseError::InvalidIndex(3, "-".into())),
+            (".a[--1]", RouteParseError::InvalidIndex(3, "--1".into())),
+            (".a[0", RouteParseError::ExpectedBracket(4)),
+            (r#".a["b"#, RouteParseError::UnterminatedKey(3)),
+            (r#".a["b"x]"#, RouteParseError::ExpectedBracket(6)),
+            (".a[0]b", RouteParseError::ExpectedComponent(5)),
+        ] {
+            assert_eq!(Route::parse(input).unwrap_err(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_route_display_roundtrip() {
+        for (route, expected) in [
+            (route!(), "."),
+            (route!("jobs", "build", "steps", 0), ".jobs.build.steps[0]"),
+            (route!("on.push"), r#"["on.push"]"#),
+            (route!("a b", 1, ""), r#"["a b"][1][""]"#),
+            (route!("a\"b\\c"), r#"["a\"b\\c"]"#),
+            (route!("foo", "0"), ".foo.0"),
+            (
+                Route::from(vec![Component::from("steps"), Component::FromEnd(0)]),
+                ".steps[-1]",
+            ),
+        ] {
+            let rendered = route.to_string();
+            assert_eq!(rendered, expected);
+            assert_eq!(Route::parse(&rendered).unwrap().route, route.route);
+        }
+    }
+
     #[test]
     fn test_query_parent() {
         let route = route!("foo", "bar", "baz");
@@ -1581,6 +2576,304 @@ baz:
         );
     }
 
+    #[test]
+    fn test_query_context() {
+        let doc = r#"
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - name: hello
+        run: echo hello
+      - { name: goodbye, run: echo goodbye }
+"#;
+
+        let doc = Document::new(doc).unwrap();
+
+        // A nested step query's context is the enclosing job mapping.
+        let job = doc
+            .query_context(&route!("jobs", "build", "steps"))
+            .unwrap();
+        assert_eq!(job.kind(), FeatureKind::BlockMapping);
+        assert_eq!(
+            doc.extract(&job),
+            doc.extract(&doc.query_exact(&route!("jobs", "build")).unwrap().unwrap())
+        );
+
+        // A sequence item's context is the sequence itself.
+        let steps = doc
+            .query_context(&route!("jobs", "build", "steps", 0))
+            .unwrap();
+        assert_eq!(steps.kind(), FeatureKind::BlockSequence);
+        assert!(doc.extract(&steps).starts_with("- name: hello"));
+
+        // A key within a step's context is the step's mapping.
+        let step = doc
+            .query_context(&route!("jobs", "build", "steps", 0, "run"))
+            .unwrap();
+        assert_eq!(step.kind(), FeatureKind::BlockMapping);
+        assert_eq!(doc.extract(&step), "name: hello\n        run: echo hello");
+
+        // Flow mappings are contexts too.
+        let step = doc
+            .query_context(&route!("jobs", "build", "steps", 1, "run"))
+            .unwrap();
+        assert_eq!(step.kind(), FeatureKind::FlowMapping);
+        assert_eq!(doc.extract(&step), "{ name: goodbye, run: echo goodbye }");
+
+        // Top-level keys are enclosed by the document's mapping.
+        let top = doc.query_context(&route!("jobs")).unwrap();
+        assert_eq!(top.kind(), FeatureKind::BlockMapping);
+        assert!(doc.extract(&top).starts_with("jobs:"));
+
+        // The document's top-level feature has no context.
+        assert!(doc.query_context(&route!()).is_err());
+
+        // Nonexistent routes fail as usual.
+        assert!(doc.query_context(&route!("jobs", "missing")).is_err());
+    }
+
+    #[test]
+    fn test_debug_tree() {
+        let doc = Document::new("foo: [bar, 'baz'] # comment\n").unwrap();
+        let tree = doc.debug_tree();
+
+        for kind in [
+            "stream",
+            "document",
+            "block_mapping_pair",
+            "flow_sequence",
+            "single_quote_scalar",
+            "comment",
+        ] {
+            assert!(tree.contains(kind), "missing {kind} in:\n{tree}");
+        }
+
+        // Leaves include their source text, and nesting is reflected
+        // in indentation.
+        assert!(tree.lines().next().unwrap().starts_with("stream [0.."));
+        assert!(tree.contains(r##"comment [18..27] "# comment""##));
+        assert!(tree.contains("\n              string_scalar [0..3] \"foo\"\n"));
+    }
+
+    #[test]
+    fn test_try_extract() {
+        let doc = Document::new("foo: bär\n").unwrap();
+
+        let mut foo = doc.query_exact(&route!("foo")).unwrap().unwrap();
+        assert_eq!(doc.try_extract(&foo).unwrap(), "bär");
+        assert_eq!(doc.try_extract(&foo).unwrap(), doc.extract(&foo));
+
+        // Past the end of the document.
+        foo.location.byte_span = (5, 100);
+        assert!(matches!(
+            doc.try_extract(&foo),
+            Err(QueryError::InvalidSpan(5, 100))
+        ));
+
+        // Inverted.
+        foo.location.byte_span = (6, 5);
+        assert!(matches!(
+            doc.try_extract(&foo),
+            Err(QueryError::InvalidSpan(6, 5))
+        ));
+
+        // Not on a character boundary, i.e. in the middle of `ä`.
+        foo.location.byte_span = (5, 7);
+        assert!(matches!(
+            doc.try_extract(&foo),
+            Err(QueryError::InvalidSpan(5, 7))
+        ));
+    }
+
+    #[test]
+    fn test_follow_merge_keys() {
+        let doc = r#"
+defaults: &defaults
+  shell: bash
+  working-directory: src
+
+extra: &extra
+  timeout: 10
+  shell: sh
+
+job:
+  <<: *defaults
+  working-directory: app
+
+multi:
+  <<: [*extra, *defaults]
+
+flow: { <<: *defaults }
+
+cycle: &cycle
+  <<: *cycle
+"#;
+
+        // Merge keys aren't followed by default.
+        let plain = Document::new(doc).unwrap();
+        assert!(matches!(
+            plain.query_exact(&route!("job", "shell")),
+            Err(QueryError::ExhaustedMapping(_))
+        ));
+
+        let doc = Document::new(doc).unwrap().follow_merge_keys(true);
+
+        // Inherited via `<<: *defaults`; the feature points into the
+        // anchored mapping.
+        let shell = doc.query_exact(&route!("job", "shell")).unwrap().unwrap();
+        assert_eq!(doc.extract(&shell), "bash");
+        assert_eq!(shell.location.point_span.0.0, 2);
+
+        let shell = doc.query_pretty(&route!("job", "shell")).unwrap();
+        assert_eq!(doc.extract(&shell), "shell: bash");
+
+        // Keys in the mapping itself take precedence over merged keys.
+        let wd = doc
+            .query_exact(&route!("job", "working-directory"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(doc.extract(&wd), "app");
+
+        // Earlier merges take precedence over later ones.
+        let shell = doc.query_exact(&route!("multi", "shell")).unwrap().unwrap();
+        assert_eq!(doc.extract(&shell), "sh");
+        let wd = doc
+            .query_exact(&route!("multi", "working-directory"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(doc.extract(&wd), "src");
+
+        let shell = doc.query_exact(&route!("flow", "shell")).unwrap().unwrap();
+        assert_eq!(doc.extract(&shell), "bash");
+
+        // Keys that aren't present anywhere are still missing.
+        assert!(matches!(
+            doc.query_exact(&route!("job", "nope")),
+            Err(QueryError::ExhaustedMapping(_))
+        ));
+
+        // Self-referential merges aren't followed.
+        assert!(matches!(
+            doc.query_exact(&route!("cycle", "nope")),
+            Err(QueryError::ExhaustedMapping(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_trimmed() {
+        let doc = r#"
+foo:
+  run: |
+
+    echo hello
+
+    echo goodbye
+
+
+  bar: baz
+quux: |+
+  keep   
+
+
+"#;
+
+        let doc = Document::new(doc).unwrap();
+
+        // Trailing blank lines in a (keep-chomped) block scalar are trimmed.
+        let quux = doc.query_exact(&route!("quux")).unwrap().unwrap();
+        assert_eq!(doc.extract(&quux), "|+\n  keep   \n\n\n");
+        assert_eq!(doc.extract_trimmed(&quux), "|+\n  keep   ");
+
+        // Interior blank lines are preserved.
+        let foo = doc.query_pretty(&route!("foo")).unwrap();
+        assert_eq!(
+            doc.extract_trimmed(&foo),
+            "foo:\n  run: |\n\n    echo hello\n\n    echo goodbye\n\n\n  bar: baz"
+        );
+
+        // Leading blank lines are trimmed, but the first line's indentation is kept.
+        let mut run = doc.query_exact(&route!("foo", "run")).unwrap().unwrap();
+        run.location.byte_span.0 += "|\n".len();
+        assert_eq!(doc.extract(&run), "\n    echo hello\n\n    echo goodbye");
+        assert_eq!(
+            doc.extract_trimmed(&run),
+            "    echo hello\n\n    echo goodbye"
+        );
+
+        // Whitespace-only features trim to nothing.
+        run.location.byte_span.1 = run.location.byte_span.0 + 1;
+        assert_eq!(doc.extract_trimmed(&run), "");
+    }
+
+    #[test]
+    fn test_block_scalar_offset() {
+        let doc = r#"
+literal: |
+  echo hello
+    echo nested
+
+  echo goodbye
+folded: >-
+    some folded
+    text
+explicit: |2
+    indented
+  exact
+steps:
+  - run: |
+      echo in a list
+  - |1
+    item
+"#;
+
+        let doc = Document::new(doc).unwrap();
+
+        let offset = |route: &Route, line, column| {
+            let feature = doc.query_exact(route).unwrap().unwrap();
+            doc.block_scalar_offset(&feature, line, column)
+                .unwrap()
+                .map(|offset| &doc.source()[offset..])
+                .map(|rest| rest.lines().next().unwrap())
+        };
+
+        // Literal scalars, with the indentation detected from the first line.
+        let literal = route!("literal");
+        assert_eq!(offset(&literal, 0, 0), Some("echo hello"));
+        assert_eq!(offset(&literal, 0, 5), Some("hello"));
+        assert_eq!(offset(&literal, 0, 10), Some(""));
+        assert_eq!(offset(&literal, 0, 11), None);
+        assert_eq!(offset(&literal, 1, 0), Some("  echo nested"));
+        assert_eq!(offset(&literal, 1, 2), Some("echo nested"));
+        assert_eq!(offset(&literal, 2, 0), Some(""));
+        assert_eq!(offset(&literal, 2, 1), None);
+        assert_eq!(offset(&literal, 3, 5), Some("goodbye"));
+        assert_eq!(offset(&literal, 4, 0), None);
+
+        // Folded scalars are addressed by their source lines.
+        let folded = route!("folded");
+        assert_eq!(offset(&folded, 0, 5), Some("folded"));
+        assert_eq!(offset(&folded, 1, 0), Some("text"));
+        assert_eq!(offset(&folded, 2, 0), None);
+
+        // An explicit indentation indicator overrides the detected indentation.
+        let explicit = route!("explicit");
+        assert_eq!(offset(&explicit, 0, 0), Some("  indented"));
+        assert_eq!(offset(&explicit, 1, 0), Some("exact"));
+
+        // Scalars nested within sequences.
+        assert_eq!(offset(&route!("steps", 0, "run"), 0, 5), Some("in a list"));
+        assert_eq!(offset(&route!("steps", 1), 0, 0), Some(" item"));
+
+        // Only block scalars are supported.
+        let bare = Document::new("foo: bar\n").unwrap();
+        let foo = bare.query_exact(&route!("foo")).unwrap().unwrap();
+        assert!(matches!(
+            bare.block_scalar_offset(&foo, 0, 0),
+            Err(QueryError::UnexpectedNode(_))
+        ));
+    }
+
     #[test]
     fn test_top_feature() {
         let doc = r#"
@@ -1596,6 +2889,60 @@ baz:
         assert_eq!(feature.kind(), FeatureKind::BlockMapping);
     }
 
+    #[test]
+    fn test_documents() {
+        let doc = r#"
+# leading comment
+foo: bar
+---
+- a
+- b
+---
+---
+{baz: quux}
+"#;
+
+        let doc = Document::new(doc).unwrap();
+        assert!(doc.is_yaml_stream_multi_doc());
+
+        // The empty third document is skipped.
+        let documents = doc.documents();
+        assert_eq!(documents.len(), 3);
+
+        assert_eq!(doc.extract(&documents[0]).trim(), "foo: bar");
+        assert_eq!(documents[0].kind(), FeatureKind::BlockMapping);
+        assert_eq!(doc.extract(&documents[1]).trim(), "- a\n- b");
+        assert_eq!(documents[1].kind(), FeatureKind::BlockSequence);
+        assert_eq!(doc.extract(&documents[2]).trim(), "{baz: quux}");
+        assert_eq!(documents[2].kind(), FeatureKind::FlowMapping);
+
+        // Queries still operate on the first document.
+        assert_eq!(
+            doc.extract(&doc.query_exact(&route!("foo")).unwrap().unwrap()),
+            "bar"
+        );
+        assert!(!doc.query_exists(&route!(0)));
+    }
+
+    #[test]
+    fn test_documents_single() {
+        for source in [
+            "foo: bar\n",
+            "---\nfoo: bar\n",
+            "# comment\n---\nfoo: bar\n",
+        ] {
+            let doc = Document::new(source).unwrap();
+            assert!(!doc.is_yaml_stream_multi_doc());
+
+            let documents = doc.documents();
+            assert_eq!(documents.len(), 1);
+            assert_eq!(
+                documents[0].location.byte_span,
+                doc.top_feature().unwrap().location.byte_span
+            );
+        }
+    }
+
     #[test]
     fn test_feature_comments() {
         let doc = r#"
@@ -1878,6 +3225,49 @@ list:
         ));
     }
 
+    #[test]
+    fn test_query_from_end() {
+        let doc = r#"
+steps:
+  - a
+  - b
+  - c
+flow: [x, y]
+map:
+  foo: bar
+        "#;
+
+        let doc = Document::new(doc).unwrap();
+        let from_end = |key: &'static str, idx| {
+            Route::from(vec![Component::from(key), Component::FromEnd(idx)])
+        };
+
+        for (route, expected) in [
+            (from_end("steps", 0), "c"),
+            (from_end("steps", 1), "b"),
+            (from_end("steps", 2), "a"),
+            (from_end("flow", 0), "y"),
+            (from_end("flow", 1), "x"),
+        ] {
+            let feature = doc.query_exact(&route).unwrap().unwrap();
+            assert_eq!(doc.extract(&feature), expected, "{route}");
+        }
+
+        let err = doc.query_exact(&from_end("steps", 3)).unwrap_err();
+        assert!(matches!(err, QueryError::ExhaustedListFromEnd(3, 3)));
+        assert_eq!(err.to_string(), "index `[-4]` exceeds list size (3)");
+
+        let err = doc.query_exact(&from_end("map", 0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "route error: expected list for index `[-1]`"
+        );
+
+        // Removal resolves the index the same way.
+        let span = doc.removal_span(&from_end("steps", 0)).unwrap();
+        assert_eq!(span, doc.removal_span(&route!("steps", 2)).unwrap());
+    }
+
     #[test]
     fn test_inline_anchor_alias_patterns() {
         let test_cases: Vec<(&str, Vec<(Route, &str)>)> = vec![
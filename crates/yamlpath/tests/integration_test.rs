@@ -63,6 +63,9 @@ fn run_testcase(path: &Path) {
             Some("pretty") | None => Some(document.query_pretty(&query).unwrap()),
             Some("exact") => document.query_exact(&query).unwrap(),
             Some("key-only") => Some(document.query_key_only(&query).unwrap()),
+            Some("pretty-with-comments") => {
+                Some(document.query_pretty_with_comments(&query).unwrap())
+            }
             Some(o) => panic!("invalid testcase mode: {o}"),
         };
 
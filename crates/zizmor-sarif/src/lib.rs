@@ -23,6 +23,8 @@ pub struct Sarif {
 pub struct Run {
     pub invocations: Vec<Invocation>,
     pub results: Vec<Result>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub taxonomies: Vec<ToolComponent>,
     pub tool: Tool,
 }
 
@@ -33,7 +35,7 @@ pub struct Tool {
     pub driver: ToolComponent,
 }
 
-/// Tool driver metadata (SARIF §3.19).
+/// Tool driver or taxonomy metadata (SARIF §3.19).
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ToolComponent {
@@ -42,10 +44,14 @@ pub struct ToolComponent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub information_uri: Option<String>,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub rules: Vec<ReportingDescriptor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_version: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub taxa: Vec<ReportingDescriptor>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
 }
@@ -61,6 +67,10 @@ pub struct Invocation {
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportingDescriptor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_configuration: Option<ReportingConfiguration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_description: Option<MultiformatMessageString>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help: Option<MultiformatMessageString>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,6 +80,33 @@ pub struct ReportingDescriptor {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<PropertyBag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<MultiformatMessageString>,
+}
+
+/// A reference to a reporting descriptor, e.g. a taxon (SARIF §3.52).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingDescriptorReference {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_component: Option<ToolComponentReference>,
+}
+
+/// A reference to a tool component, e.g. a taxonomy (SARIF §3.54).
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolComponentReference {
+    pub name: String,
+}
+
+/// A rule's default configuration (SARIF §3.50).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingConfiguration {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<ResultLevel>,
 }
 
 /// Plain-text + markdown message (SARIF §3.12).
@@ -95,6 +132,8 @@ pub struct PropertyBag {
 pub struct Result {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub code_flows: Vec<CodeFlow>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<Fix>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kind: Option<ResultKind>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -106,6 +145,8 @@ pub struct Result {
     pub properties: Option<PropertyBag>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub taxa: Vec<ReportingDescriptorReference>,
 }
 
 /// A human-readable message (SARIF §3.11).
@@ -153,6 +194,10 @@ pub struct ArtifactLocation {
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Region {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_length: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_offset: Option<i64>,
     pub end_column: i64,
     pub end_line: i64,
     pub snippet: ArtifactContent,
@@ -188,6 +233,31 @@ pub struct ThreadFlowLocation {
     pub location: Location,
 }
 
+/// A proposed fix for a result (SARIF §3.55).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fix {
+    pub artifact_changes: Vec<ArtifactChange>,
+    pub description: Message,
+}
+
+/// A change to a single artifact (SARIF §3.56).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactChange {
+    pub artifact_location: ArtifactLocation,
+    pub replacements: Vec<Replacement>,
+}
+
+/// The replacement of a single region within an artifact (SARIF §3.57).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Replacement {
+    pub deleted_region: Region,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inserted_content: Option<ArtifactContent>,
+}
+
 /// Classification of a result (SARIF §3.27.9). Serialized as a lowercase
 /// string.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -239,6 +309,7 @@ mod tests {
                 }],
                 results: vec![Result {
                     code_flows: vec![],
+                    fixes: vec![],
                     kind: Some(ResultKind::Fail),
                     level: Some(ResultLevel::Error),
                     locations: vec![Location {
@@ -252,6 +323,8 @@ mod tests {
                                 uri: "wf.yml".into(),
                             },
                             region: Region {
+                                byte_length: None,
+                                byte_offset: None,
                                 end_column: 2,
                                 end_line: 3,
                                 snippet: ArtifactContent { text: "x".into() },
@@ -264,13 +337,18 @@ mod tests {
                     message: Message { text: "msg".into() },
                     properties: None,
                     rule_id: Some("zizmor/example".into()),
+                    taxa: vec![],
                 }],
+                taxonomies: vec![],
                 tool: Tool {
                     driver: ToolComponent {
                         download_uri: None,
                         information_uri: None,
                         name: "zizmor".into(),
+                        organization: None,
                         rules: vec![ReportingDescriptor {
+                            default_configuration: None,
+                            full_description: None,
                             help: None,
                             help_uri: None,
                             id: "zizmor/example".into(),
@@ -279,8 +357,10 @@ mod tests {
                                 tags: vec!["security".into()],
                                 additional_properties: Default::default(),
                             }),
+                            short_description: None,
                         }],
                         semantic_version: None,
+                        taxa: vec![],
                         version: None,
                     },
                 },
@@ -2,6 +2,7 @@
 
 use std::fs::{self, File};
 use std::path::Path;
+use std::process::Command;
 use std::{env, io};
 
 use fst::{MapBuilder, SetBuilder};
@@ -76,8 +77,147 @@ fn do_archived_action_repos() {
     build.finish().unwrap();
 }
 
+/// Runs `git` with the given arguments in the manifest directory,
+/// returning its trimmed standard output on success.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn do_git_sha() {
+    // Packagers building outside of a Git checkout can supply the SHA
+    // themselves.
+    println!("cargo::rerun-if-env-changed=ZIZMOR_GIT_SHA");
+    if env::var("ZIZMOR_GIT_SHA").is_ok() {
+        return;
+    }
+
+    let Some(sha) = git(&["rev-parse", "HEAD"]) else {
+        return;
+    };
+
+    // Re-run when HEAD moves, either to a different ref or because
+    // the current ref gained a commit.
+    for path in [Some("HEAD".into()), git(&["symbolic-ref", "-q", "HEAD"])]
+        .into_iter()
+        .flatten()
+    {
+        if let Some(path) = git(&["rev-parse", "--path-format=absolute", "--git-path", &path]) {
+            println!("cargo::rerun-if-changed={path}");
+        }
+    }
+
+    println!("cargo::rustc-env=ZIZMOR_GIT_SHA={sha}");
+}
+
+/// Extracts each audit's summary (the first paragraph of its section in
+/// `docs/audits.md`) as plain text, for use in long-form rule descriptions.
+///
+/// The docs aren't part of the published crate, so builds from a crate
+/// tarball get no summaries.
+fn do_audit_docs() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let source = Path::new(&manifest_dir).join("../../docs/audits.md");
+    let target = Path::new(&env::var("OUT_DIR").unwrap()).join("audit-docs.rs");
+
+    println!(
+        "cargo::rerun-if-changed={source}",
+        source = source.display()
+    );
+
+    let contents = fs::read_to_string(source).unwrap_or_default();
+    let mut arms = String::new();
+
+    for section in contents.split("\n## `").skip(1) {
+        let Some((ident, body)) = section.split_once("`\n") else {
+            continue;
+        };
+
+        // The summary is the first paragraph that isn't part of the
+        // section's table, a link definition, or an admonition.
+        let Some(summary) = body
+            .split("\n\n")
+            .map(|para| para.trim_matches('\n'))
+            .find(|para| !para.is_empty() && !para.starts_with(['|', '[', '#', '!', ' ']))
+        else {
+            continue;
+        };
+
+        arms.push_str(&format!(
+            "        {ident:?} => Some({summary:?}),\n",
+            summary = plain_text(summary)
+        ));
+    }
+
+    fs::write(
+        target,
+        format!(
+            "pub(crate) fn audit_docs(ident: &str) -> Option<&'static str> {{\n    match ident {{\n{arms}        _ => None,\n    }}\n}}\n"
+        ),
+    )
+    .unwrap();
+}
+
+/// Reduces a paragraph of the docs' Markdown to plain text: unwraps lines,
+/// drops emphasis, code spans' backticks and syntax hints (`#!yaml ...`),
+/// and replaces links with their text.
+fn plain_text(markdown: &str) -> String {
+    let mut text = String::new();
+    let mut chars = markdown.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    while let Some(idx) = chars.find(['`', '[']) {
+        text.push_str(&chars[..idx].replace('*', ""));
+        let rest = &chars[idx..];
+
+        if let Some(code) = rest.strip_prefix('`') {
+            let Some((code, rest)) = code.split_once('`') else {
+                text.push_str(rest);
+                chars = String::new();
+                break;
+            };
+            let code = match code.strip_prefix("#!") {
+                Some(hinted) => hinted.split_once(' ').map_or(hinted, |(_, code)| code),
+                None => code,
+            };
+            text.push_str(code);
+            chars = rest.to_string();
+        } else {
+            let Some((label, rest)) = rest[1..].split_once(']') else {
+                text.push_str(rest);
+                chars = String::new();
+                break;
+            };
+            text.push_str(&plain_text(label));
+            // Inline links carry their target in parentheses; reference
+            // links are resolved elsewhere in the section.
+            let rest = match rest.strip_prefix('(') {
+                Some(target) => target.split_once(')').map_or("", |(_, rest)| rest),
+                None => rest,
+            };
+            chars = rest.to_string();
+        }
+    }
+
+    text.push_str(&chars.replace('*', ""));
+    text
+}
+
 fn main() {
     do_context_capabilities();
     do_codeql_injection_sinks();
     do_archived_action_repos();
+    do_audit_docs();
+    do_git_sha();
 }
@@ -25,7 +25,8 @@ pub(crate) struct AdhocPackages {
 audit_meta!(
     AdhocPackages,
     "adhoc-packages",
-    "ad-hoc installation of packages"
+    "ad-hoc installation of packages",
+    severity = Low
 );
 
 impl AdhocPackages {
@@ -1,8 +1,13 @@
 pub(crate) struct AnonymousDefinition;
 
+use camino::Utf8Path;
+use yamlpatch::{AddPosition, Op, Patch};
+
 use crate::{
     audit::AuditError,
-    finding::{Confidence, Persona, Severity, location::Locatable as _},
+    finding::{Confidence, Fix, FixDisposition, Persona, Severity, location::Locatable as _},
+    models::workflow::Workflow,
+    registry::input::InputKey,
     state::AuditState,
 };
 
@@ -16,9 +21,40 @@ const ANONYMOUS_DEFINITION_JOB_SEVERITY: Severity = Severity::Informational;
 audit_meta!(
     AnonymousDefinition,
     "anonymous-definition",
-    "workflow or action definition without a name"
+    "workflow or action definition without a name",
+    severity = Low
 );
 
+impl AnonymousDefinition {
+    /// Creates a fix that adds a top-level `name:` to the workflow,
+    /// derived from the workflow's filename.
+    ///
+    /// Returns `None` if the workflow doesn't have a meaningful filename,
+    /// e.g. because it was read from stdin.
+    fn create_add_name_fix(workflow: &Workflow) -> Option<Fix<'_>> {
+        if matches!(workflow.key, InputKey::Stdin(_)) {
+            return None;
+        }
+
+        let name = Utf8Path::new(workflow.key.filename()).file_stem()?;
+
+        Some(Fix {
+            title: format!("add `name: {name}` to the workflow"),
+            key: &workflow.key,
+            disposition: FixDisposition::Safe,
+            patches: vec![Patch {
+                route: yamlpath::route!(),
+                operation: Op::Insert {
+                    key: "name".into(),
+                    value: yaml_serde::Value::String(name.into()),
+                    // Idiomatically, `name:` comes first, right before `on:`.
+                    position: AddPosition::Before("on".into()),
+                },
+            }],
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl Audit for AnonymousDefinition {
     fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
@@ -33,15 +69,18 @@ impl Audit for AnonymousDefinition {
         let mut findings = vec![];
 
         if workflow.name.is_none() {
-            findings.push(
-                Self::finding()
-                    .severity(ANONYMOUS_DEFINITION_WORKFLOW_SEVERITY)
-                    .confidence(Confidence::High)
-                    .persona(Persona::Pedantic)
-                    .add_location(workflow.location().primary())
-                    .tip("use 'name: ...' to give this workflow a name")
-                    .build(workflow)?,
-            );
+            let mut finding = Self::finding()
+                .severity(ANONYMOUS_DEFINITION_WORKFLOW_SEVERITY)
+                .confidence(Confidence::High)
+                .persona(Persona::Pedantic)
+                .add_location(workflow.location().primary())
+                .tip("use 'name: ...' to give this workflow a name");
+
+            if let Some(fix) = Self::create_add_name_fix(workflow) {
+                finding = finding.fix(fix);
+            }
+
+            findings.push(finding.build(workflow)?);
         }
 
         for job in workflow.jobs() {
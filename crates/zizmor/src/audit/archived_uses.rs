@@ -26,7 +26,8 @@ pub(crate) struct ArchivedUses;
 audit_meta!(
     ArchivedUses,
     "archived-uses",
-    "action or reusable workflow from archived repository"
+    "action or reusable workflow from archived repository",
+    severity = Medium
 );
 
 impl ArchivedUses {
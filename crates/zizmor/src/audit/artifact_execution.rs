@@ -0,0 +1,314 @@
+//! Detects jobs that download an artifact and then execute its contents,
+//! e.g. `actions/download-artifact` followed by `run: ./build/install.sh`.
+//!
+//! Artifacts are a common channel for moving untrusted data between
+//! workflows: a `pull_request` workflow can upload an artifact that
+//! a privileged `workflow_run` workflow later downloads. "Executing"
+//! such an artifact (running a script from it, or using an action
+//! from it) gives the author of the upstream workflow code execution
+//! in the downstream one. This is sometimes called "artifact poisoning."
+//!
+//! The detection here is heuristic: we look for `run:` commands that
+//! invoke something from the artifact's download path (or, if no path
+//! is given, from the working directory that the artifact is extracted into),
+//! and for local `uses:` clauses that point into the same.
+
+use github_actions_models::common::{EnvValue, Uses, expr::LoE};
+
+use super::{Audit, AuditLoadError, audit_meta};
+use crate::{
+    audit::AuditError,
+    config::Config,
+    finding::{Confidence, Finding, Persona, Severity, location::Locatable as _},
+    models::{
+        StepBodyCommon, StepCommon as _,
+        uses::RepositoryUsesExt as _,
+        workflow::{JobCommon as _, NormalJob, Step},
+    },
+    state::AuditState,
+};
+
+/// Actions that download artifacts.
+const DOWNLOAD_ACTIONS: &[&str] = &[
+    "actions/download-artifact",
+    "dawidd6/action-download-artifact",
+];
+
+/// `with:` inputs to `actions/download-artifact` that cause it to
+/// download artifacts from a different workflow run.
+const CROSS_RUN_INPUTS: &[&str] = &["run-id", "github-token"];
+
+/// Interpreters that will execute a file passed as their first argument.
+const INTERPRETERS: &[&str] = &[
+    "sh", "bash", "zsh", "dash", "ksh", "source", ".", "python", "python3", "node", "ruby", "perl",
+    "pwsh", "php",
+];
+
+/// Commands that can wrap another command, e.g. `sudo ./install.sh`.
+const WRAPPERS: &[&str] = &["sudo", "env", "exec"];
+
+pub(crate) struct ArtifactExecution;
+
+audit_meta!(
+    ArtifactExecution,
+    "artifact-execution",
+    "execution of downloaded artifact contents",
+    severity = High
+);
+
+/// A step that downloads one or more artifacts.
+struct Download<'doc> {
+    step: Step<'doc>,
+    /// The directory the artifact is extracted into, if explicitly given.
+    ///
+    /// `None` means the artifact is extracted into the working directory,
+    /// or that the path is an expression we can't reason about.
+    path: Option<&'doc str>,
+    /// Whether the artifact comes from a different workflow run.
+    cross_run: bool,
+}
+
+impl<'doc> Download<'doc> {
+    fn from_step(step: Step<'doc>) -> Option<Self> {
+        let Some(StepBodyCommon::Uses {
+            uses: Uses::Repository(uses),
+            with,
+        }) = step.body()
+        else {
+            return None;
+        };
+
+        let action = DOWNLOAD_ACTIONS
+            .iter()
+            .find(|action| uses.matches(action))?;
+
+        let (path, cross_run) = match with {
+            LoE::Literal(with) => {
+                let path = match with.get("path") {
+                    Some(EnvValue::String(path)) if !path.contains("${{") => {
+                        Some(path.trim_start_matches("./").trim_end_matches('/').trim())
+                    }
+                    _ => None,
+                };
+
+                // `dawidd6/action-download-artifact` exists specifically to download
+                // artifacts from other workflow runs.
+                let cross_run = *action == "dawidd6/action-download-artifact"
+                    || CROSS_RUN_INPUTS
+                        .iter()
+                        .any(|input| with.contains_key(*input));
+
+                (path.filter(|p| !p.is_empty() && *p != "."), cross_run)
+            }
+            // We can't see inside the `with:`, so assume the worst.
+            LoE::Expr(_) => (None, true),
+        };
+
+        Some(Self {
+            step,
+            path,
+            cross_run,
+        })
+    }
+
+    /// Returns whether the given path-like `token` refers to something
+    /// inside this download's extraction directory.
+    fn contains(&self, token: &str) -> bool {
+        let token = token
+            .trim_matches(['"', '\''])
+            .trim_start_matches("$GITHUB_WORKSPACE/")
+            .trim_start_matches("${GITHUB_WORKSPACE}/")
+            .trim_start_matches("./");
+
+        match self.path {
+            Some(path) => token
+                .strip_prefix(path)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+            // Without an explicit path, the artifact lands in the working
+            // directory, so any relative path could come from it.
+            None => !token.starts_with('/') && !token.starts_with('-'),
+        }
+    }
+}
+
+impl ArtifactExecution {
+    /// Returns the first path-like token in `command` that is executed
+    /// by it, if any.
+    ///
+    /// This is a heuristic: it recognizes direct execution (`./foo.sh`),
+    /// interpreter execution (`bash foo.sh`), and making a file
+    /// executable (`chmod +x foo`).
+    fn executed_path(command: &str) -> Option<&str> {
+        let mut tokens = command
+            .split_whitespace()
+            .skip_while(|token| WRAPPERS.contains(token) || token.contains('='))
+            .peekable();
+
+        let first = tokens.next()?;
+
+        if first.starts_with("./") || first.starts_with("../") || first.contains("GITHUB_WORKSPACE")
+        {
+            return Some(first);
+        }
+
+        let name = first.rsplit('/').next().unwrap_or(first);
+        if INTERPRETERS.contains(&name) {
+            return tokens.find(|token| !token.starts_with('-'));
+        }
+
+        if name == "chmod" {
+            let mut tokens = tokens.filter(|token| !token.starts_with('-'));
+            let mode = tokens.next()?;
+            if mode.contains('x') || mode.chars().all(|c| c.is_ascii_digit()) {
+                return tokens.next();
+            }
+        }
+
+        None
+    }
+
+    /// Returns the first command in `run` that executes something
+    /// from `download`, if any.
+    fn executes_download<'a>(run: &'a str, download: &Download<'_>) -> Option<&'a str> {
+        run.lines()
+            .flat_map(|line| line.split(['&', ';', '|']))
+            .map(str::trim)
+            .filter(|command| !command.starts_with('#'))
+            .find(|command| Self::executed_path(command).is_some_and(|p| download.contains(p)))
+    }
+
+    fn severity(job: &NormalJob<'_>, download: &Download<'_>) -> (Severity, Persona) {
+        let workflow = job.parent();
+
+        if workflow.has_workflow_run() || workflow.has_pull_request_target() {
+            (Severity::High, Persona::Regular)
+        } else if download.cross_run {
+            (Severity::Medium, Persona::Regular)
+        } else {
+            // Artifacts from the same run are (usually) produced by
+            // the same trust domain as the consuming job.
+            (Severity::Low, Persona::Auditor)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for ArtifactExecution {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_normal_job<'doc>(
+        &self,
+        job: &NormalJob<'doc>,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+        let mut downloads: Vec<Download<'doc>> = vec![];
+
+        for step in job.steps() {
+            // Each step is checked against every download that precedes it.
+            for download in &downloads {
+                let (confidence, location) = match step.body() {
+                    Some(StepBodyCommon::Run { run, .. }) => {
+                        let Some(command) = Self::executes_download(run, download) else {
+                            continue;
+                        };
+
+                        let confidence = match download.path {
+                            Some(_) => Confidence::High,
+                            None => Confidence::Low,
+                        };
+
+                        (
+                            confidence,
+                            step.location()
+                                .primary()
+                                .with_keys(["run".into()])
+                                .subfeature(subfeature::Subfeature::new(0, command))
+                                .annotated("executes downloaded content"),
+                        )
+                    }
+                    Some(StepBodyCommon::Uses {
+                        uses: Uses::Local(local),
+                        ..
+                    }) if download.contains(&local.path) => {
+                        let confidence = match download.path {
+                            Some(_) => Confidence::High,
+                            None => Confidence::Low,
+                        };
+
+                        (
+                            confidence,
+                            step.location()
+                                .primary()
+                                .with_keys(["uses".into()])
+                                .annotated("uses an action from downloaded content"),
+                        )
+                    }
+                    _ => continue,
+                };
+
+                let (severity, persona) = Self::severity(job, download);
+
+                findings.push(
+                    Self::finding()
+                        .severity(severity)
+                        .confidence(confidence)
+                        .persona(persona)
+                        .add_location(
+                            download
+                                .step
+                                .location_with_grip()
+                                .annotated("artifact is downloaded here"),
+                        )
+                        .add_location(location)
+                        .tip("treat artifact contents as untrusted data; don't execute them")
+                        .build(&step)?,
+                );
+
+                // One finding per executing step is enough.
+                break;
+            }
+
+            if let Some(download) = Download::from_step(step) {
+                downloads.push(download);
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_executed_path() {
+        for (command, expected) in &[
+            ("./install.sh", Some("./install.sh")),
+            ("sudo ./dist/install.sh --yes", Some("./dist/install.sh")),
+            ("bash dist/build.sh", Some("dist/build.sh")),
+            ("FOO=bar sh -x dist/build.sh", Some("dist/build.sh")),
+            ("python3 dist/main.py", Some("dist/main.py")),
+            ("source dist/env.sh", Some("dist/env.sh")),
+            ("chmod +x dist/tool", Some("dist/tool")),
+            ("chmod 755 dist/tool", Some("dist/tool")),
+            (
+                "$GITHUB_WORKSPACE/dist/tool",
+                Some("$GITHUB_WORKSPACE/dist/tool"),
+            ),
+            ("cat dist/report.txt", None),
+            ("ls -la", None),
+            ("chmod -R go-w dist", None),
+            ("bash", None),
+        ] {
+            assert_eq!(
+                ArtifactExecution::executed_path(command),
+                *expected,
+                "{command}"
+            );
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::LazyLock;
 
 use github_actions_models::common::{
-    EnvValue, Uses,
+    Env, EnvValue, Uses,
     expr::{ExplicitExpr, LoE},
 };
 use itertools::Itertools as _;
@@ -21,6 +21,10 @@ use yamlpatch::{Op, Patch};
 #[allow(clippy::unwrap_used)]
 static V6: LazyLock<Version> = LazyLock::new(|| Version::parse("v6").unwrap());
 
+/// The dedup key shared by findings for checkouts that persist credentials,
+/// which [`persist_credentials`](super::persist_credentials) also reports.
+pub(crate) const PERSISTED_CREDENTIALS: &str = "persisted-credentials";
+
 pub(crate) struct Artipacked {
     client: Option<Client>,
 }
@@ -28,7 +32,8 @@ pub(crate) struct Artipacked {
 audit_meta!(
     Artipacked,
     "artipacked",
-    "credential persistence through GitHub Actions artifacts"
+    "credential persistence through GitHub Actions artifacts",
+    severity = High
 );
 
 impl Artipacked {
@@ -143,28 +148,15 @@ impl Artipacked {
                     .is_checkout_v6_or_higher(uses)
                     .await
                     .map_err(Self::err)?;
-                match with
-                    .get("persist-credentials")
-                    .map(|v| v.to_string())
-                    .as_deref()
-                {
-                    Some("false") => continue,
-                    Some("true") => {
-                        // If a user explicitly sets `persist-credentials: true`,
-                        // they probably mean it. Only report if in auditor mode.
-                        vulnerable_checkouts.push((step, Persona::Auditor, is_v6_or_higher))
-                    }
-                    Some(v) if ExplicitExpr::from_curly(v).is_some() => {
-                        vulnerable_checkouts.push((step, Persona::Pedantic, is_v6_or_higher));
-                    }
-                    _ => vulnerable_checkouts.push((step, Persona::default(), is_v6_or_higher)),
+                if let Some(persona) = Self::persisted_credentials_persona(with) {
+                    vulnerable_checkouts.push((step, persona, is_v6_or_higher));
                 }
             } else if uses.matches("actions/upload-artifact") {
                 let Some(EnvValue::String(path)) = with.get("path") else {
                     continue;
                 };
 
-                let dangerous_paths = self.dangerous_artifact_patterns(path);
+                let dangerous_paths = Self::dangerous_artifact_patterns(path);
                 if !dangerous_paths.is_empty() {
                     // TODO: plumb dangerous_paths into the annotation here.
                     vulnerable_uploads.push(step)
@@ -184,6 +176,7 @@ impl Artipacked {
                         .severity(severity)
                         .confidence(Confidence::Low)
                         .persona(*persona)
+                        .dedup_key(PERSISTED_CREDENTIALS)
                         .add_location(
                             checkout
                                 .location()
@@ -206,22 +199,26 @@ impl Artipacked {
                     let severity =
                         Self::determine_severity(*is_v6_or_higher, vulnerable_uploads.is_empty());
 
+                    let persisted_by = match checkout.label() {
+                        Some(label) => format!("step {} ({label})", checkout.number()),
+                        None => format!("step {}", checkout.number()),
+                    };
+
                     findings.push(
                         Self::finding()
                             .severity(severity)
                             .confidence(Confidence::High)
                             .persona(*persona)
+                            .dedup_key(PERSISTED_CREDENTIALS)
                             .add_location(
                                 checkout
                                     .location()
                                     .primary()
                                     .annotated("does not set persist-credentials: false"),
                             )
-                            .add_location(
-                                upload
-                                    .location()
-                                    .annotated("may leak the credentials persisted above"),
-                            )
+                            .add_location(upload.location().annotated(format!(
+                                "may leak the credentials persisted by {persisted_by}"
+                            )))
                             .fix(Self::create_persist_credentials_fix(checkout))
                             .build(checkout)?,
                     );
@@ -232,7 +229,27 @@ impl Artipacked {
         Ok(findings)
     }
 
-    fn dangerous_artifact_patterns<'b>(&self, path: &'b str) -> Vec<&'b str> {
+    /// Returns the persona at which a checkout with the given `with:` block
+    /// is reported for persisting credentials, or `None` if it doesn't
+    /// persist them.
+    pub(crate) fn persisted_credentials_persona(with: &Env) -> Option<Persona> {
+        match with
+            .get("persist-credentials")
+            .map(|v| v.to_string())
+            .as_deref()
+        {
+            Some("false") => None,
+            // If a user explicitly sets `persist-credentials: true`,
+            // they probably mean it. Only report if in auditor mode.
+            Some("true") => Some(Persona::Auditor),
+            Some(v) if ExplicitExpr::from_curly(v).is_some() => Some(Persona::Pedantic),
+            _ => Some(Persona::default()),
+        }
+    }
+
+    /// Returns the upload `path:` patterns that this audit considers
+    /// capable of leaking persisted credentials.
+    pub(crate) fn dangerous_artifact_patterns(path: &str) -> Vec<&str> {
         let mut patterns = vec![];
         for path in split_patterns(path) {
             match path {
@@ -23,7 +23,12 @@ use yamlpatch::{Op, Patch};
 
 pub(crate) struct BotConditions;
 
-audit_meta!(BotConditions, "bot-conditions", "spoofable bot actor check");
+audit_meta!(
+    BotConditions,
+    "bot-conditions",
+    "spoofable bot actor check",
+    severity = High
+);
 
 #[allow(clippy::unwrap_used)]
 static SPOOFABLE_ACTOR_NAME_CONTEXTS: LazyLock<Vec<ContextPattern>> = LazyLock::new(|| {
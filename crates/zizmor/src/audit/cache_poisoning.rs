@@ -6,15 +6,14 @@ use github_actions_expressions::op::{BinExpr, BinOp, UnOp};
 use github_actions_expressions::{Expr, SpannedExpr};
 use github_actions_models::common::EnvValue;
 use github_actions_models::common::expr::LoE;
-use github_actions_models::workflow::Trigger;
-use github_actions_models::workflow::event::{BareEvent, BranchFilters, OptionalBody};
+use github_actions_models::workflow::event::{BareEvent, BranchFilters};
 
 use crate::audit::{Audit, AuditError, audit_meta};
 use crate::config::Config;
 use crate::finding::location::{Locatable as _, Routable as _};
 use crate::finding::{Confidence, Finding, Fix, FixDisposition, Severity};
 use crate::models::coordinate::{ActionCoordinate, ControlExpr, ControlFieldType, Toggle, Usage};
-use crate::models::workflow::{JobCommon as _, NormalJob, Step, Steps};
+use crate::models::workflow::{JobCommon as _, NormalJob, Step, Steps, Workflow};
 use crate::models::{StepBodyCommon, StepCommon};
 use crate::state::AuditState;
 use crate::utils::ExtractedExpr;
@@ -404,43 +403,33 @@ pub(crate) struct CachePoisoning;
 audit_meta!(
     CachePoisoning,
     "cache-poisoning",
-    "runtime artifacts potentially vulnerable to a cache poisoning attack"
+    "runtime artifacts potentially vulnerable to a cache poisoning attack",
+    severity = High
 );
 
 impl CachePoisoning {
-    fn triggers_used_when_publishing_artifacts(&self, trigger: &Trigger) -> Vec<ReleaseTrigger> {
-        match trigger {
-            Trigger::BareEvent(BareEvent::Release) => {
-                vec![ReleaseTrigger::ReleaseEvent]
-            }
-            Trigger::BareEvents(events) if events.contains(&BareEvent::Release) => {
-                vec![ReleaseTrigger::ReleaseEvent]
-            }
-            Trigger::Events(events) => {
-                let mut triggers = vec![];
-
-                if let OptionalBody::Body(body) = &events.push {
-                    if body.tag_filters.is_some() {
-                        triggers.push(ReleaseTrigger::TagPush);
-                    }
-
-                    if let Some(BranchFilters::Branches(branches)) = &body.branch_filters
-                        && branches
-                            .iter()
-                            .any(|branch| branch.to_lowercase().contains("release"))
-                    {
-                        triggers.push(ReleaseTrigger::ReleaseBranchPush);
-                    }
-                }
+    fn triggers_used_when_publishing_artifacts(&self, workflow: &Workflow) -> Vec<ReleaseTrigger> {
+        let mut triggers = vec![];
 
-                if !matches!(events.release, OptionalBody::Missing) {
-                    triggers.push(ReleaseTrigger::ReleaseEvent);
-                }
+        if let Some(push) = workflow.trigger(BareEvent::Push) {
+            if push.tag_filters.is_some() {
+                triggers.push(ReleaseTrigger::TagPush);
+            }
 
-                triggers
+            if let Some(BranchFilters::Branches(branches)) = push.branch_filters
+                && branches
+                    .iter()
+                    .any(|branch| branch.to_lowercase().contains("release"))
+            {
+                triggers.push(ReleaseTrigger::ReleaseBranchPush);
             }
-            _ => vec![],
         }
+
+        if workflow.trigger(BareEvent::Release).is_some() {
+            triggers.push(ReleaseTrigger::ReleaseEvent);
+        }
+
+        triggers
     }
 
     fn detected_well_known_publisher_step(steps: Steps) -> Option<Step> {
@@ -455,10 +444,10 @@ impl CachePoisoning {
 
     fn is_job_publishing_artifacts<'doc>(
         &self,
-        trigger: &Trigger,
+        workflow: &Workflow,
         steps: Steps<'doc>,
     ) -> Option<PublishingScenario<'doc>> {
-        let triggers = self.triggers_used_when_publishing_artifacts(trigger);
+        let triggers = self.triggers_used_when_publishing_artifacts(workflow);
         if !triggers.is_empty() {
             return Some(PublishingScenario::UsingReleaseTriggers(triggers));
         };
@@ -689,9 +678,9 @@ impl Audit for CachePoisoning {
     ) -> Result<Vec<Finding<'doc>>, AuditError> {
         let mut findings = vec![];
         let steps = job.steps();
-        let trigger = &job.parent().on;
+        let workflow = job.parent();
 
-        let Some(scenario) = self.is_job_publishing_artifacts(trigger, steps) else {
+        let Some(scenario) = self.is_job_publishing_artifacts(workflow, steps) else {
             return Ok(findings);
         };
 
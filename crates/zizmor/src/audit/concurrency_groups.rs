@@ -0,0 +1,127 @@
+//! Detects `concurrency.group` values that don't discriminate between
+//! refs or pull requests, e.g. `concurrency: ${{ github.workflow }}`.
+//!
+//! Such groups are shared by every run of the workflow (or job), meaning
+//! that unrelated runs (e.g. a push to `main` and a pull request) will
+//! queue behind or cancel each other rather than only deduplicating
+//! runs for the same ref.
+//!
+//! This is a heuristic: we look for a context that's known to vary
+//! per-ref or per-PR, and bail if the group references a context we
+//! can't see through (like `inputs.*` or `env.*`).
+
+use std::sync::LazyLock;
+
+use github_actions_expressions::{
+    Expr,
+    context::{ContextPattern, ContextRoot},
+};
+
+use super::{Audit, AuditLoadError, audit_meta};
+use crate::{
+    audit::AuditError,
+    config::Config,
+    finding::{Confidence, Finding, Persona, Severity},
+    models::workflow::{ConcurrencyGroup, ConcurrencyScope, Workflow},
+    state::AuditState,
+};
+
+pub(crate) struct ConcurrencyGroups;
+
+audit_meta!(
+    ConcurrencyGroups,
+    "concurrency-groups",
+    "concurrency group without a per-ref or per-PR discriminator",
+    severity = Low
+);
+
+/// Contexts that vary per-ref or per-PR (or more finely), and therefore
+/// make a concurrency group specific enough to only deduplicate related runs.
+#[allow(clippy::unwrap_used)]
+static DISCRIMINATOR_CONTEXTS: LazyLock<Vec<ContextPattern>> = LazyLock::new(|| {
+    vec![
+        ContextPattern::try_new("github.ref").unwrap(),
+        ContextPattern::try_new("github.ref_name").unwrap(),
+        ContextPattern::try_new("github.head_ref").unwrap(),
+        ContextPattern::try_new("github.sha").unwrap(),
+        ContextPattern::try_new("github.run_id").unwrap(),
+        ContextPattern::try_new("github.event.number").unwrap(),
+        ContextPattern::try_new("github.event.pull_request.number").unwrap(),
+        ContextPattern::try_new("github.event.pull_request.head.ref").unwrap(),
+        ContextPattern::try_new("github.event.pull_request.head.sha").unwrap(),
+    ]
+});
+
+impl ConcurrencyGroups {
+    /// Returns whether the given group is known to lack a discriminator.
+    ///
+    /// Returns `false` if the group contains a discriminator, or if it
+    /// contains an expression we can't analyze.
+    fn lacks_discriminator(group: &ConcurrencyGroup) -> bool {
+        for expr in group.expressions() {
+            let Ok(parsed) = Expr::parse(expr.as_bare()) else {
+                tracing::warn!("couldn't parse expression: {expr}", expr = expr.as_bare());
+                return false;
+            };
+
+            for (ctx, _) in parsed.contexts() {
+                if DISCRIMINATOR_CONTEXTS.iter().any(|pat| pat.matches(ctx)) {
+                    return false;
+                }
+
+                // These can carry a discriminator that we can't see.
+                if matches!(
+                    ctx.root_kind(),
+                    Some(ContextRoot::Inputs | ContextRoot::Env | ContextRoot::Vars)
+                ) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for ConcurrencyGroups {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_workflow<'doc>(
+        &self,
+        workflow: &'doc Workflow,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        // Like with `concurrency-limits`, reusable-only workflows are
+        // expected to have their concurrency managed by their callers.
+        if workflow.is_reusable_only() {
+            return Ok(vec![]);
+        }
+
+        let mut findings = vec![];
+        for group in workflow.concurrency_groups() {
+            if !Self::lacks_discriminator(&group) {
+                continue;
+            }
+
+            let annotation = match group.scope {
+                ConcurrencyScope::Workflow => "workflow runs share this group across all refs",
+                ConcurrencyScope::Job => "job runs share this group across all refs",
+            };
+
+            findings.push(
+                Self::finding()
+                    .confidence(Confidence::Medium)
+                    .severity(Severity::Low)
+                    .persona(Persona::Pedantic)
+                    .add_location(group.location.primary().annotated(annotation))
+                    .tip("include a per-ref discriminator like `${{ github.ref }}` in the group")
+                    .build(workflow)?,
+            );
+        }
+
+        Ok(findings)
+    }
+}
@@ -14,7 +14,8 @@ pub(crate) struct ConcurrencyLimits;
 audit_meta!(
     ConcurrencyLimits,
     "concurrency-limits",
-    "insufficient job-level concurrency limits"
+    "insufficient job-level concurrency limits",
+    severity = Low
 );
 
 #[async_trait::async_trait]
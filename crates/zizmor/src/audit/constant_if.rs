@@ -0,0 +1,121 @@
+//! Detects `if:` conditions that always evaluate to the same truthiness.
+//!
+//! Conditions like `if: true` and `if: ${{ false }}` are either redundant
+//! or a sign of a logic error, e.g. `if: ${{ 'false' }}` is always true
+//! because non-empty strings are truthy.
+
+use github_actions_models::common;
+
+use super::{Audit, AuditLoadError, Job, audit_meta};
+use crate::{
+    audit::AuditError,
+    finding::{
+        Confidence, Finding, Persona, Severity,
+        location::{Locatable as _, SymbolicLocation},
+    },
+    models::{AsDocument, if_static_truthiness},
+    state::AuditState,
+};
+
+pub(crate) struct ConstantIf;
+
+audit_meta!(
+    ConstantIf,
+    "constant-if",
+    "condition always evaluates to the same result",
+    severity = Informational
+);
+
+impl ConstantIf {
+    fn process_conditions<'a, 'doc>(
+        &self,
+        doc: &'a impl AsDocument<'a, 'doc>,
+        conditions: impl Iterator<Item = (&'doc common::If, SymbolicLocation<'doc>)>,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        for (cond, loc) in conditions {
+            let Some(truthiness) = if_static_truthiness(cond) else {
+                continue;
+            };
+
+            let (annotation, tip) = if truthiness {
+                (
+                    "condition is always true, so this always runs",
+                    "remove the redundant condition",
+                )
+            } else {
+                (
+                    "condition is always false, so this never runs",
+                    "remove the condition, or remove the unreachable definition",
+                )
+            };
+
+            findings.push(
+                Self::finding()
+                    .severity(Severity::Informational)
+                    .confidence(Confidence::High)
+                    .persona(Persona::Pedantic)
+                    .add_location(loc.clone().hidden())
+                    .add_location(loc.with_keys(["if".into()]).primary().annotated(annotation))
+                    .tip(tip)
+                    .build(doc)?,
+            );
+        }
+
+        Ok(findings)
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for ConstantIf {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_workflow<'doc>(
+        &self,
+        workflow: &'doc crate::models::workflow::Workflow,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        // NOTE: We iterate over disabled jobs and steps too, since
+        // statically false conditions are exactly what we're looking for.
+        for job in workflow.jobs().with_disabled() {
+            match job {
+                Job::NormalJob(normal) => {
+                    let job_cond = normal.r#if.iter().map(|cond| (cond, normal.location()));
+                    let step_conds = normal
+                        .steps()
+                        .with_disabled()
+                        .filter_map(|step| step.r#if().map(|cond| (cond, step.location())));
+                    let conds = job_cond.chain(step_conds);
+
+                    findings.extend(self.process_conditions(workflow, conds)?);
+                }
+                Job::ReusableWorkflowCallJob(reusable) => {
+                    let conds = reusable.r#if.iter().map(|cond| (cond, reusable.location()));
+
+                    findings.extend(self.process_conditions(workflow, conds)?);
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+
+    async fn audit_action<'doc>(
+        &self,
+        action: &'doc crate::models::action::Action,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let conds = action
+            .steps()
+            .into_iter()
+            .flat_map(|steps| steps.with_disabled())
+            .filter_map(|step| step.r#if.as_ref().map(|cond| (cond, step.location())));
+
+        self.process_conditions(action, conds)
+    }
+}
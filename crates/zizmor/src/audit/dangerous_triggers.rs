@@ -1,21 +1,28 @@
+use github_actions_expressions::{Expr, context::ContextRoot};
 use github_actions_models::common::Uses;
-use github_actions_models::workflow::Job;
+use github_actions_models::workflow::event::{BareEvent, OptionalBody, PathFilters};
 use github_actions_models::workflow::job::{Step, UsesStep};
+use github_actions_models::workflow::{Job, Trigger};
+use subfeature::Subfeature;
+use yamlpatch::{Op, Patch};
 
 use super::{Audit, AuditLoadError, audit_meta};
 use crate::audit::AuditError;
 use crate::config::Config;
-use crate::finding::{Confidence, Finding, Severity};
+use crate::finding::{Confidence, Finding, Fix, FixDisposition, Severity};
+use crate::models::AsDocument as _;
 use crate::models::uses::RepositoryUsesExt as _;
 use crate::models::workflow::Workflow;
 use crate::state::AuditState;
+use crate::utils::extract_fenced_expressions;
 
 pub(crate) struct DangerousTriggers;
 
 audit_meta!(
     DangerousTriggers,
     "dangerous-triggers",
-    "use of fundamentally insecure workflow trigger"
+    "use of fundamentally insecure workflow trigger",
+    severity = High
 );
 
 impl DangerousTriggers {
@@ -38,6 +45,83 @@ impl DangerousTriggers {
         }
         false
     }
+
+    /// Returns whether the workflow (potentially) uses any secrets, either
+    /// via a `secrets.*` context or by passing secrets to a reusable workflow.
+    ///
+    /// Expressions that we can't parse are conservatively assumed to use secrets.
+    fn uses_secrets(workflow: &Workflow) -> bool {
+        if workflow
+            .jobs
+            .values()
+            .any(|job| matches!(job, Job::ReusableWorkflowCallJob(job) if job.secrets.is_some()))
+        {
+            return true;
+        }
+
+        extract_fenced_expressions(workflow.as_document().source())
+            .iter()
+            .any(|(expr, _)| match Expr::parse(expr.as_bare()) {
+                Ok(expr) => expr
+                    .contexts()
+                    .iter()
+                    .any(|(ctx, _)| ctx.root_kind() == Some(ContextRoot::Secrets)),
+                Err(_) => true,
+            })
+    }
+
+    /// Creates a fix that replaces `pull_request_target` with `pull_request`.
+    ///
+    /// This is only offered when the workflow doesn't appear to use any secrets,
+    /// and isn't already triggered by `pull_request`. Even then, the workflow
+    /// may depend on `pull_request_target`'s other semantics (e.g. a write token
+    /// or running in the context of the base branch), so the fix is unsafe.
+    fn create_pull_request_fix(workflow: &Workflow) -> Option<Fix<'_>> {
+        if Self::uses_secrets(workflow) {
+            return None;
+        }
+
+        let patch = match &workflow.on {
+            Trigger::BareEvent(BareEvent::PullRequestTarget) => Patch {
+                route: yamlpath::route!("on"),
+                operation: Op::Replace("pull_request".into()),
+            },
+            Trigger::BareEvents(events) if !events.contains(&BareEvent::PullRequest) => {
+                let idx = events
+                    .iter()
+                    .position(|event| *event == BareEvent::PullRequestTarget)?;
+
+                Patch {
+                    route: yamlpath::route!("on", idx),
+                    operation: Op::Replace("pull_request".into()),
+                }
+            }
+            Trigger::Events(events) if matches!(events.pull_request, OptionalBody::Missing) => {
+                // We rename the `pull_request_target:` key in place, preserving
+                // its body (e.g. any `types:` or `branches:` filters).
+                let key = workflow
+                    .as_document()
+                    .query_key_only(&yamlpath::route!("on", "pull_request_target"))
+                    .ok()?;
+
+                Patch {
+                    route: yamlpath::route!(),
+                    operation: Op::RewriteFragment {
+                        from: Subfeature::new(key.location.byte_span.0, "pull_request_target"),
+                        to: "pull_request".into(),
+                    },
+                }
+            }
+            _ => return None,
+        };
+
+        Some(Fix {
+            title: "replace pull_request_target with pull_request".into(),
+            key: &workflow.key,
+            disposition: FixDisposition::Unsafe,
+            patches: vec![patch],
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -52,20 +136,56 @@ impl Audit for DangerousTriggers {
         _config: &Config,
     ) -> Result<Vec<Finding<'doc>>, AuditError> {
         let mut findings = vec![];
-        if workflow.has_pull_request_target() && !Self::is_labeler_exception(workflow) {
-            findings.push(
-                Self::finding()
-                    .confidence(Confidence::Medium)
-                    .severity(Severity::High)
-                    .add_location(
-                        workflow
-                            .location()
-                            .primary()
-                            .with_keys(["on".into()])
-                            .annotated("pull_request_target is almost always used insecurely"),
-                    )
-                    .build(workflow)?,
-            );
+        if let Some(trigger) = workflow.trigger(BareEvent::PullRequestTarget)
+            && !Self::is_labeler_exception(workflow)
+        {
+            // A `pull_request_target` that only fires when a pull request
+            // is closed can't be re-triggered by pushing to it, which makes
+            // it harder (but not impossible) to abuse.
+            let (severity, annotation) = if trigger.only_types(&["closed"]) {
+                (
+                    Severity::Medium,
+                    "pull_request_target only runs on closed pull requests, but is still dangerous",
+                )
+            } else {
+                (
+                    Severity::High,
+                    "pull_request_target is almost always used insecurely",
+                )
+            };
+
+            let mut finding = Self::finding()
+                .confidence(Confidence::Medium)
+                .severity(severity)
+                .add_location(
+                    workflow
+                        .location()
+                        .primary()
+                        .with_keys(["on".into()])
+                        .annotated(annotation),
+                );
+
+            // Path filters are commonly mistaken for a mitigation, but
+            // a fork can change whatever paths it likes.
+            if let Some(filters) = trigger.path_filters {
+                let key = match filters {
+                    PathFilters::Paths(_) => "paths",
+                    PathFilters::PathsIgnore(_) => "paths-ignore",
+                };
+
+                finding = finding.add_location(
+                    workflow
+                        .location()
+                        .with_keys(["on".into(), "pull_request_target".into(), key.into()])
+                        .annotated("path filters don't prevent forks from triggering this"),
+                );
+            }
+
+            if let Some(fix) = Self::create_pull_request_fix(workflow) {
+                finding = finding.fix(fix);
+            }
+
+            findings.push(finding.build(workflow)?);
         }
         if workflow.has_workflow_run() {
             findings.push(
@@ -86,3 +206,143 @@ impl Audit for DangerousTriggers {
         Ok(findings)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::input::InputKey;
+
+    /// Audits the given workflow, returning the result of applying each fix.
+    async fn fixed(workflow_content: &str) -> Vec<String> {
+        let key = InputKey::local("fakegroup".into(), "test.yml", None, None);
+        let workflow = Workflow::from_string(workflow_content.into(), key).unwrap();
+        let audit = DangerousTriggers::new(&AuditState::default()).unwrap();
+
+        audit
+            .audit_workflow(&workflow, &Config::default())
+            .await
+            .unwrap()
+            .iter()
+            .flat_map(|finding| &finding.fixes)
+            .map(|fix| {
+                assert_eq!(fix.disposition, FixDisposition::Unsafe);
+                fix.apply(workflow.as_document())
+                    .unwrap()
+                    .source()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_pull_request_target_types_and_paths() {
+        let audit = DangerousTriggers::new(&AuditState::default()).unwrap();
+
+        for (workflow_content, severity, locations) in [
+            ("on: pull_request_target\njobs: {}\n", Severity::High, 1),
+            (
+                "on:\n  pull_request_target:\n    types: [opened]\njobs: {}\n",
+                Severity::High,
+                1,
+            ),
+            (
+                "on:\n  pull_request_target:\n    types: [opened, closed]\njobs: {}\n",
+                Severity::High,
+                1,
+            ),
+            // Only closed pull requests are less dangerous.
+            (
+                "on:\n  pull_request_target:\n    types: [closed]\njobs: {}\n",
+                Severity::Medium,
+                1,
+            ),
+            // Path filters are called out, but don't change anything.
+            (
+                "on:\n  pull_request_target:\n    paths: [src/**]\njobs: {}\n",
+                Severity::High,
+                2,
+            ),
+            (
+                "on:\n  pull_request_target:\n    types: [closed]\n    paths-ignore: [docs/**]\njobs: {}\n",
+                Severity::Medium,
+                2,
+            ),
+        ] {
+            let key = InputKey::local("fakegroup".into(), "test.yml", None, None);
+            let workflow = Workflow::from_string(workflow_content.into(), key).unwrap();
+            let findings = audit
+                .audit_workflow(&workflow, &Config::default())
+                .await
+                .unwrap();
+
+            let [finding] = findings.as_slice() else {
+                panic!("expected exactly one finding for {workflow_content:?}");
+            };
+            assert_eq!(
+                finding.determinations.severity, severity,
+                "{workflow_content}"
+            );
+            assert_eq!(finding.locations.len(), locations, "{workflow_content}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pull_request_fix() {
+        for (workflow_content, expected) in &[
+            (
+                "on: pull_request_target\njobs: {}\n",
+                "on: pull_request\njobs: {}\n",
+            ),
+            (
+                "on: [push, pull_request_target]\njobs: {}\n",
+                "on: [push, pull_request]\njobs: {}\n",
+            ),
+            (
+                "on:\n  # pull_request_target is dangerous\n  pull_request_target:\n    types: [opened]\njobs: {}\n",
+                "on:\n  # pull_request_target is dangerous\n  pull_request:\n    types: [opened]\njobs: {}\n",
+            ),
+        ] {
+            assert_eq!(fixed(workflow_content).await, [*expected]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pull_request_fix_not_offered() {
+        for workflow_content in &[
+            // Uses a secret.
+            r#"
+on: pull_request_target
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - run: echo "${{ secrets.TOKEN }}"
+"#,
+            // Uses a secret, via an index.
+            r#"
+on: pull_request_target
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - run: echo "${{ SECRETS['TOKEN'] }}"
+"#,
+            // Passes secrets to a reusable workflow.
+            r#"
+on: pull_request_target
+jobs:
+  test:
+    uses: ./.github/workflows/reusable.yml
+    secrets: inherit
+"#,
+            // Already triggered by `pull_request`.
+            "on: [pull_request, pull_request_target]\njobs: {}\n",
+            "on:\n  pull_request:\n  pull_request_target:\njobs: {}\n",
+        ] {
+            assert!(
+                fixed(workflow_content).await.is_empty(),
+                "{workflow_content}"
+            );
+        }
+    }
+}
@@ -8,7 +8,8 @@ use yamlpatch::{Op, Patch};
 audit_meta!(
     DependabotCooldown,
     "dependabot-cooldown",
-    "insufficient cooldown in Dependabot updates"
+    "insufficient cooldown in Dependabot updates",
+    severity = Medium
 );
 
 pub(crate) struct DependabotCooldown;
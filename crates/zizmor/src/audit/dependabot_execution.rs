@@ -1,7 +1,7 @@
 use github_actions_models::dependabot::v2::AllowDeny;
 
 use crate::{
-    audit::{Audit, AuditError, audit_meta},
+    audit::{Audit, AuditError, audit_meta, dependabot_registries::DependabotRegistries},
     finding::{Fix, FixDisposition, location::Locatable as _},
 };
 use yamlpatch::{Op, Patch};
@@ -9,7 +9,8 @@ use yamlpatch::{Op, Patch};
 audit_meta!(
     DependabotExecution,
     "dependabot-execution",
-    "external code execution in Dependabot updates"
+    "external code execution in Dependabot updates",
+    severity = High
 );
 
 pub(crate) struct DependabotExecution;
@@ -50,18 +51,42 @@ impl Audit for DependabotExecution {
 
         for update in dependabot.updates() {
             if matches!(update.insecure_external_code_execution, AllowDeny::Allow) {
+                // `*` makes every configured registry available to the update.
+                let wildcard = update.registries.iter().any(|r| r == "*");
+                let exposed = dependabot
+                    .registries
+                    .iter()
+                    .filter(|(name, _)| wildcard || update.registries.contains(name))
+                    .filter(|(_, registry)| !DependabotRegistries::credentials(registry).is_empty())
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>();
+
+                let mut finding = Self::finding()
+                    .confidence(crate::finding::Confidence::High)
+                    .severity(crate::finding::Severity::High)
+                    .add_location(
+                        update
+                            .location()
+                            .with_keys(["insecure-external-code-execution".into()])
+                            .primary()
+                            .annotated("enabled here"),
+                    )
+                    .add_location(update.location_with_grip());
+
+                if !exposed.is_empty() {
+                    finding = finding.add_location(
+                        update
+                            .location()
+                            .with_keys(["registries".into()])
+                            .annotated(format!(
+                                "credentials for {registries} are exposed to external code",
+                                registries = exposed.join(", ")
+                            )),
+                    );
+                }
+
                 findings.push(
-                    Self::finding()
-                        .confidence(crate::finding::Confidence::High)
-                        .severity(crate::finding::Severity::High)
-                        .add_location(
-                            update
-                                .location()
-                                .with_keys(["insecure-external-code-execution".into()])
-                                .primary()
-                                .annotated("enabled here"),
-                        )
-                        .add_location(update.location_with_grip())
+                    finding
                         .fix(Self::create_set_deny_fix(update))
                         .build(dependabot)?,
                 );
@@ -0,0 +1,93 @@
+//! Detects Dependabot registry credentials that are hard-coded rather
+//! than referenced via `${{ secrets.* }}`.
+//!
+//! Update rules that expose registry credentials to insecure external
+//! code execution are reported by `dependabot-execution`.
+
+use github_actions_models::{common::expr::ExplicitExpr, dependabot::v2::Registry};
+
+use crate::{
+    audit::{Audit, AuditError, AuditLoadError, audit_meta},
+    config::Config,
+    finding::{Confidence, Finding, Severity},
+    models::dependabot::Dependabot,
+    state::AuditState,
+};
+
+audit_meta!(
+    DependabotRegistries,
+    "dependabot-registries",
+    "exposed credentials in Dependabot registries",
+    severity = High
+);
+
+pub(crate) struct DependabotRegistries;
+
+impl DependabotRegistries {
+    /// Returns each credential field that's set on the given registry,
+    /// as `(field name, value)` pairs.
+    pub(crate) fn credentials(registry: &Registry) -> Vec<(&'static str, &str)> {
+        let (field, value) = match registry {
+            Registry::CargoRegistry { token, .. } => return vec![("token", token.as_str())],
+            Registry::HexOrganization { key, .. } => ("key", key),
+            Registry::HexRepository { auth_key, .. } => ("auth-key", auth_key),
+            Registry::TerraformRegistry { token, .. } => ("token", token),
+            Registry::ComposerRepository { password, .. }
+            | Registry::DockerRegistry { password, .. }
+            | Registry::Git { password, .. }
+            | Registry::MavenRepository { password, .. }
+            | Registry::NpmRegistry { password, .. }
+            | Registry::NugetFeed { password, .. }
+            | Registry::PythonIndex { password, .. }
+            | Registry::RubygemsServer { password, .. } => ("password", password),
+        };
+
+        value.iter().map(|value| (field, value.as_str())).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for DependabotRegistries {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError>
+    where
+        Self: Sized,
+    {
+        Ok(Self)
+    }
+
+    async fn audit_dependabot<'doc>(
+        &self,
+        dependabot: &'doc Dependabot,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        for (name, registry) in &dependabot.registries {
+            for (field, value) in Self::credentials(registry) {
+                // If the credential doesn't parse as an expression, it's hardcoded.
+                if ExplicitExpr::from_curly(value).is_none() {
+                    findings.push(
+                        Self::finding()
+                            .severity(Severity::High)
+                            .confidence(Confidence::High)
+                            .add_location(
+                                dependabot
+                                    .location()
+                                    .with_keys([
+                                        "registries".into(),
+                                        name.as_str().into(),
+                                        field.into(),
+                                    ])
+                                    .primary()
+                                    .annotated("registry credential is hard-coded"),
+                            )
+                            .tip("store the credential as a Dependabot secret and reference it with ${{ secrets.NAME }}")
+                            .build(dependabot)?,
+                    );
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+}
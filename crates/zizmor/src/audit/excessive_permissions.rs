@@ -2,12 +2,15 @@ use std::{collections::HashMap, sync::LazyLock};
 
 use github_actions_models::common::{BasePermission, Permission, Permissions};
 
+use yamlpatch::{AddPosition, Op, Patch};
+
 use super::{Audit, AuditLoadError, Job, audit_meta};
 use crate::audit::AuditError;
 use crate::finding::location::Locatable as _;
+use crate::models::workflow::Workflow;
 use crate::{
     AuditState,
-    finding::{Confidence, Persona, Severity, location::SymbolicLocation},
+    finding::{Confidence, Fix, FixDisposition, Persona, Severity, location::SymbolicLocation},
 };
 
 // Subjective mapping of permissions to severities, when given `write` access.
@@ -39,7 +42,9 @@ static KNOWN_PERMISSIONS: LazyLock<HashMap<&str, Severity>> = LazyLock::new(|| {
 audit_meta!(
     ExcessivePermissions,
     "excessive-permissions",
-    "overly broad permissions"
+    "overly broad permissions",
+    severity = High,
+    cwes = [250]
 );
 
 pub(crate) struct ExcessivePermissions;
@@ -55,7 +60,7 @@ impl Audit for ExcessivePermissions {
 
     async fn audit_workflow<'doc>(
         &self,
-        workflow: &'doc crate::models::workflow::Workflow,
+        workflow: &'doc Workflow,
         _config: &crate::config::Config,
     ) -> Result<Vec<crate::finding::Finding<'doc>>, AuditError> {
         let mut findings = vec![];
@@ -68,6 +73,14 @@ impl Audit for ExcessivePermissions {
             })
             .all(|perm| !matches!(perm, Permissions::Base(BasePermission::Default)));
 
+        let no_job_has_permissions = workflow
+            .jobs()
+            .map(|job| match job {
+                Job::NormalJob(job) => &job.permissions,
+                Job::ReusableWorkflowCallJob(job) => &job.permissions,
+            })
+            .all(|perm| matches!(perm, Permissions::Base(BasePermission::Default)));
+
         let explicit_parent_permissions = !matches!(
             &workflow.permissions,
             Permissions::Base(BasePermission::Default)
@@ -76,18 +89,34 @@ impl Audit for ExcessivePermissions {
         let workflow_is_reusable_only =
             workflow.has_workflow_call() && workflow.has_single_trigger();
 
+        // Workflows that only run when a maintainer dispatches them (or on a
+        // schedule) can't be triggered by outside activity, so their broad
+        // permissions are lower risk. We still flag them, but only for
+        // pedantic users.
+        let workflow_is_manual_only = workflow.is_manual_or_scheduled_only();
+        let manual_only_location = workflow_is_manual_only.then(|| {
+            workflow
+                .location()
+                .with_keys(["on".into()])
+                .annotated("only triggered manually or on a schedule")
+        });
+
         // Top-level permissions are a pedantic finding under the following
         // conditions:
         //
         // 1. The workflow has only one job.
         // 2. All jobs in the workflow have their own explicit permissions.
         // 3. The workflow is reusable and has only one trigger.
-        let workflow_finding_persona =
-            if workflow.jobs.len() == 1 || all_jobs_have_permissions || workflow_is_reusable_only {
-                Persona::Pedantic
-            } else {
-                Persona::Regular
-            };
+        // 4. The workflow is only triggered manually or on a schedule.
+        let workflow_finding_persona = if workflow.jobs.len() == 1
+            || all_jobs_have_permissions
+            || workflow_is_reusable_only
+            || workflow_is_manual_only
+        {
+            Persona::Pedantic
+        } else {
+            Persona::Regular
+        };
 
         // Handle top-level permissions.
         let location = workflow.location().primary();
@@ -95,22 +124,41 @@ impl Audit for ExcessivePermissions {
         for (severity, confidence, perm_location) in
             self.check_workflow_permissions(&workflow.permissions, location)
         {
-            findings.push(
-                Self::finding()
-                    .severity(severity)
-                    .confidence(confidence)
-                    .persona(workflow_finding_persona)
-                    .add_location(perm_location)
-                    .build(workflow)?,
-            );
+            let mut finding = Self::finding()
+                .severity(severity)
+                .confidence(confidence)
+                .persona(workflow_finding_persona)
+                .add_location(perm_location);
+
+            if let Some(location) = &manual_only_location {
+                finding = finding.add_location(location.clone());
+            }
+
+            // If nothing in the workflow declares permissions, every job
+            // inherits the repository's default permissions (which may be
+            // write-all). The simplest remediation is to clear them at
+            // the top level, and re-grant them to jobs that need them.
+            if !explicit_parent_permissions && no_job_has_permissions {
+                finding = finding.tip(
+                    "set `permissions: {}` at the workflow level, and grant any needed permissions to individual jobs",
+                );
+            }
+
+            if let Some(fix) =
+                Self::create_empty_permissions_fix(workflow, all_jobs_have_permissions)
+            {
+                finding = finding.fix(fix);
+            }
+
+            findings.push(finding.build(workflow)?);
         }
 
         for job in workflow.jobs() {
             let (permissions, job_location, job_finding_persona) = match job {
                 Job::NormalJob(job) => {
-                    // For normal jobs: if the workflow is reusable-only, we
-                    // emit pedantic findings.
-                    let persona = if workflow_is_reusable_only {
+                    // For normal jobs: if the workflow is reusable-only or
+                    // manual-only, we emit pedantic findings.
+                    let persona = if workflow_is_reusable_only || workflow_is_manual_only {
                         Persona::Pedantic
                     } else {
                         Persona::Regular
@@ -121,7 +169,7 @@ impl Audit for ExcessivePermissions {
                 Job::ReusableWorkflowCallJob(job) => {
                     // For reusable jobs: the caller is always responsible for
                     // permissions, so we emit regular findings even if
-                    // the workflow is reusable-only.
+                    // the workflow is reusable-only or manual-only.
                     (&job.permissions, job.location(), Persona::Regular)
                 }
             };
@@ -131,15 +179,18 @@ impl Audit for ExcessivePermissions {
                 explicit_parent_permissions,
                 job_location.clone(),
             ) {
-                findings.push(
-                    Self::finding()
-                        .severity(severity)
-                        .confidence(confidence)
-                        .persona(job_finding_persona)
-                        .add_location(job_location)
-                        .add_location(perm_location.primary())
-                        .build(workflow)?,
-                )
+                let mut finding = Self::finding()
+                    .severity(severity)
+                    .confidence(confidence)
+                    .persona(job_finding_persona)
+                    .add_location(job_location)
+                    .add_location(perm_location.primary());
+
+                if let Some(location) = &manual_only_location {
+                    finding = finding.add_location(location.clone());
+                }
+
+                findings.push(finding.build(workflow)?);
             }
         }
 
@@ -148,6 +199,48 @@ impl Audit for ExcessivePermissions {
 }
 
 impl ExcessivePermissions {
+    /// Creates a fix that clears the workflow's default permissions
+    /// by adding `permissions: {}` at the top level.
+    ///
+    /// Returns `None` if the workflow already has a top-level
+    /// `permissions:` block.
+    ///
+    /// The fix is only safe if every job declares its own permissions,
+    /// since any job that doesn't will lose the permissions it currently
+    /// inherits.
+    fn create_empty_permissions_fix(
+        workflow: &Workflow,
+        all_jobs_have_permissions: bool,
+    ) -> Option<Fix<'_>> {
+        // NOTE: `Default` means that the `permissions:` key is absent,
+        // since an explicitly null `permissions:` fails schema validation.
+        if !matches!(
+            workflow.permissions,
+            Permissions::Base(BasePermission::Default)
+        ) {
+            return None;
+        }
+
+        Some(Fix {
+            title: "add `permissions: {}` to the workflow".into(),
+            key: &workflow.key,
+            disposition: if all_jobs_have_permissions {
+                FixDisposition::Safe
+            } else {
+                FixDisposition::Unsafe
+            },
+            patches: vec![Patch {
+                route: yamlpath::route!(),
+                operation: Op::Insert {
+                    key: "permissions".into(),
+                    value: yaml_serde::Value::Mapping(Default::default()),
+                    // Idiomatically, `permissions:` comes right after `on:`.
+                    position: AddPosition::After("on".into()),
+                },
+            }],
+        })
+    }
+
     fn check_workflow_permissions<'a>(
         &self,
         permissions: &'a Permissions,
@@ -245,3 +338,96 @@ impl ExcessivePermissions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, models::AsDocument as _, registry::input::InputKey};
+
+    /// Audits the given workflow, returning each of its permission fixes.
+    async fn fixes(workflow: &Workflow) -> Vec<Fix<'_>> {
+        let audit = ExcessivePermissions::new(&AuditState::default()).unwrap();
+
+        audit
+            .audit_workflow(workflow, &Config::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .flat_map(|finding| finding.fixes)
+            .collect()
+    }
+
+    fn workflow(content: &str) -> Workflow {
+        let key = InputKey::local("fakegroup".into(), "test.yml", None, None);
+        Workflow::from_string(content.into(), key).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_empty_permissions_fix() {
+        let original = workflow(
+            "on: push
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - run: echo hello
+",
+        );
+
+        // The job relies on the default permissions, which the fix removes.
+        let fixes = fixes(&original).await;
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].disposition, FixDisposition::Unsafe);
+
+        let fixed = fixes[0].apply(original.as_document()).unwrap();
+        insta::assert_snapshot!(fixed.source(), @"
+        on: push
+        permissions: {}
+
+        jobs:
+          test:
+            runs-on: ubuntu-latest
+            steps:
+              - run: echo hello
+        ");
+
+        // Re-auditing the fixed workflow produces no further fixes.
+        let fixed = workflow(fixed.source());
+        assert!(super::tests::fixes(&fixed).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_empty_permissions_fix_safe() {
+        // Every job declares its own permissions, so clearing the
+        // workflow's permissions doesn't affect any of them.
+        let original = workflow(
+            "on: push
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    permissions:
+      contents: read
+    steps:
+      - run: echo hello
+",
+        );
+
+        let fixes = fixes(&original).await;
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].disposition, FixDisposition::Safe);
+    }
+
+    #[tokio::test]
+    async fn test_empty_permissions_fix_not_offered() {
+        for content in [
+            "on: push\npermissions: {}\njobs: {}\n",
+            "on: push\npermissions: read-all\njobs: {}\n",
+            "on: push\npermissions:\n  contents: read\njobs: {}\n",
+        ] {
+            let workflow = workflow(content);
+            assert!(fixes(&workflow).await.is_empty(), "{content}");
+        }
+    }
+}
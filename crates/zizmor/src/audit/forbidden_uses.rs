@@ -9,7 +9,12 @@ use crate::models::{StepCommon, action::CompositeStep, workflow::Step};
 
 pub(crate) struct ForbiddenUses;
 
-audit_meta!(ForbiddenUses, "forbidden-uses", "forbidden action used");
+audit_meta!(
+    ForbiddenUses,
+    "forbidden-uses",
+    "forbidden action used",
+    severity = High
+);
 
 impl ForbiddenUses {
     fn use_denied(&self, uses: &Uses, config: &ForbiddenUsesConfigInner) -> bool {
@@ -56,7 +56,8 @@ pub(crate) struct GitHubApp;
 audit_meta!(
     GitHubApp,
     "github-app",
-    "dangerous use of GitHub App tokens"
+    "dangerous use of GitHub App tokens",
+    severity = High
 );
 
 impl GitHubApp {
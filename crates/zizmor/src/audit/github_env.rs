@@ -1,26 +1,30 @@
 use std::ops::{Deref as _, Range};
 
 use anyhow::{Context as _, Result};
+use github_actions_expressions::{Expr, context::Context};
 use github_actions_models::action;
 use github_actions_models::workflow::job;
+use subfeature::Subfeature;
 use tree_sitter::{
     Language, Parser, QueryCapture, QueryCursor, QueryMatches, StreamingIterator as _, Tree,
 };
 
-use super::{Audit, AuditLoadError, audit_meta};
+use super::{Audit, AuditLoadError, audit_meta, template_injection::INJECTED_EXPRESSION};
 use crate::audit::AuditError;
 use crate::config::Config;
 use crate::finding::location::Locatable as _;
 use crate::finding::{Confidence, Finding, Severity};
-use crate::models::StepCommon as _;
+use crate::models::StepCommon;
+use crate::models::inputs::Capability;
 use crate::models::workflow::{Step, StepInner};
 use crate::state::AuditState;
 use crate::utils;
+use crate::utils::extract_fenced_expressions;
 use crate::utils::once::static_regex;
 
 static_regex!(
     GITHUB_ENV_WRITE_CMD,
-    r#"(?mi)^.+\s*>>?\s*"?%(?<destination>GITHUB_ENV|GITHUB_PATH)%"?.*$"#
+    r#"(?mi)^.+\s*>>?\s*"?%(?<destination>GITHUB_ENV|GITHUB_PATH|GITHUB_OUTPUT)%"?.*$"#
 );
 
 pub(crate) struct GitHubEnv {
@@ -34,7 +38,12 @@ pub(crate) struct GitHubEnv {
     pwsh_pipeline_query: utils::SpannedQuery,
 }
 
-audit_meta!(GitHubEnv, "github-env", "dangerous use of environment file");
+audit_meta!(
+    GitHubEnv,
+    "github-env",
+    "dangerous use of environment file",
+    severity = High
+);
 
 const BASH_REDIRECT_QUERY: &str = r#"
 (redirected_statement
@@ -48,7 +57,7 @@ const BASH_REDIRECT_QUERY: &str = r#"
      (simple_expansion (variable_name) @destination)
    ]
  ))
- (#match? @destination "^(GITHUB_ENV|GITHUB_PATH)$")
+ (#match? @destination "^(GITHUB_ENV|GITHUB_PATH|GITHUB_OUTPUT)$")
 ) @span
 "#;
 
@@ -63,7 +72,7 @@ const BASH_PIPELINE_QUERY: &str = r#"
     ]
   )
   (#match? @cmd "tee")
-  (#match? @destination "^(GITHUB_ENV|GITHUB_PATH)$")
+  (#match? @destination "^(GITHUB_ENV|GITHUB_PATH|GITHUB_OUTPUT)$")
 ) @span
 "#;
 
@@ -82,7 +91,7 @@ const PWSH_REDIRECT_QUERY: &str = r#"
       )
     (_)*
   )
-  (#match? @destination "(?i)ENV:GITHUB_ENV|ENV:GITHUB_PATH")
+  (#match? @destination "(?i)ENV:GITHUB_ENV|ENV:GITHUB_PATH|ENV:GITHUB_OUTPUT")
 )) @span
 "#;
 
@@ -102,7 +111,7 @@ const PWSH_PIPELINE_QUERY: &str = r#"
         )
         (_)*))
     (#match? @cmd "(?i)out-file|add-content|set-content|tee-object")
-    (#match? @destination "(?i)ENV:GITHUB_ENV|ENV:GITHUB_PATH")
+    (#match? @destination "(?i)ENV:GITHUB_ENV|ENV:GITHUB_PATH|ENV:GITHUB_OUTPUT")
   )
 ) @span
 "#;
@@ -268,7 +277,11 @@ impl GitHubEnv {
                     .name("destination")
                     .expect("internal error: capture with missing destination");
 
-                (dest.as_str(), dest.range())
+                let write = c
+                    .get(0)
+                    .expect("internal error: capture with missing match");
+
+                (dest.as_str(), write.range())
             })
             .collect()
     }
@@ -346,6 +359,115 @@ impl GitHubEnv {
             }
         }
     }
+
+    /// Returns whether the given write destination is `GITHUB_OUTPUT`,
+    /// which (unlike `GITHUB_ENV` and `GITHUB_PATH`) can't be used to
+    /// influence the execution of subsequent steps on its own.
+    fn is_output(dest: &str) -> bool {
+        dest.to_ascii_uppercase().ends_with("GITHUB_OUTPUT")
+    }
+
+    /// Returns the capability of the value that the given `env.NAME`
+    /// context expands to, i.e. the most dangerous capability among the
+    /// contexts in the variable's declaration.
+    ///
+    /// Returns `None` if the variable's declaration can't be found.
+    fn env_capability<'doc>(context: &Context, step: &impl StepCommon<'doc>) -> Option<Capability> {
+        let value = context
+            .single_tail()
+            .and_then(|name| step.env_value(name))?;
+
+        let mut capability = None;
+        for (expr, _) in extract_fenced_expressions(&value.to_string()) {
+            let Ok(parsed) = Expr::parse(expr.as_bare()) else {
+                continue;
+            };
+
+            for (context, _) in parsed.dataflow_contexts() {
+                match context
+                    .as_pattern()
+                    .and_then(|pattern| Capability::from_context(&pattern))
+                {
+                    Some(Capability::Arbitrary) => return Some(Capability::Arbitrary),
+                    Some(Capability::Structured) => capability = Some(Capability::Structured),
+                    Some(Capability::Fixed) => {
+                        capability = capability.or(Some(Capability::Fixed));
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        capability
+    }
+
+    /// Returns findings for each attacker-controllable context that's
+    /// expanded within a write to an environment file, e.g.
+    /// `echo "FOO=${{ github.event.issue.title }}" >> $GITHUB_ENV`.
+    ///
+    /// These are dangerous regardless of the workflow's triggers, since
+    /// an attacker can inject additional variables (or outputs) with
+    /// a newline.
+    ///
+    /// Each finding points at the same context as the corresponding
+    /// `template-injection` finding, and shares its dedup key, so that
+    /// the same expansion isn't reported twice.
+    fn tainted_writes<'doc>(
+        step: &impl StepCommon<'doc>,
+        run: &'doc str,
+        writes: &[(&str, Range<usize>)],
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        for (dest, span) in writes {
+            for (expr, expr_span) in extract_fenced_expressions(&run[span.clone()]) {
+                let Ok(parsed) = Expr::parse(expr.as_bare()) else {
+                    tracing::warn!("couldn't parse expression: {expr}", expr = expr.as_raw());
+                    continue;
+                };
+
+                for (context, origin) in parsed.dataflow_contexts() {
+                    let Some(pattern) = context.as_pattern() else {
+                        continue;
+                    };
+
+                    let capability = match Capability::from_context(&pattern) {
+                        Some(capability) => Some(capability),
+                        None if context.child_of("env") => Self::env_capability(context, step),
+                        None => None,
+                    };
+
+                    let severity = match capability {
+                        Some(Capability::Arbitrary) => Severity::High,
+                        Some(Capability::Structured) => Severity::Medium,
+                        Some(Capability::Fixed) | None => continue,
+                    };
+
+                    findings.push(
+                        Self::finding()
+                            .severity(severity)
+                            .confidence(Confidence::High)
+                            .dedup_key(INJECTED_EXPRESSION)
+                            .add_location(
+                                step.location()
+                                    .primary()
+                                    .with_keys(["run".into()])
+                                    .subfeature(Subfeature::new(
+                                        span.start + expr_span.start + origin.span.start,
+                                        origin.raw,
+                                    ))
+                                    .annotated(format!(
+                                        "attacker-controllable value is written to {dest}"
+                                    )),
+                            )
+                            .build(step)?,
+                    );
+                }
+            }
+        }
+
+        Ok(findings)
+    }
 }
 
 #[async_trait::async_trait]
@@ -390,10 +512,6 @@ impl Audit for GitHubEnv {
         let has_dangerous_triggers =
             workflow.has_workflow_run() || workflow.has_pull_request_target();
 
-        if !has_dangerous_triggers {
-            return Ok(findings);
-        }
-
         if let StepInner::Run(job::RunStep { run, .. }) = &step.deref() {
             let shell = step.shell().map(|s| s.0).unwrap_or_else(|| {
                 tracing::warn!(
@@ -409,21 +527,26 @@ impl Audit for GitHubEnv {
                 "bash"
             });
 
-            // TODO: actually use the spanning information here.
-            for (dest, _span) in self.uses_github_env(run, shell)? {
-                findings.push(
-                    Self::finding()
-                        .severity(Severity::High)
-                        .confidence(Confidence::Low)
-                        .add_location(
-                            step.location()
-                                .primary()
-                                .with_keys(["run".into()])
-                                .annotated(format!("write to {dest} may allow code execution")),
-                        )
-                        .build(step)?,
-                )
+            let writes = self.uses_github_env(run, shell)?;
+
+            if has_dangerous_triggers {
+                for (dest, _span) in writes.iter().filter(|(dest, _)| !Self::is_output(dest)) {
+                    findings.push(
+                        Self::finding()
+                            .severity(Severity::High)
+                            .confidence(Confidence::Low)
+                            .add_location(
+                                step.location()
+                                    .primary()
+                                    .with_keys(["run".into()])
+                                    .annotated(format!("write to {dest} may allow code execution")),
+                            )
+                            .build(step)?,
+                    )
+                }
             }
+
+            findings.extend(Self::tainted_writes(step, run, &writes)?);
         }
 
         Ok(findings)
@@ -453,8 +576,9 @@ impl Audit for GitHubEnv {
             "bash"
         });
 
-        // TODO: actually use the spanning information here.
-        for (dest, _span) in self.uses_github_env(run, shell)? {
+        let writes = self.uses_github_env(run, shell)?;
+
+        for (dest, _span) in writes.iter().filter(|(dest, _)| !Self::is_output(dest)) {
             findings.push(
                 Self::finding()
                     .severity(Severity::High)
@@ -469,6 +593,8 @@ impl Audit for GitHubEnv {
             )
         }
 
+        findings.extend(Self::tainted_writes(step, run, &writes)?);
+
         Ok(findings)
     }
 }
@@ -528,6 +654,13 @@ mod tests {
             ("something|tee $GITHUB_ENV", true),
             ("something |tee $GITHUB_ENV", true),
             ("something| tee $GITHUB_ENV", true),
+            // GITHUB_OUTPUT cases
+            ("echo $foo >> $GITHUB_OUTPUT", true),
+            (
+                "echo \"foo=${{ github.event.issue.title }}\" >> \"$GITHUB_OUTPUT\"",
+                true,
+            ),
+            ("something | tee $GITHUB_OUTPUT", true),
             // negative cases
             ("echo $foo >> $OTHER_ENV # not $GITHUB_ENV", false), // comments not detected
             ("something | tee \"${$OTHER_ENV}\" # not $GITHUB_ENV", false), // comments not detected
@@ -0,0 +1,149 @@
+//! Detects steps that forward the workflow's `GITHUB_TOKEN` to a
+//! third-party action, via either `with:` or `env:`.
+//!
+//! Any action that receives the token can use it with the full set of
+//! permissions granted to the job, so forwarding it to an action
+//! outside of a small set of trusted owners deserves scrutiny.
+
+use github_actions_expressions::Expr;
+use github_actions_models::common::{Env, EnvValue, RepositoryUses, Uses, expr::LoE};
+use subfeature::Subfeature;
+
+use super::{Audit, AuditLoadError, audit_meta};
+use crate::{
+    audit::AuditError,
+    config::Config,
+    finding::{Confidence, Finding, Severity},
+    models::{StepBodyCommon, StepCommon, action::CompositeStep, workflow::Step},
+    state::AuditState,
+    utils::{extract_fenced_expressions, once::warn_once},
+};
+
+/// The contexts through which a workflow can access its own token.
+const TOKEN_CONTEXTS: &[&str] = &["secrets.GITHUB_TOKEN", "github.token"];
+
+pub(crate) struct GithubTokenForwarding;
+
+audit_meta!(
+    GithubTokenForwarding,
+    "github-token-forwarding",
+    "workflow token forwarded to a third-party action",
+    severity = Medium
+);
+
+impl GithubTokenForwarding {
+    fn is_trusted(uses: &RepositoryUses, config: &Config) -> bool {
+        config
+            .github_token_forwarding_config
+            .trusted_owners
+            .iter()
+            .any(|owner| owner.eq_ignore_ascii_case(uses.owner()))
+    }
+
+    /// Returns a subfeature for each token context within `value`.
+    fn token_contexts(value: &str) -> Vec<Subfeature<'_>> {
+        let mut contexts = vec![];
+
+        for (expr, span) in extract_fenced_expressions(value) {
+            let Ok(parsed) = Expr::parse(expr.as_bare()) else {
+                warn_once!("couldn't parse expression: {expr}", expr = expr.as_bare());
+                continue;
+            };
+
+            contexts.extend(
+                parsed
+                    .contexts()
+                    .into_iter()
+                    .filter(|(ctx, _)| TOKEN_CONTEXTS.iter().any(|pat| ctx.matches(*pat)))
+                    .map(|(_, origin)| Subfeature::new(span.start + origin.span.start, origin.raw)),
+            );
+        }
+
+        contexts
+    }
+
+    fn process_step<'doc>(
+        &self,
+        step: &impl StepCommon<'doc>,
+        env: &'doc LoE<Env>,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let Some(StepBodyCommon::Uses {
+            uses: Uses::Repository(uses),
+            with,
+        }) = step.body()
+        else {
+            return Ok(vec![]);
+        };
+
+        if Self::is_trusted(uses, config) {
+            return Ok(vec![]);
+        }
+
+        let blocks = [("with", with), ("env", env)];
+        let inputs = blocks.iter().filter_map(|(block, values)| match values {
+            LoE::Literal(values) => Some(
+                values
+                    .iter()
+                    .map(move |(name, value)| (*block, name, value)),
+            ),
+            // Blocks that are wholly expressions can't be enumerated.
+            LoE::Expr(_) => None,
+        });
+
+        let mut findings = vec![];
+        for (block, name, value) in inputs.flatten() {
+            let EnvValue::String(value) = value else {
+                continue;
+            };
+
+            for context in Self::token_contexts(value) {
+                findings.push(
+                    Self::finding()
+                        .severity(Severity::Medium)
+                        .confidence(Confidence::High)
+                        .add_location(
+                            step.location()
+                                .with_keys(["uses".into()])
+                                .subfeature(Subfeature::new(0, uses.raw()))
+                                .annotated("this action"),
+                        )
+                        .add_location(
+                            step.location()
+                                .primary()
+                                .with_keys([block.into(), name.as_str().into()])
+                                .subfeature(context)
+                                .annotated("workflow token is forwarded here"),
+                        )
+                        .tip("pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token")
+                        .build(step)?,
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for GithubTokenForwarding {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_step<'doc>(
+        &self,
+        step: &Step<'doc>,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_step(step, step.env(), config)
+    }
+
+    async fn audit_composite_step<'doc>(
+        &self,
+        step: &CompositeStep<'doc>,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_step(step, &step.env, config)
+    }
+}
@@ -15,7 +15,9 @@ pub(crate) struct HardcodedContainerCredentials;
 audit_meta!(
     HardcodedContainerCredentials,
     "hardcoded-container-credentials",
-    "hardcoded credential in GitHub Actions container configurations"
+    "hardcoded credential in GitHub Actions container configurations",
+    severity = High,
+    cwes = [798]
 );
 
 #[async_trait::async_trait]
@@ -30,7 +30,9 @@ pub(crate) struct ImpostorCommit {
 audit_meta!(
     ImpostorCommit,
     "impostor-commit",
-    "commit with no history in referenced repository"
+    "commit with no history in referenced repository",
+    severity = High,
+    online = true
 );
 
 /// An intermediate result type for the impostor check. This is used to
@@ -27,7 +27,8 @@ pub(crate) struct InsecureURLScheme;
 audit_meta!(
     InsecureURLScheme,
     "insecure-url-scheme",
-    "use of an insecure scheme within a URL"
+    "use of an insecure scheme within a URL",
+    severity = High
 );
 
 #[async_trait::async_trait]
@@ -26,7 +26,9 @@ pub(crate) struct KnownVulnerableActions {
 audit_meta!(
     KnownVulnerableActions,
     "known-vulnerable-actions",
-    "action has a known vulnerability"
+    "action has a known vulnerability",
+    severity = High,
+    online = true
 );
 
 impl KnownVulnerableActions {
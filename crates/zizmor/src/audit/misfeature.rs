@@ -16,7 +16,8 @@ use crate::{
 audit_meta!(
     Misfeature,
     "misfeature",
-    "usage of GitHub Actions misfeatures"
+    "usage of GitHub Actions misfeatures",
+    severity = Low
 );
 
 pub(crate) struct Misfeature;
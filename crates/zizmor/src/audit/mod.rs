@@ -7,7 +7,7 @@ use yamlpath::Document;
 use crate::{
     config::Config,
     finding::{
-        Finding, FindingBuilder,
+        Finding, FindingBuilder, Severity,
         location::{Routable, SymbolicLocation},
     },
     models::{
@@ -24,17 +24,23 @@ use crate::{
 pub(crate) mod adhoc_packages;
 pub(crate) mod anonymous_definition;
 pub(crate) mod archived_uses;
+pub(crate) mod artifact_execution;
 pub(crate) mod artipacked;
 pub(crate) mod bot_conditions;
 pub(crate) mod cache_poisoning;
+pub(crate) mod concurrency_groups;
 pub(crate) mod concurrency_limits;
+pub(crate) mod constant_if;
+pub(crate) mod curl_pipe_bash;
 pub(crate) mod dangerous_triggers;
 pub(crate) mod dependabot_cooldown;
 pub(crate) mod dependabot_execution;
+pub(crate) mod dependabot_registries;
 pub(crate) mod excessive_permissions;
 pub(crate) mod forbidden_uses;
 pub(crate) mod github_app;
 pub(crate) mod github_env;
+pub(crate) mod github_token_forwarding;
 pub(crate) mod hardcoded_container_credentials;
 pub(crate) mod impostor_commit;
 pub(crate) mod insecure_commands;
@@ -43,11 +49,14 @@ pub(crate) mod known_vulnerable_actions;
 pub(crate) mod misfeature;
 pub(crate) mod obfuscation;
 pub(crate) mod overprovisioned_secrets;
+pub(crate) mod persist_credentials;
 pub(crate) mod ref_confusion;
 pub(crate) mod ref_version_mismatch;
+pub(crate) mod secrets_in_conditions;
 pub(crate) mod secrets_inherit;
 pub(crate) mod secrets_outside_env;
 pub(crate) mod self_hosted_runner;
+pub(crate) mod sensitive_artifacts;
 pub(crate) mod stale_action_refs;
 pub(crate) mod superfluous_actions;
 pub(crate) mod template_injection;
@@ -62,6 +71,8 @@ pub(crate) mod unsound_contains;
 pub(crate) mod unsound_ternary;
 pub(crate) mod use_trusted_publishing;
 
+include!(concat!(env!("OUT_DIR"), "/audit-docs.rs"));
+
 #[derive(Debug)]
 pub(crate) enum AuditInput {
     Workflow(Workflow),
@@ -169,6 +180,19 @@ impl From<PreCommitHooks> for AuditInput {
     }
 }
 
+/// Static metadata for an audit, as declared via [`audit_meta`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AuditMeta {
+    pub(crate) ident: &'static str,
+    pub(crate) desc: &'static str,
+    pub(crate) url: &'static str,
+    /// A plain-text summary of the audit, taken from its documentation.
+    pub(crate) docs: Option<&'static str>,
+    pub(crate) severity: Severity,
+    pub(crate) online: bool,
+    pub(crate) cwes: &'static [u32],
+}
+
 /// A supertrait for all audits.
 ///
 /// Workflow audits, action audits, and all future audit types
@@ -187,11 +211,55 @@ pub(crate) trait AuditCore {
     where
         Self: Sized;
 
+    /// The default severity of this audit's findings.
+    ///
+    /// This is the highest severity that the audit produces; individual
+    /// findings may be assigned a lower severity.
+    fn severity() -> Severity
+    where
+        Self: Sized;
+
+    /// Whether this audit requires network access, i.e. is skipped
+    /// when running with `--offline`.
+    fn online() -> bool
+    where
+        Self: Sized;
+
+    /// The [CWE](https://cwe.mitre.org/) IDs that this audit's findings
+    /// fall under, if any.
+    fn cwes() -> &'static [u32]
+    where
+        Self: Sized;
+
+    /// A long-form, plain-text summary of this audit, if its documentation
+    /// was available at build time.
+    fn docs() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        audit_docs(Self::ident())
+    }
+
+    fn meta() -> AuditMeta
+    where
+        Self: Sized,
+    {
+        AuditMeta {
+            ident: Self::ident(),
+            desc: Self::desc(),
+            url: Self::url(),
+            docs: Self::docs(),
+            severity: Self::severity(),
+            online: Self::online(),
+            cwes: Self::cwes(),
+        }
+    }
+
     fn finding<'doc>() -> FindingBuilder<'doc>
     where
         Self: Sized,
     {
-        FindingBuilder::new(Self::ident(), Self::desc(), Self::url())
+        FindingBuilder::new(Self::ident(), Self::desc(), Self::url()).cwes(Self::cwes())
     }
 
     fn err(error: impl Into<anyhow::Error>) -> AuditError
@@ -212,10 +280,28 @@ pub(crate) trait AuditCore {
 /// ```no_run
 /// struct SomeAudit;
 ///
-/// audit_meta!(SomeAudit, "some-audit", "brief description");
+/// audit_meta!(SomeAudit, "some-audit", "brief description", severity = Medium);
+/// ```
+///
+/// Audits can optionally declare the CWE IDs that their findings fall under:
+///
+/// ```no_run
+/// audit_meta!(SomeAudit, "some-audit", "brief description", severity = Medium, cwes = [94]);
 /// ```
+///
+/// Audits that require network access should also pass `online = true`.
 macro_rules! audit_meta {
-    ($t:ty, $id:literal, $desc:expr_2021) => {
+    ($t:ty, $id:literal, $desc:expr_2021, severity = $sev:ident $(, cwes = [$($cwe:literal),* $(,)?])?) => {
+        crate::audit::audit_meta!(
+            $t,
+            $id,
+            $desc,
+            severity = $sev,
+            online = false
+            $(, cwes = [$($cwe),*])?
+        );
+    };
+    ($t:ty, $id:literal, $desc:expr_2021, severity = $sev:ident, online = $online:literal $(, cwes = [$($cwe:literal),* $(,)?])?) => {
         use crate::audit::AuditCore;
 
         impl AuditCore for $t {
@@ -233,6 +319,18 @@ macro_rules! audit_meta {
             fn url() -> &'static str {
                 concat!("https://docs.zizmor.sh/audits/#", $id)
             }
+
+            fn severity() -> crate::finding::Severity {
+                crate::finding::Severity::$sev
+            }
+
+            fn online() -> bool {
+                $online
+            }
+
+            fn cwes() -> &'static [u32] {
+                &[$($($cwe),*)?]
+            }
         }
     };
 }
@@ -10,7 +10,7 @@ use crate::{
         Finding, Fix, FixDisposition, Persona,
         location::{Feature, Location, Routable as _},
     },
-    models::{StepCommon, action::CompositeStep, workflow::Step},
+    models::{StepCommon, action::CompositeStep, uses::ResolvedUses, workflow::Step},
     utils::parse_fenced_expressions_from_routable,
 };
 use subfeature::Subfeature;
@@ -22,7 +22,8 @@ pub(crate) struct Obfuscation;
 audit_meta!(
     Obfuscation,
     "obfuscation",
-    "obfuscated usage of GitHub Actions features"
+    "obfuscated usage of GitHub Actions features",
+    severity = Low
 );
 
 impl Obfuscation {
@@ -82,22 +83,15 @@ impl Obfuscation {
         }
 
         // If all components were removed, the subpath should be empty
-        if components.is_empty() {
-            Some(format!(
-                "{}/{}@{}",
-                uses.owner(),
-                uses.repo(),
-                uses.git_ref()
-            ))
-        } else {
-            Some(format!(
-                "{}/{}/{}@{}",
-                uses.owner(),
-                uses.repo(),
-                components.join("/"),
-                uses.git_ref()
-            ))
-        }
+        let subpath = components.join("/");
+        let normalized = ResolvedUses::Remote {
+            owner: uses.owner(),
+            repo: uses.repo(),
+            subpath: (!subpath.is_empty()).then_some(subpath.as_str()),
+            git_ref: uses.git_ref(),
+        };
+
+        Some(normalized.to_string())
     }
 
     /// Creates a fix for obfuscated uses paths.
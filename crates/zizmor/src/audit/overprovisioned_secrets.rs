@@ -22,7 +22,8 @@ pub(crate) struct OverprovisionedSecrets;
 audit_meta!(
     OverprovisionedSecrets,
     "overprovisioned-secrets",
-    "excessively provisioned secrets"
+    "excessively provisioned secrets",
+    severity = Medium
 );
 
 #[async_trait::async_trait]
@@ -0,0 +1,245 @@
+//! Detects `actions/checkout` steps that don't set `persist-credentials: false`.
+//!
+//! By default, `actions/checkout` persists the workflow's token into the
+//! local git configuration, where any subsequent step (or anything that
+//! exfiltrates the checkout) can read it. The `artipacked` audit flags
+//! this when the persisted credentials can plausibly leak; this audit
+//! flags every checkout that persists credentials, regardless of what
+//! happens afterwards.
+
+use github_actions_models::common::{EnvValue, Uses, expr::LoE};
+use subfeature::Subfeature;
+use yamlpatch::{Op, Patch};
+
+use super::{Audit, AuditLoadError, artipacked::PERSISTED_CREDENTIALS, audit_meta};
+use crate::{
+    audit::AuditError,
+    config::Config,
+    finding::{
+        Confidence, Finding, Fix, FixDisposition, Persona, Severity, location::Routable as _,
+    },
+    models::{
+        StepBodyCommon, StepCommon, action::CompositeStep, uses::RepositoryUsesExt as _,
+        workflow::Step,
+    },
+    state::AuditState,
+};
+
+pub(crate) struct PersistCredentials;
+
+audit_meta!(
+    PersistCredentials,
+    "persist-credentials",
+    "checkout persists credentials",
+    severity = Low
+);
+
+impl PersistCredentials {
+    fn process_step<'doc>(
+        &self,
+        step: &impl StepCommon<'doc>,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let Some(StepBodyCommon::Uses {
+            uses: Uses::Repository(uses),
+            with: LoE::Literal(with),
+        }) = step.body()
+        else {
+            return Ok(vec![]);
+        };
+
+        if !uses.matches("actions/checkout") {
+            return Ok(vec![]);
+        }
+
+        // NOTE: We share a dedup key (and primary location) with
+        // `artipacked`, which reports the same checkouts. Our low
+        // confidence means that `artipacked`'s finding wins when both
+        // are reported.
+        let finding = Self::finding()
+            .severity(Severity::Low)
+            .confidence(Confidence::Low)
+            .persona(Persona::Pedantic)
+            .dedup_key(PERSISTED_CREDENTIALS)
+            .add_location(
+                step.location()
+                    .with_keys(["uses".into()])
+                    .subfeature(Subfeature::new(0, uses.raw()))
+                    .annotated("this checkout"),
+            )
+            .tip("set `persist-credentials: false` unless later steps need the credentials");
+
+        let finding = match with.get("persist-credentials") {
+            // Explicitly disabled; nothing to do.
+            Some(EnvValue::Boolean(false)) => return Ok(vec![]),
+            Some(EnvValue::String(s)) if s == "false" => return Ok(vec![]),
+            // Anything else that's present is either an explicit opt-in
+            // or an expression; either way we don't have a safe fix.
+            Some(_) => finding.add_location(
+                step.location()
+                    .primary()
+                    .annotated("credentials are persisted here"),
+            ),
+            None => finding
+                .add_location(
+                    step.location()
+                        .primary()
+                        .annotated("does not set persist-credentials: false"),
+                )
+                .fix(Self::create_fix(step)),
+        };
+
+        Ok(vec![finding.build(step)?])
+    }
+
+    /// Create a fix that adds `persist-credentials: false` to the step,
+    /// creating the step's `with:` block if necessary.
+    fn create_fix<'doc>(step: &impl StepCommon<'doc>) -> Fix<'doc> {
+        let with_route = step.route().with_key("with");
+
+        let patch = if step.document().query_exists(&with_route) {
+            Patch {
+                route: with_route,
+                operation: Op::Add {
+                    key: "persist-credentials".into(),
+                    value: yaml_serde::Value::Bool(false),
+                },
+            }
+        } else {
+            let mut with = yaml_serde::Mapping::new();
+            with.insert("persist-credentials".into(), yaml_serde::Value::Bool(false));
+
+            Patch {
+                route: step.route(),
+                operation: Op::Add {
+                    key: "with".into(),
+                    value: yaml_serde::Value::Mapping(with),
+                },
+            }
+        };
+
+        Fix {
+            title: "set persist-credentials: false".into(),
+            key: step.location().key,
+            disposition: FixDisposition::Safe,
+            patches: vec![patch],
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for PersistCredentials {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_step<'doc>(
+        &self,
+        step: &Step<'doc>,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_step(step)
+    }
+
+    async fn audit_composite_step<'doc>(
+        &self,
+        step: &CompositeStep<'doc>,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_step(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{AsDocument as _, workflow::Workflow},
+        registry::input::InputKey,
+    };
+
+    async fn audit(with: &str) -> Vec<(usize, Option<String>)> {
+        let workflow_content = format!(
+            r#"
+on: push
+
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4{with}
+"#
+        );
+
+        let key = InputKey::local("fakegroup".into(), "test.yml", None, None);
+        let workflow = Workflow::from_string(workflow_content, key).unwrap();
+        let audit = PersistCredentials::new(&AuditState::default()).unwrap();
+
+        audit
+            .audit_workflow(&workflow, &Config::default())
+            .await
+            .unwrap()
+            .iter()
+            .map(|finding| {
+                assert_eq!(finding.ident, "persist-credentials");
+                assert_eq!(finding.dedup_key, Some(PERSISTED_CREDENTIALS));
+
+                let fixed = finding.fixes.first().map(|fix| {
+                    assert_eq!(fix.disposition, FixDisposition::Safe);
+                    fix.apply(workflow.as_document())
+                        .unwrap()
+                        .source()
+                        .to_string()
+                });
+
+                (finding.locations.len(), fixed)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_unset() {
+        // No `with:` at all.
+        let findings = audit("").await;
+        let [(2, Some(fixed))] = findings.as_slice() else {
+            panic!("expected a single finding with a fix");
+        };
+        assert!(
+            fixed.ends_with(
+                "      - uses: actions/checkout@v4\n        with:\n          persist-credentials: false\n"
+            ),
+            "{fixed}"
+        );
+
+        // A `with:` without `persist-credentials`.
+        let findings = audit("\n        with:\n          fetch-depth: 0").await;
+        let [(2, Some(fixed))] = findings.as_slice() else {
+            panic!("expected a single finding with a fix");
+        };
+        assert!(
+            fixed.ends_with(
+                "        with:\n          fetch-depth: 0\n          persist-credentials: false\n"
+            ),
+            "{fixed}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_true() {
+        for with in [
+            "\n        with:\n          persist-credentials: true",
+            "\n        with:\n          persist-credentials: ${{ inputs.persist }}",
+        ] {
+            assert_eq!(audit(with).await, [(2, None)], "{with}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_false() {
+        for with in [
+            "\n        with:\n          persist-credentials: false",
+            "\n        with:\n          persist-credentials: 'false'",
+        ] {
+            assert!(audit(with).await.is_empty(), "{with}");
+        }
+    }
+}
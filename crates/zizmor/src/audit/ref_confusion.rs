@@ -31,7 +31,9 @@ pub(crate) struct RefConfusion {
 audit_meta!(
     RefConfusion,
     "ref-confusion",
-    "git ref for action with ambiguous ref type"
+    "git ref for action with ambiguous ref type",
+    severity = Medium,
+    online = true
 );
 
 impl RefConfusion {
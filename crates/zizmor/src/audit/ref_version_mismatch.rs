@@ -22,7 +22,9 @@ pub(crate) struct RefVersionMismatch {
 audit_meta!(
     RefVersionMismatch,
     "ref-version-mismatch",
-    "action's hash pin has mismatched or missing version comment"
+    "action's hash pin has mismatched or missing version comment",
+    severity = Medium,
+    online = true
 );
 
 static_regex!(
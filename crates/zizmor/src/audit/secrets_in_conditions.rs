@@ -0,0 +1,155 @@
+//! Detects `if:` conditions that reference secrets, e.g.
+//! `if: ${{ secrets.DEPLOY_KEY != '' }}`.
+//!
+//! Conditions are evaluated outside of GitHub's secret masking, and their
+//! outcome (whether a job or step ran) is visible to anyone who can view
+//! the workflow's logs. Referencing a secret in a condition therefore leaks
+//! information about it: at minimum whether it's set, and potentially its
+//! contents when compared against other values.
+
+use github_actions_expressions::{Expr, context::ContextRoot};
+use github_actions_models::common::If;
+
+use super::{Audit, AuditLoadError, Job, audit_meta};
+use crate::{
+    audit::AuditError,
+    finding::{
+        Confidence, Finding, Persona, Severity,
+        location::{Locatable as _, SymbolicLocation},
+    },
+    models::AsDocument,
+    state::AuditState,
+    utils::{self, ExtractedExpr},
+};
+use subfeature::Subfeature;
+
+pub(crate) struct SecretsInConditions;
+
+audit_meta!(
+    SecretsInConditions,
+    "secrets-in-conditions",
+    "secrets referenced in if: conditions",
+    severity = Medium
+);
+
+impl SecretsInConditions {
+    fn process_conditions<'a, 'doc>(
+        &self,
+        doc: &'a impl AsDocument<'a, 'doc>,
+        conditions: impl Iterator<Item = (&'doc If, SymbolicLocation<'doc>)>,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        for (cond, loc) in conditions {
+            let If::Expr(raw) = cond else {
+                continue;
+            };
+
+            // Conditions can be either bare or fenced; see `bot-conditions`
+            // for why we extract fenced conditions explicitly.
+            let (bare, expr_start) = match utils::extract_fenced_expression(raw, 0) {
+                Some((expr, span)) => (expr.as_bare(), span.start),
+                None => (ExtractedExpr::new(raw).as_bare(), 0),
+            };
+
+            let Ok(expr) = Expr::parse(bare) else {
+                tracing::warn!("couldn't parse expression: {raw}");
+                continue;
+            };
+
+            let dataflow = expr.dataflow_contexts();
+
+            for (ctx, origin) in expr.contexts() {
+                if ctx.root_kind() != Some(ContextRoot::Secrets) {
+                    continue;
+                }
+
+                // A secret that flows directly into the condition's truthiness
+                // (e.g. `if: secrets.FOO`) only reveals whether it's set.
+                // Anything else (comparisons, function calls) can reveal
+                // information about the secret's value.
+                let (severity, annotation) = if dataflow.iter().any(|(c, _)| std::ptr::eq(*c, ctx))
+                {
+                    (
+                        Severity::Low,
+                        "condition reveals whether this secret is set",
+                    )
+                } else {
+                    (
+                        Severity::Medium,
+                        "condition may reveal information about this secret",
+                    )
+                };
+
+                findings.push(
+                    Self::finding()
+                        .severity(severity)
+                        .confidence(Confidence::High)
+                        .persona(Persona::Regular)
+                        .add_location(loc.clone().hidden())
+                        .add_location(
+                            loc.clone()
+                                .with_keys(["if".into()])
+                                .primary()
+                                .subfeature(Subfeature::new(expr_start + origin.span.start, origin.raw))
+                                .annotated(annotation),
+                        )
+                        .tip("expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable")
+                        .build(doc)?,
+                );
+            }
+        }
+
+        Ok(findings)
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for SecretsInConditions {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_workflow<'doc>(
+        &self,
+        workflow: &'doc crate::models::workflow::Workflow,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        for job in workflow.jobs() {
+            match job {
+                Job::NormalJob(normal) => {
+                    let job_cond = normal.r#if.iter().map(|cond| (cond, normal.location()));
+                    let step_conds = normal
+                        .steps()
+                        .filter_map(|step| step.r#if().map(|cond| (cond, step.location())));
+                    let conds = job_cond.chain(step_conds);
+
+                    findings.extend(self.process_conditions(workflow, conds)?);
+                }
+                Job::ReusableWorkflowCallJob(reusable) => {
+                    let conds = reusable.r#if.iter().map(|cond| (cond, reusable.location()));
+
+                    findings.extend(self.process_conditions(workflow, conds)?);
+                }
+            }
+        }
+
+        Ok(findings)
+    }
+
+    async fn audit_action<'doc>(
+        &self,
+        action: &'doc crate::models::action::Action,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let conds = action
+            .steps()
+            .into_iter()
+            .flatten()
+            .filter_map(|step| step.r#if.as_ref().map(|cond| (cond, step.location())));
+
+        self.process_conditions(action, conds)
+    }
+}
@@ -12,7 +12,8 @@ pub(crate) struct SecretsInherit;
 audit_meta!(
     SecretsInherit,
     "secrets-inherit",
-    "secrets unconditionally inherited by called workflow"
+    "secrets unconditionally inherited by called workflow",
+    severity = Medium
 );
 
 #[async_trait::async_trait]
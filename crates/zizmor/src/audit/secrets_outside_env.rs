@@ -18,7 +18,8 @@ pub(crate) struct SecretsOutsideEnvironment;
 audit_meta!(
     SecretsOutsideEnvironment,
     "secrets-outside-env",
-    "secrets referenced without a dedicated environment"
+    "secrets referenced without a dedicated environment",
+    severity = Medium
 );
 
 #[async_trait::async_trait]
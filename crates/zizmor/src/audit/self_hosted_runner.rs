@@ -23,9 +23,24 @@ pub(crate) struct SelfHostedRunner;
 audit_meta!(
     SelfHostedRunner,
     "self-hosted-runner",
-    "runs on a self-hosted runner"
+    "runs on a self-hosted runner",
+    severity = Medium
 );
 
+impl SelfHostedRunner {
+    /// Checks whether a matrix value used as `runs-on:` (either a single
+    /// label or a list of labels) refers to a self-hosted runner.
+    fn is_self_hosted_label(value: &yaml_serde::Value) -> bool {
+        match value {
+            yaml_serde::Value::String(label) => label.contains("self-hosted"),
+            yaml_serde::Value::Sequence(labels) => labels
+                .iter()
+                .any(|label| label.as_str().is_some_and(|l| l.contains("self-hosted"))),
+            _ => false,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl Audit for SelfHostedRunner {
     fn new(_state: &AuditState) -> Result<Self, AuditLoadError>
@@ -118,28 +133,75 @@ impl Audit for SelfHostedRunner {
                         continue;
                     };
 
-                    let self_hosted = matrix.expansions().iter().any(|expansion| {
-                        exp.as_bare() == expansion.path && expansion.value.contains("self-hosted")
-                    });
+                    let Some(combinations) = matrix.combinations() else {
+                        // The matrix can't be expanded into concrete combinations
+                        // (e.g. part of it is an expression), so we fall back to
+                        // checking each dimension's declared values on their own.
+                        let self_hosted = matrix.expansions().iter().any(|expansion| {
+                            exp.as_bare() == expansion.path
+                                && expansion.value.contains("self-hosted")
+                        });
+
+                        if self_hosted {
+                            results.push(
+                                Self::finding()
+                                    .confidence(Confidence::High)
+                                    .severity(Severity::Medium)
+                                    .persona(Persona::Auditor)
+                                    .add_location(
+                                        job.location()
+                                            .with_keys(["strategy".into()])
+                                            .annotated("matrix declares self-hosted runner"),
+                                    )
+                                    .add_location(
+                                        job.location()
+                                            .primary()
+                                            .with_keys(["runs-on".into()])
+                                            .annotated(
+                                                "expression may expand into a self-hosted runner",
+                                            ),
+                                    )
+                                    .build(workflow)?,
+                            );
+                        }
+
+                        continue;
+                    };
+
+                    // Flag each combination that resolves to a self-hosted runner.
+                    // Multiple combinations can share the same declared value
+                    // (e.g. `os: [self-hosted]` crossed with other dimensions),
+                    // so we only flag each declaration once.
+                    let mut seen: Vec<&yaml_serde::Value> = vec![];
+                    for combination in &combinations {
+                        let Some(value) = combination.get(exp.as_bare()) else {
+                            continue;
+                        };
+
+                        if !Self::is_self_hosted_label(value)
+                            || seen.iter().any(|v| std::ptr::eq(*v, value))
+                        {
+                            continue;
+                        }
+                        seen.push(value);
+
+                        let Some(declared) = combination.location(exp.as_bare()) else {
+                            continue;
+                        };
 
-                    if self_hosted {
                         results.push(
                             Self::finding()
                                 .confidence(Confidence::High)
                                 .severity(Severity::Medium)
                                 .persona(Persona::Auditor)
                                 .add_location(
-                                    job.location()
-                                        .with_keys(["strategy".into()])
-                                        .annotated("matrix declares self-hosted runner"),
+                                    declared.annotated("matrix declares self-hosted runner"),
                                 )
                                 .add_location(
                                     job.location()
                                         .primary()
                                         .with_keys(["runs-on".into()])
-                                        .annotated(
-                                            "expression may expand into a self-hosted runner",
-                                        ),
+                                        .annotated("expression expands into a self-hosted runner"),
                                 )
                                 .build(workflow)?,
                         )
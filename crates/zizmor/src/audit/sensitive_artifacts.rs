@@ -0,0 +1,241 @@
+//! Detects `actions/upload-artifact` steps whose `path:` includes the
+//! repository's `.git` directory, credential files, or the entire
+//! workspace.
+//!
+//! This complements `artipacked`: rather than pairing a checkout that
+//! persists credentials with a subsequent upload, this audit flags any
+//! upload whose paths would sweep up the places where credentials are
+//! typically persisted. Uploads that `artipacked` already pairs with
+//! a preceding checkout are left to it.
+
+use github_actions_models::common::{EnvValue, Uses, expr::ExplicitExpr, expr::LoE};
+use subfeature::Subfeature;
+
+use super::{Audit, AuditLoadError, artipacked::Artipacked, audit_meta};
+use crate::{
+    audit::AuditError,
+    config::Config,
+    finding::{Confidence, Finding, Persona, Severity},
+    models::{
+        StepBodyCommon, StepCommon, action::Action, uses::RepositoryUsesExt as _,
+        workflow::NormalJob,
+    },
+    state::AuditState,
+    utils::split_patterns_with_offsets,
+};
+
+/// Files that commonly contain credentials when present in the workspace.
+const CREDENTIAL_FILES: &[&str] = &[".git-credentials", ".netrc", ".npmrc", ".pypirc", ".docker"];
+
+pub(crate) struct SensitiveArtifacts;
+
+audit_meta!(
+    SensitiveArtifacts,
+    "sensitive-artifacts",
+    "artifact upload may include credentials",
+    severity = Medium
+);
+
+impl SensitiveArtifacts {
+    /// Returns a description of why the given `path:` pattern is
+    /// sensitive, if it is.
+    fn sensitive_pattern(pattern: &str) -> Option<&'static str> {
+        // Exclusions can only narrow an upload.
+        if pattern.starts_with('!') {
+            return None;
+        }
+
+        if let Some(expr) = ExplicitExpr::from_curly(pattern) {
+            return (expr.as_bare().trim() == "github.workspace")
+                .then_some("uploads the entire workspace");
+        }
+
+        let trimmed = pattern.trim_start_matches("./").trim_end_matches('/');
+
+        if matches!(trimmed, "" | "." | ".." | "*" | "**") {
+            return Some("uploads the entire workspace");
+        }
+
+        for component in trimmed.split('/') {
+            if component == ".git" {
+                return Some("uploads the .git directory");
+            }
+
+            if CREDENTIAL_FILES.contains(&component) {
+                return Some("uploads a file that commonly contains credentials");
+            }
+        }
+
+        None
+    }
+
+    fn process_steps<'doc>(
+        &self,
+        steps: impl Iterator<Item = impl StepCommon<'doc>>,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let mut findings = vec![];
+
+        // Whether a preceding checkout persists credentials in a way that
+        // `artipacked` reports by default.
+        let mut persisted_credentials = false;
+        for step in steps {
+            if let Some(StepBodyCommon::Uses {
+                uses: Uses::Repository(uses),
+                with: LoE::Literal(with),
+            }) = step.body()
+                && uses.matches("actions/checkout")
+                && Artipacked::persisted_credentials_persona(with) == Some(Persona::Regular)
+            {
+                persisted_credentials = true;
+            }
+
+            findings.extend(self.process_step(&step, persisted_credentials)?);
+        }
+
+        Ok(findings)
+    }
+
+    fn process_step<'doc>(
+        &self,
+        step: &impl StepCommon<'doc>,
+        persisted_credentials: bool,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let Some(StepBodyCommon::Uses {
+            uses: Uses::Repository(uses),
+            with: LoE::Literal(with),
+        }) = step.body()
+        else {
+            return Ok(vec![]);
+        };
+
+        if !uses.matches("actions/upload-artifact") {
+            return Ok(vec![]);
+        }
+
+        let Some(EnvValue::String(path)) = with.get("path") else {
+            return Ok(vec![]);
+        };
+
+        // Newer versions of `actions/upload-artifact` exclude hidden files
+        // (including `.git`) unless explicitly told otherwise.
+        let includes_hidden_files = matches!(
+            with.get("include-hidden-files"),
+            Some(EnvValue::Boolean(true))
+        ) || matches!(
+            with.get("include-hidden-files"),
+            Some(EnvValue::String(s)) if s == "true"
+        );
+
+        let confidence = if includes_hidden_files {
+            Confidence::High
+        } else {
+            Confidence::Medium
+        };
+
+        // `artipacked` already reports these uploads alongside the
+        // checkout that persisted the credentials.
+        let covered = if persisted_credentials {
+            Artipacked::dangerous_artifact_patterns(path)
+        } else {
+            vec![]
+        };
+
+        // Each pattern's offset within the parsed `path:` value is a lower
+        // bound on its offset within the value as written, which can only
+        // add quoting and indentation. Locating each pattern from there
+        // keeps it from matching within an earlier pattern.
+        let mut findings = vec![];
+        for (offset, pattern) in split_patterns_with_offsets(path) {
+            if covered.contains(&pattern) {
+                continue;
+            }
+
+            let Some(reason) = Self::sensitive_pattern(pattern) else {
+                continue;
+            };
+
+            findings.push(
+                Self::finding()
+                    .severity(Severity::Medium)
+                    .confidence(confidence)
+                    .add_location(
+                        step.location()
+                            .with_keys(["uses".into()])
+                            .subfeature(Subfeature::new(0, uses.raw()))
+                            .annotated("this upload"),
+                    )
+                    .add_location(
+                        step.location()
+                            .primary()
+                            .with_keys(["with".into(), "path".into()])
+                            .subfeature(Subfeature::new(offset, pattern))
+                            .annotated(reason),
+                    )
+                    .tip("upload only the specific files or directories that need to be shared")
+                    .build(step)?,
+            );
+        }
+
+        Ok(findings)
+    }
+}
+
+#[async_trait::async_trait]
+impl Audit for SensitiveArtifacts {
+    fn new(_state: &AuditState) -> Result<Self, AuditLoadError> {
+        Ok(Self)
+    }
+
+    async fn audit_normal_job<'doc>(
+        &self,
+        job: &NormalJob<'doc>,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        self.process_steps(job.steps())
+    }
+
+    async fn audit_action<'doc>(
+        &self,
+        action: &'doc Action,
+        _config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let Some(steps) = action.steps() else {
+            return Ok(vec![]);
+        };
+
+        self.process_steps(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SensitiveArtifacts;
+
+    #[test]
+    fn test_sensitive_pattern() {
+        for (pattern, sensitive) in [
+            (".", true),
+            ("./", true),
+            ("*", true),
+            ("**", true),
+            ("${{ github.workspace }}", true),
+            (".git", true),
+            (".git/", true),
+            ("./.git/config", true),
+            ("vendor/dep/.git", true),
+            (".npmrc", true),
+            ("home/.docker/config.json", true),
+            ("dist/", false),
+            ("dist/*.whl", false),
+            ("!.git", false),
+            (".github/workflows", false),
+            ("${{ steps.build.outputs.dir }}", false),
+        ] {
+            assert_eq!(
+                SensitiveArtifacts::sensitive_pattern(pattern).is_some(),
+                sensitive,
+                "{pattern}"
+            );
+        }
+    }
+}
@@ -22,7 +22,9 @@ pub(crate) struct StaleActionRefs {
 audit_meta!(
     StaleActionRefs,
     "stale-action-refs",
-    "commit hash does not point to a Git tag"
+    "commit hash does not point to a Git tag",
+    severity = Low,
+    online = true
 );
 
 impl StaleActionRefs {
@@ -16,7 +16,8 @@ pub(crate) struct SuperfluousActions;
 audit_meta!(
     SuperfluousActions,
     "superfluous-actions",
-    "action functionality is already included by the runner"
+    "action functionality is already included by the runner",
+    severity = Informational
 );
 
 #[async_trait::async_trait]
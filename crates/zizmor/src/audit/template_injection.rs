@@ -17,8 +17,13 @@
 
 use std::{env, ops::Deref as _, sync::LazyLock, vec};
 
-use fst::Map;
-use github_actions_expressions::{Expr, context::Context, literal::Literal};
+use github_actions_expressions::{
+    Expr, SpannedExpr,
+    call::{Call, Function},
+    context::Context,
+    literal::Literal,
+    op::BinExpr,
+};
 use github_actions_models::common::{EnvValue, RepositoryUses, Uses, expr::LoE};
 use itertools::Itertools as _;
 
@@ -40,12 +45,19 @@ use crate::{
 use subfeature::Subfeature;
 use yamlpatch::{Op, Patch};
 
+/// The dedup key shared by findings for attacker-controllable expressions
+/// expanded into code, which [`github_env`](super::github_env) also
+/// reports for writes to environment files.
+pub(crate) const INJECTED_EXPRESSION: &str = "injected-expression";
+
 pub(crate) struct TemplateInjection;
 
 audit_meta!(
     TemplateInjection,
     "template-injection",
-    "code injection via template expansion"
+    "code injection via template expansion",
+    severity = High,
+    cwes = [94]
 );
 
 #[allow(clippy::unwrap_used)]
@@ -75,23 +87,6 @@ static ACTION_INJECTION_SINKS: LazyLock<Vec<(RepositoryUsesPattern, Vec<&str>)>>
         sinks
     });
 
-static CONTEXT_CAPABILITIES_FST: LazyLock<Map<&[u8]>> = LazyLock::new(|| {
-    fst::Map::new(include_bytes!(concat!(env!("OUT_DIR"), "/context-capabilities.fst")).as_slice())
-        .expect("couldn't initialize context capabilities FST")
-});
-
-impl Capability {
-    fn from_context(context: &str) -> Option<Self> {
-        match CONTEXT_CAPABILITIES_FST.get(context) {
-            Some(0) => Some(Capability::Arbitrary),
-            Some(1) => Some(Capability::Structured),
-            Some(2) => Some(Capability::Fixed),
-            Some(_) => unreachable!("unexpected context capability"),
-            _ => None,
-        }
-    }
-}
-
 impl TemplateInjection {
     fn action_injection_sinks(uses: &RepositoryUses) -> &[&'static str] {
         // TODO: Optimize; this performs a linear scan over the map at the moment.
@@ -284,6 +279,31 @@ impl TemplateInjection {
         }
     }
 
+    /// Returns whether the given `env.NAME` context is only populated
+    /// from expressions with a [`Capability::Fixed`] capability, e.g.
+    /// `NAME: ${{ github.sha }}`.
+    ///
+    /// Interpolating such a variable is no more dangerous than interpolating
+    /// a static one.
+    fn env_is_fixed<'doc>(context: &Context, step: &impl StepCommon<'doc>) -> bool {
+        let Some(value) = context.single_tail().and_then(|name| step.env_value(name)) else {
+            return false;
+        };
+
+        extract_fenced_expressions(&value.to_string())
+            .iter()
+            .all(|(expr, _)| {
+                Expr::parse(expr.as_bare()).is_ok_and(|parsed| {
+                    parsed.dataflow_contexts().iter().all(|(context, _)| {
+                        context
+                            .as_pattern()
+                            .and_then(|pattern| Capability::from_context(&pattern))
+                            == Some(Capability::Fixed)
+                    })
+                })
+            })
+    }
+
     /// Attempts to produce a `Fix` for a given expression.
     fn attempt_fix<'doc>(
         &self,
@@ -362,6 +382,89 @@ impl TemplateInjection {
         })
     }
 
+    /// Emits a debug trace of the given expression's dataflow contexts,
+    /// classifying each as trusted or untrusted depending on whether
+    /// it produced one of the `bad_expressions`.
+    ///
+    /// These traces are enabled by `--trace-dataflow`; callers should
+    /// check that the `zizmor::dataflow` target is enabled first.
+    fn trace_dataflow(
+        expr: &ExtractedExpr,
+        parsed: &SpannedExpr,
+        expr_start: usize,
+        bad_expressions: &[(Subfeature, Option<Fix>, Severity, Confidence, Persona)],
+    ) {
+        let contexts = parsed.dataflow_contexts();
+        if contexts.is_empty() {
+            tracing::debug!(
+                target: "zizmor::dataflow",
+                "{expr}: no dataflow contexts",
+                expr = expr.as_raw()
+            );
+        }
+
+        for (_, origin) in contexts {
+            let after = expr_start + origin.span.start;
+            match bad_expressions
+                .iter()
+                .find(|(subfeature, ..)| subfeature.after == after)
+            {
+                Some((_, _, severity, confidence, persona)) => tracing::debug!(
+                    target: "zizmor::dataflow",
+                    "{expr}: context `{context}` is untrusted \
+                     (severity={severity:?}, confidence={confidence:?}, persona={persona:?})",
+                    expr = expr.as_raw(),
+                    context = origin.raw,
+                ),
+                None => tracing::debug!(
+                    target: "zizmor::dataflow",
+                    "{expr}: context `{context}` is trusted",
+                    expr = expr.as_raw(),
+                    context = origin.raw,
+                ),
+            }
+        }
+    }
+
+    /// Returns the contexts that are passed whole into a `toJSON(...)` or
+    /// `format(...)` call within the given expression, e.g. `github.event`
+    /// in `toJSON(github.event)`.
+    fn splatted_contexts<'a>(expr: &'a SpannedExpr<'a>) -> Vec<&'a Context<'a>> {
+        let mut contexts = vec![];
+
+        match expr.deref() {
+            Expr::Call(Call { func, args }) => {
+                for arg in args {
+                    match arg.deref() {
+                        Expr::Context(ctx)
+                            if matches!(func, Function::ToJSON | Function::Format) =>
+                        {
+                            contexts.push(ctx)
+                        }
+                        _ => contexts.extend(Self::splatted_contexts(arg)),
+                    }
+                }
+            }
+            Expr::BinExpr(BinExpr { lhs, rhs, .. }) => {
+                contexts.extend(Self::splatted_contexts(lhs));
+                contexts.extend(Self::splatted_contexts(rhs));
+            }
+            Expr::UnExpr { expr, .. } => contexts.extend(Self::splatted_contexts(expr)),
+            _ => (),
+        }
+
+        contexts
+    }
+
+    /// Returns whether the given context is an entire object that contains
+    /// attacker-controllable values, i.e. `github` or `github.event`.
+    ///
+    /// Narrower objects within `github.event` are handled by the FST and
+    /// the `github.*` fallback below.
+    fn is_untrusted_object(context: &Context) -> bool {
+        context.matches("github") || context.matches("github.event")
+    }
+
     fn injectable_template_expressions<'doc>(
         &self,
         script: &'doc str,
@@ -380,8 +483,26 @@ impl TemplateInjection {
                 continue;
             };
             let mut bad_expressions = vec![];
+            let splatted = Self::splatted_contexts(&parsed);
 
             for (context, origin) in parsed.dataflow_contexts() {
+                // Serializing an entire untrusted context (e.g. with
+                // `toJSON(github.event)`) expands every attacker-controllable
+                // value within it, regardless of what the FST says about
+                // the context's individual fields.
+                if splatted.iter().any(|ctx| std::ptr::eq(*ctx, context))
+                    && Self::is_untrusted_object(context)
+                {
+                    bad_expressions.push((
+                        Subfeature::new(expr_span.start + origin.span.start, origin.raw),
+                        self.attempt_fix(&expr, &parsed, step),
+                        Severity::High,
+                        Confidence::High,
+                        Persona::default(),
+                    ));
+                    continue;
+                }
+
                 // Try and turn our context into a pattern for
                 // matching against the FST.
                 match context.as_pattern().as_deref() {
@@ -459,7 +580,8 @@ impl TemplateInjection {
                                         persona,
                                     ));
                                 } else if context.child_of("env") {
-                                    let env_is_static = step.env_is_static(context);
+                                    let env_is_static = step.env_is_static(context)
+                                        || Self::env_is_fixed(context, step);
 
                                     if !env_is_static {
                                         bad_expressions.push((
@@ -551,6 +673,10 @@ impl TemplateInjection {
                 }
             }
 
+            if tracing::enabled!(target: "zizmor::dataflow", tracing::Level::DEBUG) {
+                Self::trace_dataflow(&expr, &parsed, expr_span.start, &bad_expressions);
+            }
+
             // If we didn't find anything noteworthy inside the extracted expression
             // (i.e., no injectable contexts with relevant dataflows), then
             // we emit a blanket pedantic finding for the extracted expression itself.
@@ -587,6 +713,7 @@ impl TemplateInjection {
                     .severity(severity)
                     .confidence(confidence)
                     .persona(persona)
+                    .dedup_key(INJECTED_EXPRESSION)
                     .add_location(step.location().hidden())
                     .add_location(
                         script_loc
@@ -151,7 +151,8 @@ pub(crate) struct TyposquatUses {
 audit_meta!(
     TyposquatUses,
     "typosquat-uses",
-    "action reference resembles a popular action"
+    "action reference resembles a popular action",
+    severity = High
 );
 
 impl TyposquatUses {
@@ -12,7 +12,8 @@ use crate::{
 audit_meta!(
     UndocumentedPermissions,
     "undocumented-permissions",
-    "permissions without explanatory comments"
+    "permissions without explanatory comments",
+    severity = Low
 );
 
 pub(crate) struct UndocumentedPermissions;
@@ -5,8 +5,8 @@ use crate::{
         location::{Locatable as _, SymbolicLocation},
     },
     models::{
-        AsDocument,
-        action::DockerAction,
+        AsDocument, StepCommon as _,
+        action::{CompositeStep, DockerAction},
         workflow::{StepInner, matrix::Matrix},
     },
     state::AuditState,
@@ -56,6 +56,7 @@ impl<'doc> From<&'doc DockerUses> for Image<'doc> {
 /// expanded through matrix references where possible.
 struct ImageCandidate<'doc> {
     annotation: &'static str,
+    tip: Option<&'static str>,
     confidence: Confidence,
     persona: Persona,
     location: SymbolicLocation<'doc>,
@@ -92,6 +93,7 @@ impl<'doc> ImageCandidate<'doc> {
 
         Some(Self {
             annotation,
+            tip: Some("pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`"),
             confidence: Confidence::High,
             persona,
             location,
@@ -105,6 +107,7 @@ impl<'doc> ImageCandidate<'doc> {
     fn opaque(location: SymbolicLocation<'doc>, related: Vec<SymbolicLocation<'doc>>) -> Self {
         Self {
             annotation: "container image may be unpinned",
+            tip: None,
             confidence: Confidence::Low,
             persona: Persona::Regular,
             location,
@@ -246,6 +249,10 @@ impl UnpinnedImages {
                     finding = finding.add_location(related);
                 }
 
+                if let Some(tip) = candidate.tip {
+                    finding = finding.tip(tip);
+                }
+
                 findings.push(finding.build(document)?);
             }
         }
@@ -257,7 +264,8 @@ impl UnpinnedImages {
 audit_meta!(
     UnpinnedImages,
     "unpinned-images",
-    "unpinned image references"
+    "unpinned image references",
+    severity = High
 );
 
 #[async_trait::async_trait]
@@ -284,6 +292,28 @@ impl Audit for UnpinnedImages {
         )
     }
 
+    async fn audit_composite_step<'doc>(
+        &self,
+        step: &CompositeStep<'doc>,
+        _config: &crate::config::Config,
+    ) -> anyhow::Result<Vec<Finding<'doc>>, AuditError> {
+        let Some(Uses::Docker(uses)) = step.uses() else {
+            return Ok(vec![]);
+        };
+
+        self.classify_images(
+            vec![(
+                uses.into(),
+                step.location()
+                    .primary()
+                    .with_keys(["uses".into()])
+                    .subfeature(Subfeature::new(0, uses.raw())),
+            )],
+            None,
+            step,
+        )
+    }
+
     async fn audit_normal_job<'doc>(
         &self,
         job: &super::NormalJob<'doc>,
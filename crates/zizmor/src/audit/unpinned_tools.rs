@@ -20,7 +20,8 @@ pub(crate) struct UnpinnedTools;
 audit_meta!(
     UnpinnedTools,
     "unpinned-tools",
-    "action installs an unpinned external tool"
+    "action installs an unpinned external tool",
+    severity = Medium
 );
 
 impl UnpinnedTools {
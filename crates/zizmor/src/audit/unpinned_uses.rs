@@ -21,7 +21,13 @@ pub(crate) struct UnpinnedUses {
     client: Option<github::Client>,
 }
 
-audit_meta!(UnpinnedUses, "unpinned-uses", "unpinned action reference");
+audit_meta!(
+    UnpinnedUses,
+    "unpinned-uses",
+    "unpinned action reference",
+    severity = High,
+    cwes = [829]
+);
 
 impl UnpinnedUses {
     async fn attempt_fix<'a, 'doc>(
@@ -21,7 +21,8 @@ pub(crate) struct UnredactedSecrets;
 audit_meta!(
     UnredactedSecrets,
     "unredacted-secrets",
-    "leaked secret values"
+    "leaked secret values",
+    severity = Medium
 );
 
 #[async_trait::async_trait]
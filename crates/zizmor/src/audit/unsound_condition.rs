@@ -16,7 +16,8 @@ pub(crate) struct UnsoundCondition;
 audit_meta!(
     UnsoundCondition,
     "unsound-condition",
-    "unsound conditional expression"
+    "unsound conditional expression",
+    severity = High
 );
 
 impl UnsoundCondition {
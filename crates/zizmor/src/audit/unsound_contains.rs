@@ -35,7 +35,8 @@ pub(crate) struct UnsoundContains;
 audit_meta!(
     UnsoundContains,
     "unsound-contains",
-    "unsound contains condition"
+    "unsound contains condition",
+    severity = High
 );
 
 #[async_trait::async_trait]
@@ -20,7 +20,8 @@ pub(crate) struct UnsoundTernary;
 audit_meta!(
     UnsoundTernary,
     "unsound-ternary",
-    "unsound pseudo-ternary expression"
+    "unsound pseudo-ternary expression",
+    severity = Low
 );
 
 #[async_trait::async_trait]
@@ -151,7 +151,8 @@ pub(crate) struct UseTrustedPublishing {
 audit_meta!(
     UseTrustedPublishing,
     "use-trusted-publishing",
-    "prefer trusted publishing for authentication"
+    "prefer trusted publishing for authentication",
+    severity = Informational
 );
 
 impl UseTrustedPublishing {
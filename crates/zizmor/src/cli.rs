@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 use std::fmt;
 use std::io::stdout;
+use std::num::NonZeroUsize;
 
 use annotate_snippets::renderer::{AnsiColor, Effects};
 use anstream::stream::IsTerminal;
@@ -11,10 +12,11 @@ use clap_complete::Generator;
 use clap_verbosity_flag::InfoLevel;
 use etcetera::AppStrategy as _;
 use tracing::warn;
+use url::Url;
 
 use crate::config::Config;
-use crate::finding::Persona;
-use crate::github::{GitHubHost, GitHubToken};
+use crate::finding::{Confidence, Persona, Severity};
+use crate::github::{GitHubHost, GitHubToken, parse_host_token};
 use crate::utils;
 
 const STYLES: Styles = Styles::styled()
@@ -70,9 +72,24 @@ pub(crate) struct InputArgs {
     /// to audit the repository at a particular git reference state.
     ///
     /// Use `-` to read a single input from stdin.
-    #[arg(required = true, value_name = "INPUT", display_order = 0)]
+    // NOTE: `required_unless_present_any` doesn't honor conflicts the way
+    // `required` does, so we have to list the input-less modes explicitly.
+    #[arg(
+        required_unless_present_any = ["input_from", "clear_cache", "list_audits", "build_info"],
+        value_name = "INPUT",
+        display_order = 0
+    )]
+    #[cfg_attr(feature = "lsp", arg(required_unless_present = "lsp"))]
     pub(crate) inputs: Vec<String>,
 
+    /// Read additional inputs from the given file, one per line.
+    ///
+    /// Blank lines and lines beginning with `#` are ignored.
+    /// Inputs read from the file are audited alongside any
+    /// inputs given on the command line.
+    #[arg(long, value_name = "FILE")]
+    pub(crate) input_from: Option<Utf8PathBuf>,
+
     /// Control which kinds of inputs are collected for auditing.
     ///
     /// By default, all workflows and composite actions are collected,
@@ -84,6 +101,49 @@ pub(crate) struct InputArgs {
     /// in collected inputs.
     #[arg(long)]
     pub(crate) strict_collection: bool,
+
+    /// Only audit collected inputs whose paths match the given glob.
+    ///
+    /// Can be repeated, in which case an input is audited if its path
+    /// matches any of the given globs. Paths are matched relative to
+    /// the input's repository root, when known.
+    #[arg(long = "input-glob", value_name = "PATTERN", value_parser = globset::Glob::new)]
+    pub(crate) input_globs: Vec<globset::Glob>,
+
+    /// Skip collected inputs whose paths match the given glob.
+    ///
+    /// Can be repeated, in which case an input is skipped if its path
+    /// matches any of the given globs. Exclusions take precedence over
+    /// --input-glob.
+    #[arg(long = "exclude", value_name = "PATTERN", value_parser = globset::Glob::new)]
+    pub(crate) exclude_globs: Vec<globset::Glob>,
+
+    /// The filename to use when inferring the kind of a `-` (stdin) input.
+    ///
+    /// Inputs named `action.yml` are audited as actions, `dependabot.yml`
+    /// as Dependabot configurations, `.pre-commit-config.yaml` and
+    /// `.pre-commit-hooks.yaml` as pre-commit inputs, and anything else
+    /// as a workflow.
+    #[arg(long, value_name = "FILENAME")]
+    pub(crate) stdin_filename: Option<Utf8PathBuf>,
+
+    /// The maximum directory depth at which to collect actions,
+    /// relative to each input directory.
+    ///
+    /// A depth of 0 collects only an `action.yml` in the input directory
+    /// itself. Workflows and other inputs under the input directory's
+    /// `.github/` are collected regardless of this limit.
+    /// By default, there is no limit.
+    #[arg(long, value_name = "N")]
+    pub(crate) max_depth: Option<usize>,
+
+    /// Follow symbolic links when collecting inputs from directories.
+    ///
+    /// By default, symlinks within input directories are skipped with
+    /// a warning, since they may point outside of the directory or
+    /// cause the same input to be collected more than once.
+    #[arg(long)]
+    pub(crate) follow_symlinks: bool,
 }
 
 #[derive(Debug, Args)]
@@ -103,6 +163,41 @@ pub(crate) struct AuditArgs {
     )]
     pub(crate) fix: Option<FixMode>,
 
+    /// Apply at most N fixes per run.
+    ///
+    /// Fixes beyond the limit are deferred, and can be applied by
+    /// a subsequent run.
+    #[arg(long, value_name = "N", requires = "fix")]
+    pub(crate) fix_limit: Option<usize>,
+
+    /// Write fixes to FILE as a unified diff, instead of applying them.
+    ///
+    /// No inputs are modified. The diff can be reviewed and then applied
+    /// with `git apply` from the current directory, so all inputs being
+    /// fixed must be under it.
+    #[arg(long, value_name = "FILE", requires = "fix")]
+    pub(crate) fixes_output: Option<Utf8PathBuf>,
+
+    /// Write fixed copies of each input under DIR, instead of fixing them
+    /// in place.
+    ///
+    /// Each copy keeps its input's path relative to the current directory.
+    /// No inputs are modified.
+    #[arg(
+        long,
+        value_name = "DIR",
+        requires = "fix",
+        conflicts_with = "fixes_output"
+    )]
+    pub(crate) fix_out: Option<Utf8PathBuf>,
+
+    /// Apply fixes even if the files being fixed have uncommitted changes.
+    ///
+    /// By default, --fix refuses to modify files with uncommitted changes
+    /// in a git repository, to avoid mixing fixes with unrelated edits.
+    #[arg(long, requires = "fix")]
+    pub(crate) allow_dirty: bool,
+
     /// Emit 'pedantic' findings.
     ///
     /// This is an alias for --persona=pedantic.
@@ -110,8 +205,11 @@ pub(crate) struct AuditArgs {
     pub(crate) pedantic: bool,
 
     /// The persona to use while auditing.
-    #[arg(long, group = "_persona", value_enum, default_value_t)]
-    pub(crate) persona: Persona,
+    ///
+    /// Defaults to the configured persona, or to 'regular' if none
+    /// is configured.
+    #[arg(long, group = "_persona", value_enum)]
+    pub(crate) persona: Option<Persona>,
 
     /// Filter all results below this severity.
     #[arg(long, value_name = "LEVEL")]
@@ -124,6 +222,28 @@ pub(crate) struct AuditArgs {
     /// Don't honor ignore comments or ignore rules in configuration.
     #[arg(long)]
     pub(crate) no_ignores: bool,
+
+    /// Run only the given audit, by ID. Can be repeated.
+    ///
+    /// See --list-audits for all available audit IDs.
+    #[arg(long = "only-audit", value_name = "AUDIT")]
+    pub(crate) only_audits: Vec<String>,
+
+    /// Start from an empty set of audits, rather than the default set.
+    ///
+    /// Audits must then be enabled explicitly with --only-audit.
+    #[arg(long, requires = "only_audits")]
+    pub(crate) no_default_audits: bool,
+
+    /// Audit inputs one at a time, rather than interleaving them.
+    ///
+    /// By default, the audits of multiple inputs are interleaved on a
+    /// single task, so that one input's network requests can overlap with
+    /// another's audits. This doesn't audit inputs on separate threads;
+    /// use --threads to control that. Findings and output are the same
+    /// either way.
+    #[arg(long)]
+    pub(crate) no_parallel_inputs: bool,
 }
 
 #[derive(Debug, Args)]
@@ -133,17 +253,37 @@ pub(crate) struct OutputArgs {
     pub(crate) verbose: clap_verbosity_flag::Verbosity<InfoLevel>,
 
     /// The output format to emit. By default, cargo-style diagnostics will be emitted.
-    #[arg(long, value_enum, default_value_t, value_name = "KIND")]
-    pub(crate) format: OutputFormat,
+    #[arg(long, value_enum, value_name = "KIND")]
+    pub(crate) format: Option<OutputFormat>,
 
     /// Don't show progress bars, even if the terminal supports them.
     #[arg(long)]
     pub(crate) no_progress: bool,
 
+    /// Trace the dataflow analysis behind each template injection finding.
+    ///
+    /// This prints every flagged expression's dataflow contexts and their
+    /// classification (trusted or untrusted) as debug logs on stderr.
+    #[arg(long)]
+    pub(crate) trace_dataflow: bool,
+
+    /// Record the wall-clock time spent in each audit, and print
+    /// a table of audit timings (slowest first) once auditing completes.
+    #[arg(long)]
+    pub(crate) timings: bool,
+
     /// Control the use of color in output.
     #[arg(long, value_enum, value_name = "WHEN")]
     pub(crate) color: Option<ColorMode>,
 
+    /// Replace emoji and other non-ASCII decorations in the output with
+    /// ASCII equivalents.
+    ///
+    /// This is useful for logs that don't render UTF-8 well, e.g. in
+    /// some CI systems.
+    #[arg(long, env = "ZIZMOR_NO_EMOJI")]
+    pub(crate) no_emoji: bool,
+
     /// Whether to render OSC 8 links in the output.
     ///
     /// This affects links under audit IDs, as well as any links
@@ -172,10 +312,48 @@ pub(crate) struct OutputArgs {
     )]
     pub(crate) show_audit_urls: CliShowAuditUrls,
 
+    /// The base URL to use for audit documentation links, in place
+    /// of `https://docs.zizmor.sh/`.
+    ///
+    /// Useful for mirrored or self-hosted copies of zizmor's documentation.
+    /// Only affects `--format=plain` (the default) and `--format=sarif`.
+    #[arg(long, env = "ZIZMOR_RULE_DOCS_BASE", value_name = "URL")]
+    pub(crate) rule_docs_base: Option<Url>,
+
+    /// The maximum number of annotations to emit per audit.
+    ///
+    /// GitHub only renders a limited number of annotations per step, so
+    /// additional annotations are counted in a summary line instead.
+    /// A limit of 0 emits all annotations.
+    ///
+    /// Only affects `--format=github`.
+    #[arg(long, default_value_t = 10, value_name = "N")]
+    pub(crate) github_annotations_limit: usize,
+
+    /// Render local input paths relative to the given directory.
+    ///
+    /// If no directory is given, paths are rendered relative to the
+    /// current directory. Inputs outside of the directory are rendered
+    /// as given.
+    #[arg(long, value_name = "DIR", num_args = 0..=1, default_missing_value = ".")]
+    pub(crate) relative_to: Option<Utf8PathBuf>,
+
     /// Disable all error codes besides success and tool failure.
     #[arg(long)]
     pub(crate) no_exit_codes: bool,
 
+    /// The exit code to use when there are no findings to report.
+    ///
+    /// Takes precedence over `--no-exit-codes` and `--format=sarif`.
+    #[arg(long, value_name = "CODE")]
+    pub(crate) no_findings_exit: Option<u8>,
+
+    /// Exit with a failure (code 1) when there are no findings to report.
+    ///
+    /// Equivalent to `--no-findings-exit=1`.
+    #[arg(long, conflicts_with = "no_findings_exit")]
+    pub(crate) empty_is_error: bool,
+
     /// Enable naches mode.
     #[arg(long, hide = true, env = "ZIZMOR_NACHES")]
     pub(crate) naches: bool,
@@ -204,9 +382,27 @@ pub(crate) struct NetworkArgs {
     pub(crate) zizmor_github_token: Option<GitHubToken>,
 
     /// The GitHub Server Hostname. Defaults to github.com
+    ///
+    /// `--gh-token` is only sent to this host. Remote inputs that name
+    /// another host (e.g. `host/owner/repo`) need a `--gh-host-token`
+    /// for that host.
     #[arg(long, env = "GH_HOST", default_value_t)]
     pub(crate) gh_hostname: GitHubHost,
 
+    /// A GitHub API token for a specific host, as `HOST=TOKEN`.
+    ///
+    /// May be given multiple times, once per host. Each token is only
+    /// sent to its own host.
+    #[arg(
+        long = "gh-host-token",
+        value_name = "HOST=TOKEN",
+        env = "ZIZMOR_GH_HOST_TOKENS",
+        hide_env_values = true,
+        value_delimiter = ',',
+        value_parser = parse_host_token
+    )]
+    pub(crate) gh_host_tokens: Vec<(GitHubHost, GitHubToken)>,
+
     /// Perform only offline audits.
     ///
     /// This is a weaker version of `--offline`: instead of completely
@@ -225,6 +421,10 @@ pub(crate) struct NetworkArgs {
         value_hint = ValueHint::DirPath
     )]
     pub(crate) cache_dir: Utf8PathBuf,
+
+    /// Delete the contents of the HTTP cache directory and exit.
+    #[arg(long, conflicts_with = "inputs")]
+    pub(crate) clear_cache: bool,
 }
 
 #[derive(Args, Debug)]
@@ -252,6 +452,39 @@ pub(crate) struct GlobalArgs {
     #[arg(long, group = "conf")]
     pub(crate) no_config: bool,
 
+    /// Load an audit's configuration from a separate file, as `AUDIT=FILE`.
+    /// The file's contents are used as the audit's `config`, taking
+    /// precedence over any other configuration. May be repeated.
+    #[arg(
+        long,
+        value_name = "AUDIT=FILE",
+        conflicts_with = "no_config",
+        value_hint = ValueHint::FilePath
+    )]
+    pub(crate) audit_config: Vec<AuditConfigArg>,
+
+    /// Apply the named profile from the configuration, as defaults
+    /// for any of its settings that aren't given explicitly.
+    ///
+    /// Profiles are read from the --config file if given, or otherwise
+    /// from the configuration discovered from the current directory.
+    #[arg(long, value_name = "NAME", conflicts_with = "no_config")]
+    pub(crate) profile: Option<String>,
+
+    /// Print the resolved configuration for each input group as JSON, and exit.
+    #[arg(long, requires = "inputs")]
+    pub(crate) config_dump: bool,
+
+    /// Annotate each setting in `--config-dump` with the configuration
+    /// file that supplied it.
+    #[arg(long, requires = "config_dump")]
+    pub(crate) config_provenance: bool,
+
+    /// The number of worker threads to use.
+    /// Defaults to the number of available CPUs.
+    #[arg(long, value_name = "N", env = "ZIZMOR_THREADS")]
+    pub(crate) threads: Option<NonZeroUsize>,
+
     /// Generate tab completion scripts for the specified shell.
     #[arg(long, value_enum, value_name = "SHELL", exclusive = true)]
     pub(crate) completions: Option<Shell>,
@@ -261,6 +494,14 @@ pub(crate) struct GlobalArgs {
     #[arg(long, exclusive = true)]
     pub(crate) generate_schema: bool,
 
+    /// List all available audits and exit.
+    #[arg(long, conflicts_with = "inputs")]
+    pub(crate) list_audits: bool,
+
+    /// Print version and build information as JSON, and exit.
+    #[arg(long, conflicts_with = "inputs")]
+    pub(crate) build_info: bool,
+
     /// Emit thank-you messages for zizmor's sponsors.
     #[arg(long, exclusive = true)]
     pub(crate) thanks: bool,
@@ -280,6 +521,29 @@ pub(crate) struct GlobalArgs {
     pub(crate) version: (),
 }
 
+/// An `--audit-config` argument, i.e. `AUDIT=FILE`.
+#[derive(Clone, Debug)]
+pub(crate) struct AuditConfigArg {
+    /// The audit to configure.
+    pub(crate) ident: String,
+    /// The file containing the audit's configuration.
+    pub(crate) path: Utf8PathBuf,
+}
+
+impl std::str::FromStr for AuditConfigArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((ident, path)) if !ident.is_empty() && !path.is_empty() => Ok(Self {
+                ident: ident.into(),
+                path: path.into(),
+            }),
+            _ => Err("expected `AUDIT=FILE`".into()),
+        }
+    }
+}
+
 // NOTE(ww): This can be removed once `--min-severity=unknown`
 // is fully removed.
 #[derive(Debug, Copy, Clone, ValueEnum)]
@@ -303,6 +567,54 @@ pub(crate) enum CliConfidence {
     High,
 }
 
+impl CliSeverity {
+    /// Returns the [`Severity`] named by this value, or `None` for the
+    /// deprecated `unknown`.
+    pub(crate) fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Unknown => None,
+            Self::Informational => Some(Severity::Informational),
+            Self::Low => Some(Severity::Low),
+            Self::Medium => Some(Severity::Medium),
+            Self::High => Some(Severity::High),
+        }
+    }
+}
+
+impl From<Severity> for CliSeverity {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Informational => Self::Informational,
+            Severity::Low => Self::Low,
+            Severity::Medium => Self::Medium,
+            Severity::High => Self::High,
+        }
+    }
+}
+
+impl CliConfidence {
+    /// Returns the [`Confidence`] named by this value, or `None` for the
+    /// deprecated `unknown`.
+    pub(crate) fn confidence(self) -> Option<Confidence> {
+        match self {
+            Self::Unknown => None,
+            Self::Low => Some(Confidence::Low),
+            Self::Medium => Some(Confidence::Medium),
+            Self::High => Some(Confidence::High),
+        }
+    }
+}
+
+impl From<Confidence> for CliConfidence {
+    fn from(value: Confidence) -> Self {
+        match value {
+            Confidence::Low => Self::Low,
+            Confidence::Medium => Self::Medium,
+            Confidence::High => Self::High,
+        }
+    }
+}
+
 #[cfg(feature = "lsp")]
 #[derive(Args, Debug)]
 #[group(multiple = true, conflicts_with = "inputs")]
@@ -375,6 +687,8 @@ pub(crate) enum OutputFormat {
     Json,
     /// "v1" JSON format.
     JsonV1,
+    /// "v2" JSON format.
+    JsonV2,
     /// SARIF-formatted output.
     Sarif,
     /// GitHub Actions workflow command-formatted output.
@@ -664,6 +978,22 @@ pub(crate) struct CollectionOptions {
     pub(crate) no_config: bool,
     /// Global configuration, if any.
     pub(crate) global_config: Option<Config>,
+    /// Per-audit configuration from `--audit-config`, if any. This is
+    /// applied on top of every other configuration.
+    pub(crate) audit_config: Option<Config>,
+    /// The canonical directory that local input paths are rendered
+    /// relative to, if any.
+    pub(crate) relative_to: Option<Utf8PathBuf>,
+    /// Globs that collected inputs must match at least one of, if any.
+    pub(crate) input_globs: Option<globset::GlobSet>,
+    /// Globs that collected inputs must not match any of, if any.
+    pub(crate) exclude_globs: Option<globset::GlobSet>,
+    /// The filename to infer a stdin input's kind from, if any.
+    pub(crate) stdin_filename: Option<Utf8PathBuf>,
+    /// The maximum directory depth at which to collect actions, if any.
+    pub(crate) max_depth: Option<usize>,
+    /// Whether to follow symlinks when collecting from directories.
+    pub(crate) follow_symlinks: bool,
 }
 
 pub(crate) fn completions<G: clap_complete::Generator>(generator: G, cmd: &mut clap::Command) {
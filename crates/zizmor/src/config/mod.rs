@@ -10,7 +10,7 @@ use anyhow::{Context as _, anyhow};
 use camino::Utf8Path;
 use github_actions_models::common::RepositoryUses;
 use serde::{
-    Deserialize,
+    Deserialize, Serialize,
     de::{self, DeserializeOwned},
 };
 use thiserror::Error;
@@ -21,11 +21,13 @@ pub mod schema;
 use crate::{
     App, CollectionOptions,
     audit::{
-        AuditCore as _, dependabot_cooldown::DependabotCooldown, forbidden_uses::ForbiddenUses,
+        AuditCore as _, curl_pipe_bash::CurlPipeBash, dependabot_cooldown::DependabotCooldown,
+        forbidden_uses::ForbiddenUses, github_token_forwarding::GithubTokenForwarding,
         known_vulnerable_actions::KnownVulnerableActions,
         secrets_outside_env::SecretsOutsideEnvironment, unpinned_uses::UnpinnedUses,
     },
-    finding::{Finding, Severity},
+    cli::{CliConfidence, CliSeverity, OutputFormat},
+    finding::{Confidence, Finding, Persona, Severity},
     github::{Client, ClientError},
     models::uses::RepositoryUsesPattern,
     registry::input::RepoSlug,
@@ -36,6 +38,8 @@ const CONFIG_CANDIDATES: &[&str] = &[
     ".github/zizmor.yaml",
     "zizmor.yml",
     "zizmor.yaml",
+    ".zizmor.yml",
+    ".zizmor.yaml",
 ];
 
 #[derive(Error, Debug)]
@@ -127,6 +131,19 @@ impl FromStr for WorkflowRule {
     }
 }
 
+impl std::fmt::Display for WorkflowRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.filename)?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        if let Some(column) = self.column {
+            write!(f, ":{column}")?;
+        }
+        Ok(())
+    }
+}
+
 impl<'de> Deserialize<'de> for WorkflowRule {
     fn deserialize<D>(deserializer: D) -> anyhow::Result<Self, D::Error>
     where
@@ -137,8 +154,10 @@ impl<'de> Deserialize<'de> for WorkflowRule {
     }
 }
 
-/// Severity level for use in remap configuration.
-#[derive(Clone, Copy, Debug, Deserialize)]
+/// Severity level for use in configuration, e.g. remaps and thresholds.
+///
+/// This is parsed with [`Severity`]'s [`FromStr`] implementation.
+#[derive(Clone, Copy, Debug, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum RemapSeverity {
@@ -159,7 +178,168 @@ impl From<RemapSeverity> for Severity {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl From<Severity> for RemapSeverity {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Informational => Self::Informational,
+            Severity::Low => Self::Low,
+            Severity::Medium => Self::Medium,
+            Severity::High => Self::High,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RemapSeverity {
+    fn deserialize<D>(deserializer: D) -> anyhow::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Severity::from_str(&raw)
+            .map(Into::into)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Confidence level for use in configuration.
+///
+/// This is parsed with [`Confidence`]'s [`FromStr`] implementation.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ConfigConfidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl From<ConfigConfidence> for Confidence {
+    fn from(value: ConfigConfidence) -> Self {
+        match value {
+            ConfigConfidence::Low => Self::Low,
+            ConfigConfidence::Medium => Self::Medium,
+            ConfigConfidence::High => Self::High,
+        }
+    }
+}
+
+impl From<Confidence> for ConfigConfidence {
+    fn from(value: Confidence) -> Self {
+        match value {
+            Confidence::Low => Self::Low,
+            Confidence::Medium => Self::Medium,
+            Confidence::High => Self::High,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfigConfidence {
+    fn deserialize<D>(deserializer: D) -> anyhow::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Confidence::from_str(&raw)
+            .map(Into::into)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Auditing persona for use in configuration.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ConfigPersona {
+    Auditor,
+    Pedantic,
+    Regular,
+}
+
+impl From<ConfigPersona> for Persona {
+    fn from(value: ConfigPersona) -> Self {
+        match value {
+            ConfigPersona::Auditor => Self::Auditor,
+            ConfigPersona::Pedantic => Self::Pedantic,
+            ConfigPersona::Regular => Self::Regular,
+        }
+    }
+}
+
+/// Output format for use in configuration.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ConfigFormat {
+    Plain,
+    Json,
+    JsonV1,
+    JsonV2,
+    Sarif,
+    Github,
+}
+
+impl From<ConfigFormat> for OutputFormat {
+    fn from(value: ConfigFormat) -> Self {
+        match value {
+            ConfigFormat::Plain => Self::Plain,
+            ConfigFormat::Json => Self::Json,
+            ConfigFormat::JsonV1 => Self::JsonV1,
+            ConfigFormat::JsonV2 => Self::JsonV2,
+            ConfigFormat::Sarif => Self::Sarif,
+            ConfigFormat::Github => Self::Github,
+        }
+    }
+}
+
+/// A named preset of command-line settings, selected with `--profile`.
+///
+/// Each setting is applied only if the corresponding flag isn't
+/// given explicitly.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct Profile {
+    /// The persona to use, unless overridden by `--persona`.
+    #[serde(default)]
+    persona: Option<ConfigPersona>,
+    /// Filter all results below this severity, unless overridden by `--min-severity`.
+    #[serde(default)]
+    min_severity: Option<RemapSeverity>,
+    /// Filter all results below this confidence, unless overridden by `--min-confidence`.
+    #[serde(default)]
+    min_confidence: Option<ConfigConfidence>,
+    /// The output format to emit, unless overridden by `--format`.
+    #[serde(default)]
+    format: Option<ConfigFormat>,
+}
+
+impl Profile {
+    /// Applies this profile's settings to `app`, leaving any
+    /// explicitly given flags untouched.
+    pub(crate) fn apply(&self, app: &mut App) {
+        if app.audit.persona.is_none() {
+            app.audit.persona = self.persona.map(Into::into);
+        }
+
+        if app.audit.min_severity.is_none() {
+            app.audit.min_severity = self
+                .min_severity
+                .map(|severity| CliSeverity::from(Severity::from(severity)));
+        }
+
+        if app.audit.min_confidence.is_none() {
+            app.audit.min_confidence = self
+                .min_confidence
+                .map(|confidence| CliConfidence::from(Confidence::from(confidence)));
+        }
+
+        if app.output.format.is_none() {
+            app.output.format = self.format.map(Into::into);
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub(crate) struct RemapConfig {
@@ -192,9 +372,76 @@ pub(crate) struct AuditRuleConfig {
 /// This is a "raw" representation that matches exactly what
 /// we parse from a `zizmor.yml` file.
 #[derive(Clone, Debug, Default, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 struct RawConfig {
+    /// The default persona, if not overridden by `--persona`.
+    #[serde(default)]
+    persona: Option<ConfigPersona>,
+    /// The default minimum severity, if not overridden by `--min-severity`.
+    #[serde(default)]
+    min_severity: Option<RemapSeverity>,
+    /// The default minimum confidence, if not overridden by `--min-confidence`.
+    #[serde(default)]
+    min_confidence: Option<ConfigConfidence>,
+    /// Named presets of command-line settings, selected with `--profile`.
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
     rules: HashMap<String, AuditRuleConfig>,
+    /// Where each of the above settings came from.
+    #[serde(skip)]
+    provenance: Provenance,
+}
+
+/// The source of each setting in a [`RawConfig`], i.e. the path
+/// of the configuration file that supplied it.
+///
+/// Settings without a recorded source were loaded from a configuration
+/// without a known path, or weren't set at all.
+#[derive(Clone, Debug, Default)]
+struct Provenance {
+    persona: Option<String>,
+    min_severity: Option<String>,
+    min_confidence: Option<String>,
+    rules: HashMap<String, RuleProvenance>,
+}
+
+/// The source of each setting in an [`AuditRuleConfig`].
+#[derive(Clone, Debug, Default)]
+struct RuleProvenance {
+    disable: Option<String>,
+    /// The source of each ignore rule, in the same order as
+    /// [`AuditRuleConfig::ignore`].
+    ignore: Vec<String>,
+    config: Option<String>,
+    remap: Option<String>,
+}
+
+impl Provenance {
+    /// Attributes every setting present in `raw` to `source`.
+    fn new(raw: &RawConfig, source: &str) -> Self {
+        let sourced = |present: bool| present.then(|| source.to_string());
+
+        Self {
+            persona: sourced(raw.persona.is_some()),
+            min_severity: sourced(raw.min_severity.is_some()),
+            min_confidence: sourced(raw.min_confidence.is_some()),
+            rules: raw
+                .rules
+                .iter()
+                .map(|(ident, rule)| {
+                    (
+                        ident.clone(),
+                        RuleProvenance {
+                            disable: sourced(rule.disable),
+                            ignore: vec![source.to_string(); rule.ignore.len()],
+                            config: sourced(rule.config.is_some()),
+                            remap: sourced(rule.remap.is_some()),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
 }
 
 impl RawConfig {
@@ -202,6 +449,174 @@ impl RawConfig {
         yaml_serde::from_str(contents).map_err(ConfigErrorInner::Syntax)
     }
 
+    /// Merges this (local) config on top of the given (global) base config.
+    ///
+    /// Top-level settings from `self` take precedence over `base`.
+    /// Rules are merged per audit: ignores are combined, an audit disabled
+    /// in either config stays disabled, and `config`/`remap` from `self`
+    /// replace those from `base` when present.
+    fn merged_onto(mut self, base: &RawConfig) -> Self {
+        let provenance = &mut self.provenance;
+        if self.persona.is_none() {
+            self.persona = base.persona;
+            provenance.persona.clone_from(&base.provenance.persona);
+        }
+        if self.min_severity.is_none() {
+            self.min_severity = base.min_severity;
+            provenance
+                .min_severity
+                .clone_from(&base.provenance.min_severity);
+        }
+        if self.min_confidence.is_none() {
+            self.min_confidence = base.min_confidence;
+            provenance
+                .min_confidence
+                .clone_from(&base.provenance.min_confidence);
+        }
+
+        for (name, profile) in &base.profiles {
+            self.profiles
+                .entry(name.clone())
+                .or_insert_with(|| profile.clone());
+        }
+
+        for (ident, base_rule) in &base.rules {
+            let base_provenance = base
+                .provenance
+                .rules
+                .get(ident)
+                .cloned()
+                .unwrap_or_default();
+
+            let Some(rule) = self.rules.get_mut(ident) else {
+                self.rules.insert(ident.clone(), base_rule.clone());
+                provenance.rules.insert(ident.clone(), base_provenance);
+                continue;
+            };
+
+            let rule_provenance = provenance.rules.entry(ident.clone()).or_default();
+            if !rule.disable && base_rule.disable {
+                rule.disable = true;
+                rule_provenance.disable = base_provenance.disable;
+            }
+            rule.ignore.extend(base_rule.ignore.iter().cloned());
+            rule_provenance.ignore.extend(base_provenance.ignore);
+            if rule.config.is_none() {
+                rule.config.clone_from(&base_rule.config);
+                rule_provenance.config = base_provenance.config;
+            }
+            if rule.remap.is_none() {
+                rule.remap.clone_from(&base_rule.remap);
+                rule_provenance.remap = base_provenance.remap;
+            }
+        }
+
+        self
+    }
+
+    /// Renders this config as JSON, optionally annotating each setting
+    /// with its [`Provenance`].
+    ///
+    /// With provenance, each setting `value` becomes
+    /// `{"value": value, "source": "path/to/zizmor.yml"}`.
+    fn dump(&self, provenance: bool) -> serde_json::Value {
+        let setting = |value: serde_json::Value, source: Option<&String>| {
+            if provenance {
+                serde_json::json!({ "value": value, "source": source })
+            } else {
+                value
+            }
+        };
+
+        let mut dump = serde_json::Map::new();
+        if let Some(persona) = self.persona {
+            dump.insert(
+                "persona".into(),
+                setting(serde_json::json!(persona), self.provenance.persona.as_ref()),
+            );
+        }
+        if let Some(min_severity) = self.min_severity {
+            dump.insert(
+                "min-severity".into(),
+                setting(
+                    serde_json::json!(min_severity),
+                    self.provenance.min_severity.as_ref(),
+                ),
+            );
+        }
+        if let Some(min_confidence) = self.min_confidence {
+            dump.insert(
+                "min-confidence".into(),
+                setting(
+                    serde_json::json!(min_confidence),
+                    self.provenance.min_confidence.as_ref(),
+                ),
+            );
+        }
+
+        let mut rules = self.rules.iter().collect::<Vec<_>>();
+        rules.sort_by_key(|(ident, _)| *ident);
+
+        let rules = rules
+            .into_iter()
+            .map(|(ident, rule)| {
+                let rule_provenance = self.provenance.rules.get(ident);
+
+                let mut dump = serde_json::Map::new();
+                dump.insert(
+                    "disable".into(),
+                    setting(
+                        rule.disable.into(),
+                        rule_provenance.and_then(|p| p.disable.as_ref()),
+                    ),
+                );
+                dump.insert(
+                    "ignore".into(),
+                    rule.ignore
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, ignore)| {
+                            setting(
+                                ignore.to_string().into(),
+                                rule_provenance.and_then(|p| p.ignore.get(idx)),
+                            )
+                        })
+                        .collect(),
+                );
+                if let Some(config) = &rule.config {
+                    dump.insert(
+                        "config".into(),
+                        setting(
+                            serde_json::json!(config),
+                            rule_provenance.and_then(|p| p.config.as_ref()),
+                        ),
+                    );
+                }
+                if let Some(remap) = &rule.remap {
+                    dump.insert(
+                        "remap".into(),
+                        setting(
+                            serde_json::json!(remap),
+                            rule_provenance.and_then(|p| p.remap.as_ref()),
+                        ),
+                    );
+                }
+
+                (ident.clone(), dump.into())
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        dump.insert("rules".into(), rules.into());
+        dump.into()
+    }
+
+    /// Returns `true` if this config has a `config` block for the given audit.
+    fn has_rule_config(&self, ident: &str) -> bool {
+        self.rules
+            .get(ident)
+            .is_some_and(|rule_config| rule_config.config.is_some())
+    }
+
     fn rule_config<T>(&self, ident: &'static str) -> Result<Option<T>, ConfigErrorInner>
     where
         T: DeserializeOwned,
@@ -215,6 +630,26 @@ impl RawConfig {
     }
 }
 
+/// Configuration for the `curl-pipe-bash` audit.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[serde(default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub(crate) struct CurlPipeBashConfig {
+    /// Hosts whose pinned release assets may be piped into a shell.
+    ///
+    /// This list replaces the default list (`github.com`) when set.
+    pub(crate) trusted_hosts: Vec<String>,
+}
+
+impl Default for CurlPipeBashConfig {
+    fn default() -> Self {
+        Self {
+            trusted_hosts: vec!["github.com".into()],
+        }
+    }
+}
+
 /// Configuration for the `dependabot-cooldown` audit.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
@@ -235,6 +670,27 @@ impl Default for DependabotCooldownConfig {
     }
 }
 
+/// Configuration for the `github-token-forwarding` audit.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[serde(default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub(crate) struct GithubTokenForwardingConfig {
+    /// Owners (users or organizations) whose actions may receive the
+    /// workflow's token.
+    ///
+    /// This list replaces the default list (`actions` and `github`) when set.
+    pub(crate) trusted_owners: Vec<String>,
+}
+
+impl Default for GithubTokenForwardingConfig {
+    fn default() -> Self {
+        Self {
+            trusted_owners: vec!["actions".into(), "github".into()],
+        }
+    }
+}
+
 /// An `allow` or `deny` list of `uses:` patterns for the `forbidden-uses` audit.
 #[derive(Clone, Debug, Deserialize)]
 #[cfg_attr(
@@ -487,8 +943,10 @@ pub(crate) struct KnownVulnerableActionsConfig {
 #[derive(Clone, Debug, Default)]
 pub(crate) struct Config {
     raw: RawConfig,
+    pub(crate) curl_pipe_bash_config: CurlPipeBashConfig,
     pub(crate) dependabot_cooldown_config: DependabotCooldownConfig,
     pub(crate) forbidden_uses_config: Option<ForbiddenUsesConfig>,
+    pub(crate) github_token_forwarding_config: GithubTokenForwardingConfig,
     pub(crate) secrets_outside_env_policy: SecretsOutsideEnvPolicy,
     pub(crate) unpinned_uses_policies: UnpinnedUsesPolicies,
     pub(crate) known_vulnerable_actions_config: KnownVulnerableActionsConfig,
@@ -496,8 +954,35 @@ pub(crate) struct Config {
 
 impl Config {
     /// Loads a [`Config`] from the given contents.
-    fn load(contents: &str) -> Result<Self, ConfigErrorInner> {
-        let raw = RawConfig::load(contents)?;
+    pub(crate) fn load(contents: &str) -> Result<Self, ConfigErrorInner> {
+        Self::from_raw(RawConfig::load(contents)?)
+    }
+
+    /// Loads a [`Config`] that configures only the given audit, from the
+    /// contents of that audit's `config` section.
+    ///
+    /// This is the form accepted by `--audit-config`.
+    pub(crate) fn load_audit_config(ident: &str, contents: &str) -> Result<Self, ConfigErrorInner> {
+        let config = yaml_serde::from_str(contents).map_err(ConfigErrorInner::Syntax)?;
+
+        Self::from_raw(RawConfig {
+            rules: [(
+                ident.into(),
+                AuditRuleConfig {
+                    disable: false,
+                    ignore: vec![],
+                    config: Some(config),
+                    remap: None,
+                },
+            )]
+            .into(),
+            ..Default::default()
+        })
+    }
+
+    /// Pre-computes each audit-specific config from the given [`RawConfig`].
+    fn from_raw(raw: RawConfig) -> Result<Self, ConfigErrorInner> {
+        let curl_pipe_bash_config = raw.rule_config(CurlPipeBash::ident())?.unwrap_or_default();
 
         let dependabot_cooldown_config = raw
             .rule_config(DependabotCooldown::ident())?
@@ -505,6 +990,10 @@ impl Config {
 
         let forbidden_uses_config = raw.rule_config(ForbiddenUses::ident())?;
 
+        let github_token_forwarding_config = raw
+            .rule_config(GithubTokenForwarding::ident())?
+            .unwrap_or_default();
+
         let secrets_outside_env_config =
             raw.rule_config::<SecretsOutsideEnvConfig>(SecretsOutsideEnvironment::ident())?;
         let secrets_outside_env_policy = secrets_outside_env_config
@@ -527,25 +1016,95 @@ impl Config {
 
         Ok(Self {
             raw,
+            curl_pipe_bash_config,
             dependabot_cooldown_config,
             forbidden_uses_config,
+            github_token_forwarding_config,
             secrets_outside_env_policy,
             unpinned_uses_policies,
             known_vulnerable_actions_config,
         })
     }
 
+    /// Records `source` (typically the path it was loaded from) as the
+    /// source of every setting in this [`Config`].
+    fn sourced(mut self, source: &str) -> Self {
+        self.raw.provenance = Provenance::new(&self.raw, source);
+        self
+    }
+
+    /// Renders this [`Config`] as JSON, for `--config-dump`.
+    ///
+    /// If `provenance` is `true`, each setting is annotated with
+    /// the configuration file that supplied it.
+    pub(crate) fn dump(&self, provenance: bool) -> serde_json::Value {
+        self.raw.dump(provenance)
+    }
+
+    /// Merges this (local) [`Config`] on top of the given (global) one.
+    ///
+    /// See [`RawConfig::merged_onto`] for the merge rules. Pre-computed
+    /// audit configs are taken from whichever side supplied the audit's
+    /// `config` block, so merging never needs to re-validate them.
+    pub(crate) fn merged_onto(self, base: &Config) -> Self {
+        let take = |ident| self.raw.has_rule_config(ident) || !base.raw.has_rule_config(ident);
+
+        let base = base.clone();
+        Self {
+            curl_pipe_bash_config: if take(CurlPipeBash::ident()) {
+                self.curl_pipe_bash_config
+            } else {
+                base.curl_pipe_bash_config
+            },
+            dependabot_cooldown_config: if take(DependabotCooldown::ident()) {
+                self.dependabot_cooldown_config
+            } else {
+                base.dependabot_cooldown_config
+            },
+            forbidden_uses_config: if take(ForbiddenUses::ident()) {
+                self.forbidden_uses_config
+            } else {
+                base.forbidden_uses_config
+            },
+            github_token_forwarding_config: if take(GithubTokenForwarding::ident()) {
+                self.github_token_forwarding_config
+            } else {
+                base.github_token_forwarding_config
+            },
+            secrets_outside_env_policy: if take(SecretsOutsideEnvironment::ident()) {
+                self.secrets_outside_env_policy
+            } else {
+                base.secrets_outside_env_policy
+            },
+            unpinned_uses_policies: if take(UnpinnedUses::ident()) {
+                self.unpinned_uses_policies
+            } else {
+                base.unpinned_uses_policies
+            },
+            known_vulnerable_actions_config: if take(KnownVulnerableActions::ident()) {
+                self.known_vulnerable_actions_config
+            } else {
+                base.known_vulnerable_actions_config
+            },
+            raw: self.raw.merged_onto(&base.raw),
+        }
+    }
+
     /// Discover a [`Config`] according to the collection options.
     ///
     /// This function models zizmor's current precedence rules for
     /// configuration discovery:
     /// 1. `--no-config` disables all config loading.
-    /// 2. `--config <file>` uses the given config file globally,
-    ///    which we've already loaded into `options.global_config`.
-    /// 3. Otherwise, we use the provided `discover_fn` to attempt
-    ///    to discover a config file. This function is typically one
+    /// 2. Otherwise, we use the provided `discover_fn` to attempt
+    ///    to discover a local config file. This function is typically one
     ///    of [`Config::discover_local`] or [`Config::discover_remote`]
     ///    depending on the input type.
+    /// 3. `--config <file>` supplies a global config, which we've already
+    ///    loaded into `options.global_config`. A discovered local config
+    ///    is merged on top of it; otherwise the global config is used as-is.
+    /// 4. `--audit-config <audit>=<file>` supplies per-audit configs, which
+    ///    we've already loaded into `options.audit_config`. These are merged
+    ///    on top of everything else.
     pub(crate) async fn discover<F>(
         options: &CollectionOptions,
         discover_fn: F,
@@ -556,15 +1115,29 @@ impl Config {
         if options.no_config {
             // User has explicitly disabled config loading.
             tracing::debug!("skipping config discovery: explicitly disabled");
-            Ok(Self::default())
-        } else if let Some(config) = &options.global_config {
-            // The user gave us a (legacy) global config file,
-            // which takes precedence over any discovered config.
-            tracing::debug!("config discovery: using global config: {config:?}");
-            Ok(config.clone())
-        } else {
-            // Attempt to discover a config file using the provided function.
-            discover_fn().await.map(|conf| conf.unwrap_or_default())
+            return Ok(Self::default());
+        }
+
+        let local = discover_fn().await?;
+
+        let config = match (local, &options.global_config) {
+            (Some(local), Some(global)) => {
+                tracing::debug!("config discovery: merging local config onto global config");
+                local.merged_onto(global)
+            }
+            (None, Some(global)) => {
+                tracing::debug!("config discovery: using global config");
+                global.clone()
+            }
+            (local, None) => local.unwrap_or_default(),
+        };
+
+        match &options.audit_config {
+            Some(audit_config) => {
+                tracing::debug!("config discovery: merging per-audit configs");
+                Ok(audit_config.clone().merged_onto(&config))
+            }
+            None => Ok(config),
         }
     }
 
@@ -575,8 +1148,9 @@ impl Config {
     ///    start at the parent (i.e. `blahblah/.github/`). Otherwise, start
     ///    at the given directory. This first directory is the
     ///    first candidate path.
-    /// 2. Look for `.github/zizmor.yml` or `zizmor.yml` in the
-    ///    candidate path. If found, load and return it.
+    /// 2. Look for `.github/zizmor.yml`, then `zizmor.yml`, then
+    ///    `.zizmor.yml` (or their `.yaml` variants) in the candidate path.
+    ///    If found, load and return it.
     /// 3. Otherwise, continue the search in the candidate path's
     ///    parent directory, repeating step 2, terminating when
     ///    we reach the filesystem root or the first .git directory.
@@ -601,7 +1175,10 @@ impl Config {
                 let candidate_path = candidate_path.join(candidate);
                 if candidate_path.is_file() {
                     tracing::debug!("found config candidate at `{candidate_path}`");
-                    return Ok(Some(Self::load(&fs::read_to_string(&candidate_path)?)?));
+                    return Ok(Some(
+                        Self::load(&fs::read_to_string(&candidate_path)?)?
+                            .sourced(candidate_path.as_str()),
+                    ));
                 }
             }
 
@@ -658,8 +1235,8 @@ impl Config {
 
     /// Discover a [`Config`] for a repository slug.
     ///
-    /// This will look for a `.github/zizmor.yml` or `zizmor.yml`
-    /// in the repository's root directory.
+    /// This will look for a `.github/zizmor.yml`, `zizmor.yml`,
+    /// or `.zizmor.yml` in the repository's root directory.
     pub(crate) async fn discover_remote(
         client: &Client,
         slug: &RepoSlug,
@@ -669,10 +1246,14 @@ impl Config {
                 Ok(Some(contents)) => {
                     tracing::debug!("retrieved config candidate `{candidate}` for {slug}");
 
-                    return Some(Self::load(&contents).map_err(|err| ConfigError {
-                        path: candidate.to_string(),
-                        source: err,
-                    }))
+                    return Some(
+                        Self::load(&contents)
+                            .map(|config| config.sourced(&format!("{slug}:{candidate}")))
+                            .map_err(|err| ConfigError {
+                                path: candidate.to_string(),
+                                source: err,
+                            }),
+                    )
                     .transpose();
                 }
                 Ok(None) => {
@@ -705,15 +1286,58 @@ impl Config {
                 source: ConfigErrorInner::Io(err),
             })?;
 
-            Ok(Some(Self::load(&contents).map_err(|err| ConfigError {
-                path: path.to_string(),
-                source: err,
-            })?))
+            Ok(Some(
+                Self::load(&contents)
+                    .map_err(|err| ConfigError {
+                        path: path.to_string(),
+                        source: err,
+                    })?
+                    .sourced(path),
+            ))
         } else {
             Ok(None)
         }
     }
 
+    /// Loads the per-audit [`Config`]s for the given [`App`] into a
+    /// single [`Config`].
+    ///
+    /// Returns `Ok(None)` unless the user specifies at least one
+    /// `--audit-config`. When an audit is given more than once, the last
+    /// file wins.
+    pub(crate) fn audit_configs(app: &App) -> Result<Option<Self>, ConfigError> {
+        let mut merged: Option<Self> = None;
+
+        for arg in &app.args.audit_config {
+            tracing::debug!(
+                "loading `{ident}` config from `{path}`",
+                ident = arg.ident,
+                path = arg.path
+            );
+
+            let config = fs::read_to_string(&arg.path)
+                .map_err(ConfigErrorInner::Io)
+                .and_then(|contents| Self::load_audit_config(&arg.ident, &contents))
+                .map_err(|err| ConfigError {
+                    path: arg.path.to_string(),
+                    source: err,
+                })?
+                .sourced(arg.path.as_str());
+
+            merged = Some(match merged {
+                Some(base) => config.merged_onto(&base),
+                None => config,
+            });
+        }
+
+        Ok(merged)
+    }
+
+    /// Returns the profile with the given name, if this [`Config`] defines one.
+    pub(crate) fn profile(&self, name: &str) -> Option<&Profile> {
+        self.raw.profiles.get(name)
+    }
+
     /// Returns `true` if this [`Config`] disables the given audit rule.
     pub(crate) fn disables(&self, ident: &str) -> bool {
         self.raw
@@ -776,6 +1400,21 @@ impl Config {
         false
     }
 
+    /// Returns this [`Config`]'s default persona, if configured.
+    pub(crate) fn persona(&self) -> Option<Persona> {
+        self.raw.persona.map(Into::into)
+    }
+
+    /// Returns this [`Config`]'s default minimum severity, if configured.
+    pub(crate) fn min_severity(&self) -> Option<Severity> {
+        self.raw.min_severity.map(Into::into)
+    }
+
+    /// Returns this [`Config`]'s default minimum confidence, if configured.
+    pub(crate) fn min_confidence(&self) -> Option<Confidence> {
+        self.raw.min_confidence.map(Into::into)
+    }
+
     /// Returns the remapped [`Severity`] for the given finding's rule, if configured.
     pub(crate) fn severity_remap(&self, finding: &Finding<'_>) -> Option<Severity> {
         // We discussed in https://github.com/zizmorcore/zizmor/issues/1905 whether we should also
@@ -795,7 +1434,8 @@ impl Config {
 mod tests {
     use std::str::FromStr;
 
-    use super::WorkflowRule;
+    use super::{Config, WorkflowRule};
+    use crate::finding::{Persona, Severity};
 
     #[test]
     fn test_parse_workflow_rule() -> anyhow::Result<()> {
@@ -833,4 +1473,135 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_merged_onto() -> anyhow::Result<()> {
+        let global = Config::load(
+            r#"
+persona: auditor
+min-severity: high
+rules:
+  artipacked:
+    disable: true
+  template-injection:
+    ignore:
+      - global.yml
+  unpinned-uses:
+    remap:
+      severity: low
+"#,
+        )?;
+
+        let local = Config::load(
+            r#"
+persona: pedantic
+rules:
+  template-injection:
+    ignore:
+      - local.yml
+  unpinned-uses:
+    remap:
+      severity: high
+"#,
+        )?;
+
+        let merged = local.merged_onto(&global);
+
+        // Top-level settings: local wins, falling back to global.
+        assert_eq!(merged.persona(), Some(Persona::Pedantic));
+        assert_eq!(merged.min_severity(), Some(Severity::High));
+
+        // Rules: global-only rules are retained, ignores are combined,
+        // and local remaps replace global ones.
+        assert!(merged.disables("artipacked"));
+        let ignores = &merged.raw.rules["template-injection"].ignore;
+        assert_eq!(ignores.len(), 2);
+        assert!(matches!(
+            merged.raw.rules["unpinned-uses"]
+                .remap
+                .as_ref()
+                .and_then(|r| r.severity),
+            Some(super::RemapSeverity::High)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deprecated_unknown_rejected() {
+        for config in [
+            "min-severity: unknown\nrules: {}",
+            "min-confidence: unknown\nrules: {}",
+            "rules:\n  artipacked:\n    remap:\n      severity: unknown",
+        ] {
+            let Err(err) = Config::load(config) else {
+                panic!("`unknown` should be rejected in {config:?}");
+            };
+            let err = anyhow::Error::from(err);
+            assert!(format!("{err:#}").contains("deprecated"), "{err:#}");
+        }
+    }
+
+    #[test]
+    fn test_merged_provenance() -> anyhow::Result<()> {
+        let global = Config::load(
+            r#"
+persona: auditor
+min-severity: high
+rules:
+  artipacked:
+    disable: true
+  template-injection:
+    ignore:
+      - global.yml
+"#,
+        )?
+        .sourced("global.yml");
+
+        let local = Config::load(
+            r#"
+persona: pedantic
+rules:
+  template-injection:
+    ignore:
+      - local.yml:10
+"#,
+        )?
+        .sourced("repo/.github/zizmor.yml");
+
+        let merged = local.merged_onto(&global);
+        let dump = merged.dump(true);
+
+        // Settings from the local config report the local path, while
+        // inherited ones report the global path.
+        assert_eq!(
+            dump["persona"],
+            serde_json::json!({ "value": "pedantic", "source": "repo/.github/zizmor.yml" })
+        );
+        assert_eq!(
+            dump["min-severity"],
+            serde_json::json!({ "value": "high", "source": "global.yml" })
+        );
+        assert_eq!(
+            dump["rules"]["artipacked"]["disable"],
+            serde_json::json!({ "value": true, "source": "global.yml" })
+        );
+        assert_eq!(
+            dump["rules"]["template-injection"]["ignore"],
+            serde_json::json!([
+                { "value": "local.yml:10", "source": "repo/.github/zizmor.yml" },
+                { "value": "global.yml", "source": "global.yml" },
+            ])
+        );
+
+        // Without provenance, settings are dumped as bare values.
+        let dump = merged.dump(false);
+        assert_eq!(dump["persona"], "pedantic");
+        assert_eq!(
+            dump["rules"]["template-injection"]["ignore"],
+            serde_json::json!(["local.yml:10", "global.yml"])
+        );
+
+        Ok(())
+    }
 }
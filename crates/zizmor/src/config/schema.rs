@@ -9,11 +9,14 @@
 
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 
 use super::{
-    DependabotCooldownConfig, ForbiddenUsesConfig, KnownVulnerableActionsConfig, RemapConfig,
-    SecretsOutsideEnvConfig, UnpinnedUsesConfig, WorkflowRule,
+    ConfigConfidence, ConfigPersona, CurlPipeBashConfig, DependabotCooldownConfig,
+    ForbiddenUsesConfig, GithubTokenForwardingConfig, KnownVulnerableActionsConfig, Profile,
+    RemapConfig, RemapSeverity, SecretsOutsideEnvConfig, UnpinnedUsesConfig, WorkflowRule,
 };
 
 /// Base configuration for all audit rules.
@@ -30,6 +33,17 @@ struct BaseRuleConfig {
     remap: Option<RemapConfig>,
 }
 
+/// Configuration for the `curl-pipe-bash` audit.
+#[derive(Clone, Debug, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CurlPipeBashRuleConfig {
+    #[serde(flatten)]
+    base: BaseRuleConfig,
+
+    #[serde(default)]
+    config: CurlPipeBashConfig,
+}
+
 /// Configuration for the `dependabot-cooldown` audit.
 #[derive(Clone, Debug, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -52,6 +66,17 @@ struct ForbiddenUsesRuleConfig {
     config: Option<ForbiddenUsesConfig>,
 }
 
+/// Configuration for the `github-token-forwarding` audit.
+#[derive(Clone, Debug, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct GithubTokenForwardingRuleConfig {
+    #[serde(flatten)]
+    base: BaseRuleConfig,
+
+    #[serde(default)]
+    config: Option<GithubTokenForwardingConfig>,
+}
+
 /// Configuration for the `secrets-outside-env` audit.
 #[derive(Clone, Debug, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -131,6 +156,7 @@ define_audit_rules! {
     unsound_condition,
     ref_version_mismatch,
     dependabot_execution,
+    dependabot_registries,
     concurrency_limits,
     archived_uses,
     typosquat_uses,
@@ -139,10 +165,18 @@ define_audit_rules! {
     github_app,
     unpinned_tools,
     adhoc_packages,
-    insecure_url_scheme;
-
+    insecure_url_scheme,
+    constant_if,
+    artifact_execution,
+    secrets_in_conditions,
+    persist_credentials,
+    sensitive_artifacts,
+    concurrency_groups;
+
+    [CurlPipeBashRuleConfig] curl_pipe_bash,
     [DependabotCooldownRuleConfig] dependabot_cooldown,
     [ForbiddenUsesRuleConfig] forbidden_uses,
+    [GithubTokenForwardingRuleConfig] github_token_forwarding,
     [SecretsOutsideEnvRuleConfig] secrets_outside_env,
     [UnpinnedUsesRuleConfig] unpinned_uses,
     [KnownVulnerableActionsRuleConfig] known_vulnerable_actions,
@@ -154,8 +188,24 @@ define_audit_rules! {
 ///
 /// See: https://docs.zizmor.sh/configuration/
 #[derive(Debug, Default, JsonSchema)]
-#[serde(deny_unknown_fields)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 struct Config {
+    /// The persona to use while auditing, unless overridden by `--persona`.
+    #[serde(default)]
+    persona: Option<ConfigPersona>,
+
+    /// Filter all results below this severity, unless overridden by `--min-severity`.
+    #[serde(default)]
+    min_severity: Option<RemapSeverity>,
+
+    /// Filter all results below this confidence, unless overridden by `--min-confidence`.
+    #[serde(default)]
+    min_confidence: Option<ConfigConfidence>,
+
+    /// Named presets of command-line settings, selected with `--profile`.
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
+
     #[serde(default)]
     rules: RulesConfig,
 }
@@ -315,6 +365,40 @@ mod tests {
             .expect("secrets-outside-env allow config with base config should be valid");
     }
 
+    #[test]
+    fn test_github_token_forwarding_config() {
+        let trusted_owners = r#"
+        rules:
+          github-token-forwarding:
+            config:
+              trusted-owners:
+                - actions
+                - example
+        "#;
+
+        let instance = yaml_serde::from_str::<serde_json::Value>(trusted_owners).unwrap();
+        SCHEMA_VALIDATOR
+            .validate(&instance)
+            .expect("github-token-forwarding trusted-owners config should be valid");
+    }
+
+    #[test]
+    fn test_curl_pipe_bash_config() {
+        let trusted_hosts = r#"
+        rules:
+          curl-pipe-bash:
+            config:
+              trusted-hosts:
+                - github.com
+                - github.example.com
+        "#;
+
+        let instance = yaml_serde::from_str::<serde_json::Value>(trusted_hosts).unwrap();
+        SCHEMA_VALIDATOR
+            .validate(&instance)
+            .expect("curl-pipe-bash trusted-hosts config should be valid");
+    }
+
     #[test]
     fn test_unpinned_uses_config() {
         let valid = r#"
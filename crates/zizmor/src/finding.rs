@@ -3,13 +3,14 @@
 use anyhow::anyhow;
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{borrow::Cow, fmt, ops::Range, str::FromStr};
 
 use self::location::{Location, SymbolicLocation};
 use crate::{
     InputKey, audit::AuditError, finding::location::LocationKind, models::AsDocument,
     registry::input::Group,
 };
+use url::Url;
 use yamlpatch::{self, Patch};
 
 pub(crate) mod location;
@@ -67,6 +68,66 @@ pub(crate) enum Severity {
     High,
 }
 
+impl fmt::Display for Confidence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Confidence::Low => f.pad("low"),
+            Confidence::Medium => f.pad("medium"),
+            Confidence::High => f.pad("high"),
+        }
+    }
+}
+
+impl FromStr for Confidence {
+    type Err = anyhow::Error;
+
+    /// Parses a confidence from its [`Display`](fmt::Display) form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(Confidence::Low),
+            "medium" => Ok(Confidence::Medium),
+            "high" => Ok(Confidence::High),
+            "unknown" => Err(anyhow!(
+                "`unknown` is a deprecated confidence and is no longer accepted"
+            )),
+            _ => Err(anyhow!(
+                "invalid confidence: {s} (expected one of: low, medium, high)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Severity::Informational => f.pad("informational"),
+            Severity::Low => f.pad("low"),
+            Severity::Medium => f.pad("medium"),
+            Severity::High => f.pad("high"),
+        }
+    }
+}
+
+impl FromStr for Severity {
+    type Err = anyhow::Error;
+
+    /// Parses a severity from its [`Display`](fmt::Display) form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "informational" => Ok(Severity::Informational),
+            "low" => Ok(Severity::Low),
+            "medium" => Ok(Severity::Medium),
+            "high" => Ok(Severity::High),
+            "unknown" => Err(anyhow!(
+                "`unknown` is a deprecated severity and is no longer accepted"
+            )),
+            _ => Err(anyhow!(
+                "invalid severity: {s} (expected one of: informational, low, medium, high)"
+            )),
+        }
+    }
+}
+
 /// A finding's "determination," i.e. its various classifications.
 #[derive(Copy, Clone, Serialize)]
 pub(crate) struct Determinations {
@@ -124,6 +185,10 @@ impl Fix<'_> {
     }
 }
 
+/// The base URL of zizmor's documentation, as embedded in each
+/// [`Finding::url`].
+pub(crate) const DOCS_BASE: &str = "https://docs.zizmor.sh/";
+
 pub(crate) struct Finding<'doc> {
     /// The audit ID for this finding, e.g. `template-injection`.
     pub(crate) ident: &'static str,
@@ -148,19 +213,37 @@ pub(crate) struct Finding<'doc> {
     /// can span multiple inputs, each fix is associated with a specific
     /// input via [`Fix::key`].
     pub(crate) fixes: Vec<Fix<'doc>>,
+    /// An explicit key for deduplicating this finding against findings
+    /// from other audits. When absent, the audit ID is used instead.
+    pub(crate) dedup_key: Option<&'static str>,
+    /// The CWE IDs that this finding falls under, derived from the audit.
+    pub(crate) cwes: &'static [u32],
 }
 
 impl Finding<'_> {
     /// A basic Markdown representation of the finding's metadata.
-    pub(crate) fn to_markdown(&self) -> String {
+    pub(crate) fn to_markdown(&self, docs_base: Option<&Url>) -> String {
         format!(
             "`{ident}`: {desc}\n\nDocs: <{url}>",
             ident = self.ident,
             desc = self.desc,
-            url = self.url
+            url = self.docs_url(docs_base)
         )
     }
 
+    /// Returns the URL for this finding's audit documentation, rebased
+    /// onto `docs_base` (in place of [`DOCS_BASE`]) if given.
+    pub(crate) fn docs_url(&self, docs_base: Option<&Url>) -> Cow<'static, str> {
+        match (docs_base, self.url.strip_prefix(DOCS_BASE)) {
+            (Some(docs_base), Some(path)) => format!(
+                "{base}/{path}",
+                base = docs_base.as_str().trim_end_matches('/')
+            )
+            .into(),
+            _ => self.url.into(),
+        }
+    }
+
     pub(crate) fn visible_locations(&self) -> impl Iterator<Item = &Location<'_>> {
         self.locations.iter().filter(|l| !l.symbolic.is_hidden())
     }
@@ -180,6 +263,68 @@ impl Finding<'_> {
     pub(crate) fn input_group(&self) -> &Group {
         self.primary_location().symbolic.key.group()
     }
+
+    /// The identities under which this finding is considered a duplicate
+    /// of another.
+    ///
+    /// Findings are always duplicates if they come from the same audit and
+    /// are otherwise identical, i.e. all of their locations and
+    /// annotations match. Findings with an explicit [`Finding::dedup_key`]
+    /// are additionally duplicates whenever they share that key and a
+    /// primary location (both its span and its symbolic route, so that
+    /// YAML aliases of the same content in different places stay distinct).
+    pub(crate) fn dedup_identities(&self) -> Vec<DedupIdentity> {
+        let primary = self.primary_location();
+        let key = primary.symbolic.key.clone();
+        let span = primary.concrete.location.offset_span.clone();
+
+        let mut identities = vec![DedupIdentity::Exact {
+            key: key.clone(),
+            span: span.clone(),
+            ident: self.ident,
+            locations: self
+                .locations
+                .iter()
+                .map(|l| {
+                    (
+                        l.concrete.location.offset_span.clone(),
+                        l.symbolic.annotation.to_string(),
+                    )
+                })
+                .collect(),
+        }];
+
+        if let Some(dedup_key) = self.dedup_key {
+            identities.push(DedupIdentity::Keyed {
+                key,
+                span,
+                route: primary.symbolic.route.to_string(),
+                dedup_key,
+            });
+        }
+
+        identities
+    }
+}
+
+/// Identifies a finding for the purpose of deduplication.
+/// See [`Finding::dedup_identities`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum DedupIdentity {
+    /// An otherwise identical finding from the same audit.
+    Exact {
+        key: InputKey,
+        span: Range<usize>,
+        ident: &'static str,
+        locations: Vec<(Range<usize>, String)>,
+    },
+    /// A finding sharing an explicit dedup key at the same location.
+    Keyed {
+        key: InputKey,
+        span: Range<usize>,
+        route: String,
+        dedup_key: &'static str,
+    },
 }
 
 pub(crate) struct FindingBuilder<'doc> {
@@ -190,9 +335,13 @@ pub(crate) struct FindingBuilder<'doc> {
     confidence: Confidence,
     persona: Persona,
     raw_locations: Vec<Location<'doc>>,
-    locations: Vec<SymbolicLocation<'doc>>,
+    /// Symbolic locations, each with the document it should be concretized
+    /// against. `None` means the document passed to [`FindingBuilder::build`].
+    locations: Vec<(SymbolicLocation<'doc>, Option<&'doc yamlpath::Document>)>,
     tip: Option<String>,
     fixes: Vec<Fix<'doc>>,
+    dedup_key: Option<&'static str>,
+    cwes: &'static [u32],
 }
 
 impl<'doc> FindingBuilder<'doc> {
@@ -208,6 +357,8 @@ impl<'doc> FindingBuilder<'doc> {
             locations: vec![],
             tip: None,
             fixes: vec![],
+            dedup_key: None,
+            cwes: &[],
         }
     }
 
@@ -232,7 +383,24 @@ impl<'doc> FindingBuilder<'doc> {
     }
 
     pub(crate) fn add_location(mut self, location: SymbolicLocation<'doc>) -> Self {
-        self.locations.push(location);
+        self.locations.push((location, None));
+        self
+    }
+
+    /// Add a location that belongs to a different input than the
+    /// document this finding is eventually built against.
+    ///
+    /// The location is concretized against `document`, which must be
+    /// the document for the input identified by the location's
+    /// [`SymbolicLocation::key`].
+    #[allow(dead_code)]
+    pub(crate) fn add_location_in<'a>(
+        mut self,
+        location: SymbolicLocation<'doc>,
+        document: &'a impl AsDocument<'a, 'doc>,
+    ) -> Self {
+        self.locations
+            .push((location, Some(document.as_document())));
         self
     }
 
@@ -246,14 +414,27 @@ impl<'doc> FindingBuilder<'doc> {
         self
     }
 
+    /// Set an explicit deduplication key, allowing this finding to be
+    /// collapsed with findings from other audits that share the same key
+    /// and primary location.
+    pub(crate) fn dedup_key(mut self, key: &'static str) -> Self {
+        self.dedup_key = Some(key);
+        self
+    }
+
+    pub(crate) fn cwes(mut self, cwes: &'static [u32]) -> Self {
+        self.cwes = cwes;
+        self
+    }
+
     pub(crate) fn build<'a>(
         self,
         document: &'a impl AsDocument<'a, 'doc>,
     ) -> Result<Finding<'doc>, AuditError> {
         let mut locations = self
             .locations
-            .iter()
-            .map(|l| l.clone().concretize(document.as_document()))
+            .into_iter()
+            .map(|(l, doc)| l.concretize(doc.unwrap_or_else(|| document.as_document())))
             .collect::<anyhow::Result<Vec<_>>>()
             .map_err(|e| AuditError::new(self.ident, e))?;
 
@@ -286,6 +467,8 @@ impl<'doc> FindingBuilder<'doc> {
             tip: self.tip,
             ignored: should_ignore,
             fixes: self.fixes,
+            dedup_key: self.dedup_key,
+            cwes: self.cwes,
         })
     }
 
@@ -296,3 +479,50 @@ impl<'doc> FindingBuilder<'doc> {
             .any(|c| c.ignores(id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Confidence, Severity};
+
+    #[test]
+    fn test_severity_roundtrip() {
+        for severity in [
+            Severity::Informational,
+            Severity::Low,
+            Severity::Medium,
+            Severity::High,
+        ] {
+            let Ok(parsed) = Severity::from_str(&severity.to_string()) else {
+                panic!("failed to round-trip {severity:?}");
+            };
+            assert_eq!(parsed, severity);
+        }
+
+        let Err(err) = Severity::from_str("unknown") else {
+            panic!("`unknown` should be rejected");
+        };
+        assert!(err.to_string().contains("deprecated"));
+
+        assert!(Severity::from_str("High").is_err());
+        assert!(Severity::from_str("critical").is_err());
+    }
+
+    #[test]
+    fn test_confidence_roundtrip() {
+        for confidence in [Confidence::Low, Confidence::Medium, Confidence::High] {
+            let Ok(parsed) = Confidence::from_str(&confidence.to_string()) else {
+                panic!("failed to round-trip {confidence:?}");
+            };
+            assert_eq!(parsed, confidence);
+        }
+
+        let Err(err) = Confidence::from_str("unknown") else {
+            panic!("`unknown` should be rejected");
+        };
+        assert!(err.to_string().contains("deprecated"));
+
+        assert!(Confidence::from_str("informational").is_err());
+    }
+}
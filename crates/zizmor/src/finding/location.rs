@@ -306,6 +306,50 @@ static_regex!(ANY_COMMENT, r"#.*$");
 
 static_regex!(IGNORE_EXPR, r"# zizmor: ignore\[(.+)\](?:\s+.*)?$");
 
+static_regex!(REGION_EXPR, r"^\s*# zizmor: (off|on)(?:\s+.*)?$");
+
+/// Returns the (zero-indexed) line ranges within `document` that are
+/// disabled by `# zizmor: off` ... `# zizmor: on` comment pairs.
+///
+/// An `off` comment without a matching `on` disables the remainder
+/// of the document.
+pub(crate) fn disabled_regions(document: &yamlpath::Document) -> Vec<Range<usize>> {
+    let mut regions = vec![];
+    let mut start = None;
+    let mut offset = 0;
+
+    for (row, line) in document.source().split_inclusive('\n').enumerate() {
+        let line_offset = offset;
+        offset += line.len();
+
+        let Some(caps) = REGION_EXPR.captures(line.trim_end()) else {
+            continue;
+        };
+
+        // Only YAML comments count, not e.g. shell comments within a block scalar.
+        let comment = line_offset + line.find('#').expect("internal error: missing comment");
+        if !document.offset_inside_comment(comment) {
+            continue;
+        }
+
+        match (&caps[1], start) {
+            ("off", None) => start = Some(row),
+            ("on", Some(off)) => {
+                regions.push(off..row);
+                start = None;
+            }
+            // Redundant `off`s and unpaired `on`s have no effect.
+            _ => {}
+        }
+    }
+
+    if let Some(off) = start {
+        regions.push(off..usize::MAX);
+    }
+
+    regions
+}
+
 /// Represents a single source comment.
 #[derive(Debug, Serialize)]
 #[serde(transparent)]
@@ -0,0 +1,203 @@
+//! Management of zizmor's on-disk HTTP cache.
+//!
+//! The cache directory contains a version stamp ([`VERSION_FILE`]) recording
+//! the cache format ([`VERSION`]) that wrote it. When zizmor encounters a cache
+//! with a different stamp, it clears the cache before using it.
+//!
+//! Only entries that zizmor itself creates are ever removed, and directories
+//! that don't look like a zizmor cache are refused outright, since
+//! `--cache-dir` may point anywhere.
+
+use std::{fs, io};
+
+use camino::Utf8Path;
+
+/// The current version of zizmor's on-disk cache format.
+///
+/// This should be bumped whenever a change (to zizmor itself, or to its
+/// HTTP caching dependencies) makes previously written caches incompatible.
+pub(crate) const VERSION: u32 = 1;
+
+/// The name of the version stamp file within the cache directory.
+pub(crate) const VERSION_FILE: &str = "zizmor-cache-version";
+
+/// Prefixes of the entries that the HTTP cache's backend (`cacache`)
+/// creates within the cache directory.
+const CACACHE_PREFIXES: &[&str] = &["content-v", "index-v", "tmp"];
+
+/// Returns whether the given entry name is one that zizmor creates
+/// within the cache directory.
+fn is_cache_entry(name: &str) -> bool {
+    name == VERSION_FILE
+        || CACACHE_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+/// Returns an error refusing to touch the given directory, which
+/// doesn't look like a zizmor cache.
+fn not_a_cache(cache_dir: &Utf8Path) -> io::Error {
+    io::Error::other(format!(
+        "refusing to use {cache_dir} as a cache: it contains files not created by zizmor"
+    ))
+}
+
+/// Returns whether the given existing directory looks like a zizmor cache,
+/// i.e. has a version stamp or only contains entries that zizmor creates.
+fn is_cache(cache_dir: &Utf8Path) -> io::Result<bool> {
+    if cache_dir.join(VERSION_FILE).exists() {
+        return Ok(true);
+    }
+
+    for entry in fs::read_dir(cache_dir)? {
+        if !entry?.file_name().to_str().is_some_and(is_cache_entry) {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Removes the contents of the given cache directory, leaving the
+/// directory itself in place.
+///
+/// Only entries that zizmor creates are removed. Directories that don't
+/// look like a zizmor cache are refused rather than cleared.
+///
+/// A missing cache directory is not an error.
+pub(crate) fn clear(cache_dir: &Utf8Path) -> io::Result<()> {
+    match is_cache(cache_dir) {
+        Ok(true) => (),
+        Ok(false) => return Err(not_a_cache(cache_dir)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    }
+
+    for entry in fs::read_dir(cache_dir)? {
+        let entry = entry?;
+        if !entry.file_name().to_str().is_some_and(is_cache_entry) {
+            continue;
+        }
+
+        if entry.file_type()?.is_dir() {
+            fs::remove_dir_all(entry.path())?;
+        } else {
+            fs::remove_file(entry.path())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Prepares the given cache directory for use, creating it if necessary.
+///
+/// If the directory has a version stamp from a different cache format,
+/// its contents are cleared. Directories without a stamp are assumed
+/// to be compatible if they only contain entries that zizmor creates,
+/// and are stamped with the current version; otherwise, they're refused.
+pub(crate) fn prepare(cache_dir: &Utf8Path) -> io::Result<()> {
+    fs::create_dir_all(cache_dir)?;
+
+    let stamp_path = cache_dir.join(VERSION_FILE);
+    match fs::read_to_string(&stamp_path) {
+        Ok(stamp) if stamp.trim() == VERSION.to_string() => return Ok(()),
+        Ok(stamp) => {
+            tracing::info!(
+                "invalidating incompatible cache at {cache_dir} (version {stamp}, expected {VERSION})",
+                stamp = stamp.trim(),
+            );
+            clear(cache_dir)?;
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            if !is_cache(cache_dir)? {
+                return Err(not_a_cache(cache_dir));
+            }
+        }
+        Err(e) => return Err(e),
+    }
+
+    fs::write(stamp_path, VERSION.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use camino::Utf8Path;
+
+    use super::{VERSION, VERSION_FILE, clear, prepare};
+
+    fn populate(dir: &Utf8Path) {
+        std::fs::create_dir_all(dir.join("content-v2/sha256")).unwrap();
+        std::fs::write(dir.join("content-v2/sha256/abc"), "cached").unwrap();
+        std::fs::create_dir_all(dir.join("index-v5")).unwrap();
+        std::fs::write(dir.join("index-v5/abc"), "index").unwrap();
+    }
+
+    #[test]
+    fn test_clear() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = Utf8Path::from_path(tempdir.path()).unwrap();
+        populate(dir);
+
+        clear(dir).unwrap();
+        assert!(dir.exists());
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 0);
+
+        // Clearing a nonexistent cache is a no-op.
+        clear(&dir.join("missing")).unwrap();
+
+        // Directories with other contents are refused, unless stamped;
+        // in which case, only the cache's own entries are cleared.
+        populate(dir);
+        std::fs::write(dir.join("notes.txt"), "important").unwrap();
+        assert!(clear(dir).is_err());
+        assert!(dir.join("index-v5").exists());
+
+        std::fs::write(dir.join(VERSION_FILE), VERSION.to_string()).unwrap();
+        clear(dir).unwrap();
+        assert!(!dir.join("index-v5").exists());
+        assert!(!dir.join(VERSION_FILE).exists());
+        assert!(dir.join("notes.txt").exists());
+    }
+
+    #[test]
+    fn test_prepare() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let dir = Utf8Path::from_path(tempdir.path()).unwrap();
+        let stamp = dir.join(VERSION_FILE);
+
+        // Unstamped caches are kept, and stamped.
+        populate(dir);
+        prepare(dir).unwrap();
+        assert!(dir.join("index-v5").exists());
+        assert_eq!(
+            std::fs::read_to_string(&stamp).unwrap(),
+            VERSION.to_string()
+        );
+
+        // Caches with the current stamp are kept.
+        prepare(dir).unwrap();
+        assert!(dir.join("index-v5").exists());
+
+        // Caches with a different stamp are cleared, and re-stamped.
+        std::fs::write(&stamp, "0").unwrap();
+        prepare(dir).unwrap();
+        assert!(!dir.join("index-v5").exists());
+        assert!(!dir.join("content-v2").exists());
+        assert_eq!(
+            std::fs::read_to_string(&stamp).unwrap(),
+            VERSION.to_string()
+        );
+
+        // Missing cache directories are created.
+        let nested = dir.join("nested/cache");
+        prepare(&nested).unwrap();
+        assert!(nested.join(VERSION_FILE).exists());
+
+        // Unstamped directories with other contents are refused.
+        let other = dir.join("other");
+        std::fs::create_dir_all(&other).unwrap();
+        std::fs::write(other.join("notes.txt"), "important").unwrap();
+        assert!(prepare(&other).is_err());
+        assert!(!other.join(VERSION_FILE).exists());
+    }
+}
@@ -322,8 +322,13 @@ impl Backend {
         let mut input_registry = InputRegistry::new();
         input_registry.groups.insert("lsp".into(), group);
 
-        let mut registry =
-            FindingRegistry::new(&input_registry, None, None, self.options.persona, false);
+        let mut registry = FindingRegistry::new(
+            &input_registry,
+            None,
+            None,
+            Some(self.options.persona),
+            false,
+        );
 
         for (input_key, input) in input_registry.iter_inputs() {
             for (ident, audit) in self.audit_registry.iter_audits() {
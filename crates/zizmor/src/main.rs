@@ -2,19 +2,23 @@
 
 use std::{
     io::{Write, stdout},
+    num::NonZeroUsize,
     process::ExitCode,
+    time::{Duration, Instant},
 };
 
 use annotate_snippets::{Group, Level, Renderer};
 use anstream::{eprintln, println, stderr};
 use anyhow::anyhow;
+use camino::{Utf8Path, Utf8PathBuf};
 use clap::{CommandFactory as _, Parser as _};
-use finding::{Confidence, Persona, Severity};
+use finding::{Confidence, Finding, Persona, Severity};
 use futures::stream::{FuturesOrdered, StreamExt as _};
 use indicatif::ProgressStyle;
 use owo_colors::OwoColorize as _;
 use registry::input::{InputKey, InputRegistry};
 use registry::{AuditRegistry, FindingRegistry};
+use serde::Serialize;
 use state::AuditState;
 use terminal_link::Link;
 use thiserror::Error;
@@ -23,7 +27,7 @@ use tracing_indicatif::{IndicatifLayer, span_ext::IndicatifSpanExt as _};
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt as _, util::SubscriberInitExt as _};
 
 use crate::{
-    audit::AuditError,
+    audit::{AuditError, AuditInput},
     cli::{
         App, CliConfidence, CliSeverity, CollectionModeSet, CollectionOptions, ColorMode,
         OutputFormat, completions,
@@ -31,6 +35,7 @@ use crate::{
     config::{Config, ConfigError, ConfigErrorInner},
     github::Client,
     models::AsDocument as _,
+    output::fix::FixTarget,
     registry::input::CollectionError,
     utils::once::warn_once,
 };
@@ -88,6 +93,60 @@ async fn collect_inputs(
     Ok(registry)
 }
 
+/// The findings and per-audit timings from auditing a single input.
+struct InputResults<'doc> {
+    input: &'doc AuditInput,
+    findings: Vec<Finding<'doc>>,
+    timings: Vec<(&'static str, Duration)>,
+}
+
+/// Runs every audit in `audit_registry` on `input`.
+async fn audit_input<'doc>(
+    audit_registry: &AuditRegistry,
+    input: &'doc AuditInput,
+    config: &Config,
+) -> Result<InputResults<'doc>, Error> {
+    Span::current().pb_set_message(input.key().filename());
+
+    if input.as_document().has_anchors() {
+        warn_once!(
+            "one or more inputs contains YAML anchors; see https://docs.zizmor.sh/usage/#yaml-anchors for details"
+        );
+    }
+
+    let mut findings = vec![];
+    let mut timings = vec![];
+
+    let mut completion_stream = FuturesOrdered::new();
+    for (ident, audit) in audit_registry.iter_audits() {
+        tracing::debug!("scheduling {ident} on {input}", input = input.key());
+
+        completion_stream.push_back(async move {
+            let start = Instant::now();
+            let findings = audit.audit(ident, input, config).await;
+            (*ident, start.elapsed(), findings)
+        });
+    }
+
+    while let Some((ident, elapsed, audit_findings)) = completion_stream.next().await {
+        timings.push((ident, elapsed));
+
+        findings.extend(audit_findings.map_err(|err| Error::Audit {
+            ident: err.ident(),
+            source: err,
+            input: input.key().to_string(),
+        })?);
+
+        Span::current().pb_inc(1);
+    }
+
+    Ok(InputResults {
+        input,
+        findings,
+        timings,
+    })
+}
+
 /// Top-level errors.
 #[derive(Debug, Error)]
 enum Error {
@@ -103,6 +162,21 @@ enum Error {
     /// An error from the GitHub API client.
     #[error(transparent)]
     Client(#[from] github::ClientError),
+    /// An error while clearing the HTTP cache.
+    #[error("failed to clear cache at {cache_dir}")]
+    ClearCache {
+        cache_dir: Utf8PathBuf,
+        source: std::io::Error,
+    },
+    /// A `--profile` was given, but no configuration defines it.
+    #[error("no configuration defines a profile named `{0}`")]
+    UnknownProfile(String),
+    /// An error while reading inputs from an `--input-from` file.
+    #[error("failed to read inputs from {path}")]
+    InputFrom {
+        path: Utf8PathBuf,
+        source: std::io::Error,
+    },
     /// An error while loading audit rules.
     #[error("failed to load audit rules")]
     AuditLoad(#[source] anyhow::Error),
@@ -121,25 +195,113 @@ enum Error {
     Fix(#[source] anyhow::Error),
 }
 
+/// Builds a [`globset::GlobSet`] from the given globs, or `None`
+/// if there are no globs.
+fn glob_set(globs: &[globset::Glob]) -> Result<Option<globset::GlobSet>, CollectionError> {
+    if globs.is_empty() {
+        return Ok(None);
+    }
+
+    let mut builder = globset::GlobSetBuilder::new();
+    for glob in globs {
+        builder.add(glob.clone());
+    }
+    Ok(Some(builder.build()?))
+}
+
+/// Prints a table of all known audits and their metadata.
+fn list_audits() -> Result<(), Error> {
+    // NOTE: We use a default (offline) state here, since we only need
+    // each audit's static metadata. Skipped audits are still listed.
+    let registry =
+        AuditRegistry::default_audits(&AuditState::default()).map_err(Error::AuditLoad)?;
+
+    let width = registry.idents().map(str::len).max().unwrap_or_default();
+    for meta in registry.metadata() {
+        println!(
+            "{ident:<width$}  {severity:<13}  {network:<7}  {desc}",
+            ident = meta.ident,
+            severity = meta.severity,
+            network = if meta.online { "online" } else { "offline" },
+            desc = meta.desc,
+        );
+    }
+
+    Ok(())
+}
+
+/// Machine-readable version and build information, as emitted
+/// by `--build-info`.
+#[derive(Serialize)]
+struct BuildInfo {
+    version: &'static str,
+    features: Vec<&'static str>,
+    git_sha: Option<&'static str>,
+}
+
+impl BuildInfo {
+    fn current() -> Self {
+        let features = [
+            ("lsp", cfg!(feature = "lsp")),
+            ("schema", cfg!(feature = "schema")),
+        ]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect();
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            features,
+            git_sha: option_env!("ZIZMOR_GIT_SHA"),
+        }
+    }
+}
+
 async fn run(app: &mut App) -> Result<ExitCode, Error> {
     #[cfg(feature = "lsp")]
     if app.args.lsp.lsp {
         lsp::run(lsp::LspOptions {
-            persona: app.audit.persona,
+            persona: app.audit.persona.unwrap_or_default(),
         })
         .await?;
         return Ok(ExitCode::SUCCESS);
     }
 
+    if app.args.build_info {
+        println!(
+            "{}",
+            serde_json::to_string(&BuildInfo::current()).expect("build info is serializable")
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
     if app.args.thanks {
         println!("zizmor's development is sustained by our generous sponsors:");
         for (name, url) in THANKS {
             let link = Link::new(name, url);
-            println!("🌈 {link}")
+            if app.output.no_emoji {
+                println!("* {link}")
+            } else {
+                println!("🌈 {link}")
+            }
         }
         return Ok(ExitCode::SUCCESS);
     }
 
+    if app.args.list_audits {
+        list_audits()?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if app.network.clear_cache {
+        github::cache::clear(&app.network.cache_dir).map_err(|e| Error::ClearCache {
+            cache_dir: app.network.cache_dir.clone(),
+            source: e,
+        })?;
+        eprintln!("cleared cache at {}", app.network.cache_dir);
+        return Ok(ExitCode::SUCCESS);
+    }
+
     #[cfg(feature = "schema")]
     if app.args.generate_schema {
         println!("{}", config::schema::generate_schema());
@@ -190,7 +352,7 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
 
     // `--pedantic` is a shortcut for `--persona=pedantic`.
     if app.audit.pedantic {
-        app.audit.persona = Persona::Pedantic;
+        app.audit.persona = Some(Persona::Pedantic);
     }
 
     // Merge `--github-token` or `--zizmor-github-token` into `--gh-token`, if present.
@@ -202,12 +364,23 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
         .or(app.network.github_token.take())
         .or(app.network.zizmor_github_token.take());
 
+    // A host token for `--gh-hostname` doubles as `--gh-token`.
+    if app.network.gh_token.is_none() {
+        app.network.gh_token = app
+            .network
+            .gh_host_tokens
+            .iter()
+            .find(|(host, _)| host == &app.network.gh_hostname)
+            .map(|(_, token)| token.clone());
+    }
+
     // Unset the GitHub token if we're in explicit offline mode.
     // We do this manually instead of with clap's `conflicts_with` because
     // we want to support explicitly enabling offline mode while still
     // having `GH_TOKEN` present in the environment.
     if app.network.offline {
         app.network.gh_token = None;
+        app.network.gh_host_tokens.clear();
     }
 
     let indicatif_layer = IndicatifLayer::new();
@@ -228,6 +401,15 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
     #[allow(clippy::unwrap_used)]
     let filter = filter.add_directive("http_cache::managers::cacache=error".parse().unwrap());
 
+    // `--trace-dataflow` enables the debug-level dataflow traces
+    // regardless of the overall verbosity.
+    #[allow(clippy::unwrap_used)]
+    let filter = if app.output.trace_dataflow {
+        filter.add_directive("zizmor::dataflow=debug".parse().unwrap())
+    } else {
+        filter
+    };
+
     let reg = tracing_subscriber::registry()
         .with(
             tracing_subscriber::fmt::layer()
@@ -244,7 +426,27 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
         reg.with(indicatif_layer).init();
     }
 
-    tracing::info!("🌈 zizmor v{version}", version = env!("CARGO_PKG_VERSION"));
+    let rainbow = if app.output.no_emoji { "" } else { "🌈 " };
+
+    tracing::info!(
+        "{rainbow}zizmor v{version}",
+        version = env!("CARGO_PKG_VERSION")
+    );
+
+    if let Some(path) = &app.input.input_from {
+        let contents = std::fs::read_to_string(path).map_err(|e| Error::InputFrom {
+            path: path.clone(),
+            source: e,
+        })?;
+
+        app.input.inputs.extend(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(String::from),
+        );
+    }
 
     // Validate stdin input constraints: `-` must be the only input,
     // and cannot be combined with `--fix`.
@@ -266,20 +468,44 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
             )
             .exit();
         }
+    } else if app.input.stdin_filename.is_some() {
+        let mut cmd = App::command();
+        cmd.error(
+            clap::error::ErrorKind::ArgumentConflict,
+            "`--stdin-filename` can only be used with `-` (stdin)",
+        )
+        .exit();
     }
 
     let collection_mode_set = CollectionModeSet::from(app.input.collect.as_slice());
 
+    let global_config = Config::global(app)?;
+
+    // Apply `--profile` before resolving any of the settings it can supply.
+    if let Some(name) = app.args.profile.clone() {
+        let profile_config = match &global_config {
+            Some(config) => Some(config.clone()),
+            None => Config::discover_local(Utf8Path::new(".")).await?,
+        };
+
+        let profile = profile_config
+            .as_ref()
+            .and_then(|config| config.profile(&name))
+            .cloned()
+            .ok_or(Error::UnknownProfile(name))?;
+
+        profile.apply(app);
+    }
+
+    let format = app.output.format.unwrap_or_default();
+
     let min_severity = match app.audit.min_severity {
         Some(CliSeverity::Unknown) => {
             tracing::warn!("`unknown` is a deprecated minimum severity that has no effect");
             tracing::warn!("future versions of zizmor will reject this value");
             None
         }
-        Some(CliSeverity::Informational) => Some(Severity::Informational),
-        Some(CliSeverity::Low) => Some(Severity::Low),
-        Some(CliSeverity::Medium) => Some(Severity::Medium),
-        Some(CliSeverity::High) => Some(Severity::High),
+        Some(severity) => severity.severity(),
         None => None,
     };
 
@@ -289,19 +515,20 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
             tracing::warn!("future versions of zizmor will reject this value");
             None
         }
-        Some(CliConfidence::Low) => Some(Confidence::Low),
-        Some(CliConfidence::Medium) => Some(Confidence::Medium),
-        Some(CliConfidence::High) => Some(Confidence::High),
+        Some(confidence) => confidence.confidence(),
         None => None,
     };
 
-    let global_config = Config::global(app)?;
+    let audit_config = Config::audit_configs(app)?;
 
     let gh_client = app
         .network
         .gh_token
         .as_ref()
-        .map(|token| Client::new(&app.network.gh_hostname, token, &app.network.cache_dir))
+        .map(|token| {
+            Client::new(&app.network.gh_hostname, token, &app.network.cache_dir)?
+                .with_host_tokens(&app.network.gh_host_tokens, &app.network.cache_dir)
+        })
         .transpose()?;
 
     let collection_options = CollectionOptions {
@@ -309,6 +536,18 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
         strict: app.input.strict_collection,
         no_config: app.args.no_config,
         global_config,
+        audit_config,
+        relative_to: app
+            .output
+            .relative_to
+            .as_deref()
+            .map(|dir| dir.canonicalize_utf8().map_err(CollectionError::Io))
+            .transpose()?,
+        input_globs: glob_set(&app.input.input_globs)?,
+        exclude_globs: glob_set(&app.input.exclude_globs)?,
+        stdin_filename: app.input.stdin_filename.clone(),
+        max_depth: app.input.max_depth,
+        follow_symlinks: app.input.follow_symlinks,
     };
 
     let registry = collect_inputs(
@@ -318,9 +557,62 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
     )
     .await?;
 
+    if app.args.config_dump {
+        let dump = registry
+            .groups
+            .keys()
+            .map(|group| {
+                (
+                    group.as_str().to_string(),
+                    registry.get_config(group).dump(app.args.config_provenance),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        serde_json::to_writer_pretty(stdout(), &dump).map_err(|err| Error::Output(anyhow!(err)))?;
+        println!();
+        return Ok(ExitCode::SUCCESS);
+    }
+
     let state = AuditState::new(app.network.no_online_audits, gh_client);
 
-    let audit_registry = AuditRegistry::default_audits(&state).map_err(Error::AuditLoad)?;
+    // `--no-default-audits` starts from an empty set, which `--only-audit`
+    // then populates. `--only-audit` on its own filters the default set.
+    let audit_registry = if app.audit.no_default_audits {
+        AuditRegistry::selected_audits(&state, &app.audit.only_audits)
+    } else {
+        AuditRegistry::default_audits(&state).map(|mut registry| {
+            if !app.audit.only_audits.is_empty() {
+                registry.retain_audits(&app.audit.only_audits);
+            }
+            registry
+        })
+    }
+    .map_err(Error::AuditLoad)?;
+
+    if let Some(unknown) = app
+        .audit
+        .only_audits
+        .iter()
+        .find(|ident| !audit_registry.idents().any(|known| known == ident.as_str()))
+    {
+        let mut cmd = App::command();
+        cmd.error(
+            clap::error::ErrorKind::InvalidValue,
+            format!("unknown audit for `--only-audit`: {unknown}"),
+        )
+        .exit();
+    }
+
+    for arg in &app.args.audit_config {
+        if !audit_registry.idents().any(|known| known == arg.ident) {
+            tracing::warn!(
+                "unknown audit for `--audit-config`: {ident} (ignoring {path})",
+                ident = arg.ident,
+                path = arg.path
+            );
+        }
+    }
 
     let mut results = FindingRegistry::new(
         &registry,
@@ -329,6 +621,9 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
         app.audit.persona,
         app.audit.no_ignores,
     );
+    let mut timings = app.output.timings.then(|| {
+        output::timings::Timings::new(audit_registry.iter_audits().map(|(ident, _)| *ident))
+    });
     {
         // Note: block here so that we drop the span here at the right time.
         let span = info_span!("audit");
@@ -356,76 +651,152 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
             );
         }
 
-        for (input_key, input) in registry.iter_inputs() {
-            Span::current().pb_set_message(input.key().filename());
-
-            if input.as_document().has_anchors() {
-                warn_once!(
-                    "one or more inputs contains YAML anchors; see https://docs.zizmor.sh/usage/#yaml-anchors for details"
-                );
-            }
-
-            let mut completion_stream = FuturesOrdered::new();
-            let config = registry.get_config(input_key.group());
-            for (ident, audit) in audit_registry.iter_audits() {
-                tracing::debug!("scheduling {ident} on {input}", input = input.key());
-
-                completion_stream.push_back(audit.audit(ident, input, config));
+        // Conversely, when the user explicitly disables online audits, we
+        // report each skipped audit on each input to pedantic (and auditor)
+        // users as an informational finding. We avoid producing these for
+        // everybody else, since they'd only inflate the suppression counts.
+        let explicitly_offline = app.network.offline || app.network.no_online_audits;
+
+        // Inputs are audited concurrently, up to a bound. This is cooperative
+        // interleaving on the current task rather than parallelism: an input's
+        // audits only yield to another input's while awaiting I/O (e.g. the
+        // GitHub API in online audits), so purely offline audits effectively
+        // run one input at a time. `buffered` yields each input's results in
+        // input order regardless of completion order, so findings (and
+        // therefore output) are identical to a sequential run.
+        let interleaved_inputs = if app.audit.no_parallel_inputs {
+            1
+        } else {
+            std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+        };
+
+        let mut input_stream = futures::stream::iter(registry.iter_inputs())
+            .map(|(input_key, input)| {
+                audit_input(
+                    &audit_registry,
+                    input,
+                    registry.get_config(input_key.group()),
+                )
+            })
+            .buffered(interleaved_inputs);
+
+        while let Some(input_results) = input_stream.next().await {
+            let input_results = input_results?;
+
+            if let Some(timings) = &mut timings {
+                for (ident, elapsed) in input_results.timings {
+                    timings.record(ident, elapsed);
+                }
             }
 
-            while let Some(findings) = completion_stream.next().await {
-                let findings = findings.map_err(|err| Error::Audit {
-                    ident: err.ident(),
-                    source: err,
-                    input: input.key().to_string(),
-                })?;
-
-                results.extend(findings);
-
-                Span::current().pb_inc(1);
+            results.extend(input_results.findings);
+
+            let config = registry.get_config(input_results.input.key().group());
+            let persona = app
+                .audit
+                .persona
+                .or_else(|| config.persona())
+                .unwrap_or_default();
+            if explicitly_offline && persona <= Persona::Pedantic {
+                results.extend(
+                    audit_registry
+                        .skipped_online_findings(input_results.input, config)
+                        .map_err(|err| Error::Audit {
+                            ident: err.ident(),
+                            source: err,
+                            input: input_results.input.key().to_string(),
+                        })?,
+                );
             }
 
             tracing::info!(
-                "🌈 completed {input}",
-                input = input.key().presentation_path()
+                "{rainbow}completed {input}",
+                input = input_results.input.key().presentation_path()
             );
         }
     }
 
-    match app.output.format {
+    match format {
         OutputFormat::Plain => output::plain::render_findings(
             &registry,
             &results,
             &app.output.show_audit_urls.into(),
             &app.output.render_links.into(),
+            app.output.rule_docs_base.as_ref(),
             app.output.naches,
+            app.output.no_emoji,
         ),
         OutputFormat::Json | OutputFormat::JsonV1 => {
-            output::json::v1::output(stdout(), results.findings()).map_err(Error::Output)?
-        }
-        OutputFormat::Sarif => {
-            serde_json::to_writer_pretty(stdout(), &output::sarif::build(results.findings()))
-                .map_err(|err| Error::Output(anyhow!(err)))?
+            output::json::v1::output(stdout(), &audit_registry, results.findings())
+                .map_err(Error::Output)?
         }
-        OutputFormat::Github => {
-            output::github::output(stdout(), results.findings()).map_err(Error::Output)?
+        OutputFormat::JsonV2 => {
+            output::json::v2::output(stdout(), &registry, &audit_registry, results.findings())
+                .map_err(Error::Output)?
         }
+        OutputFormat::Sarif => serde_json::to_writer_pretty(
+            stdout(),
+            &output::sarif::build(
+                &registry,
+                &audit_registry,
+                results.findings(),
+                app.output.rule_docs_base.as_ref(),
+            ),
+        )
+        .map_err(|err| Error::Output(anyhow!(err)))?,
+        OutputFormat::Github => output::github::output(
+            stdout(),
+            results.findings(),
+            app.output.github_annotations_limit,
+        )
+        .map_err(Error::Output)?,
     };
 
+    if let Some(timings) = &timings {
+        timings.render();
+    }
+
     let all_fixed = if let Some(fix_mode) = app.audit.fix {
-        let fix_result =
-            output::fix::apply_fixes(fix_mode, &results, &registry).map_err(Error::Fix)?;
+        let target = match (&app.audit.fixes_output, &app.audit.fix_out) {
+            (Some(fixes_output), _) => FixTarget::Patch(fixes_output),
+            (_, Some(fix_out)) => FixTarget::Directory(fix_out),
+            (None, None) => FixTarget::InPlace,
+        };
+
+        let fix_result = output::fix::apply_fixes(
+            fix_mode,
+            app.audit.fix_limit,
+            app.audit.allow_dirty,
+            target,
+            &results,
+            &registry,
+        )
+        .map_err(Error::Fix)?;
 
         // If all findings have applicable fixes and all were successfully applied,
-        // we should exit with success.
-        results.all_findings_have_applicable_fixes(fix_mode)
+        // we should exit with success. Fixes written to a patch or to copies
+        // haven't been applied to the inputs, so they don't count.
+        matches!(target, FixTarget::InPlace)
+            && results.all_findings_have_applicable_fixes(fix_mode)
             && fix_result.failed_count == 0
+            && fix_result.deferred_count == 0
+            && fix_result.conflict_count == 0
             && fix_result.applied_count > 0
     } else {
         false
     };
 
-    if app.output.no_exit_codes || matches!(app.output.format, OutputFormat::Sarif) {
+    let no_findings_exit = if app.output.empty_is_error {
+        Some(ExitCode::FAILURE)
+    } else {
+        app.output.no_findings_exit.map(ExitCode::from)
+    };
+
+    if let Some(code) = no_findings_exit
+        && results.is_empty()
+    {
+        Ok(code)
+    } else if app.output.no_exit_codes || matches!(format, OutputFormat::Sarif) {
         Ok(ExitCode::SUCCESS)
     } else if all_fixed {
         // All findings were auto-fixed, no manual intervention needed
@@ -435,12 +806,34 @@ async fn run(app: &mut App) -> Result<ExitCode, Error> {
     }
 }
 
-#[tokio::main]
-async fn main() -> ExitCode {
+fn main() -> ExitCode {
     human_panic::setup_panic!();
 
-    let mut app = App::parse();
+    let app = App::parse();
+
+    // NOTE: We build the runtime by hand rather than via `#[tokio::main]`
+    // so that `--threads` can size its worker pool.
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.enable_all();
+    if let Some(threads) = app.args.threads {
+        builder.worker_threads(threads.get());
+    }
 
+    let runtime = match builder.build() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            eprintln!(
+                "{fatal}: failed to start runtime: {err}",
+                fatal = "fatal".red().bold()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    runtime.block_on(async_main(app))
+}
+
+async fn async_main(mut app: App) -> ExitCode {
     // This is a little silly, but returning an ExitCode like this ensures
     // we always exit cleanly, rather than performing a hard process exit.
     match run(&mut app).await {
@@ -566,6 +959,30 @@ async fn main() -> ExitCode {
 
                         Some(report)
                     }
+                    CollectionError::Unreadable(path, source) => {
+                        let help = match source.kind() {
+                            std::io::ErrorKind::NotFound => {
+                                format!(
+                                    "{path} may be a broken symlink; ensure that its target exists"
+                                )
+                            }
+                            std::io::ErrorKind::PermissionDenied => {
+                                format!("ensure that {path} is readable by the current user")
+                            }
+                            std::io::ErrorKind::InvalidData => {
+                                format!("ensure that {path} is UTF-8 encoded")
+                            }
+                            _ => format!("ensure that {path} is a readable file"),
+                        };
+
+                        let group = Group::with_title(Level::ERROR.primary_title(err.to_string()))
+                            .element(Level::HELP.message(help));
+
+                        let renderer = Renderer::styled();
+                        let report = renderer.render(&[group]);
+
+                        Some(report)
+                    }
                     CollectionError::AmbiguousRemoteRef { slug } => {
                         let group = Group::with_title(Level::ERROR.primary_title(err.to_string()))
                             .elements([
@@ -8,10 +8,12 @@ use github_actions_models::common::expr::LoE;
 
 use crate::finding::location::{Locatable, SymbolicLocation};
 use crate::models::inputs::HasInputs;
+use crate::models::uses::{ResolvedUses, UsesExt as _};
 use crate::models::workflow::matrix::Matrix;
 use crate::registry::input::CollectionError;
 use crate::utils::ExtractedExpr;
 
+use std::borrow::Cow;
 use std::fmt::Write as _;
 
 /// Returns whether the given `if:` condition is statically known to be false,
@@ -39,6 +41,26 @@ pub(crate) fn if_is_statically_false(cond: &common::If) -> bool {
     }
 }
 
+/// Returns the truthiness of the given `if:` condition, if it's
+/// statically known.
+///
+/// Unlike [`if_is_statically_false`], this applies GitHub Actions'
+/// truthiness rules to non-Boolean results, e.g. `if: ${{ 'foo' }}`
+/// is statically true.
+///
+/// Returns `None` if the condition depends on runtime context, or if it
+/// can't be parsed.
+pub(crate) fn if_static_truthiness(cond: &common::If) -> Option<bool> {
+    match cond {
+        common::If::Bool(b) => Some(*b),
+        common::If::Expr(raw) => {
+            let bare = ExtractedExpr::new(raw).as_bare();
+            let expr = Expr::parse(bare).ok()?;
+            expr.consteval().map(|eval| eval.as_boolean())
+        }
+    }
+}
+
 pub(crate) mod action;
 pub(crate) mod coordinate;
 pub(crate) mod dependabot;
@@ -93,6 +115,10 @@ pub(crate) trait Validatable<'de> {
     fn validator() -> &'static jsonschema::Validator;
 
     fn validate(contents: &'de str) -> Result<Self::Target, CollectionError> {
+        // NOTE: yaml_serde treats a leading byte order mark as the start
+        // of another document, so we strip it like `yamlpath::Document` does.
+        let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+
         match yaml_serde::from_str::<Self::Target>(contents) {
             Ok(value) => Ok(value),
             Err(e) => {
@@ -163,6 +189,26 @@ pub(crate) enum StepBodyCommon<'s> {
     },
 }
 
+/// Returns the value of `name` in the first of the given `env:` blocks
+/// that declares it.
+///
+/// `envs` is ordered from the narrowest scope to the broadest. An `env:`
+/// block that's wholly an expression can't be enumerated and may shadow
+/// any broader declaration, so reaching one produces `None`.
+fn env_value<'doc>(name: &str, envs: &[&'doc LoE<Env>]) -> Option<&'doc common::EnvValue> {
+    for env in envs {
+        let LoE::Literal(env) = env else {
+            return None;
+        };
+
+        if let Some(value) = env.get(name) {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
 /// Common interfaces between workflow and action steps.
 pub(crate) trait StepCommon<'doc>: Locatable<'doc> + HasInputs {
     /// Returns an `Ord` implementation suitable for ordering two steps
@@ -177,13 +223,62 @@ pub(crate) trait StepCommon<'doc>: Locatable<'doc> + HasInputs {
     /// stack of traits implied by ordering (`PartialEq`, `PartialOrd`, etc).
     fn ord(&self) -> impl Ord;
 
+    /// Returns this step's 0-based index within its parent's `steps:` block.
+    ///
+    /// Steps within a `parallel:` block share their parent's index.
+    fn index(&self) -> usize;
+
+    /// Returns this step's `name:`, if it has one.
+    fn name(&self) -> Option<&'doc str>;
+
+    /// Returns this step's 1-based number within its parent's `steps:` block,
+    /// for use in human-facing messages (e.g. "step 3").
+    fn number(&self) -> usize {
+        self.index() + 1
+    }
+
+    /// Returns a human-readable label for this step, for use in messages.
+    ///
+    /// This is the step's `name:` if it has one, or the action it uses
+    /// (without its ref) otherwise. Unnamed `run:` steps have no label.
+    fn label(&self) -> Option<Cow<'doc, str>> {
+        if let Some(name) = self.name() {
+            return Some(name.into());
+        }
+
+        self.resolved_uses().map(|uses| match uses {
+            ResolvedUses::Remote {
+                owner,
+                repo,
+                subpath: Some(subpath),
+                ..
+            } => format!("{owner}/{repo}/{subpath}").into(),
+            ResolvedUses::Remote { owner, repo, .. } => format!("{owner}/{repo}").into(),
+            ResolvedUses::Local { path } => path.into(),
+            ResolvedUses::Docker { image, .. } => image.into(),
+        })
+    }
+
     /// Returns whether the given `env.name` environment access is "static,"
     /// i.e. is not influenced by another expression.
     fn env_is_static(&self, ctx: &context::Context) -> bool;
 
+    /// Returns the value of the environment variable `name` that's visible
+    /// to this step, i.e. the one in the narrowest `env:` block that
+    /// declares it.
+    ///
+    /// See [`env_value`] for when this can't be determined.
+    fn env_value(&self, name: &str) -> Option<&'doc common::EnvValue>;
+
     /// Returns a [`common::Uses`] for this step, if it has one.
     fn uses(&self) -> Option<&'doc common::Uses>;
 
+    /// Returns this step's `uses:` resolved into its component parts,
+    /// if it has one.
+    fn resolved_uses(&self) -> Option<ResolvedUses<'doc>> {
+        self.uses().map(|uses| uses.resolve())
+    }
+
     /// Returns this step's job's computed matrix, if present.
     ///
     /// Composite action steps have no matrix.
@@ -216,7 +311,7 @@ impl<'a, 'doc, T: StepCommon<'doc>> AsDocument<'a, 'doc> for T {
 
 #[cfg(test)]
 mod tests {
-    use super::if_is_statically_false;
+    use super::{if_is_statically_false, if_static_truthiness};
     use github_actions_models::common;
 
     #[test]
@@ -258,4 +353,32 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_if_static_truthiness() {
+        for (raw, expected) in &[
+            ("${{ true }}", Some(true)),
+            ("true", Some(true)),
+            ("${{ 'true' }}", Some(true)),
+            ("${{ 'false' }}", Some(true)),
+            ("${{ 1 }}", Some(true)),
+            ("${{ false }}", Some(false)),
+            ("false", Some(false)),
+            ("${{ '' }}", Some(false)),
+            ("${{ null }}", Some(false)),
+            ("${{ 0 }}", Some(false)),
+            ("${{ github.actor == 'foo' }}", None),
+            ("inputs.something", None),
+            ("${{ this is not valid", None),
+        ] {
+            assert_eq!(
+                if_static_truthiness(&common::If::Expr((*raw).into())),
+                *expected,
+                "unexpected truthiness for {raw}"
+            );
+        }
+
+        assert_eq!(if_static_truthiness(&common::If::Bool(true)), Some(true));
+        assert_eq!(if_static_truthiness(&common::If::Bool(false)), Some(false));
+    }
 }
@@ -187,6 +187,8 @@ impl<'a, 'doc> AsDocument<'a, 'doc> for DockerAction<'doc> {
 pub(crate) struct CompositeSteps<'a> {
     inner: std::iter::Enumerate<std::slice::Iter<'a, github_actions_models::action::Step>>,
     parent: &'a Action,
+    /// Whether to yield steps whose `if:` is statically false.
+    include_disabled: bool,
 }
 
 impl<'a> CompositeSteps<'a> {
@@ -196,10 +198,19 @@ impl<'a> CompositeSteps<'a> {
             action::Runs::Composite(composite) => Some(Self {
                 inner: composite.steps.iter().enumerate(),
                 parent: action,
+                include_disabled: false,
             }),
             _ => None,
         }
     }
+
+    /// Also yield steps whose `if:` condition is statically known to be false.
+    ///
+    /// See [`Jobs::with_disabled`](crate::models::workflow::Jobs::with_disabled).
+    pub(crate) fn with_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
 }
 
 impl<'a> Iterator for CompositeSteps<'a> {
@@ -207,7 +218,8 @@ impl<'a> Iterator for CompositeSteps<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         for (idx, step) in self.inner.by_ref() {
-            if let Some(cond) = step.r#if.as_ref()
+            if !self.include_disabled
+                && let Some(cond) = step.r#if.as_ref()
                 && crate::models::if_is_statically_false(cond)
             {
                 continue;
@@ -266,10 +278,22 @@ impl<'doc> StepCommon<'doc> for CompositeStep<'doc> {
         self.index
     }
 
+    fn index(&self) -> usize {
+        self.index
+    }
+
+    fn name(&self) -> Option<&'doc str> {
+        self.inner.name.as_deref()
+    }
+
     fn env_is_static(&self, ctx: &context::Context) -> bool {
         utils::env_is_static(ctx, &[&self.env])
     }
 
+    fn env_value(&self, name: &str) -> Option<&'doc common::EnvValue> {
+        crate::models::env_value(name, &[&self.inner.env])
+    }
+
     fn uses(&self) -> Option<&'doc common::Uses> {
         let action::StepBody::Uses { uses, .. } = &self.inner.body else {
             return None;
@@ -1,5 +1,14 @@
 //! Common interfaces for modeling inputs across both workflows and actions.
 
+use std::sync::LazyLock;
+
+use fst::Map;
+
+static CONTEXT_CAPABILITIES_FST: LazyLock<Map<&[u8]>> = LazyLock::new(|| {
+    fst::Map::new(include_bytes!(concat!(env!("OUT_DIR"), "/context-capabilities.fst")).as_slice())
+        .expect("couldn't initialize context capabilities FST")
+});
+
 /// Represents the "capability" of an (input) value, i.e. how it expands.
 ///
 /// This is shared by both inputs and contexts more generally, the latter
@@ -24,6 +33,17 @@ pub(crate) enum Capability {
 }
 
 impl Capability {
+    /// Returns the capability of the given context pattern, if known.
+    pub(crate) fn from_context(context: &str) -> Option<Self> {
+        match CONTEXT_CAPABILITIES_FST.get(context) {
+            Some(0) => Some(Capability::Arbitrary),
+            Some(1) => Some(Capability::Structured),
+            Some(2) => Some(Capability::Fixed),
+            Some(_) => unreachable!("unexpected context capability"),
+            _ => None,
+        }
+    }
+
     /// Unify two capabilities in favor of the more permissive one.
     pub(crate) fn unify(self, other: Self) -> Self {
         match (self, other) {
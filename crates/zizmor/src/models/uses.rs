@@ -244,10 +244,75 @@ impl RepositoryUsesExt for RepositoryUses {
     }
 }
 
+/// A `uses:` clause, resolved into its component parts.
+///
+/// This is a borrowed view of a [`Uses`] that audits can match on
+/// without re-parsing the clause or reaching through each variant's
+/// accessors.
+#[derive(Debug, PartialEq)]
+pub(crate) enum ResolvedUses<'a> {
+    /// A remote action or reusable workflow, e.g. `owner/repo/subpath@ref`.
+    Remote {
+        owner: &'a str,
+        repo: &'a str,
+        subpath: Option<&'a str>,
+        git_ref: &'a str,
+    },
+    /// A local action or reusable workflow, e.g. `./path/to/action`.
+    Local { path: &'a str },
+    /// A Docker image, e.g. `docker://registry/image:tag`.
+    Docker {
+        registry: Option<&'a str>,
+        image: &'a str,
+        tag: Option<&'a str>,
+        hash: Option<&'a str>,
+    },
+}
+
+impl std::fmt::Display for ResolvedUses<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolvedUses::Remote {
+                owner,
+                repo,
+                subpath,
+                git_ref,
+            } => {
+                write!(f, "{owner}/{repo}")?;
+                if let Some(subpath) = subpath {
+                    write!(f, "/{subpath}")?;
+                }
+                write!(f, "@{git_ref}")
+            }
+            ResolvedUses::Local { path } => write!(f, "{path}"),
+            ResolvedUses::Docker {
+                registry,
+                image,
+                tag,
+                hash,
+            } => {
+                write!(f, "docker://")?;
+                if let Some(registry) = registry {
+                    write!(f, "{registry}/")?;
+                }
+                write!(f, "{image}")?;
+                if let Some(tag) = tag {
+                    write!(f, ":{tag}")?;
+                }
+                if let Some(hash) = hash {
+                    write!(f, "@{hash}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 /// Useful APIs for interacting with all kinds of `uses:` clauses.
 pub(crate) trait UsesExt {
     fn unpinned(&self) -> bool;
     fn unhashed(&self) -> bool;
+    fn resolve(&self) -> ResolvedUses<'_>;
 }
 
 impl UsesExt for Uses {
@@ -274,6 +339,25 @@ impl UsesExt for Uses {
             Uses::Docker(docker) => docker.hash().is_none(),
         }
     }
+
+    /// Resolves the `uses:` into its component parts.
+    fn resolve(&self) -> ResolvedUses<'_> {
+        match self {
+            Uses::Local(local) => ResolvedUses::Local { path: &local.path },
+            Uses::Repository(repo) => ResolvedUses::Remote {
+                owner: repo.owner(),
+                repo: repo.repo(),
+                subpath: repo.subpath(),
+                git_ref: repo.git_ref(),
+            },
+            Uses::Docker(docker) => ResolvedUses::Docker {
+                registry: docker.registry(),
+                image: docker.image(),
+                tag: docker.tag(),
+                hash: docker.hash(),
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -283,7 +367,7 @@ mod tests {
     use anyhow::anyhow;
     use github_actions_models::common::Uses;
 
-    use super::RepositoryUsesPattern;
+    use super::{RepositoryUsesPattern, ResolvedUses, UsesExt as _};
 
     #[test]
     fn test_repositoryusespattern_parse() {
@@ -500,4 +584,75 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_uses_resolve() -> anyhow::Result<()> {
+        for (uses, expected, normalized) in [
+            (
+                "actions/checkout@v4",
+                ResolvedUses::Remote {
+                    owner: "actions",
+                    repo: "checkout",
+                    subpath: None,
+                    git_ref: "v4",
+                },
+                "actions/checkout@v4",
+            ),
+            (
+                "  github/codeql-action/init@172239021f7ba04fe7327647b213799853a9eb89  ",
+                ResolvedUses::Remote {
+                    owner: "github",
+                    repo: "codeql-action",
+                    subpath: Some("init"),
+                    git_ref: "172239021f7ba04fe7327647b213799853a9eb89",
+                },
+                "github/codeql-action/init@172239021f7ba04fe7327647b213799853a9eb89",
+            ),
+            (
+                "owner/repo/.github/workflows/reusable.yml@main",
+                ResolvedUses::Remote {
+                    owner: "owner",
+                    repo: "repo",
+                    subpath: Some(".github/workflows/reusable.yml"),
+                    git_ref: "main",
+                },
+                "owner/repo/.github/workflows/reusable.yml@main",
+            ),
+            (
+                "./.github/actions/setup",
+                ResolvedUses::Local {
+                    path: "./.github/actions/setup",
+                },
+                "./.github/actions/setup",
+            ),
+            (
+                "docker://alpine:3.8",
+                ResolvedUses::Docker {
+                    registry: None,
+                    image: "alpine",
+                    tag: Some("3.8"),
+                    hash: None,
+                },
+                "docker://alpine:3.8",
+            ),
+            (
+                "docker://ghcr.io/foo/bar@sha256:abcd",
+                ResolvedUses::Docker {
+                    registry: Some("ghcr.io"),
+                    image: "foo/bar",
+                    tag: None,
+                    hash: Some("sha256:abcd"),
+                },
+                "docker://ghcr.io/foo/bar@sha256:abcd",
+            ),
+        ] {
+            let uses = Uses::parse(uses).map_err(|e| anyhow!("invalid uses: {uses}: {e}"))?;
+            let resolved = uses.resolve();
+
+            assert_eq!(resolved, expected);
+            assert_eq!(resolved.to_string(), normalized);
+        }
+
+        Ok(())
+    }
 }
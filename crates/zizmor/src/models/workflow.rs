@@ -10,7 +10,7 @@ use github_actions_models::{
     common::{self, expr::LoE},
     workflow::{
         self, Trigger,
-        event::{BareEvent, OptionalBody},
+        event::{self, BareEvent, BranchFilters, OptionalBody, PathFilters, TagFilters},
         job,
     },
 };
@@ -27,7 +27,7 @@ use crate::{
         workflow::matrix::Matrix,
     },
     registry::input::CollectionError,
-    utils::{self, once::warn_once},
+    utils::{self, ExtractedExpr, once::warn_once},
 };
 
 static WORKFLOW_VALIDATOR: LazyLock<jsonschema::Validator> = LazyLock::new(|| {
@@ -165,31 +165,98 @@ impl Workflow {
         Jobs::new(self)
     }
 
+    /// Returns the configuration for the given trigger, if this workflow
+    /// is triggered by it.
+    ///
+    /// `schedule:` triggers are never bare, and are not covered by this API.
+    pub(crate) fn trigger(&self, event: BareEvent) -> Option<TriggerConfig<'_>> {
+        let events = match &self.on {
+            Trigger::BareEvent(bare) => return (*bare == event).then(|| TriggerConfig::new(event)),
+            Trigger::BareEvents(bares) => {
+                return bares.contains(&event).then(|| TriggerConfig::new(event));
+            }
+            Trigger::Events(events) => events,
+        };
+
+        match event {
+            BareEvent::BranchProtectionRule => {
+                TriggerConfig::with_body(event, &events.branch_protection_rule)
+            }
+            BareEvent::CheckRun => TriggerConfig::with_body(event, &events.check_run),
+            BareEvent::CheckSuite => TriggerConfig::with_body(event, &events.check_suite),
+            BareEvent::Discussion => TriggerConfig::with_body(event, &events.discussion),
+            BareEvent::DiscussionComment => {
+                TriggerConfig::with_body(event, &events.discussion_comment)
+            }
+            BareEvent::IssueComment => TriggerConfig::with_body(event, &events.issue_comment),
+            BareEvent::Issues => TriggerConfig::with_body(event, &events.issues),
+            BareEvent::Label => TriggerConfig::with_body(event, &events.label),
+            BareEvent::MergeGroup => TriggerConfig::with_body(event, &events.merge_group),
+            BareEvent::Milestone => TriggerConfig::with_body(event, &events.milestone),
+            BareEvent::Project => TriggerConfig::with_body(event, &events.project),
+            BareEvent::ProjectCard => TriggerConfig::with_body(event, &events.project_card),
+            BareEvent::ProjectColumn => TriggerConfig::with_body(event, &events.project_column),
+            BareEvent::PullRequest => TriggerConfig::with_body(event, &events.pull_request),
+            BareEvent::PullRequestComment => {
+                TriggerConfig::with_body(event, &events.pull_request_comment)
+            }
+            BareEvent::PullRequestReview => {
+                TriggerConfig::with_body(event, &events.pull_request_review)
+            }
+            BareEvent::PullRequestReviewComment => {
+                TriggerConfig::with_body(event, &events.pull_request_review_comment)
+            }
+            BareEvent::PullRequestTarget => {
+                TriggerConfig::with_body(event, &events.pull_request_target)
+            }
+            BareEvent::Push => TriggerConfig::with_body(event, &events.push),
+            BareEvent::RegistryPackage => TriggerConfig::with_body(event, &events.registry_package),
+            BareEvent::Release => TriggerConfig::with_body(event, &events.release),
+            BareEvent::RepositoryDispatch => {
+                TriggerConfig::with_body(event, &events.repository_dispatch)
+            }
+            BareEvent::Watch => TriggerConfig::with_body(event, &events.watch),
+            BareEvent::WorkflowCall => TriggerConfig::with_body(event, &events.workflow_call),
+            BareEvent::WorkflowDispatch => {
+                TriggerConfig::with_body(event, &events.workflow_dispatch)
+            }
+            BareEvent::WorkflowRun => TriggerConfig::with_body(event, &events.workflow_run),
+            // These events never have a body, and so aren't modeled
+            // in the mapping form of `on:`.
+            BareEvent::Create
+            | BareEvent::Delete
+            | BareEvent::Deployment
+            | BareEvent::DeploymentStatus
+            | BareEvent::Fork
+            | BareEvent::Gollum
+            | BareEvent::PageBuild
+            | BareEvent::Public
+            | BareEvent::Status => None,
+        }
+    }
+
+    /// Returns the configuration of each of this workflow's triggers.
+    ///
+    /// Like [`Workflow::trigger`], this doesn't include `schedule:` triggers.
+    pub(crate) fn triggers(&self) -> impl Iterator<Item = TriggerConfig<'_>> {
+        TRIGGER_EVENTS
+            .iter()
+            .filter_map(|event| self.trigger(*event))
+    }
+
     /// Whether this workflow is triggered by pull_request_target.
     pub(crate) fn has_pull_request_target(&self) -> bool {
-        match &self.on {
-            Trigger::BareEvent(event) => *event == BareEvent::PullRequestTarget,
-            Trigger::BareEvents(events) => events.contains(&BareEvent::PullRequestTarget),
-            Trigger::Events(events) => !matches!(events.pull_request_target, OptionalBody::Missing),
-        }
+        self.trigger(BareEvent::PullRequestTarget).is_some()
     }
 
     /// Whether this workflow is triggered by workflow_run.
     pub(crate) fn has_workflow_run(&self) -> bool {
-        match &self.on {
-            Trigger::BareEvent(event) => *event == BareEvent::WorkflowRun,
-            Trigger::BareEvents(events) => events.contains(&BareEvent::WorkflowRun),
-            Trigger::Events(events) => !matches!(events.workflow_run, OptionalBody::Missing),
-        }
+        self.trigger(BareEvent::WorkflowRun).is_some()
     }
 
     /// Whether this workflow is triggered by `workflow_call`, i.e. whether it's reusable or not.
     pub(crate) fn has_workflow_call(&self) -> bool {
-        match &self.on {
-            Trigger::BareEvent(event) => *event == BareEvent::WorkflowCall,
-            Trigger::BareEvents(events) => events.contains(&BareEvent::WorkflowCall),
-            Trigger::Events(events) => !matches!(events.workflow_call, OptionalBody::Missing),
-        }
+        self.trigger(BareEvent::WorkflowCall).is_some()
     }
 
     /// Whether this workflow is triggered by exactly one event.
@@ -207,6 +274,80 @@ impl Workflow {
         self.has_workflow_call() && self.has_single_trigger()
     }
 
+    /// Whether this workflow is *only* triggered manually (by `workflow_dispatch`)
+    /// or on a `schedule`, and never by other repository activity.
+    pub(crate) fn is_manual_or_scheduled_only(&self) -> bool {
+        // NOTE: `schedule:` isn't covered by `triggers()`, so we check it
+        // separately.
+        let scheduled = matches!(
+            &self.on,
+            Trigger::Events(events) if !matches!(events.schedule, OptionalBody::Missing)
+        );
+
+        let mut manual = false;
+        for trigger in self.triggers() {
+            if trigger.event != BareEvent::WorkflowDispatch {
+                return false;
+            }
+            manual = true;
+        }
+
+        manual || scheduled
+    }
+
+    /// Returns an iterator over every `env:` declaration in this workflow,
+    /// across the workflow, job, and step levels.
+    ///
+    /// Declarations are yielded from the broadest scope to the narrowest,
+    /// meaning that a declaration may be overridden by a later declaration
+    /// of the same name in a narrower scope.
+    ///
+    /// `env:` blocks that are themselves expressions can't be enumerated,
+    /// and are skipped.
+    #[allow(dead_code)]
+    pub(crate) fn env_declarations(&self) -> impl Iterator<Item = EnvDeclaration<'_>> {
+        let workflow_env = EnvDeclaration::all(&self.env, EnvScope::Workflow, self.location());
+
+        let job_envs = self
+            .jobs()
+            .filter_map(|job| match job {
+                Job::NormalJob(normal) => Some(normal),
+                Job::ReusableWorkflowCallJob(_) => None,
+            })
+            .flat_map(|job| {
+                let step_envs = job.steps().flat_map(|step| {
+                    EnvDeclaration::all(step.env(), EnvScope::Step, step.location())
+                });
+
+                EnvDeclaration::all(&job.inner.env, EnvScope::Job, job.location()).chain(step_envs)
+            });
+
+        workflow_env.chain(job_envs)
+    }
+
+    /// Returns this workflow's top-level `concurrency` group, if it has one.
+    pub(crate) fn concurrency_group(&self) -> Option<ConcurrencyGroup<'_>> {
+        Some(ConcurrencyGroup::new(
+            self.concurrency.as_ref()?,
+            ConcurrencyScope::Workflow,
+            self.location(),
+        ))
+    }
+
+    /// Returns an iterator over every `concurrency` group in this workflow,
+    /// at both the workflow and job levels.
+    ///
+    /// The workflow-level group (if any) is yielded first, followed by
+    /// each normal job's group in definition order.
+    pub(crate) fn concurrency_groups(&self) -> impl Iterator<Item = ConcurrencyGroup<'_>> {
+        let job_groups = self.jobs().filter_map(|job| match job {
+            Job::NormalJob(normal) => normal.concurrency_group(),
+            Job::ReusableWorkflowCallJob(_) => None,
+        });
+
+        self.concurrency_group().into_iter().chain(job_groups)
+    }
+
     /// Returns this workflow's [`SymbolicLocation`].
     ///
     /// NOTE: This is intentionally implemented directly on the `Workflow` type
@@ -226,6 +367,236 @@ impl Workflow {
     }
 }
 
+/// The scope at which an `env:` declaration is made.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum EnvScope {
+    /// The workflow-level `env:` block.
+    Workflow,
+    /// A job-level `env:` block.
+    Job,
+    /// A step-level `env:` block.
+    Step,
+}
+
+/// A single environment variable declaration within a workflow,
+/// as produced by [`Workflow::env_declarations`].
+#[allow(dead_code)]
+pub(crate) struct EnvDeclaration<'doc> {
+    /// The environment variable's name.
+    pub(crate) name: &'doc str,
+    /// The environment variable's (unevaluated) value.
+    pub(crate) value: &'doc common::EnvValue,
+    /// The scope that the environment variable is declared in.
+    pub(crate) scope: EnvScope,
+    /// The location of the declaration, i.e. `env.NAME` under its parent.
+    pub(crate) location: SymbolicLocation<'doc>,
+}
+
+impl<'doc> EnvDeclaration<'doc> {
+    fn all(
+        env: &'doc LoE<common::Env>,
+        scope: EnvScope,
+        parent: SymbolicLocation<'doc>,
+    ) -> impl Iterator<Item = EnvDeclaration<'doc>> + use<'doc> {
+        let env = match env {
+            LoE::Literal(env) => Some(env),
+            LoE::Expr(_) => None,
+        };
+
+        env.into_iter().flatten().map(move |(name, value)| Self {
+            name,
+            value,
+            scope,
+            location: parent
+                .with_keys(["env".into(), name.as_str().into()])
+                .annotated("environment variable declared here"),
+        })
+    }
+}
+
+/// Every event that [`Workflow::triggers`] considers, in the order
+/// that they're yielded.
+const TRIGGER_EVENTS: &[BareEvent] = &[
+    BareEvent::BranchProtectionRule,
+    BareEvent::CheckRun,
+    BareEvent::CheckSuite,
+    BareEvent::Create,
+    BareEvent::Delete,
+    BareEvent::Deployment,
+    BareEvent::DeploymentStatus,
+    BareEvent::Discussion,
+    BareEvent::DiscussionComment,
+    BareEvent::Fork,
+    BareEvent::Gollum,
+    BareEvent::IssueComment,
+    BareEvent::Issues,
+    BareEvent::Label,
+    BareEvent::MergeGroup,
+    BareEvent::Milestone,
+    BareEvent::PageBuild,
+    BareEvent::Project,
+    BareEvent::ProjectCard,
+    BareEvent::ProjectColumn,
+    BareEvent::Public,
+    BareEvent::PullRequest,
+    BareEvent::PullRequestComment,
+    BareEvent::PullRequestReview,
+    BareEvent::PullRequestReviewComment,
+    BareEvent::PullRequestTarget,
+    BareEvent::Push,
+    BareEvent::RegistryPackage,
+    BareEvent::Release,
+    BareEvent::RepositoryDispatch,
+    BareEvent::Status,
+    BareEvent::Watch,
+    BareEvent::WorkflowCall,
+    BareEvent::WorkflowDispatch,
+    BareEvent::WorkflowRun,
+];
+
+/// A structured view of a single `on:` trigger's configuration.
+///
+/// Fields that an event doesn't support (e.g. `tags:` on `pull_request`)
+/// are always empty.
+#[derive(Debug)]
+pub(crate) struct TriggerConfig<'doc> {
+    /// The triggering event.
+    pub(crate) event: BareEvent,
+    /// The trigger's activity types (`types:`).
+    ///
+    /// An empty list means that the event's default activity types apply.
+    pub(crate) types: &'doc [String],
+    /// The trigger's branch filters (`branches:` or `branches-ignore:`), if any.
+    pub(crate) branch_filters: Option<&'doc BranchFilters>,
+    /// The trigger's path filters (`paths:` or `paths-ignore:`), if any.
+    pub(crate) path_filters: Option<&'doc PathFilters>,
+    /// The trigger's tag filters (`tags:` or `tags-ignore:`), if any.
+    pub(crate) tag_filters: Option<&'doc TagFilters>,
+}
+
+impl<'doc> TriggerConfig<'doc> {
+    fn new(event: BareEvent) -> Self {
+        Self {
+            event,
+            types: &[],
+            branch_filters: None,
+            path_filters: None,
+            tag_filters: None,
+        }
+    }
+
+    fn with_body<T: TriggerBody>(event: BareEvent, body: &'doc OptionalBody<T>) -> Option<Self> {
+        let mut config = Self::new(event);
+        match body {
+            OptionalBody::Missing => return None,
+            OptionalBody::Default => {}
+            OptionalBody::Body(body) => body.configure(&mut config),
+        }
+        Some(config)
+    }
+
+    /// Whether this trigger only fires for activity types in `types`.
+    ///
+    /// A trigger without explicit activity types fires for its event's
+    /// defaults, and so never satisfies this.
+    pub(crate) fn only_types(&self, types: &[&str]) -> bool {
+        !self.types.is_empty() && self.types.iter().all(|ty| types.contains(&ty.as_str()))
+    }
+}
+
+/// An event trigger body that can be viewed as a [`TriggerConfig`].
+trait TriggerBody {
+    fn configure<'doc>(&'doc self, config: &mut TriggerConfig<'doc>);
+}
+
+impl TriggerBody for event::GenericEvent {
+    fn configure<'doc>(&'doc self, config: &mut TriggerConfig<'doc>) {
+        config.types = &self.types;
+    }
+}
+
+impl TriggerBody for event::PullRequest {
+    fn configure<'doc>(&'doc self, config: &mut TriggerConfig<'doc>) {
+        config.types = &self.types;
+        config.branch_filters = self.branch_filters.as_ref();
+        config.path_filters = self.path_filters.as_ref();
+    }
+}
+
+impl TriggerBody for event::Push {
+    fn configure<'doc>(&'doc self, config: &mut TriggerConfig<'doc>) {
+        config.branch_filters = self.branch_filters.as_ref();
+        config.path_filters = self.path_filters.as_ref();
+        config.tag_filters = self.tag_filters.as_ref();
+    }
+}
+
+impl TriggerBody for event::WorkflowRun {
+    fn configure<'doc>(&'doc self, config: &mut TriggerConfig<'doc>) {
+        config.types = &self.types;
+        config.branch_filters = self.branch_filters.as_ref();
+    }
+}
+
+impl TriggerBody for event::WorkflowCall {
+    fn configure<'doc>(&'doc self, _config: &mut TriggerConfig<'doc>) {}
+}
+
+impl TriggerBody for event::WorkflowDispatch {
+    fn configure<'doc>(&'doc self, _config: &mut TriggerConfig<'doc>) {}
+}
+
+/// The scope at which a `concurrency:` block is declared.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ConcurrencyScope {
+    /// The workflow-level `concurrency:` block.
+    Workflow,
+    /// A job-level `concurrency:` block.
+    Job,
+}
+
+/// A single `concurrency` group, in either its bare
+/// (`concurrency: group`) or rich (`concurrency: { group: ... }`) form.
+pub(crate) struct ConcurrencyGroup<'doc> {
+    /// The group's (unevaluated) value.
+    pub(crate) group: &'doc str,
+    /// The scope that the group is declared in.
+    pub(crate) scope: ConcurrencyScope,
+    /// The location of the group's value.
+    pub(crate) location: SymbolicLocation<'doc>,
+}
+
+impl<'doc> ConcurrencyGroup<'doc> {
+    fn new(
+        concurrency: &'doc workflow::Concurrency,
+        scope: ConcurrencyScope,
+        parent: SymbolicLocation<'doc>,
+    ) -> Self {
+        let (group, location) = match concurrency {
+            workflow::Concurrency::Bare(group) => {
+                (group.as_str(), parent.with_keys(["concurrency".into()]))
+            }
+            workflow::Concurrency::Rich { group, .. } => (
+                group.as_str(),
+                parent.with_keys(["concurrency".into(), "group".into()]),
+            ),
+        };
+
+        Self {
+            group,
+            scope,
+            location: location.annotated("concurrency group defined here"),
+        }
+    }
+
+    /// Returns the fenced expressions within this group's value.
+    pub(crate) fn expressions(&self) -> impl Iterator<Item = ExtractedExpr<'doc>> + use<'doc> {
+        utils::extract_fenced_expressions(self.group)
+            .into_iter()
+            .map(|(expr, _)| expr)
+    }
+}
+
 /// Represents a single "normal" GitHub Actions job.
 #[derive(Clone)]
 pub(crate) struct NormalJob<'doc> {
@@ -252,6 +623,15 @@ impl<'doc> NormalJob<'doc> {
         Steps::new(self)
     }
 
+    /// This job's `concurrency` group, if it has one.
+    pub(crate) fn concurrency_group(&self) -> Option<ConcurrencyGroup<'doc>> {
+        Some(ConcurrencyGroup::new(
+            self.inner.concurrency.as_ref()?,
+            ConcurrencyScope::Job,
+            self.location(),
+        ))
+    }
+
     /// Returns whether this job has the `id-token: write` permission.
     pub(crate) fn has_id_token(&self) -> bool {
         // Figure out which permissions we need to be looking at.
@@ -453,6 +833,8 @@ impl<'doc> Job<'doc> {
 pub(crate) struct Jobs<'doc> {
     parent: &'doc Workflow,
     inner: indexmap::map::Iter<'doc, String, workflow::Job>,
+    /// Whether to yield jobs whose `if:` is statically false.
+    include_disabled: bool,
 }
 
 impl<'doc> Jobs<'doc> {
@@ -460,8 +842,18 @@ impl<'doc> Jobs<'doc> {
         Self {
             parent: workflow,
             inner: workflow.jobs.iter(),
+            include_disabled: false,
         }
     }
+
+    /// Also yield jobs whose `if:` condition is statically known to be false.
+    ///
+    /// This is only useful for audits that concern themselves with
+    /// the conditions themselves, rather than the behavior of the job.
+    pub(crate) fn with_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
 }
 
 impl<'doc> Iterator for Jobs<'doc> {
@@ -474,7 +866,8 @@ impl<'doc> Iterator for Jobs<'doc> {
                 workflow::Job::ReusableWorkflowCallJob(reusable) => reusable.r#if.as_ref(),
             };
 
-            if let Some(cond) = cond
+            if !self.include_disabled
+                && let Some(cond) = cond
                 && crate::models::if_is_statically_false(cond)
             {
                 continue;
@@ -556,10 +949,22 @@ impl<'doc> StepCommon<'doc> for Step<'doc> {
         (self.steps_index, self.parallel_index)
     }
 
+    fn index(&self) -> usize {
+        self.steps_index
+    }
+
+    fn name(&self) -> Option<&'doc str> {
+        Step::name(self)
+    }
+
     fn env_is_static(&self, ctx: &context::Context) -> bool {
         utils::env_is_static(ctx, &[self.env(), &self.job().env, &self.workflow().env])
     }
 
+    fn env_value(&self, name: &str) -> Option<&'doc common::EnvValue> {
+        crate::models::env_value(name, &[self.env(), &self.job().env, &self.workflow().env])
+    }
+
     fn uses(&self) -> Option<&'doc common::Uses> {
         let StepInner::Uses(job::UsesStep { uses, .. }) = &self.inner else {
             return None;
@@ -759,6 +1164,8 @@ pub(crate) struct Steps<'doc> {
         std::iter::Enumerate<std::slice::Iter<'doc, job::ParallelStep>>,
     )>,
     parent: NormalJob<'doc>,
+    /// Whether to yield steps whose `if:` is statically false.
+    include_disabled: bool,
 }
 
 impl<'doc> Steps<'doc> {
@@ -768,9 +1175,18 @@ impl<'doc> Steps<'doc> {
             outer: job.steps.iter().enumerate(),
             parallel: None,
             parent: job.clone(),
+            include_disabled: false,
         }
     }
 
+    /// Also yield steps whose `if:` condition is statically known to be false.
+    ///
+    /// See [`Jobs::with_disabled`].
+    pub(crate) fn with_disabled(mut self) -> Self {
+        self.include_disabled = true;
+        self
+    }
+
     /// Yield the next flattened `(steps_index, parallel_index, step)`, expanding
     /// `parallel:` pseudo-steps inline. Unlike [`Iterator::next`], this does not
     /// filter out statically-disabled steps.
@@ -826,7 +1242,8 @@ impl<'doc> Iterator for Steps<'doc> {
                 StepInner::Run(run) => run.shared.r#if.as_ref(),
             };
 
-            if let Some(cond) = r#if
+            if !self.include_disabled
+                && let Some(cond) = r#if
                 && crate::models::if_is_statically_false(cond)
             {
                 continue;
@@ -839,9 +1256,12 @@ impl<'doc> Iterator for Steps<'doc> {
 
 #[cfg(test)]
 mod tests {
+    use github_actions_models::workflow::event::{BareEvent, BranchFilters, PathFilters};
+
     use crate::models::{
+        AsDocument as _, StepCommon as _,
         inputs::{Capability, HasInputs as _},
-        workflow::Workflow,
+        workflow::{ConcurrencyScope, EnvScope, Job, Workflow},
     };
 
     #[test]
@@ -891,4 +1311,456 @@ jobs:
 
         Ok(())
     }
+
+    #[test]
+    fn test_workflow_is_manual_or_scheduled_only() -> anyhow::Result<()> {
+        for (on, expected) in [
+            ("workflow_dispatch", true),
+            ("[workflow_dispatch]", true),
+            ("{ workflow_dispatch: {} }", true),
+            ("{ schedule: [{ cron: '0 0 * * *' }] }", true),
+            (
+                "{ workflow_dispatch: {}, schedule: [{ cron: '0 0 * * *' }] }",
+                true,
+            ),
+            ("push", false),
+            ("[workflow_dispatch, push]", false),
+            ("{ push: {}, schedule: [{ cron: '0 0 * * *' }] }", false),
+            ("{ workflow_call: {} }", false),
+        ] {
+            let workflow = Workflow::from_string(
+                format!(
+                    "on: {on}\njobs:\n  test:\n    runs-on: ubuntu-latest\n    steps:\n      - run: true\n"
+                ),
+                crate::InputKey::local("fakegroup".into(), "dummy", None, None),
+            )?;
+
+            assert_eq!(workflow.is_manual_or_scheduled_only(), expected, "{on}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_number_and_label() -> anyhow::Result<()> {
+        let workflow = r#"
+name: Test Workflow
+on: push
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - name: Build
+        run: make
+      - run: make test
+      - uses: github/codeql-action/init@v3
+      - name: Upload
+        uses: actions/upload-artifact@v4
+      - uses: ./.github/actions/local
+      - uses: docker://alpine:3.20
+"#;
+
+        let workflow = Workflow::from_string(
+            workflow.into(),
+            crate::InputKey::local("fakegroup".into(), "dummy", None, None),
+        )?;
+
+        let Some(Job::NormalJob(job)) = workflow.jobs().next() else {
+            panic!("expected a normal job");
+        };
+
+        let steps = job
+            .steps()
+            .map(|step| (step.number(), step.label()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            steps,
+            &[
+                (1, Some("actions/checkout".into())),
+                (2, Some("Build".into())),
+                (3, None),
+                (4, Some("github/codeql-action/init".into())),
+                (5, Some("Upload".into())),
+                (6, Some("./.github/actions/local".into())),
+                (7, Some("alpine".into())),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workflow_env_declarations() -> anyhow::Result<()> {
+        let workflow = r#"
+name: Test Workflow
+on: push
+
+env:
+  FOO: workflow-foo
+  BAR: workflow-bar
+
+jobs:
+  first:
+    runs-on: ubuntu-latest
+    env:
+      FOO: job-foo
+      BAZ: ${{ github.ref }}
+    steps:
+      - run: true
+        env:
+          FOO: step-foo
+      - run: true
+
+  dynamic:
+    runs-on: ubuntu-latest
+    env: ${{ fromJSON(inputs.env) }}
+    steps:
+      - uses: actions/checkout@v4
+        env:
+          BAR: 1
+
+  reusable:
+    uses: ./.github/workflows/reusable.yml
+"#;
+
+        let workflow = Workflow::from_string(
+            workflow.into(),
+            crate::InputKey::local("fakegroup".into(), "dummy", None, None),
+        )?;
+
+        // Each declaration's route should point to its own value.
+        let doc = workflow.as_document();
+        let decls = workflow
+            .env_declarations()
+            .map(|decl| {
+                let feature = doc.query_pretty(&decl.location.route).unwrap();
+                (
+                    decl.name,
+                    decl.value.to_string(),
+                    decl.scope,
+                    doc.extract(&feature),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            decls,
+            &[
+                (
+                    "FOO",
+                    "workflow-foo".into(),
+                    EnvScope::Workflow,
+                    "FOO: workflow-foo"
+                ),
+                (
+                    "BAR",
+                    "workflow-bar".into(),
+                    EnvScope::Workflow,
+                    "BAR: workflow-bar"
+                ),
+                ("FOO", "job-foo".into(), EnvScope::Job, "FOO: job-foo"),
+                (
+                    "BAZ",
+                    "${{ github.ref }}".into(),
+                    EnvScope::Job,
+                    "BAZ: ${{ github.ref }}"
+                ),
+                ("FOO", "step-foo".into(), EnvScope::Step, "FOO: step-foo"),
+                ("BAR", "1".into(), EnvScope::Step, "BAR: 1"),
+            ]
+        );
+
+        // The narrowest declaration of `FOO` is the step-level one.
+        let foo = workflow
+            .env_declarations()
+            .filter(|decl| decl.name == "FOO")
+            .max_by_key(|decl| decl.scope)
+            .unwrap();
+        assert_eq!(foo.scope, EnvScope::Step);
+        assert_eq!(foo.value.to_string(), "step-foo");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_env_value() -> anyhow::Result<()> {
+        let workflow = r#"
+name: Test Workflow
+on: push
+
+env:
+  FOO: workflow-foo
+  BAR: workflow-bar
+
+jobs:
+  first:
+    runs-on: ubuntu-latest
+    env:
+      FOO: job-foo
+      BAZ: ${{ github.ref }}
+    steps:
+      - run: true
+        env:
+          FOO: step-foo
+      - run: true
+
+  dynamic:
+    runs-on: ubuntu-latest
+    env: ${{ fromJSON(inputs.env) }}
+    steps:
+      - uses: actions/checkout@v4
+        env:
+          BAR: 1
+
+  second:
+    runs-on: ubuntu-latest
+    steps:
+      - run: true
+"#;
+
+        let workflow = Workflow::from_string(
+            workflow.into(),
+            crate::InputKey::local("fakegroup".into(), "dummy", None, None),
+        )?;
+
+        let mut jobs = workflow.jobs();
+        let (
+            Some(Job::NormalJob(first)),
+            Some(Job::NormalJob(dynamic)),
+            Some(Job::NormalJob(second)),
+        ) = (jobs.next(), jobs.next(), jobs.next())
+        else {
+            panic!("expected three normal jobs");
+        };
+
+        let value = |step: &super::Step, name| step.env_value(name).map(|v| v.to_string());
+
+        // The narrowest declaration wins.
+        let steps = first.steps().collect::<Vec<_>>();
+        assert_eq!(value(&steps[0], "FOO").as_deref(), Some("step-foo"));
+        assert_eq!(value(&steps[1], "FOO").as_deref(), Some("job-foo"));
+        assert_eq!(value(&steps[1], "BAR").as_deref(), Some("workflow-bar"));
+        assert_eq!(
+            value(&steps[1], "BAZ").as_deref(),
+            Some("${{ github.ref }}")
+        );
+        assert_eq!(value(&steps[1], "QUUX"), None);
+
+        // An expression-valued `env:` block could define anything, so
+        // lookups that reach it are unknown.
+        let step = dynamic.steps().next().unwrap();
+        assert_eq!(value(&step, "BAR").as_deref(), Some("1"));
+        assert_eq!(value(&step, "FOO"), None);
+
+        // Declarations in other jobs aren't visible.
+        let step = second.steps().next().unwrap();
+        assert_eq!(value(&step, "FOO").as_deref(), Some("workflow-foo"));
+        assert_eq!(value(&step, "BAZ"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workflow_concurrency_groups() -> anyhow::Result<()> {
+        let workflow = r#"
+name: Test Workflow
+on: push
+
+concurrency: ${{ github.workflow }}-${{ github.ref }}
+
+jobs:
+  rich:
+    runs-on: ubuntu-latest
+    concurrency:
+      group: deploy-${{ inputs.env }}
+      cancel-in-progress: true
+    steps:
+      - run: true
+
+  none:
+    runs-on: ubuntu-latest
+    steps:
+      - run: true
+
+  bare:
+    runs-on: ubuntu-latest
+    concurrency: static-group
+    steps:
+      - run: true
+
+  reusable:
+    uses: ./.github/workflows/reusable.yml
+"#;
+
+        let workflow = Workflow::from_string(
+            workflow.into(),
+            crate::InputKey::local("fakegroup".into(), "dummy", None, None),
+        )?;
+
+        // Each group's route should point to its own value.
+        let doc = workflow.as_document();
+        let groups = workflow
+            .concurrency_groups()
+            .map(|group| {
+                let feature = doc.query_pretty(&group.location.route).unwrap();
+                (
+                    group.group,
+                    group.scope,
+                    doc.extract(&feature),
+                    group
+                        .expressions()
+                        .map(|expr| expr.as_bare().trim().to_string())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            groups,
+            &[
+                (
+                    "${{ github.workflow }}-${{ github.ref }}",
+                    ConcurrencyScope::Workflow,
+                    "concurrency: ${{ github.workflow }}-${{ github.ref }}",
+                    vec!["github.workflow".to_string(), "github.ref".to_string()],
+                ),
+                (
+                    "deploy-${{ inputs.env }}",
+                    ConcurrencyScope::Job,
+                    "group: deploy-${{ inputs.env }}",
+                    vec!["inputs.env".to_string()],
+                ),
+                (
+                    "static-group",
+                    ConcurrencyScope::Job,
+                    "concurrency: static-group",
+                    vec![],
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    fn workflow(on: &str) -> anyhow::Result<Workflow> {
+        let workflow = format!("on:{on}\njobs: {{}}\n");
+        Ok(Workflow::from_string(
+            workflow,
+            crate::InputKey::local("fakegroup".into(), "dummy", None, None),
+        )?)
+    }
+
+    #[test]
+    fn test_workflow_triggers_bare() -> anyhow::Result<()> {
+        for on in [
+            " pull_request",
+            " [push, pull_request]",
+            "\n  pull_request:",
+        ] {
+            let workflow = workflow(on)?;
+
+            let trigger = workflow.trigger(BareEvent::PullRequest).unwrap();
+            assert!(trigger.types.is_empty());
+            assert!(trigger.branch_filters.is_none());
+            assert!(trigger.path_filters.is_none());
+            // Default activity types are never "only" anything.
+            assert!(!trigger.only_types(&["closed"]));
+
+            assert!(workflow.trigger(BareEvent::PullRequestTarget).is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workflow_triggers_types() -> anyhow::Result<()> {
+        let workflow = workflow(
+            r#"
+  pull_request:
+    types: [closed]
+  pull_request_target:
+    types: [opened, synchronize]
+  issues:
+    types: labeled
+  workflow_run:
+    workflows: [ci]
+    types: [completed]
+    branches: [main]
+"#,
+        )?;
+
+        let pull_request = workflow.trigger(BareEvent::PullRequest).unwrap();
+        assert_eq!(pull_request.types, ["closed"]);
+        assert!(pull_request.only_types(&["closed"]));
+
+        let pull_request_target = workflow.trigger(BareEvent::PullRequestTarget).unwrap();
+        assert_eq!(pull_request_target.types, ["opened", "synchronize"]);
+        assert!(!pull_request_target.only_types(&["closed"]));
+        assert!(pull_request_target.only_types(&["opened", "synchronize", "reopened"]));
+
+        let issues = workflow.trigger(BareEvent::Issues).unwrap();
+        assert_eq!(issues.types, ["labeled"]);
+
+        let workflow_run = workflow.trigger(BareEvent::WorkflowRun).unwrap();
+        assert_eq!(workflow_run.types, ["completed"]);
+        assert!(matches!(
+            workflow_run.branch_filters,
+            Some(BranchFilters::Branches(branches)) if branches == &["main"]
+        ));
+
+        assert_eq!(
+            workflow
+                .triggers()
+                .map(|trigger| trigger.event)
+                .collect::<Vec<_>>(),
+            [
+                BareEvent::Issues,
+                BareEvent::PullRequest,
+                BareEvent::PullRequestTarget,
+                BareEvent::WorkflowRun,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workflow_triggers_filters() -> anyhow::Result<()> {
+        let workflow = workflow(
+            r#"
+  push:
+    branches-ignore: [wip/**]
+    tags: [v*]
+  pull_request:
+    paths: [src/**]
+  workflow_dispatch:
+"#,
+        )?;
+
+        let push = workflow.trigger(BareEvent::Push).unwrap();
+        assert!(push.types.is_empty());
+        assert!(matches!(
+            push.branch_filters,
+            Some(BranchFilters::BranchesIgnore(branches)) if branches == &["wip/**"]
+        ));
+        assert!(push.path_filters.is_none());
+        assert!(push.tag_filters.is_some());
+
+        let pull_request = workflow.trigger(BareEvent::PullRequest).unwrap();
+        assert!(pull_request.branch_filters.is_none());
+        assert!(matches!(
+            pull_request.path_filters,
+            Some(PathFilters::Paths(paths)) if paths == &["src/**"]
+        ));
+
+        let workflow_dispatch = workflow.trigger(BareEvent::WorkflowDispatch).unwrap();
+        assert!(workflow_dispatch.types.is_empty());
+
+        assert!(workflow.trigger(BareEvent::Release).is_none());
+
+        Ok(())
+    }
 }
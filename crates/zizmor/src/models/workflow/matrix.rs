@@ -216,6 +216,61 @@ impl<'doc> Expansions<'doc> {
     }
 }
 
+/// A single concrete combination of a matrix's values, i.e. the values
+/// of the `matrix` context in one of the jobs that the matrix expands into.
+///
+/// For example, given a matrix like:
+///
+/// ```yaml
+/// strategy:
+///   matrix:
+///     os: [ubuntu-latest, windows-latest]
+///     node: [12, 14]
+/// ```
+///
+/// one combination is `{os: ubuntu-latest, node: 12}`.
+#[derive(Clone, Debug)]
+pub(crate) struct Combination<'doc>(IndexMap<&'doc str, CombinationValue<'doc>>);
+
+/// A value within a [`Combination`], along with where it was declared
+/// (either in one of the matrix's dimensions, or in an `include` entry).
+#[derive(Clone, Debug)]
+struct CombinationValue<'doc> {
+    value: &'doc yaml_serde::Value,
+    location: SymbolicLocation<'doc>,
+}
+
+impl<'doc> Combination<'doc> {
+    /// Returns the value at the given `matrix.`-prefixed path in this
+    /// combination, e.g. `matrix.os` or `matrix.config.runner`.
+    pub(crate) fn get(&self, path: &str) -> Option<&'doc yaml_serde::Value> {
+        let mut parts = path.strip_prefix("matrix.")?.split('.');
+        let mut value = self.0.get(parts.next()?)?.value;
+        for part in parts {
+            value = value.get(part)?;
+        }
+
+        Some(value)
+    }
+
+    /// Returns the location where the top-level value for the given
+    /// `matrix.`-prefixed path was declared.
+    pub(crate) fn location(&self, path: &str) -> Option<SymbolicLocation<'doc>> {
+        let key = path.strip_prefix("matrix.")?.split('.').next()?;
+        self.0.get(key).map(|value| value.location.clone())
+    }
+
+    /// Checks whether this combination's value for `key` is `value`.
+    fn matches(&self, key: &str, value: &yaml_serde::Value) -> bool {
+        self.0.get(key).is_some_and(|v| v.value == value)
+    }
+}
+
+/// The maximum number of jobs that a single matrix can produce.
+///
+/// See: <https://docs.github.com/en/actions/how-tos/write-workflows/choose-what-workflows-do/run-job-variations>
+pub(crate) const MAX_COMBINATIONS: usize = 256;
+
 /// Represents an execution Matrix within a Job.
 ///
 /// This type implements [`std::ops::Deref`] for [`job::NormalJob::strategy`], providing
@@ -243,6 +298,131 @@ impl<'doc> Matrix<'doc> {
         Expansions::new(self)
     }
 
+    /// Expands this matrix into the concrete combinations that it produces,
+    /// following GitHub's semantics:
+    ///
+    /// 1. The matrix's dimensions are expanded into their Cartesian product;
+    /// 2. Each `exclude` entry removes every combination that it
+    ///    (partially) matches;
+    /// 3. Each `include` entry is merged into every remaining combination
+    ///    whose original values it doesn't overwrite, or is added as a new
+    ///    combination if there are none.
+    ///
+    /// Returns `None` if the matrix (or any part of it) is an expression,
+    /// since its combinations can't be determined statically, or if it
+    /// would produce more than [`MAX_COMBINATIONS`] combinations at any
+    /// point during expansion.
+    ///
+    /// See: <https://docs.github.com/en/actions/using-jobs/using-a-matrix-for-your-jobs>
+    pub(crate) fn combinations(&self) -> Option<Vec<Combination<'doc>>> {
+        let LoE::Literal(matrix) = self.inner else {
+            return None;
+        };
+        let LoE::Literal(dimensions) = &matrix.dimensions else {
+            return None;
+        };
+        let LoE::Literal(includes) = &matrix.include else {
+            return None;
+        };
+        let LoE::Literal(excludes) = &matrix.exclude else {
+            return None;
+        };
+
+        // A matrix with no dimensions (i.e. only `include`) has no
+        // combinations before the includes are applied.
+        let mut combinations = if dimensions.is_empty() {
+            vec![]
+        } else {
+            vec![Combination(IndexMap::new())]
+        };
+
+        for (key, values) in dimensions {
+            let LoE::Literal(values) = values else {
+                return None;
+            };
+
+            // Bail before materializing a product that GitHub would
+            // reject anyways, rather than exhausting memory on it.
+            if combinations.len().checked_mul(values.len())? > MAX_COMBINATIONS {
+                return None;
+            }
+
+            combinations = combinations
+                .into_iter()
+                .flat_map(|combination| {
+                    values.iter().enumerate().map(move |(idx, value)| {
+                        let mut combination = combination.clone();
+                        combination.0.insert(
+                            key.as_str(),
+                            CombinationValue {
+                                value,
+                                location: self
+                                    .location()
+                                    .with_keys([key.as_str().into(), idx.into()])
+                                    .annotated("this expansion"),
+                            },
+                        );
+                        combination
+                    })
+                })
+                .collect();
+        }
+
+        combinations.retain(|combination| {
+            !excludes.iter().any(|exclude| {
+                exclude
+                    .iter()
+                    .all(|(key, value)| combination.matches(key, value))
+            })
+        });
+
+        // Includes are only merged into the combinations produced by the
+        // dimensions, not into combinations added by earlier includes.
+        let base = combinations.len();
+        for (idx, include) in includes.iter().enumerate() {
+            let values = include
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        key.as_str(),
+                        CombinationValue {
+                            value,
+                            location: self
+                                .location()
+                                .with_keys(["include".into(), idx.into(), key.as_str().into()])
+                                .annotated("this expansion"),
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let mut merged = false;
+
+            for combination in &mut combinations[..base] {
+                // Includes can overwrite values added by earlier includes,
+                // but not the matrix's original values.
+                let compatible = include.iter().all(|(key, value)| {
+                    !dimensions.contains_key(key) || combination.matches(key, value)
+                });
+
+                if compatible {
+                    combination.0.extend(values.iter().cloned());
+                    merged = true;
+                }
+            }
+
+            if !merged {
+                if combinations.len() == MAX_COMBINATIONS {
+                    return None;
+                }
+
+                combinations.push(Combination(values.into_iter().collect()));
+            }
+        }
+
+        Some(combinations)
+    }
+
     /// Checks whether some expanded path leads to an expression
     pub(crate) fn expands_to_static_values(&self, context: &Context) -> bool {
         // If we have an indirect matrix, we can't determine whether it expands to
@@ -285,7 +465,10 @@ mod tests {
     use crate::{
         models::{
             AsDocument,
-            workflow::{NormalJob, Workflow, matrix::Matrix},
+            workflow::{
+                NormalJob, Workflow,
+                matrix::{MAX_COMBINATIONS, Matrix},
+            },
         },
         registry::input::InputKey,
     };
@@ -1059,4 +1242,231 @@ jobs:
 
         Ok(())
     }
+
+    /// Renders each of the job's matrix combinations as `key=value` pairs
+    /// (with values as JSON), or `None` if the combinations can't be determined.
+    fn combinations(workflow_yaml: &str) -> anyhow::Result<Option<Vec<String>>> {
+        let workflow = Workflow::from_string(
+            workflow_yaml.into(),
+            InputKey::local("fakegroup".into(), "test.yml", None, None),
+        )?;
+
+        let github_actions_models::workflow::Job::NormalJob(job) =
+            workflow.jobs.get("test").unwrap()
+        else {
+            panic!("Expected a normal job");
+        };
+        let job = NormalJob::new("test", job, &workflow);
+
+        Ok(Matrix::new(&job)
+            .unwrap()
+            .combinations()
+            .map(|combinations| {
+                combinations
+                    .iter()
+                    .map(|combination| {
+                        combination
+                            .0
+                            .iter()
+                            .map(|(key, value)| format!("{key}={}", serde_json::json!(value.value)))
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect()
+            }))
+    }
+
+    #[test]
+    fn test_combinations() -> anyhow::Result<()> {
+        let workflow_yaml = r#"
+name: test
+on: push
+jobs:
+  test:
+    runs-on: ${{ matrix.os }}
+    strategy:
+      matrix:
+        os: [ubuntu-latest, windows-latest]
+        node: [14, 16]
+        exclude:
+          - os: windows-latest
+            node: 14
+        include:
+          # Merged into every ubuntu-latest combination.
+          - os: ubuntu-latest
+            experimental: true
+          # Merged into every combination, overwriting the value
+          # added by the previous include.
+          - experimental: false
+          # Doesn't match any combination's original values,
+          # so it's added as a new combination.
+          - os: [self-hosted, linux]
+            node: 18
+          # Matches an excluded combination, which isn't brought back;
+          # instead, this is also added as a new combination.
+          - os: windows-latest
+            node: 14
+    steps:
+      - run: true
+        "#;
+
+        assert_eq!(
+            combinations(workflow_yaml)?.unwrap(),
+            [
+                r#"os="ubuntu-latest" node=14 experimental=false"#,
+                r#"os="ubuntu-latest" node=16 experimental=false"#,
+                r#"os="windows-latest" node=16 experimental=false"#,
+                r#"os=["self-hosted","linux"] node=18"#,
+                r#"os="windows-latest" node=14"#,
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_combinations_include_only() -> anyhow::Result<()> {
+        let workflow_yaml = r#"
+name: test
+on: push
+jobs:
+  test:
+    runs-on: ${{ matrix.os }}
+    strategy:
+      matrix:
+        include:
+          - os: ubuntu-latest
+          - os: macos-latest
+            xcode: "15"
+    steps:
+      - run: true
+        "#;
+
+        assert_eq!(
+            combinations(workflow_yaml)?.unwrap(),
+            [r#"os="ubuntu-latest""#, r#"os="macos-latest" xcode="15""#]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_combinations_indirect() -> anyhow::Result<()> {
+        for matrix in [
+            "${{ fromJSON(inputs.matrix) }}",
+            r#"{ os: "${{ fromJSON(inputs.os) }}" }"#,
+            r#"{ os: [ubuntu-latest], include: "${{ fromJSON(inputs.include) }}" }"#,
+        ] {
+            let workflow_yaml = format!(
+                r#"
+name: test
+on: push
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    strategy:
+      matrix: {matrix}
+    steps:
+      - run: true
+        "#
+            );
+
+            assert_eq!(combinations(&workflow_yaml)?, None, "{matrix}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_combinations_limit() -> anyhow::Result<()> {
+        let matrix_yaml = |dimensions: &[&str]| {
+            format!(
+                r#"
+name: test
+on: push
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    strategy:
+      matrix:
+{dimensions}
+    steps:
+      - run: true
+        "#,
+                dimensions = dimensions
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, values)| format!("        d{idx}: {values}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+        };
+
+        // 2^8 = 256 combinations is exactly at GitHub's limit.
+        let at_limit = matrix_yaml(&["[a, b]"; 8]);
+        assert_eq!(
+            combinations(&at_limit)?.map(|c| c.len()),
+            Some(MAX_COMBINATIONS)
+        );
+
+        // 2 * 5^14 combinations is far past it, and must not be materialized.
+        let mut dimensions = vec!["[a, b]"];
+        dimensions.extend(["[a, b, c, d, e]"; 14]);
+        assert_eq!(combinations(&matrix_yaml(&dimensions))?, None);
+
+        // Includes that would add a combination past the limit also bail.
+        let past_limit = at_limit.replace(
+            "    steps:",
+            "        include:\n          - d0: c\n    steps:",
+        );
+        assert_eq!(combinations(&past_limit)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_combination_get() -> anyhow::Result<()> {
+        let workflow_yaml = r#"
+name: test
+on: push
+jobs:
+  test:
+    runs-on: ${{ matrix.config.runner }}
+    strategy:
+      matrix:
+        config:
+          - { runner: ubuntu-latest }
+    steps:
+      - run: true
+        "#;
+
+        let workflow = Workflow::from_string(
+            workflow_yaml.into(),
+            InputKey::local("fakegroup".into(), "test.yml", None, None),
+        )?;
+        let github_actions_models::workflow::Job::NormalJob(job) =
+            workflow.jobs.get("test").unwrap()
+        else {
+            panic!("Expected a normal job");
+        };
+        let job = NormalJob::new("test", job, &workflow);
+
+        let combinations = Matrix::new(&job).unwrap().combinations().unwrap();
+        let [combination] = combinations.as_slice() else {
+            panic!("expected a single combination");
+        };
+
+        assert_eq!(
+            combination
+                .get("matrix.config.runner")
+                .and_then(|v| v.as_str()),
+            Some("ubuntu-latest")
+        );
+        assert!(combination.get("matrix.config").is_some());
+        assert!(combination.get("matrix.config.missing").is_none());
+        assert!(combination.get("matrix.missing").is_none());
+        assert!(combination.get("config.runner").is_none());
+
+        Ok(())
+    }
 }
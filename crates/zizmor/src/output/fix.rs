@@ -1,10 +1,13 @@
 //! Routines for applying fixes and reporting overall fix statuses.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Write as _, process::Command};
 
 use anyhow::{Context as _, Result};
-use camino::Utf8Path;
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use indexmap::IndexMap;
+use itertools::Itertools as _;
 use owo_colors::OwoColorize as _;
+use yamlpatch::Patch;
 
 use crate::{
     cli::FixMode,
@@ -13,6 +16,19 @@ use crate::{
     registry::{FindingRegistry, input::InputKey, input::InputRegistry},
 };
 
+/// Where applied fixes are written.
+#[derive(Clone, Copy, Debug)]
+pub enum FixTarget<'a> {
+    /// Fixes are applied to each input in place.
+    InPlace,
+    /// Fixes are written to the given file as a single unified diff,
+    /// suitable for `git apply`. No inputs are modified.
+    Patch(&'a Utf8Path),
+    /// Fixed copies of each input are written under the given directory,
+    /// preserving their relative paths. No inputs are modified.
+    Directory(&'a Utf8Path),
+}
+
 /// Result of applying fixes.
 #[derive(Debug)]
 pub struct FixResult {
@@ -20,15 +36,34 @@ pub struct FixResult {
     pub applied_count: usize,
     /// Number of fixes that failed to apply.
     pub failed_count: usize,
+    /// Number of fixes that weren't attempted because the fix limit
+    /// was reached.
+    pub deferred_count: usize,
+    /// Number of fixes that were skipped because they overlap with
+    /// a previously applied fix.
+    pub conflict_count: usize,
 }
 
 /// Apply all fixes associated with findings, filtered by the specified fix mode.
+///
+/// If `fix_limit` is given, at most that many fixes are successfully applied
+/// across all inputs; any remaining fixes are deferred.
+///
+/// Unless `allow_dirty` is set, this refuses to apply any fixes in place if
+/// one or more of the inputs being fixed has uncommitted changes.
+///
+/// See [`FixTarget`] for where the applied fixes are written.
 pub fn apply_fixes(
     fix_mode: FixMode,
+    fix_limit: Option<usize>,
+    allow_dirty: bool,
+    target: FixTarget<'_>,
     results: &FindingRegistry,
     registry: &InputRegistry,
 ) -> Result<FixResult> {
-    let mut fixes_by_input: HashMap<&InputKey, Vec<(&Fix, &Finding)>> = HashMap::new();
+    // NOTE: We use an `IndexMap` here to apply fixes in a stable order,
+    // which matters when only some of them are applied due to a limit.
+    let mut fixes_by_input: IndexMap<&InputKey, Vec<(&Fix, &Finding)>> = IndexMap::new();
     let mut total_fixes = 0;
     for finding in results.fixable_findings() {
         total_fixes += finding.fixes.len();
@@ -64,13 +99,69 @@ pub fn apply_fixes(
         return Ok(FixResult {
             applied_count: 0,
             failed_count: 0,
+            deferred_count: 0,
+            conflict_count: 0,
         });
     }
 
+    // NOTE: Emitting a patch or fixed copies doesn't modify any inputs,
+    // so there's no risk of mixing fixes with uncommitted changes.
+    if !allow_dirty && matches!(target, FixTarget::InPlace) {
+        let dirty = fixes_by_input
+            .keys()
+            .filter_map(|key| match key {
+                InputKey::Local(local) => Some(local.path()),
+                _ => None,
+            })
+            .filter(|path| has_uncommitted_changes(path))
+            .collect::<Vec<_>>();
+
+        if !dirty.is_empty() {
+            anyhow::bail!(
+                "refusing to fix files with uncommitted changes (use --allow-dirty to override): {}",
+                dirty.iter().join(", ")
+            );
+        }
+    }
+
+    // NOTE: Fixed copies drop any `..` components from their inputs'
+    // paths, so distinct inputs can map to the same copy. Refuse to
+    // write any copies rather than overwriting one with another.
+    if let FixTarget::Directory(dir) = target {
+        let mut out_paths: HashMap<Utf8PathBuf, &Utf8Path> = HashMap::new();
+        for key in fixes_by_input.keys() {
+            let InputKey::Local(local) = key else {
+                continue;
+            };
+
+            let path = local.path();
+            let out_path = out_path(dir, path);
+            if let Some(other) = out_paths.get(&out_path) {
+                anyhow::bail!(
+                    "refusing to write fixed copies: {other} and {path} would both be written to {out_path}"
+                );
+            }
+            out_paths.insert(out_path, path);
+        }
+    }
+
+    // NOTE: Patches are applied from the current directory, so refuse
+    // to write one that names inputs outside of it.
+    if let FixTarget::Patch(_) = target {
+        for key in fixes_by_input.keys() {
+            if let InputKey::Local(local) = key {
+                patch_path(local.path())?;
+            }
+        }
+    }
+
     // Process each file
     let mut applied_fixes = Vec::new();
     let mut failed_fixes = Vec::new();
+    let mut conflicting_fixes = Vec::new();
     let mut total_applied = 0;
+    let mut total_deferred = 0;
+    let mut patch = String::new();
 
     for (input_key, fixes) in &fixes_by_input {
         let InputKey::Local(local) = input_key else {
@@ -84,12 +175,43 @@ pub fn apply_fixes(
 
         let mut file_applied_fixes = Vec::new();
         let mut current_document = input.as_document().clone();
+        // The patches applied so far, each with the fix it belongs to.
+        let mut applied_patches: Vec<(&Patch, &Fix, &Finding)> = vec![];
 
         // Then apply successful fixes sequentially, handling conflicts gracefully
         for (fix, finding) in fixes {
+            if fix_limit.is_some_and(|limit| total_applied >= limit) {
+                total_deferred += 1;
+                continue;
+            }
+
+            // Skip any fix that conflicts with a previously applied fix,
+            // since applying both could corrupt the input. Conflicts are
+            // checked against the original document, where all patches
+            // are expressed in the same terms.
+            if let Some((_, other_fix, other_finding)) =
+                applied_patches.iter().find(|(applied, ..)| {
+                    fix.patches
+                        .iter()
+                        .any(|patch| patch.conflicts_with(applied, input.as_document()))
+                })
+            {
+                conflicting_fixes.push((
+                    finding.ident,
+                    file_path,
+                    format!(
+                        "overlaps with '{title}' from {ident}",
+                        title = other_fix.title,
+                        ident = other_finding.ident
+                    ),
+                ));
+                continue;
+            }
+
             match fix.apply(&current_document) {
                 Ok(new_document) => {
                     current_document = new_document;
+                    applied_patches.extend(fix.patches.iter().map(|patch| (patch, *fix, *finding)));
                     file_applied_fixes.push((finding.ident, fix, finding));
                     total_applied += 1;
                 }
@@ -109,32 +231,170 @@ pub fn apply_fixes(
         if current_document.source() != input.as_document().source() {
             let num_fixes = file_applied_fixes.len();
 
-            std::fs::write(file_path, current_document.source())
-                .with_context(|| format!("failed to update {file_path}"))?;
+            match target {
+                FixTarget::InPlace => {
+                    std::fs::write(file_path, current_document.source_with_bom().as_bytes())
+                        .with_context(|| format!("failed to update {file_path}"))?;
+                }
+                FixTarget::Patch(_) => {
+                    let path = patch_path(file_path)?;
+                    write!(
+                        patch,
+                        "{}",
+                        similar::TextDiff::from_lines(
+                            &input.as_document().source_with_bom(),
+                            &current_document.source_with_bom()
+                        )
+                        .unified_diff()
+                        .header(&format!("a/{path}"), &format!("b/{path}"))
+                    )
+                    .expect("writing to a String can't fail");
+                }
+                FixTarget::Directory(dir) => {
+                    let out_path = out_path(dir, file_path);
+                    if let Some(parent) = out_path.parent() {
+                        std::fs::create_dir_all(parent)
+                            .with_context(|| format!("failed to create {parent}"))?;
+                    }
+                    std::fs::write(&out_path, current_document.source_with_bom().as_bytes())
+                        .with_context(|| format!("failed to write {out_path}"))?;
+                }
+            }
 
             applied_fixes.push((file_path, num_fixes));
         }
     }
 
+    if let FixTarget::Patch(fixes_output) = target {
+        std::fs::write(fixes_output, &patch)
+            .with_context(|| format!("failed to write fixes to {fixes_output}"))?;
+    }
+
     // Summary
-    if !applied_fixes.is_empty() || !failed_fixes.is_empty() {
-        print_summary(&applied_fixes, &failed_fixes);
+    if !applied_fixes.is_empty()
+        || !failed_fixes.is_empty()
+        || !conflicting_fixes.is_empty()
+        || total_deferred > 0
+    {
+        print_summary(
+            target,
+            &applied_fixes,
+            &failed_fixes,
+            &conflicting_fixes,
+            total_deferred,
+        );
     }
 
     Ok(FixResult {
         applied_count: total_applied,
         failed_count: failed_fixes.len(),
+        deferred_count: total_deferred,
+        conflict_count: conflicting_fixes.len(),
+    })
+}
+
+/// Returns the given input's path relative to the current directory,
+/// or `None` if the input isn't under the current directory.
+fn cwd_relative(path: &Utf8Path) -> Option<Utf8PathBuf> {
+    let relative = if path.is_absolute() {
+        let cwd = std::env::current_dir().ok()?;
+        path.strip_prefix(cwd).ok()?
+    } else {
+        path
+    };
+
+    relative
+        .components()
+        .all(|component| matches!(component, Utf8Component::Normal(_) | Utf8Component::CurDir))
+        .then(|| {
+            relative
+                .components()
+                .filter(|component| matches!(component, Utf8Component::Normal(_)))
+                .collect()
+        })
+}
+
+/// Returns the path to use for the given input within a patch.
+///
+/// Patches are applied from the current directory, so inputs outside
+/// of it can't be expressed in one.
+fn patch_path(path: &Utf8Path) -> Result<Utf8PathBuf> {
+    cwd_relative(path).with_context(|| {
+        format!(
+            "can't write a patch for {path}, since it's outside the current directory \
+             (run zizmor from a directory that contains it)"
+        )
     })
 }
 
-fn print_summary(applied_fixes: &[(&Utf8Path, usize)], failed_fixes: &[(&str, &Utf8Path, String)]) {
+/// Returns the path under `dir` to write the fixed copy of the given input to.
+///
+/// Inputs under the current directory keep their relative paths. For other
+/// inputs, any root or `..` components are dropped, so that the copy
+/// always lands under `dir`. This means that distinct inputs can share
+/// an output path; [`apply_fixes`] refuses to write copies when they do.
+fn out_path(dir: &Utf8Path, path: &Utf8Path) -> Utf8PathBuf {
+    let relative = cwd_relative(path).unwrap_or_else(|| path.to_path_buf());
+    let components = relative
+        .components()
+        .filter(|component| matches!(component, Utf8Component::Normal(_)));
+
+    let mut out_path = dir.to_path_buf();
+    out_path.extend(components);
+    out_path
+}
+
+/// Returns whether the given file has uncommitted changes, including
+/// being untracked, according to `git`.
+///
+/// Files that aren't in a git repository (or that we can't check, e.g.
+/// because `git` isn't installed) are never considered dirty.
+fn has_uncommitted_changes(path: &Utf8Path) -> bool {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name()) else {
+        return false;
+    };
+
+    let output = Command::new("git")
+        .args(["status", "--porcelain", "--"])
+        .arg(name)
+        // NOTE: `parent()` is empty for bare relative filenames.
+        .current_dir(if dir.as_str().is_empty() {
+            Utf8Path::new(".")
+        } else {
+            dir
+        })
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => !output.stdout.is_empty(),
+        _ => false,
+    }
+}
+
+fn print_summary(
+    target: FixTarget<'_>,
+    applied_fixes: &[(&Utf8Path, usize)],
+    failed_fixes: &[(&str, &Utf8Path, String)],
+    conflicting_fixes: &[(&str, &Utf8Path, String)],
+    deferred_count: usize,
+) {
     anstream::eprintln!("\n{}", "Fix Summary".green().bold());
 
     if !applied_fixes.is_empty() {
-        anstream::eprintln!(
-            "Successfully applied fixes to {} files:",
-            applied_fixes.len()
-        );
+        match target {
+            FixTarget::InPlace => anstream::eprintln!(
+                "Successfully applied fixes to {} files:",
+                applied_fixes.len()
+            ),
+            FixTarget::Patch(fixes_output) => anstream::eprintln!(
+                "Wrote fixes for {} files to {fixes_output}:",
+                applied_fixes.len()
+            ),
+            FixTarget::Directory(dir) => anstream::eprintln!(
+                "Wrote fixed copies of {} files to {dir}:",
+                applied_fixes.len()
+            ),
+        }
         for (file_path, num_fixes) in applied_fixes {
             anstream::eprintln!("  {}: {} fixes", file_path, num_fixes);
         }
@@ -146,4 +406,17 @@ fn print_summary(applied_fixes: &[(&Utf8Path, usize)], failed_fixes: &[(&str, &U
             anstream::eprintln!("  {}: {} ({})", ident, file_path, error);
         }
     }
+
+    if !conflicting_fixes.is_empty() {
+        anstream::eprintln!("Skipped {} conflicting fixes:", conflicting_fixes.len());
+        for (ident, file_path, conflict) in conflicting_fixes {
+            anstream::eprintln!("  {}: {} ({})", ident, file_path, conflict);
+        }
+    }
+
+    if deferred_count > 0 {
+        anstream::eprintln!(
+            "Deferred {deferred_count} fixes due to --fix-limit; run again to apply them."
+        );
+    }
 }
@@ -2,16 +2,18 @@
 //!
 //! See: <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions>
 
-use std::io;
+use std::{collections::HashMap, io};
 
 use anyhow::Result;
 
 use crate::{Severity, finding::Finding};
 
 impl Severity {
-    /// Converts a `Severity` to a GitHub Actions command command.
+    /// Converts a `Severity` to a GitHub Actions workflow command.
+    ///
+    /// Informational findings become notices, so that they don't show
+    /// up as warnings in the Actions UI.
     fn as_github_command(&self) -> &str {
-        // TODO: Does this mapping make sense?
         match self {
             Severity::Informational => "notice",
             Severity::Low => "warning",
@@ -30,6 +32,7 @@ impl Finding<'_> {
         // gracefully.
         let filepath = primary.symbolic.key.best_identifier();
         let start_line = primary.concrete.location.start_point.row + 1;
+        let start_col = primary.concrete.location.start_point.column + 1;
         let title = self.ident;
 
         let message = format!(
@@ -41,7 +44,7 @@ impl Finding<'_> {
 
         writeln!(
             sink,
-            "::{} file={filepath},line={start_line},title={title}::{message}",
+            "::{} file={filepath},line={start_line},col={start_col},title={title}::{message}",
             self.determinations.severity.as_github_command()
         )?;
 
@@ -49,12 +52,52 @@ impl Finding<'_> {
     }
 }
 
-pub(crate) fn output(sink: impl io::Write, findings: &[Finding]) -> Result<()> {
+/// Emits each finding as a GitHub workflow command.
+///
+/// At most `limit` commands are emitted per audit, since GitHub only
+/// renders a handful of annotations per step anyway. A `limit` of zero
+/// disables the cap. If any findings are elided, a final notice
+/// reports how many.
+pub(crate) fn output(sink: impl io::Write, findings: &[Finding], limit: usize) -> Result<()> {
     let mut sink = sink;
 
+    let mut emitted_by_audit: HashMap<&str, usize> = HashMap::new();
+    let mut elided = 0;
     for finding in findings {
+        let emitted = emitted_by_audit.entry(finding.ident).or_default();
+        if limit != 0 && *emitted >= limit {
+            elided += 1;
+            continue;
+        }
+
+        *emitted += 1;
         finding.format_command(&mut sink)?;
     }
 
+    if elided > 0 {
+        writeln!(
+            sink,
+            "::notice title=zizmor::{elided} of {total} findings not annotated (limited to {limit} per audit by --github-annotations-limit)",
+            total = findings.len(),
+        )?;
+    }
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Severity;
+
+    #[test]
+    fn test_as_github_command() {
+        for (severity, command) in [
+            (Severity::Informational, "notice"),
+            (Severity::Low, "warning"),
+            (Severity::Medium, "warning"),
+            (Severity::High, "error"),
+        ] {
+            assert_eq!(severity.as_github_command(), command);
+        }
+    }
+}
@@ -1,3 +1,4 @@
 //! zizmor's JSON output formats.
 
 pub(crate) mod v1;
+pub(crate) mod v2;
@@ -8,7 +8,7 @@
 
 use std::io;
 
-use crate::finding;
+use crate::{finding, registry::AuditRegistry};
 
 // NOTE: Internally this format still uses a lot of zizmor's internal types.
 // As those change, this module will gain "frozen" copies with converters.
@@ -19,6 +19,10 @@ struct V1Finding<'a> {
     desc: &'a str,
     url: &'a str,
     determinations: finding::Determinations,
+    /// The audit's default severity, i.e. the highest severity it produces.
+    rule_default_severity: finding::Severity,
+    /// The finding's severity after any configured remapping.
+    effective_severity: finding::Severity,
     locations: &'a [finding::location::Location<'a>],
     ignored: bool,
     fixes: Vec<V1Fix<'a>>,
@@ -41,13 +45,17 @@ impl<'a> From<&'a finding::Fix<'a>> for V1Fix<'a> {
     }
 }
 
-impl<'a> From<&'a finding::Finding<'a>> for V1Finding<'a> {
-    fn from(finding: &'a finding::Finding<'a>) -> Self {
+impl<'a> V1Finding<'a> {
+    fn new(finding: &'a finding::Finding<'a>, audits: &AuditRegistry) -> Self {
         Self {
             ident: finding.ident,
             desc: finding.desc,
             url: finding.url,
             determinations: finding.determinations,
+            rule_default_severity: audits
+                .meta(finding.ident)
+                .map_or(finding.determinations.severity, |meta| meta.severity),
+            effective_severity: finding.determinations.severity,
             locations: &finding.locations,
             ignored: finding.ignored,
             fixes: finding.fixes.iter().map(V1Fix::from).collect(),
@@ -57,11 +65,15 @@ impl<'a> From<&'a finding::Finding<'a>> for V1Finding<'a> {
 
 pub(crate) fn output<'a>(
     sink: impl io::Write,
+    audits: &AuditRegistry,
     findings: &[finding::Finding<'a>],
 ) -> anyhow::Result<()> {
     serde_json::to_writer_pretty(
         sink,
-        &findings.iter().map(V1Finding::from).collect::<Vec<_>>(),
+        &findings
+            .iter()
+            .map(|finding| V1Finding::new(finding, audits))
+            .collect::<Vec<_>>(),
     )?;
     Ok(())
 }
@@ -0,0 +1,241 @@
+//! zizmor's "v2" JSON output format.
+//!
+//! The "v2" format is an object containing run `metadata` and a `findings`
+//! array. Unlike "v1", every type in this format is a "frozen" copy defined
+//! here, rather than a dump of zizmor's internal types.
+
+use std::io;
+
+use serde::Serialize;
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::{
+    finding::{self, location::LocationKind},
+    models::AsDocument as _,
+    registry::{AuditRegistry, input::InputRegistry},
+};
+
+#[derive(Serialize)]
+struct V2Output<'a> {
+    metadata: V2Metadata,
+    findings: Vec<V2Finding<'a>>,
+}
+
+#[derive(Serialize)]
+struct V2Metadata {
+    zizmor_version: &'static str,
+    /// The (UTC) time at which the output was produced, in RFC 3339 format.
+    timestamp: String,
+}
+
+#[derive(Serialize)]
+struct V2Finding<'a> {
+    ident: &'a str,
+    desc: &'a str,
+    url: &'a str,
+    /// The finding's severity after any configured remapping.
+    severity: V2Severity,
+    /// The audit's default severity, i.e. the highest severity it produces.
+    rule_default_severity: V2Severity,
+    confidence: V2Confidence,
+    persona: V2Persona,
+    ignored: bool,
+    locations: Vec<V2Location<'a>>,
+    fixes: Vec<V2Fix<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum V2Severity {
+    Informational,
+    Low,
+    Medium,
+    High,
+}
+
+impl From<finding::Severity> for V2Severity {
+    fn from(severity: finding::Severity) -> Self {
+        match severity {
+            finding::Severity::Informational => Self::Informational,
+            finding::Severity::Low => Self::Low,
+            finding::Severity::Medium => Self::Medium,
+            finding::Severity::High => Self::High,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum V2Confidence {
+    Low,
+    Medium,
+    High,
+}
+
+impl From<finding::Confidence> for V2Confidence {
+    fn from(confidence: finding::Confidence) -> Self {
+        match confidence {
+            finding::Confidence::Low => Self::Low,
+            finding::Confidence::Medium => Self::Medium,
+            finding::Confidence::High => Self::High,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum V2Persona {
+    Auditor,
+    Pedantic,
+    Regular,
+}
+
+impl From<finding::Persona> for V2Persona {
+    fn from(persona: finding::Persona) -> Self {
+        match persona {
+            finding::Persona::Auditor => Self::Auditor,
+            finding::Persona::Pedantic => Self::Pedantic,
+            finding::Persona::Regular => Self::Regular,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum V2Role {
+    Primary,
+    Related,
+    Hidden,
+}
+
+impl From<LocationKind> for V2Role {
+    fn from(kind: LocationKind) -> Self {
+        match kind {
+            LocationKind::Primary => Self::Primary,
+            LocationKind::Related => Self::Related,
+            LocationKind::Hidden => Self::Hidden,
+        }
+    }
+}
+
+/// A 1-based line and column.
+#[derive(Serialize)]
+struct V2Point {
+    line: usize,
+    column: usize,
+}
+
+impl From<finding::location::Point> for V2Point {
+    fn from(point: finding::location::Point) -> Self {
+        Self {
+            line: point.row + 1,
+            column: point.column + 1,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct V2LineCol {
+    start: V2Point,
+    end: V2Point,
+}
+
+#[derive(Serialize)]
+struct V2Location<'a> {
+    path: &'a str,
+    /// The half-open `[start, end)` byte span of the location.
+    byte_span: [usize; 2],
+    line_col: V2LineCol,
+    role: V2Role,
+    annotation: &'a str,
+    /// The exact source text at `byte_span`.
+    snippet: &'a str,
+}
+
+impl<'a> V2Location<'a> {
+    fn new(location: &'a finding::location::Location<'a>, registry: &'a InputRegistry) -> Self {
+        let concrete = &location.concrete.location;
+        let source = registry
+            .get_input(location.symbolic.key)
+            .as_document()
+            .source();
+
+        Self {
+            path: location.symbolic.key.presentation_path(),
+            byte_span: [concrete.offset_span.start, concrete.offset_span.end],
+            line_col: V2LineCol {
+                start: concrete.start_point.into(),
+                end: concrete.end_point.into(),
+            },
+            role: location.symbolic.kind.into(),
+            annotation: &location.symbolic.annotation,
+            snippet: &source[concrete.offset_span.clone()],
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct V2Fix<'a> {
+    title: &'a str,
+    path: &'a str,
+    disposition: finding::FixDisposition,
+}
+
+impl<'a> From<&'a finding::Fix<'a>> for V2Fix<'a> {
+    fn from(fix: &'a finding::Fix<'a>) -> Self {
+        Self {
+            title: fix.title.as_str(),
+            path: fix.key.presentation_path(),
+            disposition: fix.disposition,
+        }
+    }
+}
+
+impl<'a> V2Finding<'a> {
+    fn new(
+        finding: &'a finding::Finding<'a>,
+        registry: &'a InputRegistry,
+        audits: &AuditRegistry,
+    ) -> Self {
+        Self {
+            ident: finding.ident,
+            desc: finding.desc,
+            url: finding.url,
+            severity: finding.determinations.severity.into(),
+            rule_default_severity: audits
+                .meta(finding.ident)
+                .map_or(finding.determinations.severity, |meta| meta.severity)
+                .into(),
+            confidence: finding.determinations.confidence.into(),
+            persona: finding.determinations.persona.into(),
+            ignored: finding.ignored,
+            locations: finding
+                .locations
+                .iter()
+                .map(|location| V2Location::new(location, registry))
+                .collect(),
+            fixes: finding.fixes.iter().map(V2Fix::from).collect(),
+        }
+    }
+}
+
+pub(crate) fn output<'a>(
+    sink: impl io::Write,
+    registry: &InputRegistry,
+    audits: &AuditRegistry,
+    findings: &[finding::Finding<'a>],
+) -> anyhow::Result<()> {
+    let output = V2Output {
+        metadata: V2Metadata {
+            zizmor_version: env!("CARGO_PKG_VERSION"),
+            timestamp: OffsetDateTime::now_utc().format(&Rfc3339)?,
+        },
+        findings: findings
+            .iter()
+            .map(|finding| V2Finding::new(finding, registry, audits))
+            .collect(),
+    };
+
+    serde_json::to_writer_pretty(sink, &output)?;
+    Ok(())
+}
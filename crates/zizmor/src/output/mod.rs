@@ -3,3 +3,4 @@ pub(crate) mod github;
 pub(crate) mod json;
 pub(crate) mod plain;
 pub(crate) mod sarif;
+pub(crate) mod timings;
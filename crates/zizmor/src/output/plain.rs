@@ -6,6 +6,7 @@ use std::collections::{HashMap, hash_map::Entry};
 use annotate_snippets::{Annotation, AnnotationKind, Group, Level, Renderer, Snippet};
 use anstream::{eprintln, print, println};
 use owo_colors::OwoColorize as _;
+use url::Url;
 
 use crate::{
     cli::{RenderLinks, ShowAuditUrls},
@@ -15,7 +16,7 @@ use crate::{
     },
     models::AsDocument as _,
     registry::{
-        FindingRegistry,
+        FindingRegistry, SuppressionReason,
         input::{InputKey, InputRegistry},
     },
 };
@@ -103,27 +104,52 @@ pub(crate) fn render_findings(
     findings: &FindingRegistry,
     show_urls_mode: &ShowAuditUrls,
     render_links_mode: &RenderLinks,
+    docs_base: Option<&Url>,
     naches_mode: bool,
+    no_emoji: bool,
 ) {
-    for finding in findings.findings() {
-        render_finding(registry, finding, show_urls_mode, render_links_mode);
-        println!();
+    let findings_by_input = findings.by_input();
+    // Only bother with per-input headers when there's more than one input
+    // to distinguish between.
+    let grouped = findings_by_input.len() > 1;
+
+    for (input_key, input_findings) in &findings_by_input {
+        if grouped {
+            render_input_header(
+                registry,
+                input_key,
+                input_findings.len(),
+                render_links_mode,
+                no_emoji,
+            );
+            println!();
+        }
+
+        for finding in input_findings {
+            render_finding(
+                registry,
+                finding,
+                show_urls_mode,
+                render_links_mode,
+                docs_base,
+                no_emoji,
+            );
+            println!();
+        }
     }
 
     let mut qualifiers = vec![];
 
-    if !findings.ignored().is_empty() {
-        qualifiers.push(format!(
-            "{nignored} ignored",
-            nignored = findings.ignored().len().bright_yellow()
-        ));
-    }
+    for (reason, count) in findings.suppression_counts() {
+        let reason = match reason {
+            SuppressionReason::IgnoreComment => "ignored by comment",
+            SuppressionReason::ConfigIgnore => "ignored by config",
+            SuppressionReason::BelowThreshold => "below threshold",
+            SuppressionReason::Persona => "suppressed",
+            SuppressionReason::Duplicate => "deduplicated",
+        };
 
-    if !findings.suppressed().is_empty() {
-        qualifiers.push(format!(
-            "{nsuppressed} suppressed",
-            nsuppressed = findings.suppressed().len().bright_yellow()
-        ));
+        qualifiers.push(format!("{count} {reason}", count = count.bright_yellow()));
     }
 
     let fixes_by_disposition: HashMap<FixDisposition, usize> = findings
@@ -208,21 +234,76 @@ pub(crate) fn render_findings(
     }
 }
 
+/// The width to render separators at when the terminal's width is unknown.
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// Returns the width to render separators at, as indicated by `COLUMNS`.
+fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .filter(|columns| *columns > 0)
+        .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+}
+
+fn render_input_header(
+    registry: &InputRegistry,
+    input_key: &InputKey,
+    nfindings: usize,
+    render_links_mode: &RenderLinks,
+    no_emoji: bool,
+) {
+    let path = match render_links_mode {
+        RenderLinks::Always => registry
+            .get_input(input_key)
+            .link()
+            .unwrap_or(input_key.presentation_path()),
+        RenderLinks::Never => input_key.presentation_path(),
+    };
+
+    let title = format!(
+        "{path} ({nfindings} finding{s})",
+        s = if nfindings == 1 { "" } else { "s" }
+    );
+
+    // Pad the header out to the terminal's width, with a minimal
+    // trailing separator if the title is already too wide.
+    let fill = terminal_width()
+        .saturating_sub(title.chars().count() + 4)
+        .max(2);
+
+    let rule = if no_emoji { "-" } else { "─" };
+
+    println!(
+        "{lead} {title} {trail}",
+        lead = rule.repeat(2).dimmed(),
+        title = title.bold(),
+        trail = rule.repeat(fill).dimmed(),
+    );
+}
+
 fn render_finding(
     registry: &InputRegistry,
     finding: &Finding,
     show_urls_mode: &ShowAuditUrls,
     render_links_mode: &RenderLinks,
+    docs_base: Option<&Url>,
+    no_emoji: bool,
 ) {
+    let url = finding.docs_url(docs_base);
+    let arrow = if no_emoji { "->" } else { "→" };
     let mut title = Level::from(&finding.determinations.severity)
         .primary_title(finding.desc)
         .id(finding.ident);
 
     if matches!(render_links_mode, RenderLinks::Always) {
-        title = title.id_url(finding.url);
+        title = title.id_url(url.as_ref());
     }
 
-    let confidence = format!("audit confidence → {:?}", finding.determinations.confidence);
+    let confidence = format!(
+        "audit confidence {arrow} {:?}",
+        finding.determinations.confidence
+    );
 
     let mut group = Group::with_title(title)
         .elements(finding_snippets(registry, finding, render_links_mode))
@@ -238,8 +319,8 @@ fn render_finding(
 
     if matches!(show_urls_mode, ShowAuditUrls::Always) {
         group = group.element(Level::HELP.message(format!(
-            "audit documentation → {url}",
-            url = finding.url.green()
+            "audit documentation {arrow} {url}",
+            url = url.green()
         )))
     }
 
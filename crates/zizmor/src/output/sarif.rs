@@ -1,15 +1,32 @@
 //! SARIF output.
 
-use std::collections::{BTreeMap, HashSet};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    ops::Range,
+};
 
+use url::Url;
 use zizmor_sarif::{
-    ArtifactContent, ArtifactLocation, CodeFlow, Invocation, Location as SarifLocation,
-    LogicalLocation, Message, MultiformatMessageString, PhysicalLocation, PropertyBag, Region,
-    ReportingDescriptor, Result as SarifResult, ResultKind, ResultLevel, Run, Sarif, ThreadFlow,
-    ThreadFlowLocation, ThreadFlowLocationImportance, Tool, ToolComponent,
+    ArtifactChange, ArtifactContent, ArtifactLocation, CodeFlow, Fix as SarifFix, Invocation,
+    Location as SarifLocation, LogicalLocation, Message, MultiformatMessageString,
+    PhysicalLocation, PropertyBag, Region, Replacement, ReportingConfiguration,
+    ReportingDescriptor, ReportingDescriptorReference, Result as SarifResult, ResultKind,
+    ResultLevel, Run, Sarif, ThreadFlow, ThreadFlowLocation, ThreadFlowLocationImportance, Tool,
+    ToolComponent, ToolComponentReference,
+};
+
+use crate::{
+    audit::{AuditMeta, audit_docs},
+    finding::{
+        Finding, Fix, Severity,
+        location::{ConcreteLocation, Location},
+    },
+    models::AsDocument as _,
+    registry::{AuditRegistry, input::InputRegistry},
 };
 
-use crate::finding::{Finding, Severity, location::Location};
+/// The name of the CWE taxonomy, as referenced by each result's `taxa`.
+const CWE_TAXONOMY: &str = "CWE";
 
 impl From<Severity> for ResultKind {
     fn from(value: Severity) -> Self {
@@ -34,68 +51,182 @@ impl From<Severity> for ResultLevel {
     }
 }
 
-pub(crate) fn build(findings: &[Finding]) -> Sarif {
+pub(crate) fn build(
+    registry: &InputRegistry,
+    audits: &AuditRegistry,
+    findings: &[Finding],
+    docs_base: Option<&Url>,
+) -> Sarif {
+    // Findings are produced in audit and input order, which isn't stable
+    // across runs (e.g. when audits run concurrently). Sort them by
+    // (rule, artifact, start position) so that SARIF output is reproducible.
+    let mut findings = findings.iter().collect::<Vec<_>>();
+    findings.sort_by_cached_key(|finding| {
+        let primary = finding.primary_location();
+        let start = &primary.concrete.location.start_point;
+        (
+            finding.ident,
+            primary.symbolic.key.best_identifier(),
+            start.row,
+            start.column,
+        )
+    });
+
     Sarif {
         schema: Some(
             "https://docs.oasis-open.org/sarif/sarif/v2.1.0/os/schemas/sarif-schema-2.1.0.json"
                 .into(),
         ),
-        runs: vec![build_run(findings)],
+        runs: vec![build_run(registry, audits, &findings, docs_base)],
         version: "2.1.0".into(),
     }
 }
 
-fn build_run(findings: &[Finding]) -> Run {
+fn build_run(
+    registry: &InputRegistry,
+    audits: &AuditRegistry,
+    findings: &[&Finding],
+    docs_base: Option<&Url>,
+) -> Run {
+    // Like rules, the taxonomy only includes the CWEs that our findings
+    // actually reference. Each result refers to its CWEs by index
+    // into this list.
+    let cwes = findings
+        .iter()
+        .flat_map(|finding| finding.cwes)
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
     Run {
         invocations: vec![Invocation {
             // We only produce results on successful executions.
             execution_successful: true,
         }],
-        results: build_results(findings),
+        results: build_results(registry, findings, &cwes),
+        taxonomies: build_taxonomies(&cwes),
         tool: Tool {
             driver: ToolComponent {
                 download_uri: Some(env!("CARGO_PKG_REPOSITORY").into()),
                 information_uri: Some(env!("CARGO_PKG_HOMEPAGE").into()),
                 name: env!("CARGO_CRATE_NAME").into(),
-                rules: build_rules(findings),
+                organization: None,
+                rules: build_rules(audits, findings, docs_base),
                 semantic_version: Some(env!("CARGO_PKG_VERSION").into()),
+                taxa: vec![],
                 version: Some(env!("CARGO_PKG_VERSION").into()),
             },
         },
     }
 }
 
-fn build_rules(findings: &[Finding]) -> Vec<ReportingDescriptor> {
+fn build_taxonomies(cwes: &[u32]) -> Vec<ToolComponent> {
+    if cwes.is_empty() {
+        return vec![];
+    }
+
+    vec![ToolComponent {
+        download_uri: None,
+        information_uri: Some("https://cwe.mitre.org/".into()),
+        name: CWE_TAXONOMY.into(),
+        organization: Some("MITRE".into()),
+        rules: vec![],
+        semantic_version: None,
+        taxa: cwes
+            .iter()
+            .map(|cwe| ReportingDescriptor {
+                default_configuration: None,
+                full_description: None,
+                help: None,
+                help_uri: Some(format!("https://cwe.mitre.org/data/definitions/{cwe}.html")),
+                id: format!("CWE-{cwe}"),
+                name: None,
+                properties: None,
+                short_description: None,
+            })
+            .collect(),
+        version: None,
+    }]
+}
+
+fn build_rules(
+    audits: &AuditRegistry,
+    findings: &[&Finding],
+    docs_base: Option<&Url>,
+) -> Vec<ReportingDescriptor> {
     // use the set to filter out duplicate rules
     let mut unique_rules = HashSet::new();
     findings
         .iter()
         .filter(|finding| unique_rules.insert(finding.ident))
-        .map(|finding| build_rule(finding))
+        .map(|finding| {
+            // NOTE: Every finding should come from a registered audit, but
+            // we fall back to the finding's own metadata just in case.
+            let meta = audits.meta(finding.ident).copied().unwrap_or(AuditMeta {
+                ident: finding.ident,
+                desc: finding.desc,
+                url: finding.url,
+                docs: audit_docs(finding.ident),
+                severity: finding.determinations.severity,
+                online: false,
+                cwes: finding.cwes,
+            });
+
+            build_rule(finding, &meta, docs_base)
+        })
         .collect()
 }
 
-fn build_rule(finding: &Finding) -> ReportingDescriptor {
+fn build_rule(finding: &Finding, meta: &AuditMeta, docs_base: Option<&Url>) -> ReportingDescriptor {
+    let url = finding.docs_url(docs_base);
+
     ReportingDescriptor {
+        default_configuration: Some(ReportingConfiguration {
+            level: Some(meta.severity.into()),
+        }),
+        full_description: meta.docs.map(|docs| MultiformatMessageString {
+            markdown: None,
+            text: docs.into(),
+        }),
         help: Some(MultiformatMessageString {
-            markdown: Some(finding.to_markdown()),
-            text: finding.desc.into(),
+            markdown: Some(finding.to_markdown(docs_base)),
+            text: meta.desc.into(),
         }),
-        help_uri: Some(finding.url.into()),
-        id: format!("zizmor/{id}", id = finding.ident),
-        name: Some(finding.ident.into()),
+        help_uri: Some(url.into_owned()),
+        id: format!("zizmor/{id}", id = meta.ident),
+        name: Some(meta.ident.into()),
         properties: Some(PropertyBag {
-            tags: vec!["security".into()],
+            // NOTE: GitHub's code scanning uses `external/cwe/cwe-NNN` tags
+            // to display a rule's CWEs.
+            tags: std::iter::once("security".into())
+                .chain(
+                    meta.cwes
+                        .iter()
+                        .map(|cwe| format!("external/cwe/cwe-{cwe:03}")),
+                )
+                .collect(),
             additional_properties: BTreeMap::new(),
         }),
+        short_description: Some(MultiformatMessageString {
+            markdown: None,
+            text: meta.desc.into(),
+        }),
     }
 }
 
-fn build_results(findings: &[Finding]) -> Vec<SarifResult> {
-    findings.iter().map(build_result).collect()
+fn build_results(
+    registry: &InputRegistry,
+    findings: &[&Finding],
+    cwes: &[u32],
+) -> Vec<SarifResult> {
+    findings
+        .iter()
+        .map(|finding| build_result(registry, finding, cwes))
+        .collect()
 }
 
-fn build_result(finding: &Finding<'_>) -> SarifResult {
+fn build_result(registry: &InputRegistry, finding: &Finding<'_>, cwes: &[u32]) -> SarifResult {
     let primary = finding.primary_location();
 
     // Build code flows for better visualization of location chains.
@@ -139,6 +270,11 @@ fn build_result(finding: &Finding<'_>) -> SarifResult {
 
     SarifResult {
         code_flows,
+        fixes: finding
+            .fixes
+            .iter()
+            .filter_map(|fix| build_fix(registry, fix))
+            .collect(),
         kind: Some(ResultKind::from(finding.determinations.severity)),
         level: Some(ResultLevel::from(finding.determinations.severity)),
         locations: vec![build_location(primary, None)],
@@ -160,15 +296,112 @@ fn build_result(finding: &Finding<'_>) -> SarifResult {
             additional_properties,
         }),
         rule_id: Some(format!("zizmor/{id}", id = finding.ident)),
+        taxa: finding
+            .cwes
+            .iter()
+            .map(|cwe| ReportingDescriptorReference {
+                id: format!("CWE-{cwe}"),
+                index: cwes.binary_search(cwe).ok().map(|idx| idx as i64),
+                tool_component: Some(ToolComponentReference {
+                    name: CWE_TAXONOMY.into(),
+                }),
+            })
+            .collect(),
     }
 }
 
+/// Returns the smallest byte range of `before` that, when replaced with
+/// the returned slice of `after`, turns `before` into `after`.
+fn minimal_replacement<'a>(before: &str, after: &'a str) -> (Range<usize>, &'a str) {
+    let mut prefix = before
+        .bytes()
+        .zip(after.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    // NOTE: Both sides share the prefix, so a boundary in one is a boundary in the other.
+    while !before.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+
+    let mut suffix = before[prefix..]
+        .bytes()
+        .rev()
+        .zip(after[prefix..].bytes().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !before.is_char_boundary(before.len() - suffix) {
+        suffix -= 1;
+    }
+
+    (
+        prefix..before.len() - suffix,
+        &after[prefix..after.len() - suffix],
+    )
+}
+
+/// Builds a SARIF fix from the given [`Fix`].
+///
+/// Rather than translating each YAML patch individually (which would require
+/// us to reimplement `yamlpatch`'s formatting logic), we apply the fix and
+/// express the difference between the original and patched documents as
+/// a single replacement. This guarantees that applying the SARIF fix
+/// produces exactly the same result as `--fix`.
+///
+/// Returns `None` if the fix fails to apply or doesn't change anything.
+fn build_fix(registry: &InputRegistry, fix: &Fix<'_>) -> Option<SarifFix> {
+    let document = registry.get_input(fix.key).as_document();
+    let patched = match fix.apply(document) {
+        Ok(patched) => patched,
+        Err(err) => {
+            tracing::warn!("skipping SARIF fix '{title}': {err}", title = fix.title);
+            return None;
+        }
+    };
+
+    let (deleted, inserted) = minimal_replacement(document.source(), patched.source());
+    if deleted.is_empty() && inserted.is_empty() {
+        return None;
+    }
+
+    let concrete = ConcreteLocation::from_span(deleted.clone(), document);
+
+    Some(SarifFix {
+        artifact_changes: vec![ArtifactChange {
+            artifact_location: ArtifactLocation {
+                uri: fix.key.best_identifier().into(),
+            },
+            replacements: vec![Replacement {
+                deleted_region: Region {
+                    byte_length: Some(deleted.len() as i64),
+                    byte_offset: Some(deleted.start as i64),
+                    end_column: (concrete.end_point.column as i64) + 1,
+                    end_line: (concrete.end_point.row as i64) + 1,
+                    snippet: ArtifactContent {
+                        text: document.source()[deleted].into(),
+                    },
+                    source_language: "yaml".into(),
+                    start_column: (concrete.start_point.column as i64) + 1,
+                    start_line: (concrete.start_point.row as i64) + 1,
+                },
+                inserted_content: Some(ArtifactContent {
+                    text: inserted.into(),
+                }),
+            }],
+        }],
+        description: Message {
+            text: fix.title.clone(),
+        },
+    })
+}
+
 fn build_physical_location(location: &Location<'_>) -> PhysicalLocation {
     PhysicalLocation {
         artifact_location: ArtifactLocation {
             uri: location.symbolic.key.best_identifier().into(),
         },
         region: Region {
+            byte_length: None,
+            byte_offset: None,
             // NOTE: SARIF lines/columns are 1-based.
             end_column: (location.concrete.location.end_point.column as i64) + 1,
             end_line: (location.concrete.location.end_point.row as i64) + 1,
@@ -212,7 +445,15 @@ fn build_location(location: &Location<'_>, id: Option<i64>) -> SarifLocation {
 mod tests {
     use zizmor_sarif::ResultKind;
 
-    use crate::finding::Severity;
+    use crate::{
+        finding::{Finding, FindingBuilder, Severity},
+        models::workflow::Workflow,
+        registry::{
+            AuditRegistry,
+            input::{InputKey, InputRegistry},
+        },
+        state::AuditState,
+    };
 
     #[test]
     fn test_resultkind_from_severity() {
@@ -221,4 +462,188 @@ mod tests {
             "\"fail\""
         );
     }
+
+    #[test]
+    fn test_minimal_replacement() {
+        for (before, after, expected) in &[
+            ("abc", "abc", (3..3, "")),
+            ("abc", "abxc", (2..2, "x")),
+            ("abc", "ac", (1..2, "")),
+            ("foo: 1\nbar: 2\n", "foo: 1\nbar: 3\n", (12..13, "3")),
+            ("", "new", (0..0, "new")),
+            // Shared bytes within a multi-byte character aren't split.
+            ("é", "è", (0..2, "è")),
+        ] {
+            assert_eq!(
+                super::minimal_replacement(before, after),
+                expected.clone(),
+                "{before:?} -> {after:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_multi_file_finding() {
+        let caller = Workflow::from_string(
+            "on: push\njobs:\n  call:\n    uses: ./.github/workflows/callee.yml\n".into(),
+            InputKey::local("fakegroup".into(), "caller.yml", None, None),
+        )
+        .unwrap();
+        let callee = Workflow::from_string(
+            "on: workflow_call\njobs:\n  job:\n    runs-on: ubuntu-latest\n    steps:\n      - run: true\n"
+                .into(),
+            InputKey::local("fakegroup".into(), "callee.yml", None, None),
+        )
+        .unwrap();
+
+        let finding = FindingBuilder::new("fake", "fake finding", "https://example.com")
+            .add_location(
+                caller
+                    .location()
+                    .with_keys(["jobs".into(), "call".into()])
+                    .primary()
+                    .annotated("caller"),
+            )
+            .add_location_in(
+                callee
+                    .location()
+                    .with_keys(["jobs".into(), "job".into()])
+                    .annotated("callee"),
+                &callee,
+            )
+            .build(&caller)
+            .unwrap();
+
+        let sarif = super::build(
+            &InputRegistry::new(),
+            &AuditRegistry::default_audits(&AuditState::default()).unwrap(),
+            &[finding],
+            None,
+        );
+        let result = &sarif.runs[0].results[0];
+
+        let uris = result.code_flows[0].thread_flows[0]
+            .locations
+            .iter()
+            .map(|loc| {
+                loc.location
+                    .physical_location
+                    .as_ref()
+                    .unwrap()
+                    .artifact_location
+                    .uri
+                    .as_str()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(uris, ["caller.yml", "callee.yml"]);
+
+        // The callee's location must be concretized against its own document.
+        let callee_region = &result.code_flows[0].thread_flows[0].locations[1]
+            .location
+            .physical_location
+            .as_ref()
+            .unwrap()
+            .region;
+        assert!(
+            callee_region
+                .snippet
+                .text
+                .contains("runs-on: ubuntu-latest")
+        );
+    }
+
+    #[test]
+    fn test_stable_result_order() {
+        let first = Workflow::from_string(
+            "on: push\njobs:\n  a:\n    runs-on: ubuntu-latest\n    steps:\n      - run: true\n  b:\n    runs-on: ubuntu-latest\n    steps:\n      - run: true\n"
+                .into(),
+            InputKey::local("fakegroup".into(), "first.yml", None, None),
+        )
+        .unwrap();
+        let second = Workflow::from_string(
+            "on: push\njobs:\n  a:\n    runs-on: ubuntu-latest\n    steps:\n      - run: true\n"
+                .into(),
+            InputKey::local("fakegroup".into(), "second.yml", None, None),
+        )
+        .unwrap();
+
+        fn finding<'doc>(
+            ident: &'static str,
+            workflow: &'doc Workflow,
+            job: &'static str,
+        ) -> Finding<'doc> {
+            FindingBuilder::new(ident, "fake finding", "https://example.com")
+                .add_location(
+                    workflow
+                        .location()
+                        .with_keys(["jobs".into(), job.into()])
+                        .primary()
+                        .annotated("here"),
+                )
+                .build(workflow)
+                .unwrap()
+        }
+
+        let audits = AuditRegistry::default_audits(&AuditState::default()).unwrap();
+        let registry = InputRegistry::new();
+        let render = |findings: &[_]| {
+            serde_json::to_string(&super::build(&registry, &audits, findings, None)).unwrap()
+        };
+
+        let forwards = render(&[
+            finding("zeta", &first, "a"),
+            finding("alpha", &second, "a"),
+            finding("alpha", &first, "b"),
+            finding("alpha", &first, "a"),
+        ]);
+        let backwards = render(&[
+            finding("alpha", &first, "a"),
+            finding("alpha", &first, "b"),
+            finding("alpha", &second, "a"),
+            finding("zeta", &first, "a"),
+        ]);
+        assert_eq!(forwards, backwards);
+
+        // Results are ordered by rule, then artifact, then position.
+        let sarif: serde_json::Value = serde_json::from_str(&forwards).unwrap();
+        let order = sarif["runs"][0]["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| {
+                let region = &result["locations"][0]["physicalLocation"];
+                (
+                    result["ruleId"].as_str().unwrap().to_string(),
+                    region["artifactLocation"]["uri"]
+                        .as_str()
+                        .unwrap()
+                        .to_string(),
+                    region["region"]["startLine"].as_i64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            [
+                ("zizmor/alpha".into(), "first.yml".into(), 3),
+                ("zizmor/alpha".into(), "first.yml".into(), 7),
+                ("zizmor/alpha".into(), "second.yml".into(), 3),
+                ("zizmor/zeta".into(), "first.yml".into(), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rule_full_descriptions() {
+        let audits = AuditRegistry::default_audits(&AuditState::default()).unwrap();
+
+        // Every audit's long-form description comes from its docs.
+        for meta in audits.metadata() {
+            let docs = meta
+                .docs
+                .unwrap_or_else(|| panic!("no docs summary for {}", meta.ident));
+            assert!(!docs.contains(['`', '\n']), "{}: {docs}", meta.ident);
+            assert_ne!(docs, meta.desc, "{}", meta.ident);
+        }
+    }
 }
@@ -0,0 +1,91 @@
+//! Per-audit timing metrics, for `--timings`.
+
+use std::time::Duration;
+
+use indexmap::IndexMap;
+use owo_colors::OwoColorize as _;
+
+/// Wall-clock time spent in each audit, aggregated across all inputs.
+pub(crate) struct Timings(IndexMap<&'static str, Duration>);
+
+impl Timings {
+    /// Creates a new set of timings, with an entry for each of the given audits.
+    pub(crate) fn new(idents: impl IntoIterator<Item = &'static str>) -> Self {
+        Self(
+            idents
+                .into_iter()
+                .map(|ident| (ident, Duration::ZERO))
+                .collect(),
+        )
+    }
+
+    /// Records `elapsed` against the given audit.
+    pub(crate) fn record(&mut self, ident: &'static str, elapsed: Duration) {
+        *self.0.entry(ident).or_default() += elapsed;
+    }
+
+    /// Returns each audit and its total time, slowest first.
+    fn sorted(&self) -> Vec<(&'static str, Duration)> {
+        let mut timings = self
+            .0
+            .iter()
+            .map(|(ident, elapsed)| (*ident, *elapsed))
+            .collect::<Vec<_>>();
+
+        timings.sort_by(|(a_ident, a), (b_ident, b)| b.cmp(a).then(a_ident.cmp(b_ident)));
+        timings
+    }
+
+    /// Prints a table of audit timings to stderr, slowest first.
+    pub(crate) fn render(&self) {
+        let timings = self.sorted();
+        let width = timings
+            .iter()
+            .map(|(ident, _)| ident.len())
+            .max()
+            .unwrap_or_default();
+        let total = timings
+            .iter()
+            .map(|(_, elapsed)| *elapsed)
+            .sum::<Duration>();
+
+        anstream::eprintln!("\n{}", "Audit Timings".green().bold());
+        for (ident, elapsed) in &timings {
+            anstream::eprintln!(
+                "  {ident:<width$}  {ms:>10.2}ms",
+                ms = elapsed.as_secs_f64() * 1000.0
+            );
+        }
+        anstream::eprintln!(
+            "  {total_label:<width$}  {ms:>10.2}ms",
+            total_label = "total",
+            ms = total.as_secs_f64() * 1000.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Timings;
+
+    #[test]
+    fn test_timings_sorted() {
+        let mut timings = Timings::new(["a", "b", "c", "d"]);
+        timings.record("a", Duration::from_millis(1));
+        timings.record("b", Duration::from_millis(5));
+        timings.record("a", Duration::from_millis(5));
+        timings.record("c", Duration::from_millis(5));
+
+        assert_eq!(
+            timings.sorted(),
+            [
+                ("a", Duration::from_millis(6)),
+                ("b", Duration::from_millis(5)),
+                ("c", Duration::from_millis(5)),
+                ("d", Duration::ZERO),
+            ]
+        );
+    }
+}
@@ -1,14 +1,25 @@
 //! Functionality for registering and managing the lifecycles of
 //! audits.
 
-use std::process::ExitCode;
+use std::{
+    collections::{BTreeMap, HashMap},
+    ops::Range,
+    process::ExitCode,
+};
 
+use github_actions_models::common::Uses;
 use indexmap::IndexMap;
+use subfeature::Subfeature;
 
 use crate::{
-    audit::{self, Audit, AuditLoadError},
+    audit::{self, Audit, AuditError, AuditInput, AuditLoadError, AuditMeta},
     cli::FixMode,
-    finding::{Confidence, Finding, FixDisposition, Persona, Severity},
+    config::Config,
+    finding::{
+        Confidence, DedupIdentity, Finding, FindingBuilder, FixDisposition, Persona, Severity,
+        location::{Locatable as _, SymbolicLocation, disabled_regions},
+    },
+    models::{AsDocument as _, StepCommon as _, workflow::Job},
     registry::input::{InputKey, InputRegistry},
     state::AuditState,
 };
@@ -17,17 +28,44 @@ pub(crate) mod input;
 
 pub(crate) struct AuditRegistry {
     pub(crate) audits: IndexMap<&'static str, Box<dyn Audit + Send + Sync>>,
+    /// Metadata for every known audit, including audits that were
+    /// skipped during registration (e.g. online audits in offline mode).
+    metadata: IndexMap<&'static str, AuditMeta>,
+    /// Online audits that were requested but skipped during registration,
+    /// along with the reason they were skipped.
+    skipped_online: IndexMap<&'static str, String>,
 }
 
 impl AuditRegistry {
     fn empty() -> Self {
         Self {
             audits: Default::default(),
+            metadata: Default::default(),
+            skipped_online: Default::default(),
         }
     }
 
     /// Constructs a new [`AuditRegistry`] with all default audits registered.
     pub(crate) fn default_audits(audit_state: &AuditState) -> anyhow::Result<Self> {
+        Self::audits_matching(audit_state, |_| true)
+    }
+
+    /// Constructs a new [`AuditRegistry`] with only the given audits
+    /// registered, starting from an empty set.
+    ///
+    /// Metadata is still recorded for every known audit, so that
+    /// callers can validate the requested idents via [`AuditRegistry::idents`].
+    pub(crate) fn selected_audits(
+        audit_state: &AuditState,
+        idents: &[String],
+    ) -> anyhow::Result<Self> {
+        Self::audits_matching(audit_state, |ident| idents.iter().any(|i| i == ident))
+    }
+
+    fn audits_matching(
+        audit_state: &AuditState,
+        enabled: impl Fn(&str) -> bool,
+    ) -> anyhow::Result<Self> {
         let mut registry = Self::empty();
 
         macro_rules! register_audit {
@@ -36,10 +74,16 @@ impl AuditRegistry {
                 use $rule as base;
 
                 use crate::audit::AuditCore as _;
-                match base::new(&audit_state) {
-                    Ok(audit) => registry.register_audit(base::ident(), Box::new(audit)),
-                    Err(AuditLoadError::Skip(e)) => {
-                        tracing::debug!("skipping {audit}: {e}", audit = base::ident())
+                registry.metadata.insert(base::ident(), base::meta());
+                if enabled(base::ident()) {
+                    match base::new(&audit_state) {
+                        Ok(audit) => registry.register_audit(base::ident(), Box::new(audit)),
+                        Err(AuditLoadError::Skip(e)) => {
+                            tracing::debug!("skipping {audit}: {e}", audit = base::ident());
+                            if base::online() {
+                                registry.skipped_online.insert(base::ident(), e.to_string());
+                            }
+                        }
                     }
                 }
             }};
@@ -75,6 +119,7 @@ impl AuditRegistry {
         register_audit!(audit::ref_version_mismatch::RefVersionMismatch);
         register_audit!(audit::dependabot_execution::DependabotExecution);
         register_audit!(audit::dependabot_cooldown::DependabotCooldown);
+        register_audit!(audit::dependabot_registries::DependabotRegistries);
         register_audit!(audit::concurrency_limits::ConcurrencyLimits);
         register_audit!(audit::archived_uses::ArchivedUses);
         register_audit!(audit::typosquat_uses::TyposquatUses);
@@ -85,6 +130,14 @@ impl AuditRegistry {
         register_audit!(audit::unpinned_tools::UnpinnedTools);
         register_audit!(audit::adhoc_packages::AdhocPackages);
         register_audit!(audit::insecure_url_scheme::InsecureURLScheme);
+        register_audit!(audit::curl_pipe_bash::CurlPipeBash);
+        register_audit!(audit::constant_if::ConstantIf);
+        register_audit!(audit::artifact_execution::ArtifactExecution);
+        register_audit!(audit::secrets_in_conditions::SecretsInConditions);
+        register_audit!(audit::persist_credentials::PersistCredentials);
+        register_audit!(audit::concurrency_groups::ConcurrencyGroups);
+        register_audit!(audit::github_token_forwarding::GithubTokenForwarding);
+        register_audit!(audit::sensitive_artifacts::SensitiveArtifacts);
 
         Ok(registry)
     }
@@ -101,11 +154,108 @@ impl AuditRegistry {
         self.audits.insert(ident, audit);
     }
 
+    /// Narrows the registered audits down to the given idents.
+    ///
+    /// Unlike [`AuditRegistry::selected_audits`], this filters an existing
+    /// set (e.g. the default set) rather than starting from an empty one.
+    pub(crate) fn retain_audits(&mut self, idents: &[String]) {
+        self.audits
+            .retain(|ident, _| idents.iter().any(|i| i == ident));
+        self.skipped_online
+            .retain(|ident, _| idents.iter().any(|i| i == ident));
+    }
+
+    /// Returns the idents of all known audits, in registration order.
+    ///
+    /// Unlike [`AuditRegistry::iter_audits`], this includes audits
+    /// that were skipped during registration.
+    pub(crate) fn idents(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.metadata.keys().copied()
+    }
+
+    /// Returns the metadata for all known audits, in registration order.
+    pub(crate) fn metadata(&self) -> impl Iterator<Item = &AuditMeta> {
+        self.metadata.values()
+    }
+
+    /// Returns the metadata for the audit with the given ident, if known.
+    pub(crate) fn meta(&self, ident: &str) -> Option<&AuditMeta> {
+        self.metadata.get(ident)
+    }
+
     pub(crate) fn iter_audits(
         &self,
     ) -> indexmap::map::Iter<'_, &'static str, Box<dyn Audit + Send + Sync>> {
         self.audits.iter()
     }
+
+    /// Returns an informational finding on `input` for each online audit
+    /// that was skipped during registration, noting the reduced coverage.
+    ///
+    /// These findings are attributed to the skipped audit itself, so that
+    /// they can be ignored and filtered like any of that audit's findings.
+    /// Every online audit works on repository `uses:` clauses, so each
+    /// finding points at the first such clause in `input`, and inputs
+    /// without one produce no findings at all.
+    pub(crate) fn skipped_online_findings<'doc>(
+        &self,
+        input: &'doc AuditInput,
+        config: &Config,
+    ) -> Result<Vec<Finding<'doc>>, AuditError> {
+        let Some((location, uses)) = first_repository_uses(input) else {
+            return Ok(vec![]);
+        };
+
+        self.skipped_online
+            .iter()
+            .filter(|(ident, _)| !config.disables(ident))
+            .map(|(ident, reason)| {
+                let meta = &self.metadata[ident];
+
+                FindingBuilder::new(meta.ident, "online audit skipped", meta.url)
+                    .severity(Severity::Informational)
+                    .confidence(Confidence::High)
+                    .persona(Persona::Pedantic)
+                    .add_location(
+                        location
+                            .clone()
+                            .primary()
+                            .subfeature(Subfeature::new(0, uses))
+                            .annotated(format!("not audited by {ident}: {reason}")),
+                    )
+                    .tip("run with network access and a GitHub token to enable this audit")
+                    .build(input)
+            })
+            .collect()
+    }
+}
+
+/// Returns the location and raw text of the first repository `uses:`
+/// clause in `input`, whether on a step or a reusable workflow call.
+fn first_repository_uses(input: &AuditInput) -> Option<(SymbolicLocation<'_>, &str)> {
+    match input {
+        AuditInput::Workflow(workflow) => workflow.jobs().find_map(|job| match job {
+            Job::NormalJob(normal) => normal.steps().find_map(|step| match step.uses() {
+                Some(Uses::Repository(uses)) => {
+                    Some((step.location().with_keys(["uses".into()]), uses.raw()))
+                }
+                _ => None,
+            }),
+            Job::ReusableWorkflowCallJob(reusable) => match &reusable.uses {
+                Uses::Repository(uses) => {
+                    Some((reusable.location().with_keys(["uses".into()]), uses.raw()))
+                }
+                _ => None,
+            },
+        }),
+        AuditInput::Action(action) => action.steps()?.find_map(|step| match step.uses() {
+            Some(Uses::Repository(uses)) => {
+                Some((step.location().with_keys(["uses".into()]), uses.raw()))
+            }
+            _ => None,
+        }),
+        _ => None,
+    }
 }
 
 impl std::fmt::Debug for AuditRegistry {
@@ -116,17 +266,47 @@ impl std::fmt::Debug for AuditRegistry {
     }
 }
 
+/// The reason a finding was filtered out of a [`FindingRegistry`]'s
+/// reportable findings.
+///
+/// NOTE: Audits that are disabled via configuration are never run,
+/// and therefore never produce findings to account for here.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum SuppressionReason {
+    /// Ignored via an inline `# zizmor: ignore[...]` comment, or
+    /// via a `# zizmor: off` ... `# zizmor: on` block.
+    IgnoreComment,
+    /// Ignored via an `ignore` rule in the configuration.
+    ConfigIgnore,
+    /// Below the user's minimum severity or confidence.
+    BelowThreshold,
+    /// Suppressed because of the user's selected persona.
+    Persona,
+    /// Collapsed into an identical finding with equal or higher confidence.
+    Duplicate,
+}
+
 /// A registry of all findings discovered during a `zizmor` run.
 pub(crate) struct FindingRegistry<'a> {
     input_registry: &'a InputRegistry,
+    /// The user's minimum severity, if given. Takes precedence over
+    /// any minimum severity in the finding's configuration.
     minimum_severity: Option<Severity>,
+    /// The user's minimum confidence, if given. Takes precedence over
+    /// any minimum confidence in the finding's configuration.
     minimum_confidence: Option<Confidence>,
-    persona: Persona,
+    /// The user's persona, if given. Takes precedence over any persona
+    /// in the finding's configuration.
+    persona: Option<Persona>,
     no_ignores: bool,
-    suppressed: Vec<Finding<'a>>,
-    ignored: Vec<Finding<'a>>,
+    suppression_counts: BTreeMap<SuppressionReason, usize>,
     findings: Vec<Finding<'a>>,
-    highest_seen_severity: Option<Severity>,
+    /// Indices into `findings`, keyed by each finding's
+    /// [`Finding::dedup_identities`].
+    dedup_index: HashMap<DedupIdentity, usize>,
+    /// The lines disabled by `# zizmor: off` blocks, per input.
+    /// Populated lazily as findings are added.
+    disabled_regions: HashMap<InputKey, Vec<Range<usize>>>,
 }
 
 impl<'a> FindingRegistry<'a> {
@@ -134,7 +314,7 @@ impl<'a> FindingRegistry<'a> {
         input_registry: &'a InputRegistry,
         minimum_severity: Option<Severity>,
         minimum_confidence: Option<Confidence>,
-        persona: Persona,
+        persona: Option<Persona>,
         no_ignores: bool,
     ) -> Self {
         Self {
@@ -143,62 +323,113 @@ impl<'a> FindingRegistry<'a> {
             minimum_confidence,
             persona,
             no_ignores,
-            suppressed: Default::default(),
-            ignored: Default::default(),
+            suppression_counts: Default::default(),
             findings: Default::default(),
-            highest_seen_severity: None,
+            dedup_index: Default::default(),
+            disabled_regions: Default::default(),
         }
     }
 
     /// Adds one or more findings to the current findings set,
     /// filtering with the configuration in the process.
+    ///
+    /// Duplicate findings (see [`Finding::dedup_identities`]) are collapsed
+    /// into a single finding, keeping the one with the highest confidence
+    /// (and then severity).
     pub(crate) fn extend(&mut self, results: Vec<Finding<'a>>) {
         // TODO: is it faster to iterate like this, or do `find_by_max`
         // and then `extend`?
         for mut finding in results {
-            finding.determinations.severity = self
-                .input_registry
-                .get_config(finding.input_group())
+            let config = self.input_registry.get_config(finding.input_group());
+
+            finding.determinations.severity = config
                 .severity_remap(&finding)
                 .unwrap_or(finding.determinations.severity);
 
             // A finding is ignored either if it's marked as ignored (i.e. via an ignore comment),
             // or the config for its input group ignores it, but only the user hasn't
             // overridden all ignores with `--no-ignores`.
-            let ignored = (finding.ignored
-                || self
-                    .input_registry
-                    .get_config(finding.input_group())
-                    .ignores(&finding))
-                && !self.no_ignores;
-
-            if self.persona > finding.determinations.persona {
-                self.suppressed.push(finding);
-            } else if ignored
-                || self
-                    .minimum_severity
-                    .is_some_and(|min| min > finding.determinations.severity)
-                || self
-                    .minimum_confidence
-                    .is_some_and(|min| min > finding.determinations.confidence)
-            {
-                self.ignored.push(finding);
+            let ignore_reason = if self.no_ignores {
+                None
+            } else if finding.ignored || self.in_disabled_region(&finding) {
+                Some(SuppressionReason::IgnoreComment)
+            } else if config.ignores(&finding) {
+                Some(SuppressionReason::ConfigIgnore)
             } else {
-                if self
-                    .highest_seen_severity
-                    .is_none_or(|s| finding.determinations.severity > s)
-                {
-                    self.highest_seen_severity = Some(finding.determinations.severity);
-                }
+                None
+            };
 
-                self.findings.push(finding);
+            let minimum_severity = self.minimum_severity.or_else(|| config.min_severity());
+            let minimum_confidence = self.minimum_confidence.or_else(|| config.min_confidence());
+            let persona = self
+                .persona
+                .or_else(|| config.persona())
+                .unwrap_or_default();
+
+            let below_threshold = minimum_severity
+                .is_some_and(|min| min > finding.determinations.severity)
+                || minimum_confidence.is_some_and(|min| min > finding.determinations.confidence);
+
+            if persona > finding.determinations.persona {
+                *self
+                    .suppression_counts
+                    .entry(SuppressionReason::Persona)
+                    .or_default() += 1;
+            } else if let Some(reason) =
+                ignore_reason.or(below_threshold.then_some(SuppressionReason::BelowThreshold))
+            {
+                *self.suppression_counts.entry(reason).or_default() += 1;
+            } else {
+                self.insert_deduplicated(finding);
             }
         }
     }
 
+    /// Whether the finding's primary location falls within a
+    /// `# zizmor: off` ... `# zizmor: on` block in its input.
+    fn in_disabled_region(&mut self, finding: &Finding) -> bool {
+        let location = finding.primary_location();
+        let key = location.symbolic.key;
+        let regions = self
+            .disabled_regions
+            .entry(key.clone())
+            .or_insert_with(|| disabled_regions(self.input_registry.get_input(key).as_document()));
+
+        let row = location.concrete.location.start_point.row;
+        regions.iter().any(|region| region.contains(&row))
+    }
+
+    fn insert_deduplicated(&mut self, finding: Finding<'a>) {
+        let identities = finding.dedup_identities();
+        let Some(idx) = identities
+            .iter()
+            .find_map(|identity| self.dedup_index.get(identity).copied())
+        else {
+            let idx = self.findings.len();
+            self.dedup_index
+                .extend(identities.into_iter().map(|identity| (identity, idx)));
+            self.findings.push(finding);
+            return;
+        };
+
+        for identity in identities {
+            self.dedup_index.entry(identity).or_insert(idx);
+        }
+
+        *self
+            .suppression_counts
+            .entry(SuppressionReason::Duplicate)
+            .or_default() += 1;
+
+        let rank = |f: &Finding| (f.determinations.confidence, f.determinations.severity);
+        if rank(&finding) > rank(&self.findings[idx]) {
+            self.findings[idx] = finding;
+        }
+    }
+
     /// The total count of all findings, regardless of status.
     pub(crate) fn count(&self) -> usize {
-        self.findings.len() + self.ignored.len() + self.suppressed.len()
+        self.findings.len() + self.suppression_counts.values().sum::<usize>()
     }
 
     /// All non-ignored and non-suppressed findings.
@@ -206,6 +437,28 @@ impl<'a> FindingRegistry<'a> {
         &self.findings
     }
 
+    /// All non-ignored and non-suppressed findings, grouped by the input
+    /// that contains each finding's primary location.
+    ///
+    /// Inputs are ordered by their first finding, and findings retain
+    /// their relative order within each input.
+    pub(crate) fn by_input(&self) -> IndexMap<&InputKey, Vec<&Finding<'a>>> {
+        let mut by_input: IndexMap<&InputKey, Vec<&Finding<'a>>> = IndexMap::new();
+        for finding in &self.findings {
+            by_input
+                .entry(finding.primary_location().symbolic.key)
+                .or_default()
+                .push(finding);
+        }
+
+        by_input
+    }
+
+    /// Whether there are no non-ignored and non-suppressed findings.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
     /// Findings from [`FindingRegistry::findings`] that are fixable.
     ///
     /// A finding is considered fixable if it has at least one
@@ -241,20 +494,26 @@ impl<'a> FindingRegistry<'a> {
         })
     }
 
-    /// All ignored findings.
-    pub(crate) fn ignored(&self) -> &[Finding<'a>] {
-        &self.ignored
-    }
-
-    /// All persona-suppressed findings.
-    pub(crate) fn suppressed(&self) -> &[Finding<'a>] {
-        &self.suppressed
+    /// The number of filtered findings for each [`SuppressionReason`],
+    /// in a stable order.
+    ///
+    /// Reasons that didn't filter any findings are not included.
+    pub(crate) fn suppression_counts(&self) -> impl Iterator<Item = (SuppressionReason, usize)> {
+        self.suppression_counts
+            .iter()
+            .map(|(reason, count)| (*reason, *count))
     }
 
     /// Returns an appropriate exit code based on the registry's
     /// highest-seen severity.
     pub(crate) fn exit_code(&self) -> ExitCode {
-        match self.highest_seen_severity {
+        let highest_seen_severity = self
+            .findings
+            .iter()
+            .map(|f| f.determinations.severity)
+            .max();
+
+        match highest_seen_severity {
             Some(sev) => match sev {
                 Severity::Informational => ExitCode::from(11),
                 Severity::Low => ExitCode::from(12),
@@ -265,3 +524,239 @@ impl<'a> FindingRegistry<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        audit::{
+            AuditCore as _, AuditInput, artipacked::Artipacked,
+            self_hosted_runner::SelfHostedRunner,
+        },
+        config::Config,
+        finding::{FindingBuilder, location::SymbolicLocation},
+        models::workflow::{Job, JobCommon as _, Workflow},
+        registry::input::{InputGroup, InputKind},
+    };
+
+    const WORKFLOW: &str = r#"
+name: test
+on: push
+
+jobs:
+  commented:
+    runs-on: ubuntu-latest # zizmor: ignore[artipacked]
+    steps:
+      - run: true
+
+  configured:
+    runs-on: ubuntu-latest
+    steps:
+      - run: true
+
+  normal:
+    runs-on: ubuntu-latest
+    steps:
+      - run: true
+"#;
+
+    fn input_registry(config: &str) -> (InputRegistry, InputKey) {
+        let key = InputKey::local("fakegroup".into(), "test.yml", None, None);
+
+        let mut group = InputGroup::new(Config::load(config).unwrap(), None);
+        group
+            .register(InputKind::Workflow, WORKFLOW.into(), key.clone(), true)
+            .unwrap();
+
+        let mut registry = InputRegistry::new();
+        registry.groups.insert("fakegroup".into(), group);
+
+        (registry, key)
+    }
+
+    fn findings<'a>(workflow: &'a Workflow) -> Vec<Finding<'a>> {
+        let job = |id: &str| {
+            workflow
+                .jobs()
+                .find_map(|job| match job {
+                    Job::NormalJob(job) if job.id() == id => Some(job),
+                    _ => None,
+                })
+                .unwrap()
+        };
+
+        let finding = |id: &str, severity, confidence, persona| {
+            Artipacked::finding()
+                .severity(severity)
+                .confidence(confidence)
+                .persona(persona)
+                .add_location(job(id).location().primary().with_keys(["runs-on".into()]))
+                .build(workflow)
+                .unwrap()
+        };
+
+        vec![
+            finding(
+                "commented",
+                Severity::High,
+                Confidence::High,
+                Persona::Regular,
+            ),
+            finding(
+                "configured",
+                Severity::High,
+                Confidence::High,
+                Persona::Regular,
+            ),
+            finding("normal", Severity::Low, Confidence::High, Persona::Regular),
+            finding("normal", Severity::High, Confidence::Low, Persona::Regular),
+            finding(
+                "normal",
+                Severity::High,
+                Confidence::High,
+                Persona::Pedantic,
+            ),
+            finding("normal", Severity::High, Confidence::High, Persona::Regular),
+        ]
+    }
+
+    #[test]
+    fn test_suppression_counts() {
+        // Line 12 is `configured`'s `runs-on:`.
+        let (inputs, key) = input_registry(
+            r#"
+rules:
+  artipacked:
+    ignore:
+      - test.yml:12
+"#,
+        );
+        let AuditInput::Workflow(workflow) = inputs.get_input(&key) else {
+            panic!("expected a workflow");
+        };
+
+        let mut registry = FindingRegistry::new(
+            &inputs,
+            Some(Severity::Medium),
+            Some(Confidence::Medium),
+            Some(Persona::Regular),
+            false,
+        );
+        registry.extend(findings(workflow));
+
+        assert_eq!(registry.findings().len(), 1);
+        assert_eq!(registry.count(), 6);
+        assert_eq!(
+            registry.suppression_counts().collect::<Vec<_>>(),
+            [
+                (SuppressionReason::IgnoreComment, 1),
+                (SuppressionReason::ConfigIgnore, 1),
+                (SuppressionReason::BelowThreshold, 2),
+                (SuppressionReason::Persona, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suppression_counts_no_ignores() {
+        let (inputs, key) = input_registry(
+            r#"
+rules:
+  artipacked:
+    ignore:
+      - test.yml:12
+"#,
+        );
+        let AuditInput::Workflow(workflow) = inputs.get_input(&key) else {
+            panic!("expected a workflow");
+        };
+
+        // With `--no-ignores`, neither the comment nor the config ignore applies.
+        // The three regular `normal` findings share a location, and collapse
+        // into one.
+        let mut registry = FindingRegistry::new(&inputs, None, None, Some(Persona::Regular), true);
+        registry.extend(findings(workflow));
+
+        assert_eq!(registry.findings().len(), 3);
+        assert_eq!(
+            registry.suppression_counts().collect::<Vec<_>>(),
+            [
+                (SuppressionReason::Persona, 1),
+                (SuppressionReason::Duplicate, 2)
+            ]
+        );
+    }
+
+    fn finding_at<'a>(
+        builder: FindingBuilder<'a>,
+        location: &SymbolicLocation<'a>,
+        workflow: &'a Workflow,
+        confidence: Confidence,
+    ) -> Finding<'a> {
+        builder
+            .severity(Severity::Medium)
+            .confidence(confidence)
+            .add_location(location.clone())
+            .build(workflow)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_dedup_keeps_highest_confidence() {
+        let (inputs, key) = input_registry("rules: {}");
+        let AuditInput::Workflow(workflow) = inputs.get_input(&key) else {
+            panic!("expected a workflow");
+        };
+        let job = workflow
+            .jobs()
+            .find_map(|job| match job {
+                Job::NormalJob(job) if job.id() == "normal" => Some(job),
+                _ => None,
+            })
+            .unwrap();
+
+        let location = job.location().primary().with_keys(["runs-on".into()]);
+        let finding = |builder, confidence| finding_at(builder, &location, workflow, confidence);
+
+        let mut registry = FindingRegistry::new(&inputs, None, None, None, false);
+        registry.extend(vec![finding(Artipacked::finding(), Confidence::Low)]);
+        registry.extend(vec![
+            finding(Artipacked::finding(), Confidence::High),
+            finding(Artipacked::finding(), Confidence::Medium),
+        ]);
+
+        assert_eq!(registry.findings().len(), 1);
+        assert_eq!(registry.count(), 3);
+        assert_eq!(
+            registry.findings()[0].determinations.confidence,
+            Confidence::High
+        );
+        assert_eq!(
+            registry.suppression_counts().collect::<Vec<_>>(),
+            [(SuppressionReason::Duplicate, 2)]
+        );
+
+        // Findings from different audits are only collapsed when they
+        // share an explicit dedup key.
+        let mut registry = FindingRegistry::new(&inputs, None, None, None, false);
+        registry.extend(vec![
+            finding(Artipacked::finding(), Confidence::Medium),
+            finding(SelfHostedRunner::finding(), Confidence::Low),
+        ]);
+        assert_eq!(registry.findings().len(), 2);
+
+        let mut registry = FindingRegistry::new(&inputs, None, None, None, false);
+        registry.extend(vec![
+            finding(
+                Artipacked::finding().dedup_key("shared"),
+                Confidence::Medium,
+            ),
+            finding(
+                SelfHostedRunner::finding().dedup_key("shared"),
+                Confidence::High,
+            ),
+        ]);
+        assert_eq!(registry.findings().len(), 1);
+        assert_eq!(registry.findings()[0].ident, SelfHostedRunner::ident());
+    }
+}
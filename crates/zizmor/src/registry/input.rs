@@ -17,7 +17,7 @@ use crate::{
     CollectionOptions,
     audit::AuditInput,
     config::{Config, ConfigError},
-    github::{Client, ClientError},
+    github::{Client, ClientError, GitHubHost},
     models::{
         action::Action,
         dependabot::Dependabot,
@@ -60,13 +60,13 @@ pub(crate) enum CollectionError {
 
     /// The user provided the same input in the same group more than once.
     #[error("can't register the same input more than once: {0}")]
-    DuplicateInput(InputKey),
+    DuplicateInput(Box<InputKey>),
 
     /// The user wants us to fetch a remote repo, but we don't have a
     /// functional GitHub client (maybe because we're offline, or
     /// because no token was provided).
     #[error("can't fetch remote repository: {0}")]
-    NoGitHubClient(RepoSlug),
+    NoGitHubClient(Box<RepoSlug>),
 
     /// An error occurred while processing ignore rules.
     #[error("error while processing ignore rules")]
@@ -90,10 +90,19 @@ pub(crate) enum CollectionError {
     #[error("GitHub API error while fetching remote input")]
     Client(#[from] ClientError),
 
+    /// The user's `--input-glob` patterns couldn't be compiled.
+    #[error("invalid input glob")]
+    InputGlob(#[from] globset::Error),
+
     /// An I/O error occurred while loading the input.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// An input file couldn't be read, e.g. because of its permissions,
+    /// because it isn't valid UTF-8, or because it's a broken symlink.
+    #[error("couldn't read {0}")]
+    Unreadable(Utf8PathBuf, #[source] std::io::Error),
+
     /// The input path isn't valid UTF-8.
     #[error("invalid path (not UTF-8): {1:?}")]
     InvalidPath(#[source] camino::FromPathError, PathBuf),
@@ -110,7 +119,7 @@ pub(crate) enum CollectionError {
         "remote input has an ambiguous Git reference ({0:?} is both a tag and a branch)",
         .slug.git_ref.as_deref().unwrap_or("HEAD"))
     ]
-    AmbiguousRemoteRef { slug: RepoSlug },
+    AmbiguousRemoteRef { slug: Box<RepoSlug> },
 }
 
 impl CollectionError {
@@ -152,9 +161,14 @@ impl std::fmt::Display for InputKind {
     }
 }
 
-/// A GitHub repository slug, i.e. `owner/repo[@ref]`.
+/// A GitHub repository slug, i.e. `[host/]owner/repo[@ref]`.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub(crate) struct RepoSlug {
+    /// An optional GitHub host, e.g. `github.com` or a GHES hostname.
+    ///
+    /// When absent, the host given by `--gh-hostname` is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) host: Option<GitHubHost>,
     /// The owner of the repository.
     pub(crate) owner: String,
     /// The name of the repository.
@@ -192,10 +206,23 @@ impl std::str::FromStr for RepoSlug {
 
         match components.len() {
             2 => Ok(Self {
+                host: None,
                 owner: components[0].into(),
                 repo: components[1].into(),
                 git_ref: git_ref.map(|s| s.into()),
             }),
+            // A leading component that looks like a domain name is a host,
+            // e.g. `ghes.example.com/owner/repo`. Anything else is a subpath,
+            // which we forbid.
+            3 if components[0].contains('.') => Ok(Self {
+                host: Some(
+                    GitHubHost::new(components[0])
+                        .map_err(|_| CollectionError::InvalidInput(s.into()))?,
+                ),
+                owner: components[1].into(),
+                repo: components[2].into(),
+                git_ref: git_ref.map(|s| s.into()),
+            }),
             x if x < 2 => Err(CollectionError::InvalidInput(s.into())),
             _ => Err(CollectionError::InvalidInput(s.into())),
         }
@@ -204,6 +231,10 @@ impl std::str::FromStr for RepoSlug {
 
 impl std::fmt::Display for RepoSlug {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(ref host) = self.host {
+            write!(f, "{host}/")?;
+        }
+
         if let Some(ref git_ref) = self.git_ref {
             write!(f, "{}/{}@{}", self.owner, self.repo, git_ref)
         } else {
@@ -298,6 +329,9 @@ pub(crate) struct RemoteKey {
     /// The group this input belongs to.
     #[serde(skip)]
     group: Group,
+    /// The host this input was fetched from.
+    #[serde(skip)]
+    host: GitHubHost,
     slug: RepoSlug,
     /// The path to the input file within the repository.
     path: Utf8PathBuf,
@@ -332,7 +366,8 @@ impl std::fmt::Display for InputKey {
                 let git_ref = remote.slug.git_ref.as_deref().unwrap_or("HEAD");
                 write!(
                     f,
-                    "https://github.com/{owner}/{repo}/blob/{git_ref}/{path}",
+                    "https://{host}/{owner}/{repo}/blob/{git_ref}/{path}",
+                    host = remote.host,
                     owner = remote.slug.owner,
                     repo = remote.slug.repo,
                     path = remote.path
@@ -381,9 +416,34 @@ impl InputKey {
         })
     }
 
-    pub(crate) fn remote(slug: &RepoSlug, path: String) -> Self {
+    /// Rebases this [`InputKey`]'s presentation path onto `base`, if given.
+    ///
+    /// `base` is expected to be a canonical path. Local inputs that
+    /// aren't within `base` keep their existing presentation path,
+    /// as do remote and stdin inputs.
+    pub(crate) fn relative_to(self, base: Option<&Utf8Path>) -> Self {
+        let Some(base) = base else {
+            return self;
+        };
+
+        match self {
+            Self::Local(mut local) => {
+                if let Ok(canonical) = local.verbatim_path.canonicalize_utf8()
+                    && let Ok(relative) = canonical.strip_prefix(base)
+                {
+                    local.native_path = relative.components().collect();
+                }
+
+                Self::Local(local)
+            }
+            _ => self,
+        }
+    }
+
+    pub(crate) fn remote(host: &GitHubHost, slug: &RepoSlug, path: String) -> Self {
         Self::Remote(RemoteKey {
             group: slug.into(),
+            host: host.clone(),
             slug: slug.clone(),
             path: path.into(),
         })
@@ -417,7 +477,8 @@ impl InputKey {
     /// Return a "presentation" path for this [`InputKey`].
     ///
     /// This will always be a relative path for remote keys,
-    /// and will be the native path for local keys.
+    /// and will be the native path for local keys (rebased by
+    /// [`InputKey::relative_to`], if applicable).
     pub(crate) fn presentation_path(&self) -> &str {
         match self {
             InputKey::Local(local) => local.native_path.as_str(),
@@ -457,6 +518,12 @@ impl InputKey {
 #[derive(Debug, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub(crate) struct Group(String);
 
+impl Group {
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 impl From<&str> for Group {
     fn from(value: &str) -> Self {
         Self(value.to_string())
@@ -490,7 +557,7 @@ impl InputGroup {
 
     pub(crate) fn register_input(&mut self, input: AuditInput) -> Result<(), CollectionError> {
         if self.inputs.contains_key(input.key()) {
-            return Err(CollectionError::DuplicateInput(input.key().clone()));
+            return Err(CollectionError::DuplicateInput(input.key().clone().into()));
         }
 
         self.inputs.insert(input.key().clone(), input);
@@ -604,43 +671,94 @@ impl InputGroup {
         let mut group = Self::new(config, Self::discover_root(path));
         let root = group.root.as_deref();
 
-        // When collecting individual files, we don't know which part
-        // of the input path is the prefix.
-        let (key, kind) = match (path.file_stem(), path.extension()) {
+        let kind = match (path.file_stem(), path.extension()) {
             // TODO: Do we need the `is_workflow_path` disambiguation here?
             // The only way this could be wrong is if the user does something
             // bizarre like `.github/workflows/.pre-commit-{config,hooks}.yml`.
-            (Some(".pre-commit-config"), Some("yml" | "yaml")) if !is_workflow_path => (
-                InputKey::local(Group(path.as_str().into()), path, None, root),
-                InputKind::PreCommitConfig,
-            ),
-            (Some(".pre-commit-hooks"), Some("yml" | "yaml")) if !is_workflow_path => (
-                InputKey::local(Group(path.as_str().into()), path, None, root),
-                InputKind::PreCommitHooks,
-            ),
-            (Some("dependabot"), Some("yml" | "yaml")) if !is_workflow_path => (
-                InputKey::local(Group(path.as_str().into()), path, None, root),
-                InputKind::Dependabot,
-            ),
-            (Some("action"), Some("yml" | "yaml")) if !is_workflow_path => (
-                InputKey::local(Group(path.as_str().into()), path, None, root),
-                InputKind::Action,
-            ),
-            (Some(_), Some("yml" | "yaml")) => (
-                InputKey::local(Group(path.as_str().into()), path, None, root),
-                InputKind::Workflow,
-            ),
+            (Some(".pre-commit-config"), Some("yml" | "yaml")) if !is_workflow_path => {
+                InputKind::PreCommitConfig
+            }
+            (Some(".pre-commit-hooks"), Some("yml" | "yaml")) if !is_workflow_path => {
+                InputKind::PreCommitHooks
+            }
+            (Some("dependabot"), Some("yml" | "yaml")) if !is_workflow_path => {
+                InputKind::Dependabot
+            }
+            (Some("action"), Some("yml" | "yaml")) if !is_workflow_path => InputKind::Action,
+            (Some(_), Some("yml" | "yaml")) => InputKind::Workflow,
             _ => return Err(CollectionError::InvalidExtension),
         };
 
+        // When collecting individual files, we don't know which part
+        // of the input path is the prefix.
+        let key = InputKey::local(Group(path.as_str().into()), path, None, root)
+            .relative_to(options.relative_to.as_deref());
+
         let contents = std::fs::read_to_string(path).map_err(|e| {
-            CollectionError::Inner(CollectionError::Io(e).into(), key.to_string(), kind)
+            CollectionError::Inner(
+                CollectionError::Unreadable(path.into(), e).into(),
+                key.to_string(),
+                kind,
+            )
         })?;
         group.register(kind, contents, key, options.strict)?;
 
         Ok(group)
     }
 
+    /// Returns the kinds of input that the given entry (found at `depth`
+    /// while walking an input directory) is collected as, if any.
+    ///
+    /// This only considers the entry's path, not whether it's a file.
+    fn entry_kinds(
+        entry: &Utf8Path,
+        depth: usize,
+        options: &CollectionOptions,
+    ) -> Result<Vec<InputKind>, CollectionError> {
+        let mut kinds = vec![];
+
+        if options.mode_set.workflows()
+            && matches!(entry.extension(), Some("yml" | "yaml"))
+            && camino::absolute_utf8(entry)?
+                .parent()
+                .is_some_and(|dir| dir.ends_with(".github/workflows"))
+        {
+            kinds.push(InputKind::Workflow);
+        }
+
+        if options.mode_set.actions()
+            && matches!(entry.file_name(), Some("action.yml" | "action.yaml"))
+            && options
+                .max_depth
+                .is_none_or(|max_depth| depth <= max_depth + 1)
+        {
+            kinds.push(InputKind::Action);
+        }
+
+        if options.mode_set.dependabot()
+            && matches!(
+                entry.file_name(),
+                Some("dependabot.yml" | "dependabot.yaml")
+            )
+        {
+            kinds.push(InputKind::Dependabot);
+        }
+
+        if options.mode_set.pre_commit() {
+            match entry.file_name() {
+                Some(".pre-commit-config.yml" | ".pre-commit-config.yaml") => {
+                    kinds.push(InputKind::PreCommitConfig)
+                }
+                Some(".pre-commit-hooks.yml" | ".pre-commit-hooks.yaml") => {
+                    kinds.push(InputKind::PreCommitHooks)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(kinds)
+    }
+
     async fn collect_from_dir(
         path: &Utf8Path,
         options: &CollectionOptions,
@@ -672,107 +790,103 @@ impl InputGroup {
                 .git_exclude(true);
         }
 
+        // Symlinks are skipped below unless the user opts into following
+        // them, in which case we also descend into symlinked directories.
+        walker.follow_links(options.follow_symlinks);
+
+        // If the user has bounded the depth of action collection, we
+        // avoid descending into directories past that depth entirely.
+        // The exception is the input directory's `.github/`, which is
+        // always walked in full since it contains the workflows.
+        //
+        // NOTE: The walker assigns depth 0 to the input directory itself,
+        // so files in a directory at depth N are at depth N + 1.
+        if let Some(max_depth) = options.max_depth {
+            let github_dir = path.join(".github");
+            walker.filter_entry(move |entry| {
+                entry.depth() <= max_depth + 1 || entry.path().starts_with(&github_dir)
+            });
+        }
+
         let root = group.root.clone();
+        let relative_to = options.relative_to.as_deref();
         for entry in walker.build() {
             let entry = entry?;
-            let entry = <&Utf8Path>::try_from(entry.path())
-                .map_err(|e| CollectionError::InvalidPath(e, entry.path().into()))?;
-            // Pre-compute file status so we don't call `stat()` once per mode
-            // check below.
-            let entry_is_file = entry.is_file();
-            let root = root.as_deref();
-
-            if options.mode_set.workflows()
-                && entry_is_file
-                && matches!(entry.extension(), Some("yml" | "yaml"))
-                && camino::absolute_utf8(entry)?
-                    .parent()
-                    .is_some_and(|dir| dir.ends_with(".github/workflows"))
-            {
-                let key = InputKey::local(Group(path.as_str().into()), entry, Some(path), root);
-                let contents = std::fs::read_to_string(entry).map_err(|e| {
-                    CollectionError::Inner(
-                        CollectionError::Io(e).into(),
-                        key.to_string(),
-                        InputKind::Workflow,
-                    )
-                })?;
-                group.register(InputKind::Workflow, contents, key, options.strict)?;
+            let depth = entry.depth();
+
+            // NOTE: The input directory itself (depth 0) is always followed,
+            // since the user named it explicitly.
+            if entry.path_is_symlink() && depth > 0 && !options.follow_symlinks {
+                // Only warn about symlinks that we would have collected,
+                // since skipping anything else doesn't reduce coverage.
+                let collectable = match <&Utf8Path>::try_from(entry.path()) {
+                    Ok(entry) => !Self::entry_kinds(entry, depth, options)?.is_empty(),
+                    Err(_) => false,
+                };
+
+                if collectable {
+                    tracing::warn!(
+                        "skipping symlink {path} (use --follow-symlinks to follow it)",
+                        path = entry.path().display()
+                    );
+                } else {
+                    tracing::debug!("skipping symlink {path}", path = entry.path().display());
+                }
+                continue;
             }
 
-            if options.mode_set.actions()
-                && entry_is_file
-                && matches!(entry.file_name(), Some("action.yml" | "action.yaml"))
-            {
-                let key = InputKey::local(Group(path.as_str().into()), entry, Some(path), root);
-                let contents = std::fs::read_to_string(entry).map_err(|e| {
-                    CollectionError::Inner(
-                        CollectionError::Io(e).into(),
-                        key.to_string(),
-                        InputKind::Action,
-                    )
-                })?;
-                group.register(InputKind::Action, contents, key, options.strict)?;
+            let entry = <&Utf8Path>::try_from(entry.path())
+                .map_err(|e| CollectionError::InvalidPath(e, entry.path().into()))?;
+            if !entry.is_file() {
+                continue;
             }
 
-            if options.mode_set.dependabot()
-                && entry_is_file
-                && matches!(
-                    entry.file_name(),
-                    Some("dependabot.yml" | "dependabot.yaml")
+            for kind in Self::entry_kinds(entry, depth, options)? {
+                let key = InputKey::local(
+                    Group(path.as_str().into()),
+                    entry,
+                    Some(path),
+                    root.as_deref(),
                 )
-            {
-                let key = InputKey::local(Group(path.as_str().into()), entry, Some(path), root);
+                .relative_to(relative_to);
                 let contents = std::fs::read_to_string(entry).map_err(|e| {
                     CollectionError::Inner(
-                        CollectionError::Io(e).into(),
+                        CollectionError::Unreadable(entry.into(), e).into(),
                         key.to_string(),
-                        InputKind::Dependabot,
+                        kind,
                     )
                 })?;
-                group.register(InputKind::Dependabot, contents, key, options.strict)?;
-            }
-
-            if options.mode_set.pre_commit() && entry_is_file {
-                if matches!(
-                    entry.file_name(),
-                    Some(".pre-commit-config.yml" | ".pre-commit-config.yaml")
-                ) {
-                    let key = InputKey::local(Group(path.as_str().into()), entry, Some(path), root);
-                    let contents = std::fs::read_to_string(entry).map_err(|e| {
-                        CollectionError::Inner(
-                            CollectionError::Io(e).into(),
-                            key.to_string(),
-                            InputKind::PreCommitConfig,
-                        )
-                    })?;
-                    group.register(InputKind::PreCommitConfig, contents, key, options.strict)?;
-                } else if matches!(
-                    entry.file_name(),
-                    Some(".pre-commit-hooks.yml" | ".pre-commit-hooks.yaml")
-                ) {
-                    let key = InputKey::local(Group(path.as_str().into()), entry, Some(path), root);
-                    let contents = std::fs::read_to_string(entry).map_err(|e| {
-                        CollectionError::Inner(
-                            CollectionError::Io(e).into(),
-                            key.to_string(),
-                            InputKind::PreCommitHooks,
-                        )
-                    })?;
-                    group.register(InputKind::PreCommitHooks, contents, key, options.strict)?;
-                }
+                group.register(kind, contents, key, options.strict)?;
             }
         }
 
         Ok(group)
     }
 
+    /// Returns the client to fetch `slug` with.
+    ///
+    /// Slugs without an explicit host use `--gh-hostname`'s client;
+    /// slugs with one use the client for that host, if we have a
+    /// token for it.
+    fn client_for_slug<'a>(
+        slug: &RepoSlug,
+        gh_client: Option<&'a Client>,
+    ) -> Result<&'a Client, CollectionError> {
+        let client =
+            gh_client.ok_or_else(|| CollectionError::NoGitHubClient(slug.clone().into()))?;
+
+        match &slug.host {
+            Some(host) => Ok(client.for_host(host)?),
+            None => Ok(client),
+        }
+    }
+
     async fn collect_from_repo_slug(
         slug: RepoSlug,
         options: &CollectionOptions,
         gh_client: Option<&Client>,
     ) -> Result<Self, CollectionError> {
-        let client = gh_client.ok_or_else(|| CollectionError::NoGitHubClient(slug.clone()))?;
+        let client = Self::client_for_slug(&slug, gh_client)?;
 
         let config = Config::discover(options, || Config::discover_remote(client, &slug)).await?;
         let mut group = Self::new(config, None);
@@ -791,16 +905,33 @@ impl InputGroup {
             let len = after - before;
 
             tracing::info!(
-                "collected {len} inputs from {owner}/{repo}",
+                "collected {len} inputs from {owner}/{repo} on {host}",
                 owner = slug.owner,
-                repo = slug.repo
+                repo = slug.repo,
+                host = client.host()
             );
         }
 
         Ok(group)
     }
 
-    async fn collect_from_stdin() -> Result<Self, CollectionError> {
+    /// Infer an input's kind from its filename, as given by `--stdin-filename`.
+    ///
+    /// Unlike [`InputGroup::collect_from_file`], there's no parent path to
+    /// disambiguate with, so only the basename is considered.
+    fn stdin_kind(filename: &Utf8Path) -> InputKind {
+        match filename.file_name() {
+            Some("action.yml" | "action.yaml") => InputKind::Action,
+            Some("dependabot.yml" | "dependabot.yaml") => InputKind::Dependabot,
+            Some(".pre-commit-config.yml" | ".pre-commit-config.yaml") => {
+                InputKind::PreCommitConfig
+            }
+            Some(".pre-commit-hooks.yml" | ".pre-commit-hooks.yaml") => InputKind::PreCommitHooks,
+            _ => InputKind::Workflow,
+        }
+    }
+
+    async fn collect_from_stdin(options: &CollectionOptions) -> Result<Self, CollectionError> {
         let mut contents = String::new();
         std::io::stdin()
             .read_to_string(&mut contents)
@@ -809,6 +940,14 @@ impl InputGroup {
         let mut group = Self::new(Config::default(), None);
         let key = InputKey::stdin();
 
+        // If the user told us what the input is called, we don't need to guess.
+        if let Some(filename) = &options.stdin_filename {
+            let kind = Self::stdin_kind(filename);
+            tracing::debug!("stdin: inferred {kind} from filename {filename}");
+            group.register(kind, contents, key, true)?;
+            return Ok(group);
+        }
+
         // Infer the input type by trying each parser in order.
         // Workflow is tried first since it's the most common stdin use case.
         match group.register(InputKind::Workflow, contents.clone(), key.clone(), true) {
@@ -853,7 +992,7 @@ impl InputGroup {
         gh_client: Option<&Client>,
     ) -> Result<Self, CollectionError> {
         if request == "-" {
-            return Self::collect_from_stdin().await;
+            return Self::collect_from_stdin(options).await;
         }
 
         let path = Utf8Path::new(request);
@@ -862,6 +1001,12 @@ impl InputGroup {
             Self::collect_from_file(path, options).await
         } else if path.is_dir() {
             Self::collect_from_dir(path, options).await
+        } else if path.is_symlink()
+            && let Err(e) = path.metadata()
+        {
+            // A broken symlink isn't a repo slug, so we report it directly
+            // rather than failing to parse it below.
+            Err(CollectionError::Unreadable(path.into(), e))
         } else {
             let slug = RepoSlug::from_str(request)?;
             Self::collect_from_repo_slug(slug, options, gh_client).await
@@ -937,7 +1082,26 @@ impl InputRegistry {
         // duplicated the input multiple times on the command line by accident.
         // We just ignore any duplicate registrations.
         if let btree_map::Entry::Vacant(e) = self.groups.entry(Group(name.into())) {
-            e.insert(InputGroup::collect(name, options, gh_client).await?);
+            let mut group = InputGroup::collect(name, options, gh_client).await?;
+
+            // Skip any inputs that don't match the user's `--input-glob`s.
+            // Standard input is always kept, since it has no path to match.
+            if let Some(globs) = &options.input_globs {
+                group.inputs.retain(|key, _| match key {
+                    InputKey::Stdin(_) => true,
+                    _ => globs.is_match(key.best_identifier()),
+                });
+            }
+
+            // Then, skip any inputs that match the user's `--exclude`s.
+            if let Some(globs) = &options.exclude_globs {
+                group.inputs.retain(|key, _| match key {
+                    InputKey::Stdin(_) => true,
+                    _ => !globs.is_match(key.best_identifier()),
+                });
+            }
+
+            e.insert(group);
         }
 
         Ok(())
@@ -974,7 +1138,9 @@ mod tests {
 
     use crate::registry::input::InputGroup;
 
-    use super::{InputKey, RepoSlug};
+    use crate::github::{Client, ClientError, GitHubHost, GitHubToken};
+
+    use super::{CollectionError, Group, InputKey, RepoSlug};
 
     #[test]
     fn test_input_key_display() {
@@ -983,7 +1149,11 @@ mod tests {
 
         // No ref
         let slug = RepoSlug::from_str("foo/bar").unwrap();
-        let remote = InputKey::remote(&slug, ".github/workflows/baz.yml".into());
+        let remote = InputKey::remote(
+            &GitHubHost::default(),
+            &slug,
+            ".github/workflows/baz.yml".into(),
+        );
         assert_eq!(
             remote.to_string(),
             "https://github.com/foo/bar/blob/HEAD/.github/workflows/baz.yml"
@@ -991,13 +1161,106 @@ mod tests {
 
         // With a git ref
         let slug = RepoSlug::from_str("foo/bar@v1").unwrap();
-        let remote = InputKey::remote(&slug, ".github/workflows/baz.yml".into());
+        let remote = InputKey::remote(
+            &GitHubHost::default(),
+            &slug,
+            ".github/workflows/baz.yml".into(),
+        );
         assert_eq!(
             remote.to_string(),
             "https://github.com/foo/bar/blob/v1/.github/workflows/baz.yml"
         );
     }
 
+    #[test]
+    fn test_repo_slug_host() {
+        let slug = RepoSlug::from_str("foo/bar").unwrap();
+        assert_eq!(slug.host, None);
+        assert_eq!(slug.to_string(), "foo/bar");
+
+        let slug = RepoSlug::from_str("github.com/foo/bar").unwrap();
+        assert_eq!(slug.host, Some(GitHubHost::default()));
+        assert_eq!(slug.to_string(), "github.com/foo/bar");
+
+        let slug = RepoSlug::from_str("ghes.example.com/foo/bar@v1").unwrap();
+        assert_eq!(
+            slug.host,
+            Some(GitHubHost::Enterprise("ghes.example.com".into()))
+        );
+        assert_eq!((slug.owner.as_str(), slug.repo.as_str()), ("foo", "bar"));
+        assert_eq!(slug.git_ref(), "v1");
+        assert_eq!(slug.to_string(), "ghes.example.com/foo/bar@v1");
+
+        // A leading component that isn't a domain name is a subpath.
+        assert!(RepoSlug::from_str("foo/bar/baz").is_err());
+        assert!(RepoSlug::from_str("ghes.example.com/foo/bar/baz").is_err());
+    }
+
+    #[test]
+    fn test_input_key_display_mixed_hosts() {
+        let dotcom = RepoSlug::from_str("foo/bar").unwrap();
+        let ghes = RepoSlug::from_str("ghes.example.com/foo/bar").unwrap();
+        let ghes_host = GitHubHost::new("ghes.example.com").unwrap();
+
+        assert_eq!(
+            InputKey::remote(&GitHubHost::default(), &dotcom, "action.yml".into()).to_string(),
+            "https://github.com/foo/bar/blob/HEAD/action.yml"
+        );
+        assert_eq!(
+            InputKey::remote(&ghes_host, &ghes, "action.yml".into()).to_string(),
+            "https://ghes.example.com/foo/bar/blob/HEAD/action.yml"
+        );
+
+        // The same repository on different hosts is a different group.
+        assert_ne!(Group::from(&dotcom), Group::from(&ghes));
+    }
+
+    #[test]
+    fn test_client_for_slug_mixed_hosts() {
+        let ghes_host = GitHubHost::new("ghes.example.com").unwrap();
+        let client = Client::new(
+            &GitHubHost::default(),
+            &GitHubToken::new("gha_dotcom").unwrap(),
+            "/tmp".into(),
+        )
+        .unwrap()
+        .with_host_tokens(
+            &[(ghes_host.clone(), GitHubToken::new("gha_ghes").unwrap())],
+            "/tmp".into(),
+        )
+        .unwrap();
+
+        // A github.com slug and a GHES slug in the same run each get
+        // the client for their own host.
+        let dotcom = RepoSlug::from_str("foo/bar").unwrap();
+        let ghes = RepoSlug::from_str("ghes.example.com/foo/bar").unwrap();
+        assert_eq!(
+            InputGroup::client_for_slug(&dotcom, Some(&client))
+                .unwrap()
+                .host(),
+            &GitHubHost::default()
+        );
+        assert_eq!(
+            InputGroup::client_for_slug(&ghes, Some(&client))
+                .unwrap()
+                .host(),
+            &ghes_host
+        );
+
+        // Hosts without a token are refused.
+        let other = RepoSlug::from_str("other.example.com/foo/bar").unwrap();
+        assert!(matches!(
+            InputGroup::client_for_slug(&other, Some(&client)),
+            Err(CollectionError::Client(ClientError::NoTokenForHost { .. }))
+        ));
+
+        // As is everything, without any client.
+        assert!(matches!(
+            InputGroup::client_for_slug(&dotcom, None),
+            Err(CollectionError::NoGitHubClient(_))
+        ));
+    }
+
     /// Tests that [`InputKey::presentation_path`] returns the exact path that the user
     /// supplied (regardless of prefix or root), but normalized for the host's default
     /// separator.
@@ -1018,6 +1281,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_input_key_relative_to() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let temp_path = Utf8PathBuf::try_from(temp_dir.path().to_path_buf())
+            .unwrap()
+            .canonicalize_utf8()
+            .unwrap();
+
+        let child = temp_path.join("foo/bar/baz.yml");
+        std::fs::create_dir_all(child.parent().unwrap()).unwrap();
+        std::fs::write(&child, "contents").unwrap();
+
+        // No base: the presentation path is unchanged.
+        let local =
+            InputKey::local("fakegroup".into(), child.as_path(), None, None).relative_to(None);
+        assert_eq!(
+            local.presentation_path(),
+            child.components().collect::<Utf8PathBuf>()
+        );
+
+        // Inputs within the base are rebased onto it.
+        let local = InputKey::local("fakegroup".into(), child.as_path(), None, None)
+            .relative_to(Some(&temp_path));
+        if cfg!(target_os = "windows") {
+            assert_eq!(local.presentation_path(), "foo\\bar\\baz.yml");
+        } else {
+            assert_eq!(local.presentation_path(), "foo/bar/baz.yml");
+        }
+
+        // Inputs outside of the base are unchanged.
+        let local = InputKey::local("fakegroup".into(), child.as_path(), None, None)
+            .relative_to(Some(&temp_path.join("other")));
+        assert_eq!(
+            local.presentation_path(),
+            child.components().collect::<Utf8PathBuf>()
+        );
+
+        // The best identifier is unaffected.
+        let local = InputKey::local("fakegroup".into(), child.as_path(), None, Some(&temp_path))
+            .relative_to(Some(&temp_path.join("foo")));
+        assert_eq!(local.best_identifier(), "foo/bar/baz.yml");
+    }
+
     #[test]
     fn test_input_key_local_best_identifier() {
         // "Rootless" cases: with no group root, best_identifier falls back to
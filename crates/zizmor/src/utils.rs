@@ -280,15 +280,25 @@ pub(crate) static DEFAULT_ENVIRONMENT_VARIABLES: &[(
 /// Splits the given `patterns` string into one or more patterns, using
 /// approximately the same rules as GitHub's `@actions/glob` package.
 pub(crate) fn split_patterns(patterns: &str) -> impl Iterator<Item = &str> {
+    split_patterns_with_offsets(patterns).map(|(_, pattern)| pattern)
+}
+
+/// Like [`split_patterns`], but also yields each pattern's byte offset
+/// within `patterns`.
+pub(crate) fn split_patterns_with_offsets(patterns: &str) -> impl Iterator<Item = (usize, &str)> {
     // GitHub's pattern splitting is very basic: each line is processed in sequence,
     // with empty or comment (#) lines removed. Everything remaining is considered
     // a pattern.
     // See: https://github.com/actions/toolkit/blob/6c4e082c181a/packages/glob/src/internal-globber.ts#L161-L190
 
     patterns
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start + line.len() - line.trim_start().len(), line.trim()))
+        })
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
 }
 
 /// Represents an expression that has been extracted from some surrounding
@@ -646,6 +656,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn split_patterns_with_offsets() {
+        let patterns = "foo\n  # comment\n\n  bar/.git  \r\n.git\n";
+
+        let pats = super::split_patterns_with_offsets(patterns).collect::<Vec<_>>();
+        assert_eq!(pats, &[(0, "foo"), (19, "bar/.git"), (31, ".git")]);
+
+        for (offset, pattern) in pats {
+            assert!(patterns[offset..].starts_with(pattern));
+        }
+    }
+
     #[test]
     fn test_extract_fenced_expression() {
         let exprs = &[
@@ -35,6 +35,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |
        = note: audit confidence → High
        = tip: use 'name: ...' to give this workflow a name
+       = note: this finding has an auto-fix
 
     info[anonymous-definition]: workflow or action definition without a name
       --> @@INPUT@@:21:3
@@ -45,9 +46,89 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        = note: audit confidence → High
        = tip: use 'name: ...' to give this job a name
 
-    2 findings: 1 informational, 1 low, 0 medium, 0 high
+    2 findings (1 safe fixes): 1 informational, 1 low, 0 medium, 0 high
     "#
     );
 
     Ok(())
 }
+
+#[test]
+fn test_named() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("anonymous-definition/named.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"No findings to report. Good job!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unnamed() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("anonymous-definition/unnamed.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    help[anonymous-definition]: workflow or action definition without a name
+      --> @@INPUT@@:3:1
+       |
+     3 | / on: push
+     4 | |
+     5 | | permissions: {}
+    ...  |
+    15 | |     steps:
+    16 | |       - run: echo hello
+       | |________________________^ this workflow
+       |
+       = note: audit confidence → High
+       = tip: use 'name: ...' to give this workflow a name
+       = note: this finding has an auto-fix
+
+    1 findings (1 safe fixes): 0 informational, 1 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unnamed_fix() -> anyhow::Result<()> {
+    // Fixes are applied in place, so we work on a copy of the input.
+    let tempdir = tempfile::tempdir()?;
+    let input = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("unnamed.yml");
+    std::fs::copy(input_under_test("anonymous-definition/unnamed.yml"), &input)?;
+
+    zizmor()
+        .input(input.clone())
+        .args(["--persona=pedantic", "--fix=safe"])
+        .run()?;
+
+    insta::assert_snapshot!(std::fs::read_to_string(&input)?, @"
+    # The workflow's name is purposefully left unset for this test.
+
+    name: unnamed
+    on: push
+
+    permissions: {}
+
+    concurrency:
+      group: ${{ github.workflow }}-${{ github.ref }}
+      cancel-in-progress: true
+
+    jobs:
+      test:
+        name: test
+        runs-on: ubuntu-latest
+        steps:
+          - run: echo hello
+    ");
+
+    Ok(())
+}
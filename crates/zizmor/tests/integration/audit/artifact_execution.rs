@@ -0,0 +1,105 @@
+use anyhow::Result;
+
+use crate::common::{NetworkMode, input_under_test, zizmor};
+
+#[test]
+fn test_dangerous() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("artifact-execution/dangerous.yml"))
+            .run()?,
+        @"
+    error[dangerous-triggers]: use of fundamentally insecure workflow trigger
+     --> @@INPUT@@:3:1
+      |
+    3 | / on:
+    4 | |   workflow_run:
+    5 | |     workflows: [ci]
+    6 | |     types: [completed]
+      | |______________________^ workflow_run is almost always used insecurely
+      |
+      = note: audit confidence → Medium
+
+    error[artifact-execution]: execution of downloaded artifact contents
+      --> @@INPUT@@:25:11
+       |
+    14 |       - name: download build
+       |         -------------------- artifact is downloaded here
+    ...
+    25 |           chmod +x dist/deploy.sh
+       |           ^^^^^^^^^^^^^^^^^^^^^^^ executes downloaded content
+       |
+       = note: audit confidence → High
+       = tip: treat artifact contents as untrusted data; don't execute them
+
+    error[artifact-execution]: execution of downloaded artifact contents
+      --> @@INPUT@@:28:9
+       |
+    14 |       - name: download build
+       |         -------------------- artifact is downloaded here
+    ...
+    28 |       - uses: ./dist/action
+       |         ^^^^^^^^^^^^^^^^^^^ uses an action from downloaded content
+       |
+       = note: audit confidence → High
+       = tip: treat artifact contents as untrusted data; don't execute them
+
+    error[artifact-execution]: execution of downloaded artifact contents
+      --> @@INPUT@@:38:14
+       |
+    33 |         - uses: dawidd6/action-download-artifact@ac66b43f0e6a346234dd65d4d0c8fbb31cb316e5 # v11
+       |  _________-
+    34 | |         with:
+    35 | |           workflow: ci.yml
+    36 | |           name: preview
+       | |_______________________- artifact is downloaded here
+    37 |
+    38 |         - run: bash scripts/preview.sh
+       |                ^^^^^^^^^^^^^^^^^^^^^^^ executes downloaded content
+       |
+       = note: audit confidence → Low
+       = tip: treat artifact contents as untrusted data; don't execute them
+
+    7 findings (3 suppressed): 0 informational, 0 low, 0 medium, 4 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_benign() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("artifact-execution/benign.yml"))
+            .args(["--persona=auditor"])
+            .run()?,
+        @"
+    info[anonymous-definition]: workflow or action definition without a name
+     --> @@INPUT@@:9:3
+      |
+    9 |   repackage:
+      |   ^^^^^^^^^ this job
+      |
+      = note: audit confidence → High
+      = tip: use 'name: ...' to give this job a name
+
+    help[concurrency-limits]: insufficient job-level concurrency limits
+     --> @@INPUT@@:3:1
+      |
+    3 | / on:
+    4 | |   pull_request:
+      | |_______________^ workflow is missing concurrency setting
+    ...
+    9 |     repackage:
+      |     --------- job affected by missing workflow concurrency
+      |
+      = note: audit confidence → High
+
+    2 findings: 1 informational, 1 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::common::{input_under_test, zizmor};
+use crate::common::{NetworkMode, input_under_test, zizmor};
 
 #[test]
 fn test_regular_persona() -> anyhow::Result<()> {
@@ -14,7 +14,7 @@ fn test_regular_persona() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    2 findings (1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
+    4 findings (3 suppressed, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
     "
     );
 
@@ -25,6 +25,7 @@ fn test_regular_persona() -> anyhow::Result<()> {
 fn test_pedantic_persona() -> anyhow::Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("artipacked.yml"))
             .args(["--persona=pedantic"])
             .run()?,
@@ -38,7 +39,21 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    2 findings (1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:28:9
+       |
+    28 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    29 | |         with:
+    30 | |           persist-credentials: true
+       | |____________________________________^ credentials are persisted here
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+
+    4 findings (1 suppressed, 1 deduplicated, 1 unsafe fixes): 0 informational, 1 low, 1 medium, 0 high
     "
     );
 
@@ -49,6 +64,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
 fn test_auditor_persona() -> anyhow::Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("artipacked.yml"))
             .args(["--persona=auditor"])
             .run()?,
@@ -74,7 +90,7 @@ fn test_auditor_persona() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    2 findings (2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
+    4 findings (2 deduplicated, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
     "
     );
 
@@ -89,6 +105,7 @@ fn test_auditor_persona() -> anyhow::Result<()> {
 fn test_issue_447() -> anyhow::Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("artipacked/issue-447-repro.yml"))
             .args(["--persona=auditor"])
             .run()?,
@@ -107,7 +124,7 @@ fn test_issue_447() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    1 findings (1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
+    2 findings (1 deduplicated, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
     "#
     );
 
@@ -122,6 +139,7 @@ fn test_issue_447() -> anyhow::Result<()> {
 fn test_issue_1709() -> anyhow::Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("artipacked/issue-1709-repro.yml"))
             .args(["--persona=pedantic"])
             .run()?,
@@ -138,7 +156,7 @@ fn test_issue_1709() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    1 findings (1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
+    2 findings (1 deduplicated, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
     "
     );
 
@@ -152,7 +170,11 @@ fn test_issue_1709() -> anyhow::Result<()> {
 #[test]
 fn test_issue_1769() -> anyhow::Result<()> {
     insta::assert_snapshot!(
-        zizmor().input(input_under_test("artipacked/issue-1769-repro.yml")).args(["--persona=pedantic"]).run()?,
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("artipacked/issue-1769-repro.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
         @"
     info[artipacked]: credential persistence through GitHub Actions artifacts
       --> @@INPUT@@:19:9
@@ -164,7 +186,7 @@ fn test_issue_1769() -> anyhow::Result<()> {
        |
        = note: audit confidence → High
 
-    2 findings (1 ignored): 1 informational, 0 low, 0 medium, 0 high
+    2 findings (1 ignored by comment): 1 informational, 0 low, 0 medium, 0 high
     "
     );
 
@@ -176,6 +198,7 @@ fn test_issue_1769() -> anyhow::Result<()> {
 fn test_composite_action() -> anyhow::Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("artipacked/demo-action/action.yml"))
             .args(["--persona=auditor"])
             .run()?,
@@ -204,7 +227,40 @@ fn test_composite_action() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    2 findings (2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
+    4 findings (2 deduplicated, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+/// Ensures that the upload location names the step that persisted
+/// the credentials.
+#[test]
+fn test_upload() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("artipacked/upload.yml"))
+            .run()?,
+        @"
+    error[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:13:9
+       |
+    13 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+    14 |
+    15 |         - name: Upload workspace
+       |  _________-
+    16 | |         uses: actions/upload-artifact@ea165f8d65b6e75b540449e92b4886f43607fa02 # v4.6.2
+    17 | |         with:
+    18 | |           name: workspace
+    19 | |           path: .
+       | |__________________- may leak the credentials persisted by step 2 (actions/checkout)
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    3 findings (2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "
     );
 
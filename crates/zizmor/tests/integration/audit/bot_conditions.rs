@@ -14,6 +14,7 @@ fn test_regular_persona() -> anyhow::Result<()> {
       | ^^^^^^^^^^^^^^^^^^^^^^^ pull_request_target is almost always used insecurely
       |
       = note: audit confidence → Medium
+      = note: this finding has an auto-fix
 
     error[bot-conditions]: spoofable bot actor check
       --> @@INPUT@@:11:9
@@ -147,9 +148,63 @@ fn test_regular_persona() -> anyhow::Result<()> {
        = note: audit confidence → High
        = note: this finding has an auto-fix
 
-    13 findings (1 suppressed, 11 safe fixes): 0 informational, 0 low, 0 medium, 12 high
+    13 findings (1 suppressed, 11 safe fixes, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 12 high
     "
     );
 
     Ok(())
 }
+
+#[test]
+fn test_spoofable_triggering_actor() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "bot-conditions/spoofable-triggering-actor.yml"
+            ))
+            .args(["--no-default-audits", "--only-audit=bot-conditions"])
+            .run()?,
+        @r#"
+    error[bot-conditions]: spoofable bot actor check
+      --> @@INPUT@@:14:13
+       |
+    12 |       - name: vulnerable-1
+       |         ------------------ this step
+    13 |         run: gh pr merge --auto --merge "$PR_URL"
+    14 |         if: github.triggering_actor == 'dependabot[bot]'
+       |             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ actor context may be spoofable
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    error[bot-conditions]: spoofable bot actor check
+      --> @@INPUT@@:20:17
+       |
+    18 |       - name: vulnerable-2
+       |         ------------------ this step
+    19 |         run: gh pr merge --auto --merge "$PR_URL"
+    20 |         if: ${{ github.actor == 'dependabot[bot]' || github.triggering_actor == 'dependabot[bot]' }}
+       |                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ actor context may be spoofable
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    2 findings (2 safe fixes): 0 informational, 0 low, 0 medium, 2 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_robust_alternatives() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("bot-conditions/robust-alternatives.yml"))
+            .args(["--no-default-audits", "--only-audit=bot-conditions"])
+            .run()?,
+        @"No findings to report. Good job!"
+    );
+
+    Ok(())
+}
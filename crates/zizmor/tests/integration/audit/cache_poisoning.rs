@@ -67,7 +67,7 @@ fn test_caching_opt_in_boolean_toggle() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    4 findings (1 ignored, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    4 findings (1 ignored by comment, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#,
     );
 
@@ -99,7 +99,7 @@ fn test_caching_opt_in_expression() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    3 findings (1 ignored, 1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (1 ignored by comment, 1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#,
     );
 
@@ -156,7 +156,7 @@ fn test_no_cache_aware_steps() -> anyhow::Result<()> {
         zizmor()
             .input(input_under_test("cache-poisoning/no-cache-aware-steps.yml"))
             .run()?,
-        @"No findings to report. Good job! (1 ignored, 2 suppressed)"
+        @"No findings to report. Good job! (1 ignored by comment, 2 suppressed)"
     );
 
     Ok(())
@@ -184,7 +184,7 @@ fn test_workflow_tag_trigger() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    4 findings (1 ignored, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    5 findings (1 ignored by comment, 3 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -243,7 +243,7 @@ fn test_publisher_step() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    3 findings (1 ignored, 1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (1 ignored by comment, 1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "
     );
 
@@ -345,7 +345,7 @@ fn test_caching_not_configurable() -> anyhow::Result<()> {
        |
        = note: audit confidence → Low
 
-    4 findings (1 ignored, 2 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    4 findings (1 ignored by comment, 2 suppressed): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -376,7 +376,7 @@ fn test_workflow_release_branch_trigger() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    4 findings (1 ignored, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    4 findings (1 ignored by comment, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -611,7 +611,7 @@ fn test_workflow_release_trigger_object() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    4 findings (1 ignored, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    4 findings (1 ignored by comment, 2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -0,0 +1,46 @@
+use crate::common::{input_under_test, zizmor};
+
+#[test]
+fn test_regular_persona() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("concurrency-groups/concurrency-groups.yml"))
+            .run()?,
+        @"No findings to report. Good job! (2 suppressed)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_pedantic_persona() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("concurrency-groups/concurrency-groups.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+      --> @@INPUT@@:18:7
+       |
+    18 |       group: deploy
+       |       ^^^^^^^^^^^^^ job runs share this group across all refs
+       |
+       = note: audit confidence → Medium
+       = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+      --> @@INPUT@@:27:5
+       |
+    27 |     concurrency: ${{ github.workflow }}-build
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ job runs share this group across all refs
+       |
+       = note: audit confidence → Medium
+       = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    2 findings: 0 informational, 2 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
@@ -61,7 +61,16 @@ fn test_no_cancel() -> anyhow::Result<()> {
       |
       = note: audit confidence → High
 
-    1 finding: 0 informational, 1 low, 0 medium, 0 high
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+     --> @@INPUT@@:5:1
+      |
+    5 | concurrency: group
+      | ^^^^^^^^^^^^^^^^^^ workflow runs share this group across all refs
+      |
+      = note: audit confidence → Medium
+      = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    2 findings: 0 informational, 2 low, 0 medium, 0 high
     "
     );
 
@@ -100,7 +109,16 @@ fn test_jobs_missing_no_cancel() -> anyhow::Result<()> {
        |
        = note: audit confidence → High
 
-    2 findings: 0 informational, 2 low, 0 medium, 0 high
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+     --> @@INPUT@@:9:5
+      |
+    9 |     concurrency: group
+      |     ^^^^^^^^^^^^^^^^^^ job runs share this group across all refs
+      |
+      = note: audit confidence → Medium
+      = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    3 findings: 0 informational, 3 low, 0 medium, 0 high
     "
     );
 
@@ -0,0 +1,157 @@
+use crate::common::{input_under_test, zizmor};
+
+#[test]
+fn test_constant() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("constant-if/constant.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    help[obfuscation]: obfuscated usage of GitHub Actions features
+      --> @@INPUT@@:18:17
+       |
+    18 |         if: ${{ false }}
+       |                 ^^^^^ can be replaced by its static evaluation
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    help[obfuscation]: obfuscated usage of GitHub Actions features
+      --> @@INPUT@@:23:17
+       |
+    23 |         if: ${{ 'false' }}
+       |                 ^^^^^^^ can be replaced by its static evaluation
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    info[constant-if]: condition always evaluates to the same result
+      --> @@INPUT@@:15:5
+       |
+    15 |     if: true
+       |     ^^^^^^^^ condition is always true, so this always runs
+       |
+       = note: audit confidence → High
+       = tip: remove the redundant condition
+
+    info[constant-if]: condition always evaluates to the same result
+      --> @@INPUT@@:18:9
+       |
+    18 |         if: ${{ false }}
+       |         ^^^^^^^^^^^^^^^^ condition is always false, so this never runs
+       |
+       = note: audit confidence → High
+       = tip: remove the condition, or remove the unreachable definition
+
+    info[constant-if]: condition always evaluates to the same result
+      --> @@INPUT@@:23:9
+       |
+    23 |         if: ${{ 'false' }}
+       |         ^^^^^^^^^^^^^^^^^^ condition is always true, so this always runs
+       |
+       = note: audit confidence → High
+       = tip: remove the redundant condition
+
+    info[constant-if]: condition always evaluates to the same result
+      --> @@INPUT@@:27:9
+       |
+    27 |         if: true && !false
+       |         ^^^^^^^^^^^^^^^^^^ condition is always true, so this always runs
+       |
+       = note: audit confidence → High
+       = tip: remove the redundant condition
+
+    info[constant-if]: condition always evaluates to the same result
+      --> @@INPUT@@:33:5
+       |
+    33 |     if: false
+       |     ^^^^^^^^^ condition is always false, so this never runs
+       |
+       = note: audit confidence → High
+       = tip: remove the condition, or remove the unreachable definition
+
+    7 findings (2 safe fixes): 5 informational, 2 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_dynamic() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("constant-if/dynamic.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"No findings to report. Good job!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_action() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("constant-if/action/action.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    help[obfuscation]: obfuscated usage of GitHub Actions features
+     --> @@INPUT@@:8:15
+      |
+    8 |       if: ${{ false }}
+      |               ^^^^^ can be replaced by its static evaluation
+      |
+      = note: audit confidence → High
+      = note: this finding has an auto-fix
+
+    info[constant-if]: condition always evaluates to the same result
+     --> @@INPUT@@:8:7
+      |
+    8 |       if: ${{ false }}
+      |       ^^^^^^^^^^^^^^^^ condition is always false, so this never runs
+      |
+      = note: audit confidence → High
+      = tip: remove the condition, or remove the unreachable definition
+
+    2 findings (1 safe fixes): 1 informational, 1 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_regular_persona() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("constant-if/constant.yml"))
+            .run()?,
+        @"
+    help[obfuscation]: obfuscated usage of GitHub Actions features
+      --> @@INPUT@@:18:17
+       |
+    18 |         if: ${{ false }}
+       |                 ^^^^^ can be replaced by its static evaluation
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    help[obfuscation]: obfuscated usage of GitHub Actions features
+      --> @@INPUT@@:23:17
+       |
+    23 |         if: ${{ 'false' }}
+       |                 ^^^^^^^ can be replaced by its static evaluation
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    7 findings (5 suppressed, 2 safe fixes): 0 informational, 2 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
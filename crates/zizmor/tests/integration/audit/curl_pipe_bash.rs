@@ -0,0 +1,145 @@
+use anyhow::Result;
+
+use crate::common::{input_under_test, zizmor};
+
+#[test]
+fn test_vulnerable() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("curl-pipe-bash/vulnerable.yml"))
+            .run()?,
+        @r#"
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:13:14
+       |
+    13 |         run: curl -fsSL https://example.com/install.sh | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:18:11
+       |
+    16 |         run: |
+       |         --- this step
+    17 |           echo "installing..."
+    18 |           wget -qO- https://example.com/install.sh | sudo sh
+       |           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:21:14
+       |
+    21 |         run: curl -sSL https://github.com/example/tool/releases/download/latest/install.sh | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:24:14
+       |
+    24 |         run: curl -sSL "$INSTALLER_URL" | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → Medium
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:29:14
+       |
+    29 |         run: curl -sSL https://github.example.com/example/tool/releases/download/v1.2.3/install.sh | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    6 findings (1 suppressed): 0 informational, 0 low, 5 medium, 0 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_benign() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("curl-pipe-bash/benign.yml"))
+            .run()?,
+        @"No findings to report. Good job! (1 suppressed)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_trusted_hosts() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("curl-pipe-bash/vulnerable.yml"))
+            .config(input_under_test("curl-pipe-bash/configs/trusted-hosts.yml"))
+            .run()?,
+        @r#"
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:13:14
+       |
+    13 |         run: curl -fsSL https://example.com/install.sh | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:18:11
+       |
+    16 |         run: |
+       |         --- this step
+    17 |           echo "installing..."
+    18 |           wget -qO- https://example.com/install.sh | sudo sh
+       |           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:21:14
+       |
+    21 |         run: curl -sSL https://github.com/example/tool/releases/download/latest/install.sh | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → High
+       = tip: download the script, verify it, and then execute it
+
+    warning[curl-pipe-bash]: remote script is piped directly into a shell
+      --> @@INPUT@@:24:14
+       |
+    24 |         run: curl -sSL "$INSTALLER_URL" | bash
+       |         ---  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ remote script is piped into a shell
+       |         |
+       |         this step
+       |
+       = note: audit confidence → Medium
+       = tip: download the script, verify it, and then execute it
+
+    5 findings (1 suppressed): 0 informational, 0 low, 4 medium, 0 high
+    "#
+    );
+
+    Ok(())
+}
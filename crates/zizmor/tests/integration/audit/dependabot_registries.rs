@@ -0,0 +1,102 @@
+use crate::common::{input_under_test, zizmor};
+
+#[test]
+fn test_risky() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "dependabot-registries/risky/dependabot.yml"
+            ))
+            .run()?,
+        @"
+    error[dependabot-execution]: external code execution in Dependabot updates
+      --> @@INPUT@@:34:5
+       |
+    28 |     - package-ecosystem: pip
+       |       ---------------------- this ecosystem
+    ...
+    34 |       insecure-external-code-execution: allow
+       |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ enabled here
+    35 | /     registries:
+    36 | |       - pypi-internal
+    37 | |       - public-mirror
+       | |______________________- credentials for pypi-internal are exposed to external code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    error[dependabot-registries]: exposed credentials in Dependabot registries
+     --> @@INPUT@@:8:5
+      |
+    8 |     password: hunter2
+      |     ^^^^^^^^^^^^^^^^^ registry credential is hard-coded
+      |
+      = note: audit confidence → High
+      = tip: store the credential as a Dependabot secret and reference it with ${{ secrets.NAME }}
+
+    2 findings (1 unsafe fixes): 0 informational, 0 low, 0 medium, 2 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_wildcard() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "dependabot-registries/wildcard/dependabot.yml"
+            ))
+            .run()?,
+        @r#"
+    error[dependabot-execution]: external code execution in Dependabot updates
+      --> @@INPUT@@:20:5
+       |
+    14 |   - package-ecosystem: npm
+       |     ---------------------- this ecosystem
+    ...
+    20 |     insecure-external-code-execution: allow
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ enabled here
+    21 |     registries: "*"
+       |     --------------- credentials for npm-internal are exposed to external code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    1 findings (1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_safe() -> anyhow::Result<()> {
+    // The update allowing external code execution only uses a registry
+    // without credentials, so no credentials are reported as exposed.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "dependabot-registries/safe/dependabot.yml"
+            ))
+            .run()?,
+        @"
+    error[dependabot-execution]: external code execution in Dependabot updates
+      --> @@INPUT@@:34:5
+       |
+    28 |   - package-ecosystem: pip
+       |     ---------------------- this ecosystem
+    ...
+    34 |     insecure-external-code-execution: allow
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ enabled here
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    1 findings (1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    "
+    );
+
+    Ok(())
+}
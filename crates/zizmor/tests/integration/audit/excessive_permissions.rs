@@ -1,4 +1,4 @@
-use crate::common::{input_under_test, zizmor};
+use crate::common::{NetworkMode, input_under_test, zizmor};
 use anyhow::Result;
 
 #[test]
@@ -19,6 +19,7 @@ fn test_issue_336_repro() -> Result<()> {
 fn test_issue_336_repro_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test(
                 "excessive-permissions/issue-336-repro.yml"
             ))
@@ -44,6 +45,7 @@ fn test_issue_336_repro_pedantic() -> Result<()> {
 fn test_workflow_default_perms_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test(
                 "excessive-permissions/workflow-default-perms.yml"
             ))
@@ -62,6 +64,8 @@ fn test_workflow_default_perms_pedantic() -> Result<()> {
        | |_____________________________________^ default permissions used due to no permissions: block
        |
        = note: audit confidence → Medium
+       = tip: set `permissions: {}` at the workflow level, and grant any needed permissions to individual jobs
+       = note: this finding has an auto-fix
 
     warning[excessive-permissions]: overly broad permissions
       --> @@INPUT@@:14:3
@@ -80,7 +84,7 @@ fn test_workflow_default_perms_pedantic() -> Result<()> {
        |
        = note: audit confidence → Medium
 
-    2 findings: 0 informational, 0 low, 2 medium, 0 high
+    2 findings (1 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
     "
     );
 
@@ -293,12 +297,16 @@ fn test_reusable_workflow_call() -> Result<()> {
     warning[excessive-permissions]: overly broad permissions
       --> @@INPUT@@:7:3
        |
+     3 | / on:
+     4 | |   workflow_dispatch:
+       | |____________________- only triggered manually or on a schedule
+    ...
      7 | /   job1:
      8 | |     name: job1
      9 | |     # finding: reusable jobs should always specify their permissions
     10 | |     uses: ./.github/workflows/zizmor-child.yml
        | |                                               ^
-       | |                                               |
+       | |_______________________________________________|
        | |_______________________________________________this job
        |                                                 default permissions used due to no permissions: block
        |
@@ -333,6 +341,8 @@ fn test_reusable_workflow_other_triggers() -> Result<()> {
        | |_______________________________________^ default permissions used due to no permissions: block
        |
        = note: audit confidence → Medium
+       = tip: set `permissions: {}` at the workflow level, and grant any needed permissions to individual jobs
+       = note: this finding has an auto-fix
 
     warning[excessive-permissions]: overly broad permissions
       --> @@INPUT@@:11:3
@@ -366,7 +376,188 @@ fn test_reusable_workflow_other_triggers() -> Result<()> {
        |
        = note: audit confidence → Medium
 
-    4 findings (1 suppressed): 0 informational, 0 low, 3 medium, 0 high
+    4 findings (1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 3 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_no_permissions_anywhere() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "excessive-permissions/no-permissions-anywhere.yml"
+            ))
+            .args(["--pedantic"])
+            .run()?,
+        @r#"
+    warning[excessive-permissions]: overly broad permissions
+      --> @@INPUT@@:4:1
+       |
+     4 | / on: push
+     5 | |
+     6 | | name: no-permissions-anywhere
+    ...  |
+    22 | |     steps:
+    23 | |       - run: echo "goodbye"
+       | |____________________________^ default permissions used due to no permissions: block
+       |
+       = note: audit confidence → Medium
+       = tip: set `permissions: {}` at the workflow level, and grant any needed permissions to individual jobs
+       = note: this finding has an auto-fix
+
+    warning[excessive-permissions]: overly broad permissions
+      --> @@INPUT@@:13:3
+       |
+    13 | /   job1:
+    14 | |     name: job1
+    15 | |     runs-on: ubuntu-latest
+    16 | |     steps:
+    17 | |       - run: echo "hello"
+       | |                         ^
+       | |                         |
+       | |_________________________this job
+       |                           default permissions used due to no permissions: block
+       |
+       = note: audit confidence → Medium
+
+    warning[excessive-permissions]: overly broad permissions
+      --> @@INPUT@@:19:3
+       |
+    19 | /   job2:
+    20 | |     name: job2
+    21 | |     runs-on: ubuntu-latest
+    22 | |     steps:
+    23 | |       - run: echo "goodbye"
+       | |                            ^
+       | |                            |
+       | |____________________________this job
+       |                              default permissions used due to no permissions: block
+       |
+       = note: audit confidence → Medium
+
+    3 findings (1 unsafe fixes): 0 informational, 0 low, 3 medium, 0 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_no_permissions_workflow_empty() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "excessive-permissions/no-permissions-workflow-empty.yml"
+            ))
+            .args(["--pedantic"])
+            .run()?,
+        @"No findings to report. Good job!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_dispatch_only_write_all() -> Result<()> {
+    // Manually dispatched or scheduled workflows can't be triggered by
+    // outside activity, so their findings are pedantic only.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "excessive-permissions/dispatch-only-write-all.yml"
+            ))
+            .run()?,
+        @"No findings to report. Good job! (3 suppressed)"
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "excessive-permissions/dispatch-only-write-all.yml"
+            ))
+            .args([
+                "--no-default-audits",
+                "--only-audit=excessive-permissions",
+                "--persona=pedantic",
+            ])
+            .run()?,
+        @r#"
+    error[excessive-permissions]: overly broad permissions
+     --> @@INPUT@@:8:1
+      |
+    1 | / on:
+    2 | |   workflow_dispatch:
+    3 | |   schedule:
+    4 | |     - cron: "0 0 * * *"
+      | |_______________________- only triggered manually or on a schedule
+    ...
+    8 |   permissions: write-all
+      |   ^^^^^^^^^^^^^^^^^^^^^^ uses write-all permissions
+      |
+      = note: audit confidence → High
+
+    error[excessive-permissions]: overly broad permissions
+      --> @@INPUT@@:22:5
+       |
+     1 | / on:
+     2 | |   workflow_dispatch:
+     3 | |   schedule:
+     4 | |     - cron: "0 0 * * *"
+       | |_______________________- only triggered manually or on a schedule
+    ...
+    19 | /   job2:
+    20 | |     name: job2
+    21 | |     runs-on: ubuntu-latest
+    22 | |     permissions: write-all
+       | |     ^^^^^^^^^^^^^^^^^^^^^^ uses write-all permissions
+    ...  |
+    25 | |         with:
+    26 | |           persist-credentials: false
+       | |_____________________________________- this job
+       |
+       = note: audit confidence → High
+
+    2 findings: 0 informational, 0 low, 0 medium, 2 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_push_write_all() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("excessive-permissions/push-write-all.yml"))
+            .run()?,
+        @"
+    error[excessive-permissions]: overly broad permissions
+     --> @@INPUT@@:5:1
+      |
+    5 | permissions: write-all
+      | ^^^^^^^^^^^^^^^^^^^^^^ uses write-all permissions
+      |
+      = note: audit confidence → High
+
+    error[excessive-permissions]: overly broad permissions
+      --> @@INPUT@@:19:5
+       |
+    16 | /   job2:
+    17 | |     name: job2
+    18 | |     runs-on: ubuntu-latest
+    19 | |     permissions: write-all
+       | |     ^^^^^^^^^^^^^^^^^^^^^^ uses write-all permissions
+    ...  |
+    22 | |         with:
+    23 | |           persist-credentials: false
+       | |_____________________________________- this job
+       |
+       = note: audit confidence → High
+
+    3 findings (1 suppressed): 0 informational, 0 low, 0 medium, 2 high
     "
     );
 
@@ -35,7 +35,7 @@ fn test_action() -> Result<()> {
        |
        = note: audit confidence → Low
 
-    4 findings (1 ignored): 0 informational, 0 low, 0 medium, 3 high
+    4 findings (1 ignored by comment): 0 informational, 0 low, 0 medium, 3 high
     "#
     );
 
@@ -59,7 +59,7 @@ fn test_github_path() -> Result<()> {
        |
        = note: audit confidence → Low
 
-    3 findings (1 ignored, 1 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (1 ignored by comment, 1 suppressed): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -83,7 +83,7 @@ fn test_issue_397_repro() -> Result<()> {
        |
        = note: audit confidence → Low
 
-    3 findings (1 ignored, 1 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (1 ignored by comment, 1 suppressed): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -121,7 +121,123 @@ fn test_issue_2200() -> Result<()> {
             .setenv("RUST_LOG", "warn")
             .input(input_under_test("github-env/issue-2200-repro.yml"))
             .run()?,
-        @"No findings to report. Good job! (1 ignored)"
+        @"No findings to report. Good job! (1 ignored by comment, 1 suppressed)"
+    );
+
+    Ok(())
+}
+
+/// Writes of attacker-controllable expressions to `GITHUB_ENV` or
+/// `GITHUB_OUTPUT` are flagged, regardless of the workflow's triggers.
+#[test]
+fn test_tainted_write() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-env/tainted-write.yml"))
+            .args(["--no-default-audits", "--only-audit=github-env"])
+            .run()?,
+        @r#"
+    error[github-env]: dangerous use of environment file
+      --> @@INPUT@@:16:27
+       |
+    16 |           echo "TITLE=${{ github.event.issue.title }}" >> "$GITHUB_ENV"
+       |                           ^^^^^^^^^^^^^^^^^^^^^^^^ attacker-controllable value is written to GITHUB_ENV
+       |
+       = note: audit confidence → High
+
+    error[github-env]: dangerous use of environment file
+      --> @@INPUT@@:21:27
+       |
+    21 |           echo "title=${{ github.event.issue.title }}" >> "$GITHUB_OUTPUT"
+       |                           ^^^^^^^^^^^^^^^^^^^^^^^^ attacker-controllable value is written to GITHUB_OUTPUT
+       |
+       = note: audit confidence → High
+
+    2 findings: 0 informational, 0 low, 0 medium, 2 high
+    "#
+    );
+
+    Ok(())
+}
+
+/// Writes of `env.NAME` contexts are flagged when the variable visible
+/// to the step is declared with an attacker-controllable expression.
+#[test]
+fn test_tainted_env_write() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-env/tainted-env-write.yml"))
+            .args(["--no-default-audits", "--only-audit=github-env"])
+            .run()?,
+        @r#"
+    error[github-env]: dangerous use of environment file
+      --> @@INPUT@@:21:27
+       |
+    21 |           echo "TITLE=${{ env.TITLE }}" >> "$GITHUB_ENV"
+       |                           ^^^^^^^^^ attacker-controllable value is written to GITHUB_ENV
+       |
+       = note: audit confidence → High
+
+    1 finding: 0 informational, 0 low, 0 medium, 1 high
+    "#
+    );
+
+    Ok(())
+}
+
+/// Tainted writes are also template injections, which are only
+/// reported once.
+#[test]
+fn test_tainted_write_with_template_injection() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-env/tainted-write.yml"))
+            .args([
+                "--no-default-audits",
+                "--only-audit=github-env",
+                "--only-audit=template-injection",
+            ])
+            .run()?,
+        @r#"
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:16:27
+       |
+    15 |         run: |
+       |         --- this run block
+    16 |           echo "TITLE=${{ github.event.issue.title }}" >> "$GITHUB_ENV"
+       |                           ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:21:27
+       |
+    20 |         run: |
+       |         --- this run block
+    21 |           echo "title=${{ github.event.issue.title }}" >> "$GITHUB_OUTPUT"
+       |                           ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    4 findings (2 deduplicated, 2 unsafe fixes): 0 informational, 0 low, 0 medium, 2 high
+    "#
+    );
+
+    Ok(())
+}
+
+/// Writes of constant values (or expressions that an attacker can't
+/// control) are not flagged.
+#[test]
+fn test_constant_write() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-env/constant-write.yml"))
+            .args(["--no-default-audits", "--only-audit=github-env"])
+            .run()?,
+        @"No findings to report. Good job!"
     );
 
     Ok(())
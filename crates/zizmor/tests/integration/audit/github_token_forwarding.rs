@@ -0,0 +1,120 @@
+use crate::common::{input_under_test, zizmor};
+use anyhow::Result;
+
+#[test]
+fn test_third_party() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-token-forwarding/third-party.yml"))
+            .run()?,
+        @r#"
+    warning[github-token-forwarding]: workflow token forwarded to a third-party action
+      --> @@INPUT@@:15:22
+       |
+    13 |       - uses: example/release-action@0123456789abcdef0123456789abcdef01234567 # v1.0.0
+       |               --------------------------------------------------------------- this action
+    14 |         with:
+    15 |           token: ${{ secrets.GITHUB_TOKEN }}
+       |                      ^^^^^^^^^^^^^^^^^^^^ workflow token is forwarded here
+       |
+       = note: audit confidence → High
+       = tip: pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token
+
+    warning[github-token-forwarding]: workflow token forwarded to a third-party action
+      --> @@INPUT@@:19:25
+       |
+    17 |       - uses: example/comment-action@0123456789abcdef0123456789abcdef01234567 # v1.0.0
+       |               --------------------------------------------------------------- this action
+    18 |         env:
+    19 |           GH_TOKEN: ${{ github.token }}
+       |                         ^^^^^^^^^^^^ workflow token is forwarded here
+       |
+       = note: audit confidence → High
+       = tip: pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token
+
+    warning[github-token-forwarding]: workflow token forwarded to a third-party action
+      --> @@INPUT@@:23:30
+       |
+    21 |       - uses: example/auth-action@0123456789abcdef0123456789abcdef01234567 # v1.0.0
+       |               ------------------------------------------------------------ this action
+    22 |         with:
+    23 |           header: "token ${{ github.token }}, fallback ${{ github.token }}"
+       |                              ^^^^^^^^^^^^ workflow token is forwarded here
+       |
+       = note: audit confidence → High
+       = tip: pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token
+
+    warning[github-token-forwarding]: workflow token forwarded to a third-party action
+      --> @@INPUT@@:23:60
+       |
+    21 |       - uses: example/auth-action@0123456789abcdef0123456789abcdef01234567 # v1.0.0
+       |               ------------------------------------------------------------ this action
+    22 |         with:
+    23 |           header: "token ${{ github.token }}, fallback ${{ github.token }}"
+       |                                                            ^^^^^^^^^^^^ workflow token is forwarded here
+       |
+       = note: audit confidence → High
+       = tip: pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token
+
+    6 findings (2 suppressed): 0 informational, 0 low, 4 medium, 0 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_trusted() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-token-forwarding/trusted.yml"))
+            .run()?,
+        @"No findings to report. Good job! (1 suppressed)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_trusted_owners() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-token-forwarding/third-party.yml"))
+            .config(input_under_test(
+                "github-token-forwarding/configs/trusted-owners.yml"
+            ))
+            .run()?,
+        @"No findings to report. Good job! (2 suppressed)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_no_trusted_owners() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("github-token-forwarding/trusted.yml"))
+            .config(input_under_test(
+                "github-token-forwarding/configs/no-trusted-owners.yml"
+            ))
+            .run()?,
+        @"
+    warning[github-token-forwarding]: workflow token forwarded to a third-party action
+      --> @@INPUT@@:15:22
+       |
+    13 |       - uses: actions/checkout@08c6903cd8c0fde910a37f88322edcfb5dd907a8 # v5.0.0
+       |               --------------------------------------------------------- this action
+    14 |         with:
+    15 |           token: ${{ secrets.GITHUB_TOKEN }}
+       |                      ^^^^^^^^^^^^^^^^^^^^ workflow token is forwarded here
+       |
+       = note: audit confidence → High
+       = tip: pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token
+
+    2 findings (1 suppressed): 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
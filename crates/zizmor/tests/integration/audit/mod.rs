@@ -3,17 +3,23 @@
 mod adhoc_packages;
 mod anonymous_definition;
 mod archived_uses;
+mod artifact_execution;
 mod artipacked;
 mod bot_conditions;
 mod cache_poisoning;
+mod concurrency_groups;
 mod concurrency_limits;
+mod constant_if;
+mod curl_pipe_bash;
 mod dangerous_triggers;
 mod dependabot_cooldown;
 mod dependabot_execution;
+mod dependabot_registries;
 mod excessive_permissions;
 mod forbidden_uses;
 mod github_app;
 mod github_env;
+mod github_token_forwarding;
 // mod hardcoded_container_credentials; // TODO
 mod impostor_commit;
 mod insecure_commands;
@@ -22,11 +28,14 @@ mod known_vulnerable_actions;
 mod misfeature;
 mod obfuscation;
 mod overprovisioned_secrets;
+mod persist_credentials;
 mod ref_confusion;
 mod ref_version_mismatch;
+mod secrets_in_conditions;
 mod secrets_inherit;
 mod secrets_outside_env;
 mod self_hosted_runner;
+mod sensitive_artifacts;
 mod stale_action_refs;
 mod superfluous_actions;
 mod template_injection;
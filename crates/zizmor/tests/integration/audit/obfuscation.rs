@@ -195,7 +195,7 @@ fn test_obfuscation() -> Result<()> {
        = note: audit confidence → High
        = note: this finding has an auto-fix
 
-    37 findings (1 ignored, 16 suppressed, 19 safe fixes): 0 informational, 20 low, 0 medium, 0 high
+    37 findings (1 ignored by comment, 16 suppressed, 19 safe fixes): 0 informational, 20 low, 0 medium, 0 high
     "
     );
 
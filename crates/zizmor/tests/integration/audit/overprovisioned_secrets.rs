@@ -23,7 +23,7 @@ fn overprovisioned_secrets() -> anyhow::Result<()> {
        |
        = note: audit confidence → High
 
-    4 findings (1 ignored, 1 suppressed): 0 informational, 0 low, 2 medium, 0 high
+    4 findings (1 ignored by comment, 1 suppressed): 0 informational, 0 low, 2 medium, 0 high
     "
     );
 
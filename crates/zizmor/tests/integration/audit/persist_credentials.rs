@@ -0,0 +1,160 @@
+use crate::common::{NetworkMode, input_under_test, zizmor};
+
+#[test]
+fn test_unset() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("persist-credentials/unset.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:16:9
+       |
+    16 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:18:9
+       |
+    18 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |  _________^
+    19 | |         with:
+    20 | |           fetch-depth: 0
+       | |_________________________^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    4 findings (2 deduplicated, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unset_only() -> anyhow::Result<()> {
+    // Without artipacked, this audit's own finding (and its fix) is reported.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("persist-credentials/unset.yml"))
+            .args([
+                "--persona=pedantic",
+                "--no-default-audits",
+                "--only-audit=persist-credentials",
+            ])
+            .run()?,
+        @"
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:16:9
+       |
+    16 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |         ^^^^^^---------------------------------------------------------^^^^^^^^^
+       |         |     |
+       |         |     this checkout
+       |         does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+       = note: this finding has an auto-fix
+
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:18:9
+       |
+    18 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    19 | |         with:
+    20 | |           fetch-depth: 0
+       | |_________________________^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+       = note: this finding has an auto-fix
+
+    2 findings (2 safe fixes): 0 informational, 2 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_true() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("persist-credentials/true.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:16:9
+       |
+    16 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    17 | |         with:
+    18 | |           persist-credentials: true
+       | |____________________________________^ credentials are persisted here
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+
+    2 findings (1 suppressed): 0 informational, 1 low, 0 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_true_auditor() -> anyhow::Result<()> {
+    // Auditors also get artipacked's finding for the same checkout,
+    // which the persist-credentials finding is deduplicated into.
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("persist-credentials/true.yml"))
+            .args(["--persona=auditor"])
+            .run()?,
+        @"
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:16:9
+       |
+    16 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |  _________^
+    17 | |         with:
+    18 | |           persist-credentials: true
+       | |____________________________________^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    2 findings (1 deduplicated, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_false() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("persist-credentials/false.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"No findings to report. Good job!"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::common::{input_under_test, zizmor};
+
+#[test]
+fn test_secret_in_if() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("secrets-in-conditions/secret-in-if.yml"))
+            .run()?,
+        @"
+    warning[secrets-in-conditions]: secrets referenced in if: conditions
+      --> @@INPUT@@:11:13
+       |
+    11 |     if: ${{ secrets.DEPLOY_KEY != '' }}
+       |             ^^^^^^^^^^^^^^^^^^ condition may reveal information about this secret
+       |
+       = note: audit confidence → High
+       = tip: expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable
+
+    warning[secrets-in-conditions]: secrets referenced in if: conditions
+      --> @@INPUT@@:14:13
+       |
+    14 |         if: secrets.DEPLOY_ENV == 'production'
+       |             ^^^^^^^^^^^^^^^^^^ condition may reveal information about this secret
+       |
+       = note: audit confidence → High
+       = tip: expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable
+
+    help[secrets-in-conditions]: secrets referenced in if: conditions
+      --> @@INPUT@@:18:17
+       |
+    18 |         if: ${{ secrets.DEPLOY_KEY }}
+       |                 ^^^^^^^^^^^^^^^^^^ condition reveals whether this secret is set
+       |
+       = note: audit confidence → High
+       = tip: expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable
+
+    warning[secrets-in-conditions]: secrets referenced in if: conditions
+      --> @@INPUT@@:22:59
+       |
+    22 |         if: github.ref == 'refs/heads/main' && startsWith(secrets['DEPLOY_ENV'], 'prod')
+       |                                                           ^^^^^^^^^^^^^^^^^^^^^ condition may reveal information about this secret
+       |
+       = note: audit confidence → High
+       = tip: expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable
+
+    warning[secrets-in-conditions]: secrets referenced in if: conditions
+      --> @@INPUT@@:26:17
+       |
+    26 |         if: ${{ secrets.DEPLOY_ENV != '' && secrets.DEPLOY_ENV != 'dev' }}
+       |                 ^^^^^^^^^^^^^^^^^^ condition may reveal information about this secret
+       |
+       = note: audit confidence → High
+       = tip: expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable
+
+    warning[secrets-in-conditions]: secrets referenced in if: conditions
+      --> @@INPUT@@:26:45
+       |
+    26 |         if: ${{ secrets.DEPLOY_ENV != '' && secrets.DEPLOY_ENV != 'dev' }}
+       |                                             ^^^^^^^^^^^^^^^^^^ condition may reveal information about this secret
+       |
+       = note: audit confidence → High
+       = tip: expose the secret via `env:` and check the environment variable instead, or condition on a non-secret input or variable
+
+    11 findings (5 suppressed): 0 informational, 1 low, 5 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_benign() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("secrets-in-conditions/benign.yml"))
+            .run()?,
+        @"No findings to report. Good job! (2 suppressed)"
+    );
+
+    Ok(())
+}
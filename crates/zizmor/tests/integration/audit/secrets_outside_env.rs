@@ -163,7 +163,7 @@ fn test_issue_2157() -> Result<()> {
         .args(["--persona=auditor"])
         .input(input_under_test("secrets-outside-env/issue-2157-repro.yml"))
         .run()?,
-        @"No findings to report. Good job! (1 ignored)"
+        @"No findings to report. Good job! (1 ignored by comment)"
     );
     Ok(())
 }
@@ -96,13 +96,44 @@ fn test_self_hosted_matrix_dimension() -> Result<()> {
     warning[self-hosted-runner]: runs on a self-hosted runner
       --> @@INPUT@@:15:5
        |
+    15 |     runs-on: ${{ matrix.os }}
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^ expression expands into a self-hosted runner
+    ...
+    19 |         os: [self-hosted, ubuntu-latest]
+       |              ----------- matrix declares self-hosted runner
+       |
+       = note: audit confidence → High
+
+    1 finding: 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_self_hosted_matrix_indirect_dimension() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "self-hosted/self-hosted-matrix-indirect-dimension.yml"
+            ))
+            .args(["--persona=auditor"])
+            .run()?,
+        @"
+    warning[self-hosted-runner]: runs on a self-hosted runner
+      --> @@INPUT@@:15:5
+       |
     15 |       runs-on: ${{ matrix.os }}
        |       ^^^^^^^^^^^^^^^^^^^^^^^^^ expression may expand into a self-hosted runner
     16 |
     17 | /     strategy:
     18 | |       matrix:
     19 | |         os: [self-hosted, ubuntu-latest]
-       | |________________________________________- matrix declares self-hosted runner
+    20 | |         # an indirect dimension means the matrix can't be fully expanded,
+    21 | |         # but the self-hosted `os` value is still visible.
+    22 | |         arch: ${{ fromJSON(inputs.arches) }}
+       | |____________________________________________- matrix declares self-hosted runner
        |
        = note: audit confidence → High
 
@@ -126,15 +157,11 @@ fn test_self_hosted_matrix_inclusion() -> Result<()> {
     warning[self-hosted-runner]: runs on a self-hosted runner
       --> @@INPUT@@:15:5
        |
-    15 |       runs-on: ${{ matrix.os }}
-       |       ^^^^^^^^^^^^^^^^^^^^^^^^^ expression may expand into a self-hosted runner
-    16 |
-    17 | /     strategy:
-    18 | |       matrix:
-    19 | |         os: [macOS-latest, ubuntu-latest]
-    20 | |         include:
-    21 | |           - os: self-hosted
-       | |___________________________- matrix declares self-hosted runner
+    15 |     runs-on: ${{ matrix.os }}
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^ expression expands into a self-hosted runner
+    ...
+    21 |           - os: self-hosted
+       |             --------------- matrix declares self-hosted runner
        |
        = note: audit confidence → High
 
@@ -160,6 +187,43 @@ fn test_self_hosted_matrix_exclusion() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_self_hosted_matrix_mixed() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("self-hosted/self-hosted-matrix-mixed.yml"))
+            .args(["--persona=auditor"])
+            .run()?,
+        @"
+    warning[self-hosted-runner]: runs on a self-hosted runner
+      --> @@INPUT@@:15:5
+       |
+    15 |     runs-on: ${{ matrix.os }}
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^ expression expands into a self-hosted runner
+    ...
+    19 |         os: [ubuntu-latest, self-hosted, [self-hosted, linux, arm64]]
+       |                             ----------- matrix declares self-hosted runner
+       |
+       = note: audit confidence → High
+
+    warning[self-hosted-runner]: runs on a self-hosted runner
+      --> @@INPUT@@:15:5
+       |
+    15 |     runs-on: ${{ matrix.os }}
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^ expression expands into a self-hosted runner
+    ...
+    19 |         os: [ubuntu-latest, self-hosted, [self-hosted, linux, arm64]]
+       |                                          --------------------------- matrix declares self-hosted runner
+       |
+       = note: audit confidence → High
+
+    2 findings: 0 informational, 0 low, 2 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
 /// Fixed regressions
 #[test]
 fn test_issue_283_repro() -> Result<()> {
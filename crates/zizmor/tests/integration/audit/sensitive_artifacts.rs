@@ -0,0 +1,124 @@
+use crate::common::{input_under_test, zizmor};
+use anyhow::Result;
+
+#[test]
+fn test_workspace() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("sensitive-artifacts/workspace.yml"))
+            .args(["--no-default-audits", "--only-audit=sensitive-artifacts"])
+            .run()?,
+        @"
+    warning[sensitive-artifacts]: artifact upload may include credentials
+      --> @@INPUT@@:14:17
+       |
+    11 |       - uses: actions/upload-artifact@ea165f8d65b6e75b540449e92b4886f43607fa02 # v4.6.2
+       |               ---------------------------------------------------------------- this upload
+    ...
+    14 |           path: .
+       |                 ^ uploads the entire workspace
+       |
+       = note: audit confidence → Medium
+       = tip: upload only the specific files or directories that need to be shared
+
+    1 finding: 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_git_dir() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("sensitive-artifacts/git-dir.yml"))
+            .args(["--no-default-audits", "--only-audit=sensitive-artifacts"])
+            .run()?,
+        @"
+    warning[sensitive-artifacts]: artifact upload may include credentials
+      --> @@INPUT@@:14:17
+       |
+    11 |       - uses: actions/upload-artifact@ea165f8d65b6e75b540449e92b4886f43607fa02 # v4.6.2
+       |               ---------------------------------------------------------------- this upload
+    ...
+    14 |           path: .git
+       |                 ^^^^ uploads the .git directory
+       |
+       = note: audit confidence → High
+       = tip: upload only the specific files or directories that need to be shared
+
+    1 finding: 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_dist() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("sensitive-artifacts/dist.yml"))
+            .args(["--no-default-audits", "--only-audit=sensitive-artifacts"])
+            .run()?,
+        @"No findings to report. Good job!"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_multiple_paths() -> Result<()> {
+    // `.git` is flagged on its own line, not within `.github-cache`.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("sensitive-artifacts/multiple-paths.yml"))
+            .args(["--no-default-audits", "--only-audit=sensitive-artifacts"])
+            .run()?,
+        @"
+    warning[sensitive-artifacts]: artifact upload may include credentials
+      --> @@INPUT@@:16:13
+       |
+    11 |       - uses: actions/upload-artifact@ea165f8d65b6e75b540449e92b4886f43607fa02 # v4.6.2
+       |               ---------------------------------------------------------------- this upload
+    ...
+    16 |             .git
+       |             ^^^^ uploads the .git directory
+       |
+       = note: audit confidence → High
+       = tip: upload only the specific files or directories that need to be shared
+
+    1 finding: 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_persisted_checkout() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("sensitive-artifacts/persisted-checkout.yml"))
+            .args(["--no-default-audits", "--only-audit=sensitive-artifacts"])
+            .run()?,
+        @"
+    warning[sensitive-artifacts]: artifact upload may include credentials
+      --> @@INPUT@@:23:17
+       |
+    20 |       - uses: actions/upload-artifact@ea165f8d65b6e75b540449e92b4886f43607fa02 # v4.6.2
+       |               ---------------------------------------------------------------- this upload
+    ...
+    23 |           path: .git
+       |                 ^^^^ uploads the .git directory
+       |
+       = note: audit confidence → Medium
+       = tip: upload only the specific files or directories that need to be shared
+
+    1 finding: 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
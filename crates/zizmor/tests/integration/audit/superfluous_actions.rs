@@ -1,9 +1,10 @@
-use crate::common::{input_under_test, zizmor};
+use crate::common::{NetworkMode, input_under_test, zizmor};
 
 #[test]
 fn test_superfluous_actions() -> anyhow::Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("superfluous-actions.yml"))
             .args(["--persona=pedantic"])
             .run()?,
@@ -28,7 +29,7 @@ fn test_superfluous_actions() -> anyhow::Result<()> {
        |
        = note: audit confidence → Low
 
-    3 findings (1 ignored): 2 informational, 0 low, 0 medium, 0 high
+    3 findings (1 ignored by comment): 2 informational, 0 low, 0 medium, 0 high
     ");
 
     Ok(())
@@ -1,4 +1,4 @@
-use crate::common::{input_under_test, zizmor};
+use crate::common::{NetworkMode, OutputMode, input_under_test, zizmor};
 use anyhow::Result;
 
 #[test]
@@ -157,7 +157,19 @@ fn test_issue_339_repro() -> Result<()> {
        |
        = note: audit confidence → Low
 
-    2 findings (1 suppressed): 1 informational, 0 low, 0 medium, 0 high
+    warning[github-token-forwarding]: workflow token forwarded to a third-party action
+      --> @@INPUT@@:25:29
+       |
+    21 |         uses: octokit/request-action@dad4362715b7fb2ddedf9772c8670824af564f0d # v2.4.0
+       |               --------------------------------------------------------------- this action
+    ...
+    25 |           GITHUB_TOKEN: ${{ secrets.GITHUB_TOKEN }}
+       |                             ^^^^^^^^^^^^^^^^^^^^ workflow token is forwarded here
+       |
+       = note: audit confidence → High
+       = tip: pass a narrowly-scoped token instead, or vet the action before forwarding the workflow token
+
+    3 findings (1 suppressed): 1 informational, 0 low, 1 medium, 0 high
     "#
     );
 
@@ -544,7 +556,7 @@ fn test_issue_1638() -> Result<()> {
         zizmor()
             .input(input_under_test("template-injection/issue-1638-repro.yml"))
             .run()?,
-        @"No findings to report. Good job! (1 suppressed)"
+        @"No findings to report. Good job! (2 suppressed)"
     );
 
     insta::assert_snapshot!(
@@ -563,7 +575,16 @@ fn test_issue_1638() -> Result<()> {
        |
        = note: audit confidence → High
 
-    1 finding: 0 informational, 1 low, 0 medium, 0 high
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+      --> @@INPUT@@:10:3
+       |
+    10 |   group: issue-1638-repro
+       |   ^^^^^^^^^^^^^^^^^^^^^^^ workflow runs share this group across all refs
+       |
+       = note: audit confidence → Medium
+       = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    2 findings: 0 informational, 2 low, 0 medium, 0 high
     "#
     );
 
@@ -694,7 +715,7 @@ fn test_issue_1903() -> Result<()> {
        |
        = note: audit confidence → Low
 
-    1 finding: 1 informational, 0 low, 0 medium, 0 high
+    2 findings (1 suppressed): 1 informational, 0 low, 0 medium, 0 high
     "#
     );
 
@@ -711,7 +732,141 @@ fn test_issue_2197() -> Result<()> {
         zizmor()
             .input(input_under_test("template-injection/issue-2197-repro.yml"))
             .run()?,
-        @"No findings to report. Good job! (1 suppressed)"
+        @"No findings to report. Good job! (2 suppressed)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_github_script() -> Result<()> {
+    // Only the `injectable` job is flagged; the `safe` job passes
+    // the attacker-controlled context through the environment.
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("template-injection/github-script.yml"))
+            .args(["--persona=auditor"])
+            .run()?,
+        @r#"
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:19:32
+       |
+    16 |       - uses: actions/github-script@60a0d83039c74a4aee543508d2ffcb1c3799cdea # tag=v7.0.1
+       |         -------------------------------------------------------------------- action accepts arbitrary code
+    17 |         with:
+    18 |           script: |
+       |           ------ via this input
+    19 |             const title = "${{ github.event.issue.title }}";
+       |                                ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+
+    1 finding: 0 informational, 0 low, 0 medium, 1 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_env_indirection() -> Result<()> {
+    // The `safe` and `env-fixed` jobs aren't flagged, while `direct` and
+    // `env-interpolated` are.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("template-injection/env-indirection.yml"))
+            .run()?,
+        @r#"
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:25:37
+       |
+    25 |       - run: echo "issue opened ${{ github.event.issue.title }}"
+       |         --- this run block          ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    help[template-injection]: code injection via template expansion
+      --> @@INPUT@@:35:37
+       |
+    35 |       - run: echo "issue opened ${{ env.ISSUE_TITLE }}"
+       |         --- this run block          ^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    3 findings (1 suppressed, 2 unsafe fixes): 0 informational, 1 low, 0 medium, 1 high
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_trace_dataflow() -> Result<()> {
+    // `--trace-dataflow` classifies each dataflow context in a flagged
+    // expression, even without any extra verbosity.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("template-injection/trace-dataflow.yml"))
+            .args(["--trace-dataflow", "--format=json"])
+            .output(OutputMode::Stderr)
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    DEBUG audit:audit{input=Workflow(file://@@INPUT@@)}: zizmor::dataflow: ${{ github.sha || github.event.pull_request.title }}: context `github.sha` is trusted
+    DEBUG audit:audit{input=Workflow(file://@@INPUT@@)}: zizmor::dataflow: ${{ github.sha || github.event.pull_request.title }}: context `github.event.pull_request.title` is untrusted (severity=High, confidence=High, persona=Regular)
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_tojson_splat() -> Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("template-injection/tojson-splat.yml"))
+            .run()?,
+        @"
+    error[dangerous-triggers]: use of fundamentally insecure workflow trigger
+     --> @@INPUT@@:3:1
+      |
+    3 | / on:
+    4 | |   pull_request_target:
+      | |______________________^ pull_request_target is almost always used insecurely
+      |
+      = note: audit confidence → Medium
+      = note: this finding has an auto-fix
+
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:17:31
+       |
+    17 |       - run: echo '${{ toJSON(github.event) }}'
+       |         --- this run block    ^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:20:38
+       |
+    20 |       - run: echo '${{ format('{0}', github) }}'
+       |         --- this run block           ^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+
+    info[template-injection]: code injection via template expansion
+      --> @@INPUT@@:23:31
+       |
+    23 |       - run: echo '${{ toJSON(steps.x.outputs) }}'
+       |         --- this run block    ^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → Low
+
+    5 findings (1 suppressed, 1 unsafe fixes): 1 informational, 0 low, 0 medium, 3 high
+    "
     );
 
     Ok(())
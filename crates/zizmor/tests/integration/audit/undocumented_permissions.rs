@@ -1,4 +1,4 @@
-use crate::common::{input_under_test, zizmor};
+use crate::common::{NetworkMode, input_under_test, zizmor};
 use anyhow::Result;
 
 /// Test with pedantic persona (should find issues)
@@ -6,6 +6,7 @@ use anyhow::Result;
 fn test_undocumented_permissions_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("undocumented-permissions.yml"))
             .args(["--persona=pedantic"])
             .run()?,
@@ -50,7 +51,7 @@ fn test_undocumented_permissions_pedantic() -> Result<()> {
        |
        = note: audit confidence → High
 
-    5 findings (1 ignored): 0 informational, 4 low, 0 medium, 0 high
+    5 findings (1 ignored by comment): 0 informational, 4 low, 0 medium, 0 high
     "
     );
 
@@ -75,6 +76,7 @@ fn test_undocumented_permissions_default() -> Result<()> {
 fn test_documented_permissions_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("undocumented-permissions/documented.yml"))
             .args(["--persona=pedantic"])
             .run()?,
@@ -103,7 +105,7 @@ fn test_documented_permissions_pedantic() -> Result<()> {
        |
        = note: audit confidence → High
 
-    4 findings (1 ignored): 0 informational, 0 low, 0 medium, 3 high
+    4 findings (1 ignored by comment): 0 informational, 0 low, 0 medium, 3 high
     "
     );
 
@@ -115,6 +117,7 @@ fn test_documented_permissions_pedantic() -> Result<()> {
 fn test_contents_read_only_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test(
                 "undocumented-permissions/contents-read-only.yml"
             ))
@@ -141,6 +144,7 @@ fn test_contents_read_only_pedantic() -> Result<()> {
 fn test_empty_permissions_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test(
                 "undocumented-permissions/empty-permissions.yml"
             ))
@@ -167,6 +171,7 @@ fn test_empty_permissions_pedantic() -> Result<()> {
 fn test_contents_read_with_other_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test(
                 "undocumented-permissions/contents-read-with-other.yml"
             ))
@@ -209,6 +214,7 @@ fn test_contents_read_with_other_pedantic() -> Result<()> {
 fn test_partially_documented_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test(
                 "undocumented-permissions/partially-documented.yml"
             ))
@@ -257,7 +263,7 @@ fn test_partially_documented_pedantic() -> Result<()> {
        |
        = note: audit confidence → High
 
-    7 findings (2 ignored): 0 informational, 3 low, 0 medium, 2 high
+    7 findings (2 ignored by comment): 0 informational, 3 low, 0 medium, 2 high
     "
     );
 
@@ -15,6 +15,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is unpinned
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:32:9
@@ -23,6 +24,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is unpinned
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:40:7
@@ -31,6 +33,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image uses the floating 'latest' tag
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:49:9
@@ -39,6 +42,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image uses the floating 'latest' tag
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:57:7
@@ -47,6 +51,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is not pinned to a SHA256 hash
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:66:9
@@ -55,6 +60,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
        |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is not pinned to a SHA256 hash
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
        --> @@INPUT@@:101:50
@@ -63,6 +69,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
         |                                                  ^^^^^^^^^ container image is not pinned to a SHA256 hash
         |
         = note: audit confidence → High
+        = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
        --> @@INPUT@@:118:18
@@ -87,6 +94,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
         |                        ^^^^^^ container image is unpinned
         |
         = note: audit confidence → High
+        = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
        --> @@INPUT@@:127:24
@@ -95,6 +103,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
         |                        ^^^^^^^^^^^^^ container image uses the floating 'latest' tag
         |
         = note: audit confidence → High
+        = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
        --> @@INPUT@@:128:24
@@ -103,6 +112,7 @@ fn test_pedantic_persona() -> anyhow::Result<()> {
         |                        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is unpinned
         |
         = note: audit confidence → High
+        = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     12 findings: 0 informational, 0 low, 0 medium, 12 high
     "
@@ -133,6 +143,7 @@ fn test_matrix_in_image_pedantic() -> anyhow::Result<()> {
        |             ------------------- this expansion of matrix.image
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:20:7
@@ -147,6 +158,7 @@ fn test_matrix_in_image_pedantic() -> anyhow::Result<()> {
        |             ------------------- this expansion of matrix.image
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:20:7
@@ -161,6 +173,7 @@ fn test_matrix_in_image_pedantic() -> anyhow::Result<()> {
        |             ------------- this expansion of matrix.image
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:20:7
@@ -175,6 +188,7 @@ fn test_matrix_in_image_pedantic() -> anyhow::Result<()> {
        |             -------------------- this expansion of matrix.image
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     4 findings: 0 informational, 0 low, 0 medium, 4 high
     "
@@ -204,6 +218,7 @@ fn test_matrix_in_image_regular() -> anyhow::Result<()> {
        |             ------------- this expansion of matrix.image
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     error[unpinned-images]: unpinned image references
       --> @@INPUT@@:20:7
@@ -218,6 +233,7 @@ fn test_matrix_in_image_regular() -> anyhow::Result<()> {
        |             -------------------- this expansion of matrix.image
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     4 findings (2 suppressed): 0 informational, 0 low, 0 medium, 2 high
     "
@@ -248,6 +264,7 @@ fn test_urllib3_empty_matrix_container_regular() -> anyhow::Result<()> {
        |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is unpinned
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     2 findings (1 suppressed): 0 informational, 0 low, 0 medium, 1 high
     "
@@ -271,6 +288,7 @@ fn test_issue_1942_repro() -> anyhow::Result<()> {
        |     ^^^^^^^^^^^^^^^^^^ container image is not pinned to a SHA256 hash
        |
        = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     1 finding: 0 informational, 0 low, 0 medium, 1 high
     "
@@ -296,6 +314,7 @@ fn test_docker_action() -> anyhow::Result<()> {
       |   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ container image is not pinned to a SHA256 hash
       |
       = note: audit confidence → High
+      = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
 
     1 finding: 0 informational, 0 low, 0 medium, 1 high
     "#
@@ -317,3 +336,95 @@ fn test_issue_2097_repro() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that `unpinned-images` flags `uses: docker://` steps that aren't
+/// pinned to a digest, in both workflows and composite actions.
+#[test]
+fn test_docker_uses() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("unpinned-images/docker-uses.yml"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    error[unpinned-images]: unpinned image references
+      --> @@INPUT@@:13:24
+       |
+    13 |       - uses: docker://alpine:3
+       |                        ^^^^^^^^ container image is not pinned to a SHA256 hash
+       |
+       = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
+
+    error[unpinned-images]: unpinned image references
+      --> @@INPUT@@:19:24
+       |
+    19 |       - uses: docker://alpine
+       |                        ^^^^^^ container image is unpinned
+       |
+       = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
+
+    help[concurrency-limits]: insufficient job-level concurrency limits
+     --> @@INPUT@@:3:1
+      |
+    3 | on: push
+      | ^^^^^^^^ workflow is missing concurrency setting
+    ...
+    9 |     name: docker-uses
+      |     ----------------- job affected by missing workflow concurrency
+      |
+      = note: audit confidence → High
+
+    3 findings: 0 informational, 1 low, 0 medium, 2 high
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("unpinned-images/docker-uses.yml"))
+            .run()?,
+        @"
+    error[unpinned-images]: unpinned image references
+      --> @@INPUT@@:19:24
+       |
+    19 |       - uses: docker://alpine
+       |                        ^^^^^^ container image is unpinned
+       |
+       = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
+
+    3 findings (2 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("unpinned-images/docker-uses-action/"))
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    error[unpinned-images]: unpinned image references
+     --> @@INPUT@@action.yml:8:22
+      |
+    8 |     - uses: docker://alpine:3
+      |                      ^^^^^^^^ container image is not pinned to a SHA256 hash
+      |
+      = note: audit confidence → High
+      = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
+
+    error[unpinned-images]: unpinned image references
+      --> @@INPUT@@action.yml:14:22
+       |
+    14 |     - uses: docker://alpine
+       |                      ^^^^^^ container image is unpinned
+       |
+       = note: audit confidence → High
+       = tip: pin the image to a SHA256 digest, e.g. `image:tag@sha256:<digest>`
+
+    2 findings: 0 informational, 0 low, 0 medium, 2 high
+    "
+    );
+
+    Ok(())
+}
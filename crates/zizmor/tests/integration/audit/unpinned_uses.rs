@@ -1,10 +1,11 @@
-use crate::common::{input_under_test, zizmor};
+use crate::common::{NetworkMode, input_under_test, zizmor};
 use anyhow::Result;
 
 #[test]
 fn test_unpinned_uses_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("unpinned-uses.yml"))
             .args(["--pedantic"])
             .run()?,
@@ -50,6 +51,7 @@ fn test_unpinned_uses_default() -> Result<()> {
 fn test_action_pedantic() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("unpinned-uses/action.yml"))
             .args(["--pedantic"])
             .run()?,
@@ -95,6 +97,7 @@ fn test_issue_433_repro() -> Result<()> {
 fn test_issue_659_repro() -> Result<()> {
     insta::assert_snapshot!(
         zizmor()
+            .offline(NetworkMode::Implicit)
             .input(input_under_test("unpinned-uses/issue-659-repro.yml"))
             .args(["--pedantic"])
             .run()?,
@@ -87,7 +87,7 @@ fn test_normal_persona() -> anyhow::Result<()> {
        |
        = note: audit confidence → High
 
-    16 findings (6 ignored, 1 suppressed, 3 safe fixes): 0 informational, 0 low, 0 medium, 9 high
+    16 findings (6 ignored by comment, 1 suppressed, 3 safe fixes): 0 informational, 0 low, 0 medium, 9 high
     "#
     );
 
@@ -96,7 +96,7 @@ fn test_use_trusted_publishing() -> Result<()> {
        |
        = note: audit confidence → High
 
-    27 findings (16 ignored, 3 suppressed): 8 informational, 0 low, 0 medium, 0 high
+    27 findings (16 ignored by comment, 3 suppressed): 8 informational, 0 low, 0 medium, 0 high
     "
     );
 
@@ -123,7 +123,7 @@ fn test_demo_action() -> Result<()> {
        |
        = note: audit confidence → High
 
-    2 findings (1 ignored): 1 informational, 0 low, 0 medium, 0 high
+    2 findings (1 ignored by comment): 1 informational, 0 low, 0 medium, 0 high
     "
     );
 
@@ -341,7 +341,7 @@ fn test_npm_publish() -> Result<()> {
         |
         = note: audit confidence → High
 
-    29 findings (6 ignored, 11 suppressed): 12 informational, 0 low, 0 medium, 0 high
+    29 findings (6 ignored by comment, 11 suppressed): 12 informational, 0 low, 0 medium, 0 high
     "
     );
 
@@ -1,4 +1,4 @@
-use crate::common::zizmor;
+use crate::common::{NetworkMode, OutputMode, input_under_test, zizmor};
 
 /// Test that `-` reads a workflow from stdin.
 #[test]
@@ -48,7 +48,7 @@ jobs:
       |
       = note: audit confidence → High
 
-    7 findings (4 suppressed): 0 informational, 0 low, 2 medium, 1 high
+    8 findings (5 suppressed): 0 informational, 0 low, 2 medium, 1 high
     "
     );
 
@@ -86,7 +86,7 @@ runs:
       |
       = note: audit confidence → High
 
-    2 findings: 0 informational, 0 low, 1 medium, 1 high
+    3 findings (1 suppressed): 0 informational, 0 low, 1 medium, 1 high
     "
     );
 
@@ -127,6 +127,148 @@ updates:
     Ok(())
 }
 
+/// Test that `--stdin-filename` infers an action from an `action.yml` basename.
+#[test]
+fn test_stdin_filename_action() -> anyhow::Result<()> {
+    let action = "\
+name: My Action
+description: Test action
+runs:
+  using: composite
+  steps:
+    - uses: actions/checkout@v3
+";
+    insta::assert_snapshot!(
+        zizmor()
+            .stdin(action)
+            .no_config(true)
+            .args(["--stdin-filename=some/dir/action.yml", "-"])
+            .run()?,
+        @"
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+     --> <stdin>:6:7
+      |
+    6 |     - uses: actions/checkout@v3
+      |       ^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+      |
+      = note: audit confidence → Low
+      = note: this finding has an auto-fix
+
+    error[unpinned-uses]: unpinned action reference
+     --> <stdin>:6:13
+      |
+    6 |     - uses: actions/checkout@v3
+      |             ^^^^^^^^^^^^^^^^^^^ action is not pinned to a hash (required by blanket policy)
+      |
+      = note: audit confidence → High
+
+    3 findings (1 suppressed): 0 informational, 0 low, 1 medium, 1 high
+    "
+    );
+
+    // A workflow named `action.yml` is loaded as an action, not a workflow.
+    insta::assert_snapshot!(
+        zizmor()
+            .stdin("on: push\njobs:\n  test:\n    runs-on: ubuntu-latest\n    steps:\n      - run: true\n")
+            .no_config(true)
+            .expects_failure(1)
+            .args(["--stdin-filename=action.yml", "-"])
+            .run()?,
+        @r#"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    fatal: no audit was performed
+    failed to load <stdin> as action
+
+    Caused by:
+        0: input does not match expected validation schema
+        1: "name" is a required property
+           "description" is a required property
+           "runs" is a required property
+           Additional properties are not allowed ('jobs', 'on' were unexpected)
+    "#
+    );
+
+    Ok(())
+}
+
+/// Test that `--stdin-filename` infers a Dependabot config from a
+/// `dependabot.yml` basename.
+#[test]
+fn test_stdin_filename_dependabot() -> anyhow::Result<()> {
+    let dependabot = "\
+version: 2
+updates:
+  - package-ecosystem: github-actions
+    directory: /
+    schedule:
+      interval: weekly
+";
+    insta::assert_snapshot!(
+        zizmor()
+            .stdin(dependabot)
+            .no_config(true)
+            .args(["--stdin-filename=.github/dependabot.yml", "-"])
+            .run()?,
+        @"
+    warning[dependabot-cooldown]: insufficient cooldown in Dependabot updates
+     --> <stdin>:3:5
+      |
+    3 |   - package-ecosystem: github-actions
+      |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ insufficient implicit default-days (less than 7)
+      |
+      = note: audit confidence → High
+      = note: this finding has an auto-fix
+
+    1 finding: 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    // Anything else is loaded as a workflow.
+    insta::assert_snapshot!(
+        zizmor()
+            .stdin(dependabot)
+            .no_config(true)
+            .expects_failure(1)
+            .args(["--stdin-filename=ci.yml", "-"])
+            .run()?,
+        @r#"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    fatal: no audit was performed
+    failed to load <stdin> as workflow
+
+    Caused by:
+        0: input does not match expected validation schema
+        1: "on" is a required property
+           "jobs" is a required property
+           Additional properties are not allowed ('updates', 'version' were unexpected)
+    "#
+    );
+
+    Ok(())
+}
+
+/// Test that `--stdin-filename` requires `-`.
+#[test]
+fn test_stdin_filename_without_stdin() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .no_config(true)
+            .expects_failure(2)
+            .args(["--stdin-filename=action.yml", "some-dir/"])
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    error: `--stdin-filename` can only be used with `-` (stdin)
+
+    Usage: zizmor [OPTIONS] [INPUT]...
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
 /// Test that `-` cannot be combined with other inputs.
 #[test]
 fn test_stdin_with_other_inputs() -> anyhow::Result<()> {
@@ -141,7 +283,7 @@ fn test_stdin_with_other_inputs() -> anyhow::Result<()> {
      INFO zizmor: 🌈 zizmor v@@VERSION@@
     error: `-` (stdin) cannot be combined with other inputs
 
-    Usage: zizmor [OPTIONS] <INPUT>...
+    Usage: zizmor [OPTIONS] [INPUT]...
 
     For more information, try '--help'.
     "
@@ -164,7 +306,7 @@ fn test_stdin_with_fix() -> anyhow::Result<()> {
      INFO zizmor: 🌈 zizmor v@@VERSION@@
     error: `--fix` cannot be used with `-` (stdin)
 
-    Usage: zizmor [OPTIONS] <INPUT>...
+    Usage: zizmor [OPTIONS] [INPUT]...
 
     For more information, try '--help'.
     "
@@ -340,6 +482,39 @@ jobs:
                   ]
                 }
               ],
+              "fixes": [
+                {
+                  "artifactChanges": [
+                    {
+                      "artifactLocation": {
+                        "uri": "<stdin>"
+                      },
+                      "replacements": [
+                        {
+                          "deletedRegion": {
+                            "byteLength": 0,
+                            "byteOffset": 95,
+                            "endColumn": 1,
+                            "endLine": 7,
+                            "snippet": {
+                              "text": ""
+                            },
+                            "sourceLanguage": "yaml",
+                            "startColumn": 1,
+                            "startLine": 7
+                          },
+                          "insertedContent": {
+                            "text": "        with:\n          persist-credentials: false\n"
+                          }
+                        }
+                      ]
+                    }
+                  ],
+                  "description": {
+                    "text": "set persist-credentials: false"
+                  }
+                }
+              ],
               "kind": "fail",
               "level": "warning",
               "locations": [
@@ -565,7 +740,16 @@ jobs:
                 "zizmor/persona": "Regular",
                 "zizmor/severity": "Medium"
               },
-              "ruleId": "zizmor/excessive-permissions"
+              "ruleId": "zizmor/excessive-permissions",
+              "taxa": [
+                {
+                  "id": "CWE-250",
+                  "index": 0,
+                  "toolComponent": {
+                    "name": "CWE"
+                  }
+                }
+              ]
             },
             {
               "codeFlows": [
@@ -713,7 +897,33 @@ jobs:
                 "zizmor/persona": "Regular",
                 "zizmor/severity": "High"
               },
-              "ruleId": "zizmor/unpinned-uses"
+              "ruleId": "zizmor/unpinned-uses",
+              "taxa": [
+                {
+                  "id": "CWE-829",
+                  "index": 1,
+                  "toolComponent": {
+                    "name": "CWE"
+                  }
+                }
+              ]
+            }
+          ],
+          "taxonomies": [
+            {
+              "informationUri": "https://cwe.mitre.org/",
+              "name": "CWE",
+              "organization": "MITRE",
+              "taxa": [
+                {
+                  "helpUri": "https://cwe.mitre.org/data/definitions/250.html",
+                  "id": "CWE-250"
+                },
+                {
+                  "helpUri": "https://cwe.mitre.org/data/definitions/829.html",
+                  "id": "CWE-829"
+                }
+              ]
             }
           ],
           "tool": {
@@ -723,6 +933,12 @@ jobs:
               "name": "zizmor",
               "rules": [
                 {
+                  "defaultConfiguration": {
+                    "level": "error"
+                  },
+                  "fullDescription": {
+                    "text": "Detects local filesystem git credential storage on GitHub Actions, as well as potential avenues for unintentional persistence of credentials in artifacts."
+                  },
                   "help": {
                     "markdown": "`artipacked`: credential persistence through GitHub Actions artifacts\n\nDocs: <https://docs.zizmor.sh/audits/#artipacked>",
                     "text": "credential persistence through GitHub Actions artifacts"
@@ -734,9 +950,18 @@ jobs:
                     "tags": [
                       "security"
                     ]
+                  },
+                  "shortDescription": {
+                    "text": "credential persistence through GitHub Actions artifacts"
                   }
                 },
                 {
+                  "defaultConfiguration": {
+                    "level": "error"
+                  },
+                  "fullDescription": {
+                    "text": "Detects excessive permissions in workflows, both at the workflow level and individual job levels."
+                  },
                   "help": {
                     "markdown": "`excessive-permissions`: overly broad permissions\n\nDocs: <https://docs.zizmor.sh/audits/#excessive-permissions>",
                     "text": "overly broad permissions"
@@ -746,11 +971,21 @@ jobs:
                   "name": "excessive-permissions",
                   "properties": {
                     "tags": [
-                      "security"
+                      "security",
+                      "external/cwe/cwe-250"
                     ]
+                  },
+                  "shortDescription": {
+                    "text": "overly broad permissions"
                   }
                 },
                 {
+                  "defaultConfiguration": {
+                    "level": "error"
+                  },
+                  "fullDescription": {
+                    "text": "Detects \"unpinned\" uses: clauses."
+                  },
                   "help": {
                     "markdown": "`unpinned-uses`: unpinned action reference\n\nDocs: <https://docs.zizmor.sh/audits/#unpinned-uses>",
                     "text": "unpinned action reference"
@@ -760,8 +995,12 @@ jobs:
                   "name": "unpinned-uses",
                   "properties": {
                     "tags": [
-                      "security"
+                      "security",
+                      "external/cwe/cwe-829"
                     ]
+                  },
+                  "shortDescription": {
+                    "text": "unpinned action reference"
                   }
                 }
               ],
@@ -833,3 +1072,415 @@ fn test_stdin_valid_yaml_unknown_schema_strict() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Test that `--clear-cache` empties the cache directory and exits.
+#[test]
+fn test_clear_cache() -> anyhow::Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let cache_dir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+
+    std::fs::create_dir_all(cache_dir.join("content-v2/sha256"))?;
+    std::fs::write(cache_dir.join("content-v2/sha256/abc"), "cached")?;
+    std::fs::write(cache_dir.join("zizmor-cache-version"), "1")?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .no_config(true)
+            .args(["--clear-cache", "--cache-dir", cache_dir.as_str()])
+            .run()?,
+        @""
+    );
+
+    assert!(cache_dir.exists());
+    assert_eq!(std::fs::read_dir(cache_dir)?.count(), 0);
+
+    Ok(())
+}
+
+/// Test that `--timings` prints a timing table listing each registered audit.
+#[test]
+fn test_timings() -> anyhow::Result<()> {
+    // Online audits aren't registered in offline mode, so we expect
+    // exactly the offline audits in the timing table.
+    let audits = zizmor().args(["--list-audits"]).run()?;
+    let mut expected = audits
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let ident = columns.next()?;
+            (columns.nth(1)? == "offline").then_some(ident)
+        })
+        .collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    let output = zizmor()
+        .stdin("on: push\njobs:\n  test:\n    runs-on: ubuntu-latest\n    steps:\n      - run: echo hello\n")
+        .no_config(true)
+        .offline(NetworkMode::ExplicitOffline)
+        .output(OutputMode::Stderr)
+        .args(["--timings", "-"])
+        .run()?;
+
+    let (_, table) = output
+        .split_once("Audit Timings\n")
+        .expect("missing timing table");
+    let mut rows = table
+        .lines()
+        .map(|line| {
+            let (ident, elapsed) = line.trim().split_once(' ').expect("malformed row");
+            assert!(elapsed.trim().ends_with("ms"), "{line}");
+            ident
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(rows.pop(), Some("total"));
+    rows.sort_unstable();
+    assert_eq!(rows, expected);
+
+    Ok(())
+}
+
+/// Test that `--no-findings-exit` and `--empty-is-error` control the exit code
+/// when there are no findings, and only then.
+#[test]
+fn test_no_findings_exit() -> anyhow::Result<()> {
+    let clean = "\
+on: push
+permissions: {}
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - run: echo hello
+";
+    let dirty = "\
+on: push
+permissions: {}
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v3
+        with:
+          persist-credentials: false
+";
+
+    for (workflow, args, expected) in [
+        // By default, no findings means success.
+        (clean, &[][..], 0),
+        (clean, &["--no-findings-exit=42"], 42),
+        (clean, &["--no-findings-exit=0"], 0),
+        // `--no-findings-exit` takes precedence over modes that
+        // otherwise force a successful exit.
+        (clean, &["--no-findings-exit=42", "--no-exit-codes"], 42),
+        (clean, &["--no-findings-exit=42", "--format=sarif"], 42),
+        (clean, &["--empty-is-error"], 1),
+        // With findings, the usual severity-based exit codes apply.
+        (dirty, &["--no-findings-exit=42"], 14),
+        (dirty, &["--empty-is-error"], 14),
+        (dirty, &["--empty-is-error", "--no-exit-codes"], 0),
+        // The two flags are mutually exclusive.
+        (clean, &["--empty-is-error", "--no-findings-exit=42"], 2),
+    ] {
+        zizmor()
+            .stdin(workflow)
+            .no_config(true)
+            .offline(NetworkMode::ExplicitOffline)
+            .args(["-"])
+            .args(args.iter().copied())
+            .expects_exit(expected)
+            .run()?;
+    }
+
+    Ok(())
+}
+
+/// Test that `--threads` is accepted, and doesn't change findings.
+#[test]
+fn test_threads() -> anyhow::Result<()> {
+    let run = |args: &[&str]| {
+        zizmor()
+            .input(input_under_test("e2e-menagerie"))
+            .offline(NetworkMode::ExplicitOffline)
+            .args(args.iter().copied())
+            .run()
+    };
+
+    let baseline = run(&[])?;
+    assert_eq!(run(&["--threads=1"])?, baseline);
+    assert_eq!(run(&["--threads=2"])?, baseline);
+
+    // Zero threads isn't a valid pool size.
+    zizmor()
+        .offline(NetworkMode::ExplicitOffline)
+        .args(["--threads=0", "-"])
+        .expects_failure(2)
+        .run()?;
+
+    Ok(())
+}
+
+/// Test that `--rule-docs-base` replaces the base of audit documentation
+/// links in plain and SARIF output.
+#[test]
+fn test_rule_docs_base() -> anyhow::Result<()> {
+    let workflow = "\
+on: push
+permissions: {}
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v3
+        with:
+          persist-credentials: false
+";
+
+    let plain = zizmor()
+        .stdin(workflow)
+        .no_config(true)
+        .offline(NetworkMode::ExplicitOffline)
+        .show_audit_urls(true)
+        .args(["--rule-docs-base=https://mirror.example.com/zizmor/", "-"])
+        .run()?;
+    assert!(
+        plain.contains(
+            "audit documentation → https://mirror.example.com/zizmor/audits/#unpinned-uses"
+        )
+    );
+    assert!(!plain.contains("docs.zizmor.sh"));
+
+    let sarif = zizmor()
+        .stdin(workflow)
+        .no_config(true)
+        .offline(NetworkMode::ExplicitOffline)
+        .args([
+            "--rule-docs-base=https://mirror.example.com/zizmor",
+            "--format=sarif",
+            "-",
+        ])
+        .run()?;
+    let sarif: serde_json::Value = serde_json::from_str(&sarif)?;
+    assert_eq!(
+        sarif["runs"][0]["tool"]["driver"]["rules"][0]["helpUri"],
+        "https://mirror.example.com/zizmor/audits/#unpinned-uses"
+    );
+
+    // The base must be a valid URL.
+    zizmor()
+        .stdin(workflow)
+        .no_config(true)
+        .offline(NetworkMode::ExplicitOffline)
+        .args(["--rule-docs-base=not a url", "-"])
+        .expects_failure(2)
+        .run()?;
+
+    Ok(())
+}
+
+/// Test that `--input-from` reads inputs from a file, and composes
+/// with positional inputs.
+#[test]
+fn test_input_from() -> anyhow::Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let list = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("inputs.txt");
+
+    std::fs::write(
+        &list,
+        format!(
+            "# workflows to audit\n\n{artipacked}\n  {concurrency}  \n",
+            artipacked = input_under_test("artipacked.yml"),
+            concurrency = input_under_test("concurrency-groups/concurrency-groups.yml"),
+        ),
+    )?;
+
+    // Inputs from the file are audited alongside positional inputs.
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .input(input_under_test("persist-credentials/true.yml"))
+            .args(["--input-from", list.as_str(), "--persona=pedantic"])
+            .run()?,
+        @"
+    ── @@WORKING_DIR@@/@@TEST_PREFIX@@/artipacked.yml (2 findings) ──
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@WORKING_DIR@@/@@TEST_PREFIX@@/artipacked.yml:22:9
+       |
+    22 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    help[persist-credentials]: checkout persists credentials
+      --> @@WORKING_DIR@@/@@TEST_PREFIX@@/artipacked.yml:28:9
+       |
+    28 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    29 | |         with:
+    30 | |           persist-credentials: true
+       | |____________________________________^ credentials are persisted here
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+
+    ── @@WORKING_DIR@@/@@TEST_PREFIX@@/concurrency-groups/concurrency-groups.yml (2 findings) ──
+
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+      --> @@WORKING_DIR@@/@@TEST_PREFIX@@/concurrency-groups/concurrency-groups.yml:18:7
+       |
+    18 |       group: deploy
+       |       ^^^^^^^^^^^^^ job runs share this group across all refs
+       |
+       = note: audit confidence → Medium
+       = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    help[concurrency-groups]: concurrency group without a per-ref or per-PR discriminator
+      --> @@WORKING_DIR@@/@@TEST_PREFIX@@/concurrency-groups/concurrency-groups.yml:27:5
+       |
+    27 |     concurrency: ${{ github.workflow }}-build
+       |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ job runs share this group across all refs
+       |
+       = note: audit confidence → Medium
+       = tip: include a per-ref discriminator like `${{ github.ref }}` in the group
+
+    ── @@INPUT@@ (1 finding) ──
+
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:16:9
+       |
+    16 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    17 | |         with:
+    18 | |           persist-credentials: true
+       | |____________________________________^ credentials are persisted here
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+
+    8 findings (2 suppressed, 1 deduplicated, 1 unsafe fixes): 0 informational, 4 low, 1 medium, 0 high
+    "
+    );
+
+    // Entries can also be remote slugs, which are collected like
+    // any other slug input (and therefore fail when offline).
+    std::fs::write(
+        &list,
+        format!(
+            "{artipacked}\nwoodruffw/gha-hazmat\n",
+            artipacked = input_under_test("artipacked.yml"),
+        ),
+    )?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .args(["--input-from", list.as_str()])
+            .expects_failure(1)
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    fatal: no audit was performed
+    error: can't fetch remote repository: woodruffw/gha-hazmat
+      |
+      = help: set a GitHub token with --gh-token or GH_TOKEN
+
+    Caused by:
+        can't fetch remote repository: woodruffw/gha-hazmat
+    "
+    );
+
+    // A missing input file is an error.
+    zizmor()
+        .offline(NetworkMode::Implicit)
+        .args(["--input-from", "does-not-exist.txt"])
+        .expects_failure(1)
+        .run()?;
+
+    Ok(())
+}
+
+/// Test that `--no-default-audits` produces no findings unless audits
+/// are explicitly enabled with `--only-audit`.
+#[test]
+fn test_no_default_audits() -> anyhow::Result<()> {
+    let workflow = "\
+on: push
+permissions: {}
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v3
+";
+
+    let run = |args: &[&str]| {
+        zizmor()
+            .stdin(workflow)
+            .no_config(true)
+            .offline(NetworkMode::ExplicitOffline)
+            .args(["--format=json", "-"])
+            .args(args.iter().copied())
+            .run()
+    };
+
+    let idents = |output: String| -> anyhow::Result<Vec<String>> {
+        let findings: serde_json::Value = serde_json::from_str(&output)?;
+        Ok(findings
+            .as_array()
+            .expect("findings should be an array")
+            .iter()
+            .map(|f| {
+                f["ident"]
+                    .as_str()
+                    .expect("finding should have an ident")
+                    .to_string()
+            })
+            .collect())
+    };
+
+    // Sanity check: the default set produces findings from several audits.
+    let defaults = idents(run(&[])?)?;
+    assert!(defaults.iter().any(|i| i == "unpinned-uses"));
+    assert!(defaults.iter().any(|i| i == "artipacked"));
+
+    // Only the explicitly enabled audits run.
+    let only = idents(run(&["--no-default-audits", "--only-audit=unpinned-uses"])?)?;
+    assert!(!only.is_empty());
+    assert!(only.iter().all(|i| i == "unpinned-uses"));
+
+    // `--only-audit` on its own narrows the default set the same way.
+    let filtered = idents(run(&["--only-audit=unpinned-uses"])?)?;
+    assert_eq!(filtered, only);
+
+    // Enabling an audit with nothing to report yields no findings.
+    let none = idents(run(&["--no-default-audits", "--only-audit=github-env"])?)?;
+    assert!(none.is_empty());
+
+    // `--no-default-audits` requires at least one `--only-audit`.
+    zizmor()
+        .stdin(workflow)
+        .no_config(true)
+        .offline(NetworkMode::ExplicitOffline)
+        .args(["--no-default-audits", "-"])
+        .expects_failure(2)
+        .run()?;
+
+    // Unknown audits are rejected.
+    zizmor()
+        .stdin(workflow)
+        .no_config(true)
+        .offline(NetworkMode::ExplicitOffline)
+        .args(["--no-default-audits", "--only-audit=not-an-audit", "-"])
+        .expects_failure(2)
+        .run()?;
+
+    Ok(())
+}
@@ -46,7 +46,6 @@ pub fn input_under_test(name: &str) -> Utf8PathBuf {
 
 pub enum OutputMode {
     Stdout,
-    #[allow(dead_code, reason = "currently not used by any integration test")]
     Stderr,
     Both,
 }
@@ -55,6 +54,10 @@ pub enum OutputMode {
 pub enum NetworkMode {
     /// The zizmor run is implicitly offline or online, i.e. depends
     /// on whether `--gh-token`, etc.
+    ///
+    /// Pedantic and auditor tests that don't exercise network behavior
+    /// use this, since explicitly offline runs report each skipped online
+    /// audit to those personas.
     Implicit,
     /// The `zizmor` run is explicitly offline, i.e. runs 100% offline
     /// regardless of any other flags or state.
@@ -75,6 +78,7 @@ pub struct Zizmor {
     no_config: bool,
     output: OutputMode,
     expects_failure: Option<i32>,
+    expects_exit: Option<i32>,
     show_audit_urls: bool,
 }
 
@@ -90,6 +94,7 @@ const SCRUBBED_ENV_VARS: &[&str] = &[
     "CLICOLOR",
     "CLICOLOR_FORCE",
     "GIT_CEILING_DIRECTORIES",
+    "COLUMNS",
 ];
 
 /// Environment variable prefixes scrubbed for the same reason as
@@ -130,6 +135,7 @@ impl Zizmor {
             no_config: false,
             output: OutputMode::Stdout,
             expects_failure: None,
+            expects_exit: None,
             show_audit_urls: false,
         }
     }
@@ -190,6 +196,13 @@ impl Zizmor {
         self
     }
 
+    /// Expect zizmor to exit with exactly the given code, whether or not
+    /// that code indicates failure.
+    pub fn expects_exit(mut self, code: i32) -> Self {
+        self.expects_exit = Some(code);
+        self
+    }
+
     pub fn show_audit_urls(mut self, flag: bool) -> Self {
         self.show_audit_urls = flag;
         self
@@ -292,7 +305,14 @@ impl Zizmor {
             OutputMode::Both => [output.stderr, output.stdout].concat(),
         })?;
 
-        if let Some(exit_code) = output.status.code() {
+        if let Some(expected_code) = self.expects_exit {
+            let exit_code = output.status.code();
+            if exit_code != Some(expected_code) {
+                anyhow::bail!(
+                    "zizmor exited with unexpected code {exit_code:?} (expected {expected_code}): {raw}"
+                );
+            }
+        } else if let Some(exit_code) = output.status.code() {
             // There are other nonzero exit codes that don't indicate failure;
             // these do. 1/2 are general errors, 3 is a collection error, 101 is Rust's panic exit code.
             let is_failure = matches!(exit_code, 1 | 2 | 3 | 101);
@@ -17,7 +17,7 @@ fn test_discovers_config_in_root() -> anyhow::Result<()> {
     DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
     DEBUG zizmor::config: attempting config discovery in `@@INPUT@@`
     DEBUG zizmor::config: found config candidate at `@@INPUT@@/zizmor.yml`
-    No findings to report. Good job! (1 ignored, 1 suppressed)
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
     "
     );
 
@@ -42,7 +42,7 @@ fn test_discovers_config_in_root_from_file_input() -> anyhow::Result<()> {
     DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
     DEBUG zizmor::config: attempting config discovery in `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/config-in-root/.github/workflows`
     DEBUG zizmor::config: found config candidate at `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/config-in-root/zizmor.yml`
-    No findings to report. Good job! (1 ignored, 1 suppressed)
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
     "
     );
 
@@ -67,7 +67,7 @@ fn test_discovers_config_in_root_from_child_dir() -> anyhow::Result<()> {
     DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
     DEBUG zizmor::config: attempting config discovery in `@@INPUT@@`
     DEBUG zizmor::config: found config candidate at `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/config-in-root/zizmor.yml`
-    No findings to report. Good job! (1 ignored, 1 suppressed)
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
     "
     );
 
@@ -144,7 +144,7 @@ fn test_discovers_config_in_dotgithub() -> anyhow::Result<()> {
     DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
     DEBUG zizmor::config: attempting config discovery in `@@INPUT@@`
     DEBUG zizmor::config: found config candidate at `@@INPUT@@/.github/zizmor.yml`
-    No findings to report. Good job! (1 ignored, 1 suppressed)
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
     ",
     );
 
@@ -168,7 +168,7 @@ fn test_discovers_dotyaml_config_in_dotgithub() -> anyhow::Result<()> {
     DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
     DEBUG zizmor::config: attempting config discovery in `@@INPUT@@`
     DEBUG zizmor::config: found config candidate at `@@INPUT@@/.github/zizmor.yaml`
-    No findings to report. Good job! (1 ignored, 1 suppressed)
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
     ",
     );
 
@@ -193,7 +193,7 @@ fn test_discovers_config_in_dotgithub_from_file_input() -> anyhow::Result<()> {
     DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
     DEBUG zizmor::config: attempting config discovery in `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/config-in-dotgithub/.github/workflows`
     DEBUG zizmor::config: found config candidate at `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/config-in-dotgithub/.github/zizmor.yml`
-    No findings to report. Good job! (1 ignored, 1 suppressed)
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
     "
     );
 
@@ -278,7 +278,7 @@ fn test_invalid_configs() -> anyhow::Result<()> {
     Caused by:
         0: configuration error in @@CONFIG@@
         1: invalid configuration syntax
-        2: unknown field `rule`, expected `rules` at line 4 column 1
+        2: unknown field `rule`, expected one of `persona`, `min-severity`, `min-confidence`, `profiles`, `rules` at line 4 column 1
     "
     );
 
@@ -303,7 +303,7 @@ fn test_severity_remap() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    2 findings (1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "
     );
 
@@ -329,13 +329,57 @@ fn test_severity_remap_affects_min_severity() -> anyhow::Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    2 findings (1 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "
     );
 
     Ok(())
 }
 
+/// Ensures that JSON output exposes both the audit's default severity
+/// and the remapped (effective) severity of a finding.
+#[test]
+fn test_severity_remap_json() -> anyhow::Result<()> {
+    // A local config remaps artipacked to High (its default), while an
+    // explicit global config remaps it to Low.
+    for (format, remap, default, effective) in [
+        ("json-v1", "high", "High", "High"),
+        ("json-v1", "low", "High", "Low"),
+        ("json-v2", "high", "high", "high"),
+        ("json-v2", "low", "high", "low"),
+    ] {
+        let zizmor = match remap {
+            "high" => zizmor().input(input_under_test("config-scenarios/severity-remap")),
+            _ => zizmor()
+                .input(input_under_test("artipacked.yml"))
+                .config(input_under_test(
+                    "config-scenarios/zizmor.severity-remap-low.yml",
+                )),
+        };
+        let output = zizmor.args([format!("--format={format}").as_str()]).run()?;
+
+        let parsed: serde_json::Value = serde_json::from_str(&output)?;
+        let (findings, effective_key) = match format {
+            "json-v1" => (&parsed, "effective_severity"),
+            _ => (&parsed["findings"], "severity"),
+        };
+        let finding = findings
+            .as_array()
+            .expect("findings should be an array")
+            .iter()
+            .find(|f| f["ident"] == "artipacked")
+            .expect("expected an artipacked finding");
+
+        assert_eq!(
+            finding["rule_default_severity"], default,
+            "{format} {remap}"
+        );
+        assert_eq!(finding[effective_key], effective, "{format} {remap}");
+    }
+
+    Ok(())
+}
+
 /// Without remap config, an artipacked Medium finding is filtered out by --min-severity=high.
 #[test]
 fn test_no_remap_filtered_by_min_severity() -> anyhow::Result<()> {
@@ -345,7 +389,7 @@ fn test_no_remap_filtered_by_min_severity() -> anyhow::Result<()> {
             .input(input_under_test("config-scenarios/severity-remap"))
             .args(["--min-severity=high"])
             .run()?,
-        @"No findings to report. Good job! (1 ignored, 1 suppressed)"
+        @"No findings to report. Good job! (1 below threshold, 2 suppressed)"
     );
 
     Ok(())
@@ -361,7 +405,398 @@ fn test_severity_remap_is_negated_by_no_config() -> anyhow::Result<()> {
             .input(input_under_test("config-scenarios/severity-remap"))
             .args(["--min-severity=high", "--no-config"])
             .run()?,
-        @"No findings to report. Good job! (1 ignored, 1 suppressed)"
+        @"No findings to report. Good job! (1 below threshold, 2 suppressed)"
+    );
+
+    Ok(())
+}
+
+/// Ensures that a configured persona is used when `--persona` isn't given,
+/// and that an explicit `--persona` takes precedence.
+#[test]
+fn test_config_persona() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("config-scenarios/persona"))
+            .run()?,
+        @"
+    help[anonymous-definition]: workflow or action definition without a name
+      --> @@INPUT@@/.github/workflows/hackme.yml:1:1
+       |
+     1 | / on: push
+     2 | |
+     3 | | permissions: {}
+    ...  |
+     9 | |     steps:
+    10 | |       - run: true
+       | |__________________^ this workflow
+       |
+       = note: audit confidence → High
+       = tip: use 'name: ...' to give this workflow a name
+       = note: this finding has an auto-fix
+
+    help[concurrency-limits]: insufficient job-level concurrency limits
+     --> @@INPUT@@/.github/workflows/hackme.yml:1:1
+      |
+    1 | on: push
+      | ^^^^^^^^ workflow is missing concurrency setting
+    ...
+    7 |     name: test
+      |     ---------- job affected by missing workflow concurrency
+      |
+      = note: audit confidence → High
+
+    2 findings (1 safe fixes): 0 informational, 2 low, 0 medium, 0 high
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("config-scenarios/persona"))
+            .args(["--persona=regular"])
+            .run()?,
+        @"No findings to report. Good job! (2 suppressed)"
+    );
+
+    Ok(())
+}
+
+/// Ensures we discover a dotfile config, i.e. `dotfile-config/.zizmor.yml`.
+#[test]
+fn test_discovers_dotfile_config() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("config-scenarios/dotfile-config"))
+            .setenv("RUST_LOG", "zizmor::config=debug")
+            .output(OutputMode::Both)
+            .run()?,
+        @"
+    DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
+    DEBUG zizmor::config: attempting config discovery in `@@INPUT@@`
+    DEBUG zizmor::config: found config candidate at `@@INPUT@@/.zizmor.yml`
+    No findings to report. Good job! (1 ignored by config, 1 suppressed)
+    "
+    );
+
+    Ok(())
+}
+
+/// Ensures that the nearest config wins when configs exist at multiple
+/// levels, i.e. `nested-configs/inner/zizmor.yml` is used instead of
+/// `nested-configs/zizmor.yml` (which would ignore the finding).
+#[test]
+fn test_nearest_config_wins() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test(
+                "config-scenarios/nested-configs/inner/.github/workflows/hackme.yml"
+            ))
+            .setenv("RUST_LOG", "zizmor::config=debug")
+            .output(OutputMode::Both)
+            .run()?,
+        @r#"
+    DEBUG zizmor::config: discovering config for local input `@@INPUT@@`
+    DEBUG zizmor::config: attempting config discovery in `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/nested-configs/inner/.github/workflows`
+    DEBUG zizmor::config: found config candidate at `@@WORKING_DIR@@/@@TEST_PREFIX@@/config-scenarios/nested-configs/inner/zizmor.yml`
+    help[template-injection]: code injection via template expansion
+      --> @@INPUT@@:16:40
+       |
+    13 |       - uses: actions/github-script@60a0d83039c74a4aee543508d2ffcb1c3799cdea # tag=v7.0.1
+       |         -------------------------------------------------------------------- action accepts arbitrary code
+    14 |         with:
+    15 |           script: |
+       |           ------ via this input
+    16 |             return "doing a thing: ${{ github.event.issue.title }}"
+       |                                        ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+
+    2 findings (1 suppressed): 0 informational, 1 low, 0 medium, 0 high
+    "#
+    );
+
+    Ok(())
+}
+
+/// Ensures that a discovered local config is merged onto the global
+/// `--config` config: the local persona overrides the global one,
+/// while the global audit disablement still applies.
+/// An explicit `--persona` still takes precedence over both.
+#[test]
+fn test_local_config_merges_with_global() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .config(input_under_test(
+                "config-scenarios/global/disable-anonymous-definition.yml"
+            ))
+            .input(input_under_test("config-scenarios/persona"))
+            .run()?,
+        @"
+    help[concurrency-limits]: insufficient job-level concurrency limits
+     --> @@INPUT@@/.github/workflows/hackme.yml:1:1
+      |
+    1 | on: push
+      | ^^^^^^^^ workflow is missing concurrency setting
+    ...
+    7 |     name: test
+      |     ---------- job affected by missing workflow concurrency
+      |
+      = note: audit confidence → High
+
+    1 finding: 0 informational, 1 low, 0 medium, 0 high
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .config(input_under_test(
+                "config-scenarios/global/disable-anonymous-definition.yml"
+            ))
+            .input(input_under_test("config-scenarios/persona"))
+            .args(["--persona=regular"])
+            .run()?,
+        @"No findings to report. Good job! (1 suppressed)"
+    );
+
+    Ok(())
+}
+
+/// Ensures that `--config-dump --config-provenance` attributes settings
+/// from a discovered local config to the local config's path, and
+/// inherited settings to the global `--config` path.
+#[test]
+fn test_config_dump_provenance() -> anyhow::Result<()> {
+    insta::assert_snapshot!(
+        zizmor()
+            .config(input_under_test(
+                "config-scenarios/global/disable-anonymous-definition.yml"
+            ))
+            .input(input_under_test("config-scenarios/persona"))
+            .args(["--config-dump", "--config-provenance"])
+            .run()?,
+        @r#"
+    {
+      "@@INPUT@@": {
+        "persona": {
+          "source": "@@INPUT@@/zizmor.yml",
+          "value": "pedantic"
+        },
+        "rules": {
+          "anonymous-definition": {
+            "disable": {
+              "source": "@@CONFIG@@",
+              "value": true
+            },
+            "ignore": []
+          }
+        }
+      }
+    }
+    "#
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .config(input_under_test(
+                "config-scenarios/global/disable-anonymous-definition.yml"
+            ))
+            .input(input_under_test("config-scenarios/persona"))
+            .args(["--config-dump"])
+            .run()?,
+        @r#"
+    {
+      "@@INPUT@@": {
+        "persona": "pedantic",
+        "rules": {
+          "anonymous-definition": {
+            "disable": true,
+            "ignore": []
+          }
+        }
+      }
+    }
+    "#
+    );
+
+    Ok(())
+}
+
+/// Ensures that `--audit-config` replaces the named audit's config
+/// from the discovered config, without affecting other audits.
+#[test]
+fn test_audit_config() -> anyhow::Result<()> {
+    let split = format!(
+        "unpinned-uses={path}",
+        path = input_under_test("config-scenarios/audit-config/unpinned-uses.yml")
+    );
+
+    // Without `--audit-config`, the discovered config's `ref-pin` policy
+    // allows the action, and its trusted owners suppress the forwarded token.
+    insta::assert_snapshot!(
+        zizmor()
+            .no_config(false)
+            .input(input_under_test("config-scenarios/audit-config"))
+            .run()?,
+        @"No findings to report. Good job! (1 suppressed)"
+    );
+
+    // With it, the split file's `hash-pin` policy takes effect while
+    // `github-token-forwarding` keeps its config from the discovered config.
+    insta::assert_snapshot!(
+        zizmor()
+            .no_config(false)
+            .input(input_under_test("config-scenarios/audit-config"))
+            .args(["--audit-config", &split])
+            .run()?,
+        @"
+    error[unpinned-uses]: unpinned action reference
+      --> @@INPUT@@/.github/workflows/hackme.yml:12:15
+       |
+    12 |       - uses: example/action@v1
+       |               ^^^^^^^^^^^^^^^^^ action is not pinned to a hash (required by blanket policy)
+       |
+       = note: audit confidence → High
+
+    2 findings (1 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .no_config(false)
+            .input(input_under_test("config-scenarios/audit-config"))
+            .args(["--audit-config", &split])
+            .args(["--config-dump", "--config-provenance"])
+            .run()?,
+        @r#"
+    {
+      "@@INPUT@@": {
+        "rules": {
+          "github-token-forwarding": {
+            "config": {
+              "source": "@@INPUT@@/zizmor.yml",
+              "value": {
+                "trusted-owners": [
+                  "example"
+                ]
+              }
+            },
+            "disable": {
+              "source": null,
+              "value": false
+            },
+            "ignore": []
+          },
+          "unpinned-uses": {
+            "config": {
+              "source": "@@INPUT@@/unpinned-uses.yml",
+              "value": {
+                "policies": {
+                  "*": "hash-pin"
+                }
+              }
+            },
+            "disable": {
+              "source": null,
+              "value": false
+            },
+            "ignore": []
+          }
+        }
+      }
+    }
+    "#
+    );
+
+    Ok(())
+}
+
+/// Ensures that `--profile` applies a profile's settings as defaults,
+/// and that explicit flags take precedence over them.
+#[test]
+fn test_profiles() -> anyhow::Result<()> {
+    let config = input_under_test("config-scenarios/profiles/zizmor.yml");
+
+    // Without a profile, the config's profiles have no effect.
+    insta::assert_snapshot!(
+        zizmor()
+            .config(config.as_str())
+            .input(input_under_test("config-scenarios/profiles"))
+            .run()?,
+        @"
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@/.github/workflows/hackme.yml:11:9
+       |
+    11 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    4 findings (3 suppressed, 1 unsafe fixes): 0 informational, 0 low, 1 medium, 0 high
+    "
+    );
+
+    // The `ci` profile selects the pedantic persona, high-confidence
+    // findings of low severity and above, and GitHub output.
+    insta::assert_snapshot!(
+        zizmor()
+            .config(config.as_str())
+            .input(input_under_test("config-scenarios/profiles"))
+            .args(["--profile", "ci"])
+            .run()?,
+        @"::warning file=@@INPUT@@/.github/workflows/hackme.yml,line=3,col=1,title=concurrency-limits::hackme.yml:3: insufficient job-level concurrency limits: workflow is missing concurrency setting"
+    );
+
+    // Explicit flags override the profile's settings, while the
+    // profile's other settings still apply.
+    insta::assert_snapshot!(
+        zizmor()
+            .config(config.as_str())
+            .input(input_under_test("config-scenarios/profiles"))
+            .args(["--profile", "ci", "--format", "plain", "--persona", "regular"])
+            .run()?,
+        @"No findings to report. Good job! (1 below threshold, 3 suppressed)"
+    );
+
+    // Without `--config`, profiles are read from the config discovered
+    // from the current directory.
+    insta::assert_snapshot!(
+        zizmor()
+            .no_config(false)
+            .working_dir(input_under_test("config-scenarios/profiles"))
+            .input(".github/workflows/hackme.yml")
+            .args(["--profile", "ci", "--format", "plain"])
+            .run()?,
+        @"
+    help[concurrency-limits]: insufficient job-level concurrency limits
+     --> @@INPUT@@:3:1
+      |
+    3 | on: push
+      | ^^^^^^^^ workflow is missing concurrency setting
+    ...
+    8 |   hackme:
+      |   ------ job affected by missing workflow concurrency
+      |
+      = note: audit confidence → High
+
+    9 findings (8 below threshold): 0 informational, 1 low, 0 medium, 0 high
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .expects_failure(1)
+            .output(OutputMode::Stderr)
+            .config(config.as_str())
+            .input(input_under_test("config-scenarios/profiles"))
+            .args(["--profile", "nope"])
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    fatal: no audit was performed
+    no configuration defines a profile named `nope`
+    "
     );
 
     Ok(())
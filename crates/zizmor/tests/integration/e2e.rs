@@ -8,6 +8,7 @@ mod anchors;
 mod collect;
 mod crater;
 mod json_v1;
+mod json_v2;
 mod parallel_steps;
 
 #[cfg_attr(not(feature = "gh-token-tests"), ignore)]
@@ -83,6 +84,149 @@ fn menagerie() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn plain_groups_by_input() -> Result<()> {
+    // Findings across multiple inputs are grouped under per-input headers.
+    // We use relative inputs here so that the separators have a stable width.
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .working_dir(input_under_test("persist-credentials"))
+            .input("unset.yml")
+            .input("true.yml")
+            .args(["--persona=pedantic"])
+            .run()?,
+        @"
+    ── @@INPUT@@ (1 finding) ────────────────────────────────────────────────────────
+
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:16:9
+       |
+    16 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    17 | |         with:
+    18 | |           persist-credentials: true
+       | |____________________________________^ credentials are persisted here
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+
+    ── @@INPUT@@ (2 findings) ──────────────────────────────────────────────────────
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:16:9
+       |
+    16 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:18:9
+       |
+    18 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |  _________^
+    19 | |         with:
+    20 | |           fetch-depth: 0
+       | |_________________________^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    6 findings (1 suppressed, 2 deduplicated, 2 unsafe fixes): 0 informational, 1 low, 2 medium, 0 high
+    "
+    );
+
+    // Separators respect the terminal's width.
+    insta::assert_snapshot!(
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .working_dir(input_under_test("persist-credentials"))
+            .input("unset.yml")
+            .input("true.yml")
+            .args(["--persona=pedantic"])
+            .setenv("COLUMNS", "40")
+            .run()?,
+        @"
+    ── @@INPUT@@ (1 finding) ────────────────
+
+    help[persist-credentials]: checkout persists credentials
+      --> @@INPUT@@:16:9
+       |
+    16 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |           ^     --------------------------------------------------------- this checkout
+       |  _________|
+       | |
+    17 | |         with:
+    18 | |           persist-credentials: true
+       | |____________________________________^ credentials are persisted here
+       |
+       = note: audit confidence → Low
+       = tip: set `persist-credentials: false` unless later steps need the credentials
+
+    ── @@INPUT@@ (2 findings) ──────────────
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:16:9
+       |
+    16 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:18:9
+       |
+    18 |         - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # v4.2.2
+       |  _________^
+    19 | |         with:
+    20 | |           fetch-depth: 0
+       | |_________________________^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    6 findings (1 suppressed, 2 deduplicated, 2 unsafe fixes): 0 informational, 1 low, 2 medium, 0 high
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn no_emoji() -> Result<()> {
+    let run = |args: &[&str]| {
+        zizmor()
+            .offline(NetworkMode::Implicit)
+            .output(OutputMode::Both)
+            .working_dir(input_under_test("persist-credentials"))
+            .input("unset.yml")
+            .input("true.yml")
+            .args(["--persona=pedantic"])
+            .args(args.iter().copied())
+            .run()
+    };
+
+    // By default, the banner and findings contain non-ASCII decorations.
+    let output = run(&[])?;
+    assert!(output.contains('🌈'));
+    assert!(!output.is_ascii());
+
+    // With `--no-emoji`, the entire output (including the banner and
+    // per-input headers) is ASCII.
+    let output = run(&["--no-emoji"])?;
+    assert!(output.is_ascii(), "non-ASCII output: {output}");
+    assert!(output.contains("INFO zizmor: zizmor v"));
+    assert!(output.contains("-- @@INPUT@@ (2 findings) ---"));
+    assert!(output.contains("audit confidence -> Low"));
+
+    Ok(())
+}
+
 /// Regression test for #1907.
 ///
 /// Ensures that directory collection finds workflows when invoked
@@ -369,6 +513,60 @@ fn test_issue_1394() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn unreadable_input() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+
+    let non_utf8 = dir.join("non-utf8.yml");
+    std::fs::write(&non_utf8, b"on: push\n\xff\xfe\n")?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .expects_failure(1)
+            .input(non_utf8)
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    fatal: no audit was performed
+    error: failed to load file://@@INPUT@@ as workflow
+      |
+      = help: ensure that @@INPUT@@ is UTF-8 encoded
+
+    Caused by:
+        0: failed to load file://@@INPUT@@ as workflow
+        1: couldn't read @@INPUT@@
+        2: stream did not contain valid UTF-8
+    "
+    );
+
+    #[cfg(unix)]
+    {
+        let broken = dir.join("broken.yml");
+        std::os::unix::fs::symlink(dir.join("missing.yml"), &broken)?;
+
+        insta::assert_snapshot!(
+            zizmor()
+                .expects_failure(1)
+                .input(broken)
+                .run()?,
+            @"
+         INFO zizmor: 🌈 zizmor v@@VERSION@@
+        fatal: no audit was performed
+        error: couldn't read @@INPUT@@
+          |
+          = help: @@INPUT@@ may be a broken symlink; ensure that its target exists
+
+        Caused by:
+            0: couldn't read @@INPUT@@
+            1: No such file or directory (os error 2)
+        "
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn invalid_input_not_strict() -> Result<()> {
     for tc in ["invalid-workflow", "invalid-action-1/action"] {
@@ -539,6 +737,77 @@ fn warn_on_implicit_offline() -> Result<()> {
     Ok(())
 }
 
+/// Ensures that online audits that are skipped in offline mode are
+/// reported as informational findings on each input they would have
+/// audited, but only to pedantic users.
+#[test]
+fn skipped_online_audits() -> Result<()> {
+    let run = |offline: NetworkMode, args: &[&str]| -> Result<Vec<String>> {
+        let output = zizmor()
+            .offline(offline)
+            .input(input_under_test("persist-credentials/true.yml"))
+            .input(input_under_test("artipacked.yml"))
+            // No `uses:` clauses, so nothing is skipped here.
+            .input(input_under_test("several-vulnerabilities.yml"))
+            .args(["--format=json"])
+            .args(args.iter().copied())
+            .expects_exit(14)
+            .run()?;
+
+        let findings: serde_json::Value = serde_json::from_str(&output)?;
+        Ok(findings
+            .as_array()
+            .expect("findings should be an array")
+            .iter()
+            .filter(|f| f["desc"] == "online audit skipped")
+            .map(|f| {
+                let location = &f["locations"][0];
+                format!(
+                    "{ident} {severity} {persona} jobs.{job} line {line}",
+                    ident = f["ident"].as_str().expect("finding should have an ident"),
+                    severity = f["determinations"]["severity"],
+                    persona = f["determinations"]["persona"],
+                    job = location["symbolic"]["route"]["route"][1]["Key"]
+                        .as_str()
+                        .expect("finding should be on a job"),
+                    line = location["concrete"]["location"]["start_point"]["row"]
+                        .as_u64()
+                        .expect("finding should have a line")
+                        + 1,
+                )
+            })
+            .collect())
+    };
+
+    // One finding per skipped audit per input, on each input's first
+    // repository `uses:` clause.
+    insta::assert_snapshot!(run(NetworkMode::ExplicitOffline, &["--pedantic"])?.join("\n"), @r#"
+    impostor-commit "Informational" "Pedantic" jobs.artipacked line 22
+    ref-confusion "Informational" "Pedantic" jobs.artipacked line 22
+    known-vulnerable-actions "Informational" "Pedantic" jobs.artipacked line 22
+    stale-action-refs "Informational" "Pedantic" jobs.artipacked line 22
+    ref-version-mismatch "Informational" "Pedantic" jobs.artipacked line 22
+    impostor-commit "Informational" "Pedantic" jobs.explicit-true line 16
+    ref-confusion "Informational" "Pedantic" jobs.explicit-true line 16
+    known-vulnerable-actions "Informational" "Pedantic" jobs.explicit-true line 16
+    stale-action-refs "Informational" "Pedantic" jobs.explicit-true line 16
+    ref-version-mismatch "Informational" "Pedantic" jobs.explicit-true line 16
+    "#);
+
+    // Explicitly disabling online audits has the same effect.
+    assert_eq!(
+        run(NetworkMode::Implicit, &["--pedantic", "--no-online-audits"])?,
+        run(NetworkMode::ExplicitOffline, &["--pedantic"])?
+    );
+
+    // Implicitly offline runs are already covered by the offline-by-default
+    // warning, and regular users don't see these findings at all.
+    assert!(run(NetworkMode::Implicit, &["--pedantic"])?.is_empty());
+    assert!(run(NetworkMode::ExplicitOffline, &[])?.is_empty());
+
+    Ok(())
+}
+
 /// Regression test for #1207.
 ///
 /// Ensures that we correctly handle single-inputs that aren't given
@@ -674,8 +943,8 @@ fn issue_1745() -> Result<()> {
             .args(["--format=github"])
             .run()?,
         @"
-    ::warning file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/@@INPUT@@/workflows/test.yml,line=10,title=artipacked::test.yml:10: credential persistence through GitHub Actions artifacts: does not set persist-credentials: false
-    ::error file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/@@INPUT@@/workflows/test.yml,line=10,title=unpinned-uses::test.yml:10: unpinned action reference: action is not pinned to a hash (required by blanket policy)
+    ::warning file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/@@INPUT@@/workflows/test.yml,line=10,col=9,title=artipacked::test.yml:10: credential persistence through GitHub Actions artifacts: does not set persist-credentials: false
+    ::error file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/@@INPUT@@/workflows/test.yml,line=10,col=15,title=unpinned-uses::test.yml:10: unpinned action reference: action is not pinned to a hash (required by blanket policy)
     "
     );
 
@@ -686,8 +955,8 @@ fn issue_1745() -> Result<()> {
             .args([".", "--format=github"])
             .run()?,
         @"
-    ::warning file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/.github/workflows/test.yml,line=10,title=artipacked::test.yml:10: credential persistence through GitHub Actions artifacts: does not set persist-credentials: false
-    ::error file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/.github/workflows/test.yml,line=10,title=unpinned-uses::test.yml:10: unpinned action reference: action is not pinned to a hash (required by blanket policy)
+    ::warning file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/.github/workflows/test.yml,line=10,col=9,title=artipacked::test.yml:10: credential persistence through GitHub Actions artifacts: does not set persist-credentials: false
+    ::error file=@@WORKING_DIR@@/@@TEST_PREFIX@@/issue-1745-repro/.github/workflows/test.yml,line=10,col=15,title=unpinned-uses::test.yml:10: unpinned action reference: action is not pinned to a hash (required by blanket policy)
     "
     );
 
@@ -743,6 +1012,91 @@ fn test_cant_retrieve_no_gh_token() -> Result<()> {
     Ok(())
 }
 
+/// Ensures that `--relative-to` renders local input paths relative
+/// to the given directory, or the current directory if none is given.
+#[test]
+fn test_relative_to() -> Result<()> {
+    let test_data = input_under_test("");
+
+    let relative_to_dir = zizmor()
+        .input(input_under_test("several-vulnerabilities.yml"))
+        .args(["--relative-to", test_data.as_str(), "--persona=auditor"])
+        .run()?;
+
+    // Without an explicit directory, paths are relative to the current directory.
+    let relative_to_cwd = zizmor()
+        .working_dir(test_data.as_str())
+        .input(input_under_test("several-vulnerabilities.yml"))
+        .args(["--relative-to", "--persona=auditor"])
+        .run()?;
+    assert_eq!(relative_to_dir, relative_to_cwd);
+
+    insta::assert_snapshot!(
+        relative_to_dir,
+        @r#"
+    error[excessive-permissions]: overly broad permissions
+     --> several-vulnerabilities.yml:5:1
+      |
+    5 | permissions: write-all
+      | ^^^^^^^^^^^^^^^^^^^^^^ uses write-all permissions
+      |
+      = note: audit confidence → High
+
+    error[excessive-permissions]: overly broad permissions
+      --> several-vulnerabilities.yml:11:5
+       |
+     8 | /   hackme:
+     9 | |     name: hackme
+    10 | |     runs-on: ubuntu-latest
+    11 | |     permissions: write-all
+       | |     ^^^^^^^^^^^^^^^^^^^^^^ uses write-all permissions
+    ...  |
+    15 | |         run: |
+    16 | |           echo "${{ github.event.pull_request.title }}"
+       | |________________________________________________________- this job
+       |
+       = note: audit confidence → High
+
+    error[dangerous-triggers]: use of fundamentally insecure workflow trigger
+     --> several-vulnerabilities.yml:2:1
+      |
+    2 | / on:
+    3 | |   pull_request_target:
+      | |______________________^ pull_request_target is almost always used insecurely
+      |
+      = note: audit confidence → Medium
+      = note: this finding has an auto-fix
+
+    error[template-injection]: code injection via template expansion
+      --> several-vulnerabilities.yml:16:21
+       |
+    15 |         run: |
+       |         --- this run block
+    16 |           echo "${{ github.event.pull_request.title }}"
+       |                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    help[concurrency-limits]: insufficient job-level concurrency limits
+     --> several-vulnerabilities.yml:2:1
+      |
+    2 | / on:
+    3 | |   pull_request_target:
+      | |______________________^ workflow is missing concurrency setting
+    ...
+    9 |       name: hackme
+      |       ------------ job affected by missing workflow concurrency
+      |
+      = note: audit confidence → High
+
+    5 findings (2 unsafe fixes): 0 informational, 1 low, 0 medium, 4 high
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_github_output() -> Result<()> {
     insta::assert_snapshot!(
@@ -751,11 +1105,55 @@ fn test_github_output() -> Result<()> {
             .args(["--persona=auditor", "--format=github"])
             .run()?,
         @"
-    ::error file=@@INPUT@@,line=5,title=excessive-permissions::several-vulnerabilities.yml:5: overly broad permissions: uses write-all permissions
-    ::error file=@@INPUT@@,line=11,title=excessive-permissions::several-vulnerabilities.yml:11: overly broad permissions: uses write-all permissions
-    ::error file=@@INPUT@@,line=2,title=dangerous-triggers::several-vulnerabilities.yml:2: use of fundamentally insecure workflow trigger: pull_request_target is almost always used insecurely
-    ::error file=@@INPUT@@,line=16,title=template-injection::several-vulnerabilities.yml:16: code injection via template expansion: may expand into attacker-controllable code
-    ::warning file=@@INPUT@@,line=2,title=concurrency-limits::several-vulnerabilities.yml:2: insufficient job-level concurrency limits: workflow is missing concurrency setting
+    ::error file=@@INPUT@@,line=5,col=1,title=excessive-permissions::several-vulnerabilities.yml:5: overly broad permissions: uses write-all permissions
+    ::error file=@@INPUT@@,line=11,col=5,title=excessive-permissions::several-vulnerabilities.yml:11: overly broad permissions: uses write-all permissions
+    ::error file=@@INPUT@@,line=2,col=1,title=dangerous-triggers::several-vulnerabilities.yml:2: use of fundamentally insecure workflow trigger: pull_request_target is almost always used insecurely
+    ::error file=@@INPUT@@,line=16,col=21,title=template-injection::several-vulnerabilities.yml:16: code injection via template expansion: may expand into attacker-controllable code
+    ::warning file=@@INPUT@@,line=2,col=1,title=concurrency-limits::several-vulnerabilities.yml:2: insufficient job-level concurrency limits: workflow is missing concurrency setting
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_github_output_annotations_limit() -> Result<()> {
+    // excessive-permissions produces two findings here, one of which
+    // is elided by the limit.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("several-vulnerabilities.yml"))
+            .args([
+                "--persona=auditor",
+                "--format=github",
+                "--github-annotations-limit=1",
+            ])
+            .run()?,
+        @"
+    ::error file=@@INPUT@@,line=5,col=1,title=excessive-permissions::several-vulnerabilities.yml:5: overly broad permissions: uses write-all permissions
+    ::error file=@@INPUT@@,line=2,col=1,title=dangerous-triggers::several-vulnerabilities.yml:2: use of fundamentally insecure workflow trigger: pull_request_target is almost always used insecurely
+    ::error file=@@INPUT@@,line=16,col=21,title=template-injection::several-vulnerabilities.yml:16: code injection via template expansion: may expand into attacker-controllable code
+    ::warning file=@@INPUT@@,line=2,col=1,title=concurrency-limits::several-vulnerabilities.yml:2: insufficient job-level concurrency limits: workflow is missing concurrency setting
+    ::notice title=zizmor::1 of 5 findings not annotated (limited to 1 per audit by --github-annotations-limit)
+    "
+    );
+
+    // A limit of zero disables the cap entirely.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("several-vulnerabilities.yml"))
+            .args([
+                "--persona=auditor",
+                "--format=github",
+                "--github-annotations-limit=0",
+            ])
+            .run()?,
+        @"
+    ::error file=@@INPUT@@,line=5,col=1,title=excessive-permissions::several-vulnerabilities.yml:5: overly broad permissions: uses write-all permissions
+    ::error file=@@INPUT@@,line=11,col=5,title=excessive-permissions::several-vulnerabilities.yml:11: overly broad permissions: uses write-all permissions
+    ::error file=@@INPUT@@,line=2,col=1,title=dangerous-triggers::several-vulnerabilities.yml:2: use of fundamentally insecure workflow trigger: pull_request_target is almost always used insecurely
+    ::error file=@@INPUT@@,line=16,col=21,title=template-injection::several-vulnerabilities.yml:16: code injection via template expansion: may expand into attacker-controllable code
+    ::warning file=@@INPUT@@,line=2,col=1,title=concurrency-limits::several-vulnerabilities.yml:2: insufficient job-level concurrency limits: workflow is missing concurrency setting
     "
     );
 
@@ -774,6 +1172,125 @@ fn test_sarif_zizmor_properties() -> Result<()> {
     Ok(())
 }
 
+/// Ensures that every rule referenced by a SARIF result has a complete
+/// rule definition.
+#[test]
+fn test_sarif_rules_complete() -> Result<()> {
+    let sarif: serde_json::Value = serde_json::from_str(
+        &zizmor()
+            .input(input_under_test("several-vulnerabilities.yml"))
+            .args(["--format=sarif"])
+            .run()?,
+    )?;
+
+    let run = &sarif["runs"][0];
+    let rules = run["tool"]["driver"]["rules"]
+        .as_array()
+        .expect("missing rules");
+    let results = run["results"].as_array().expect("missing results");
+    assert!(!results.is_empty());
+
+    for result in results {
+        let rule_id = &result["ruleId"];
+        let rule = rules
+            .iter()
+            .find(|rule| &rule["id"] == rule_id)
+            .unwrap_or_else(|| panic!("no rule definition for {rule_id}"));
+
+        let ident = rule["name"].as_str().expect("missing rule name");
+        assert_eq!(rule_id.as_str(), Some(format!("zizmor/{ident}").as_str()));
+        assert!(rule["shortDescription"]["text"].is_string());
+        assert_ne!(
+            rule["fullDescription"]["text"].as_str(),
+            rule["shortDescription"]["text"].as_str()
+        );
+        assert!(rule["fullDescription"]["text"].is_string());
+        assert_eq!(
+            rule["helpUri"].as_str(),
+            Some(format!("https://docs.zizmor.sh/audits/#{ident}").as_str())
+        );
+        assert!(matches!(
+            rule["defaultConfiguration"]["level"].as_str(),
+            Some("note" | "warning" | "error")
+        ));
+    }
+
+    // Each rule is only defined once.
+    let ids = rules
+        .iter()
+        .filter_map(|rule| rule["id"].as_str())
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(ids.len(), rules.len());
+
+    Ok(())
+}
+
+/// Ensures that every taxon referenced by a SARIF result resolves to
+/// an entry in the run's taxonomies.
+#[test]
+fn test_sarif_taxonomies_resolve() -> Result<()> {
+    let sarif: serde_json::Value = serde_json::from_str(
+        &zizmor()
+            .input(input_under_test("several-vulnerabilities.yml"))
+            .args(["--format=sarif"])
+            .run()?,
+    )?;
+
+    let run = &sarif["runs"][0];
+    let taxonomies = run["taxonomies"].as_array().expect("missing taxonomies");
+    let results = run["results"].as_array().expect("missing results");
+
+    let mut referenced = std::collections::HashSet::new();
+    for result in results {
+        for taxon_ref in result["taxa"].as_array().into_iter().flatten() {
+            let name = &taxon_ref["toolComponent"]["name"];
+            let taxonomy = taxonomies
+                .iter()
+                .find(|taxonomy| &taxonomy["name"] == name)
+                .unwrap_or_else(|| panic!("no taxonomy named {name}"));
+
+            let index = taxon_ref["index"].as_u64().expect("missing taxon index") as usize;
+            let taxon = &taxonomy["taxa"][index];
+            assert_eq!(taxon["id"], taxon_ref["id"]);
+            assert!(taxon["helpUri"].is_string());
+
+            referenced.insert(taxon_ref["id"].as_str().expect("missing taxon id"));
+        }
+    }
+
+    // template-injection is CWE-94, and excessive-permissions is CWE-250.
+    assert!(referenced.contains("CWE-94"));
+    assert!(referenced.contains("CWE-250"));
+
+    // Every taxon in the taxonomy is referenced by at least one result.
+    let defined = taxonomies
+        .iter()
+        .flat_map(|taxonomy| taxonomy["taxa"].as_array().into_iter().flatten())
+        .filter_map(|taxon| taxon["id"].as_str())
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(defined, referenced);
+
+    Ok(())
+}
+
+/// SARIF output is byte-identical across runs, regardless of how many
+/// worker threads the audits are scheduled across.
+#[test]
+fn test_sarif_stable_order() -> Result<()> {
+    let run = |threads: &str| {
+        zizmor()
+            .input(input_under_test("e2e-menagerie"))
+            .args(["--format=sarif", "--threads", threads])
+            .run()
+    };
+
+    let baseline = run("1")?;
+    assert_eq!(baseline, run("1")?);
+    assert_eq!(baseline, run("4")?);
+
+    Ok(())
+}
+
 /// Ensures that the `--show-audit-urls` flag works as expected.
 #[test]
 fn test_show_urls() -> Result<()> {
@@ -801,7 +1318,7 @@ fn test_no_ignores() -> Result<()> {
         zizmor()
             .input(input_under_test("ignore.yml"))
             .run()?,
-        @"No findings to report. Good job! (1 ignored)"
+        @"No findings to report. Good job! (1 ignored by comment)"
     );
 
     // With `--no-ignores`, the ignored finding should be included in the output.
@@ -829,6 +1346,109 @@ fn test_no_ignores() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_region_ignores() -> Result<()> {
+    // Only the finding between `# zizmor: off` and `# zizmor: on` is ignored.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("region-ignores/bounded.yml"))
+            .run()?,
+        @r#"
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:11:24
+       |
+    11 |       - run: echo "${{ github.event.issue.title }}"
+       |         ---            ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |         |
+       |         this run block
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:26:21
+       |
+    24 |       - run: |
+       |         --- this run block
+    25 |           # zizmor: off
+    26 |           echo "${{ github.event.issue.title }}"
+       |                     ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    7 findings (1 ignored by comment, 4 suppressed, 2 unsafe fixes): 0 informational, 0 low, 0 medium, 2 high
+    "#
+    );
+
+    // An unterminated `# zizmor: off` ignores the rest of the file.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("region-ignores/unterminated.yml"))
+            .run()?,
+        @r#"
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:11:24
+       |
+    11 |       - run: echo "${{ github.event.issue.title }}"
+       |         ---            ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |         |
+       |         this run block
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    7 findings (2 ignored by comment, 4 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
+    "#
+    );
+
+    // `--no-ignores` overrides region ignores too.
+    insta::assert_snapshot!(
+        zizmor()
+            .input(input_under_test("region-ignores/unterminated.yml"))
+            .args(["--no-ignores"])
+            .run()?,
+        @r#"
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:11:24
+       |
+    11 |       - run: echo "${{ github.event.issue.title }}"
+       |         ---            ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |         |
+       |         this run block
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:17:24
+       |
+    17 |       - run: echo "${{ github.event.issue.title }}"
+       |         ---            ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |         |
+       |         this run block
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    error[template-injection]: code injection via template expansion
+      --> @@INPUT@@:22:24
+       |
+    22 |       - run: echo "${{ github.event.issue.title }}"
+       |         ---            ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
+       |         |
+       |         this run block
+       |
+       = note: audit confidence → High
+       = note: this finding has an auto-fix
+
+    7 findings (4 suppressed, 3 unsafe fixes): 0 informational, 0 low, 0 medium, 3 high
+    "#
+    );
+
+    Ok(())
+}
+
 /// Regression test for #2182.
 ///
 /// Ensures that `--collect=[MODE]` is respected for remote inputs.
@@ -889,3 +1509,685 @@ fn issue_2202() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn list_audits() -> Result<()> {
+    let output = zizmor().args(["--list-audits"]).run()?;
+
+    // Every audit is listed, including online audits that can't run offline.
+    assert!(output.lines().count() > 40);
+
+    let template_injection = output
+        .lines()
+        .find(|line| line.starts_with("template-injection "))
+        .expect("template-injection should be listed");
+    insta::assert_snapshot!(template_injection, @"template-injection               high           offline  code injection via template expansion");
+
+    let impostor_commit = output
+        .lines()
+        .find(|line| line.starts_with("impostor-commit "))
+        .expect("impostor-commit should be listed");
+    insta::assert_snapshot!(impostor_commit, @"impostor-commit                  high           online   commit with no history in referenced repository");
+
+    Ok(())
+}
+
+#[test]
+fn build_info() -> Result<()> {
+    let output = zizmor().args(["--build-info"]).run()?;
+
+    let info: serde_json::Value = serde_json::from_str(&output)?;
+    // The harness redacts the crate version, so seeing the placeholder
+    // means that the crate version was emitted verbatim.
+    assert_eq!(info["version"], "@@VERSION@@");
+    assert!(info["features"].is_array());
+    assert!(info["git_sha"].is_string() || info["git_sha"].is_null());
+
+    Ok(())
+}
+
+#[test]
+fn fix_limit() -> Result<()> {
+    // Fixes are applied in place, so we work on a copy of the input.
+    let tempdir = tempfile::tempdir()?;
+    let input = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("fixable.yml");
+    std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--fix=all", "--fix-limit=1"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Successfully applied fixes to 1 files:
+      @@INPUT@@: 1 fixes
+    Deferred 2 fixes due to --fix-limit; run again to apply them.
+    "
+    );
+
+    let fixed = std::fs::read_to_string(&input)?;
+    assert_eq!(fixed.matches("persist-credentials: false").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn fix_preserves_bom() -> Result<()> {
+    // Fixes are applied in place, so we work on a copy of the input.
+    let tempdir = tempfile::tempdir()?;
+    let input = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("fixable.yml");
+    let original = std::fs::read_to_string(input_under_test("fix-limit/fixable.yml"))?;
+    std::fs::write(&input, format!("\u{feff}{original}"))?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--fix=all"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Successfully applied fixes to 1 files:
+      @@INPUT@@: 3 fixes
+    "
+    );
+
+    // The fixed input keeps its byte order mark, and nothing else
+    // changes besides the fixes themselves.
+    let fixed = std::fs::read_to_string(&input)?;
+    let fixed = fixed.strip_prefix('\u{feff}').expect("BOM was dropped");
+    assert!(!fixed.starts_with('\u{feff}'));
+    assert_eq!(fixed.matches("persist-credentials: false").count(), 3);
+    assert_eq!(
+        fixed.replace("        with:\n          persist-credentials: false\n", ""),
+        original
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fix_conflicts() -> Result<()> {
+    // Both template-injection fixes introduce the same environment variable,
+    // but with different values, so only the first one should be applied.
+    let tempdir = tempfile::tempdir()?;
+    let input = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("same-env.yml");
+    std::fs::copy(input_under_test("fix-conflicts/same-env.yml"), &input)?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--fix=all"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Successfully applied fixes to 1 files:
+      @@INPUT@@: 1 fixes
+    Skipped 1 conflicting fixes:
+      template-injection: @@INPUT@@ (overlaps with 'replace expression with environment variable' from template-injection)
+    "
+    );
+
+    // Only the first expansion is rewritten.
+    let fixed = std::fs::read_to_string(&input)?;
+    assert_eq!(fixed.matches("${GITHUB_EVENT_ISSUE_TITLE}").count(), 1);
+    assert!(fixed.contains("${{ github.event.issue.TITLE }}"));
+
+    Ok(())
+}
+
+#[test]
+fn fix_conflicts_compatible() -> Result<()> {
+    // Both template-injection fixes rewrite the same fragment of the same
+    // step and merge the same `env:` entry, which is compatible: each
+    // rewrite applies to the next remaining expansion.
+    let tempdir = tempfile::tempdir()?;
+    let input = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("overlapping.yml");
+    std::fs::copy(input_under_test("fix-conflicts/overlapping.yml"), &input)?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--fix=all"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Successfully applied fixes to 1 files:
+      @@INPUT@@: 2 fixes
+    "
+    );
+
+    let fixed = std::fs::read_to_string(&input)?;
+    assert_eq!(fixed.matches("${GITHUB_EVENT_ISSUE_TITLE}").count(), 2);
+    assert_eq!(fixed.matches("GITHUB_EVENT_ISSUE_TITLE:").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn fixes_output() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+    let input = dir.join("fixable.yml");
+    std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .expects_exit(13)
+            .working_dir(dir.as_str())
+            .args(["--fix=all", "--fixes-output", "fixes.patch"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Wrote fixes for 1 files to fixes.patch:
+      @@INPUT@@: 3 fixes
+    "
+    );
+
+    // The input itself is left untouched.
+    assert_eq!(
+        std::fs::read_to_string(&input)?,
+        std::fs::read_to_string(input_under_test("fix-limit/fixable.yml"))?
+    );
+
+    let patch = std::fs::read_to_string(dir.join("fixes.patch"))?;
+    assert!(patch.starts_with("--- a/fixable.yml\n+++ b/fixable.yml\n"));
+    assert_eq!(
+        patch
+            .matches("+          persist-credentials: false")
+            .count(),
+        3
+    );
+
+    let status = std::process::Command::new("git")
+        .args(["apply", "--check", "fixes.patch"])
+        .current_dir(dir)
+        .status()?;
+    assert!(status.success(), "patch doesn't apply cleanly");
+
+    Ok(())
+}
+
+#[test]
+fn fixes_output_outside_cwd() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+    let cwd = dir.join("cwd");
+    std::fs::create_dir(&cwd)?;
+    let input = dir.join("elsewhere/fixable.yml");
+    std::fs::create_dir(input.parent().expect("input has a parent"))?;
+    std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+
+    // Inputs outside the current directory (whether given absolutely
+    // or relatively) can't be expressed in a patch applied from it.
+    for given in [input.as_str(), "../elsewhere/fixable.yml"] {
+        let output = zizmor()
+            .expects_failure(1)
+            .working_dir(cwd.as_str())
+            .args(["--fix=all", "--fixes-output", "fixes.patch"])
+            .input(given)
+            .run()?;
+
+        assert!(
+            output.contains("since it's outside the current directory"),
+            "{output}"
+        );
+        assert!(!cwd.join("fixes.patch").exists());
+    }
+
+    // From a directory that contains the input, the patch applies cleanly.
+    zizmor()
+        .output(OutputMode::Stderr)
+        .expects_exit(13)
+        .working_dir(dir.as_str())
+        .args(["--fix=all", "--fixes-output", "fixes.patch"])
+        .input(input.as_str())
+        .run()?;
+
+    let patch = std::fs::read_to_string(dir.join("fixes.patch"))?;
+    assert!(patch.starts_with("--- a/elsewhere/fixable.yml\n+++ b/elsewhere/fixable.yml\n"));
+
+    let status = std::process::Command::new("git")
+        .args(["apply", "--check", "fixes.patch"])
+        .current_dir(dir)
+        .status()?;
+    assert!(status.success(), "patch doesn't apply cleanly");
+
+    Ok(())
+}
+
+#[test]
+fn fix_out() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+    let input = dir.join(".github/workflows/fixable.yml");
+    std::fs::create_dir_all(input.parent().expect("input has a parent"))?;
+    std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .expects_exit(13)
+            .working_dir(dir.as_str())
+            .args(["--fix=all", "--fix-out", "fixed"])
+            .input(".github/workflows/fixable.yml")
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Wrote fixed copies of 1 files to fixed:
+      @@INPUT@@: 3 fixes
+    "
+    );
+
+    // The input itself is left untouched.
+    let original = std::fs::read_to_string(input_under_test("fix-limit/fixable.yml"))?;
+    assert_eq!(std::fs::read_to_string(&input)?, original);
+
+    // The fixed copy keeps the input's relative path under the out dir.
+    let fixed = std::fs::read_to_string(dir.join("fixed/.github/workflows/fixable.yml"))?;
+    assert_ne!(fixed, original);
+    assert_eq!(fixed.matches("persist-credentials: false").count(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn fix_out_collision() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let dir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+    let cwd = dir.join("repo");
+    for input in [
+        dir.join(".github/workflows/fixable.yml"),
+        cwd.join(".github/workflows/fixable.yml"),
+    ] {
+        std::fs::create_dir_all(input.parent().expect("input has a parent"))?;
+        std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+    }
+
+    // Both inputs would be written to fixed/.github/workflows/fixable.yml.
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .expects_exit(1)
+            .working_dir(cwd.as_str())
+            .args(["--fix=all", "--fix-out", "fixed"])
+            .input(".github/workflows/fixable.yml")
+            .input("../.github/workflows/fixable.yml")
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed ../@@INPUT@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+    fatal: no audit was performed
+    failed to apply fixes
+
+    Caused by:
+        refusing to write fixed copies: ../@@INPUT@@ and @@INPUT@@ would both be written to fixed/@@INPUT@@
+    "
+    );
+
+    assert!(!cwd.join("fixed").exists());
+
+    Ok(())
+}
+
+#[test]
+fn sarif_fixes_match_fix_output() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let input = camino::Utf8Path::from_path(tempdir.path())
+        .expect("tempdir is not UTF-8")
+        .join("fixable.yml");
+    std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+    let original = std::fs::read_to_string(&input)?;
+
+    let sarif: serde_json::Value = serde_json::from_str(
+        &zizmor()
+            .args(["--format=sarif"])
+            .input(input.clone())
+            .run()?,
+    )?;
+
+    // Collect every replacement across every result, then apply them
+    // back-to-front so that earlier byte offsets stay valid.
+    let mut replacements = sarif["runs"][0]["results"]
+        .as_array()
+        .expect("results should be an array")
+        .iter()
+        .flat_map(|result| result["fixes"].as_array().cloned().unwrap_or_default())
+        .flat_map(|fix| {
+            fix["artifactChanges"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+        })
+        .flat_map(|change| {
+            change["replacements"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+        })
+        .map(|replacement| {
+            let region = &replacement["deletedRegion"];
+            let offset = region["byteOffset"].as_u64().expect("missing byteOffset") as usize;
+            let length = region["byteLength"].as_u64().expect("missing byteLength") as usize;
+            let inserted = replacement["insertedContent"]["text"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            (offset..offset + length, inserted)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(replacements.len(), 3);
+
+    replacements.sort_by_key(|(span, _)| std::cmp::Reverse(span.start));
+    let mut sarif_fixed = original.clone();
+    for (span, inserted) in replacements {
+        sarif_fixed.replace_range(span, &inserted);
+    }
+
+    zizmor()
+        .output(OutputMode::Stderr)
+        .args(["--fix=all"])
+        .input(input.clone())
+        .run()?;
+    let cli_fixed = std::fs::read_to_string(&input)?;
+
+    assert_ne!(cli_fixed, original);
+    assert_eq!(sarif_fixed, cli_fixed);
+
+    Ok(())
+}
+
+/// Runs `git` with the given arguments in `dir`, with a fixed identity.
+fn git(dir: &camino::Utf8Path, args: &[&str]) -> Result<()> {
+    let status = std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=zizmor",
+            "-c",
+            "user.email=zizmor@example.com",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .status()?;
+    anyhow::ensure!(status.success(), "git {args:?} failed");
+    Ok(())
+}
+
+#[test]
+fn fix_requires_clean_git() -> Result<()> {
+    let tempdir = tempfile::tempdir()?;
+    let repo = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+    let input = repo.join("fixable.yml");
+    std::fs::copy(input_under_test("fix-limit/fixable.yml"), &input)?;
+
+    git(repo, &["init", "-q"])?;
+    git(repo, &["add", "fixable.yml"])?;
+    git(repo, &["commit", "-q", "-m", "initial"])?;
+
+    // Dirty the input. Fixing it should be refused, and leave it untouched.
+    std::fs::write(
+        &input,
+        format!("# local edit\n{}", std::fs::read_to_string(&input)?),
+    )?;
+    let dirty = std::fs::read_to_string(&input)?;
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .expects_failure(1)
+            .args(["--fix=all"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+    fatal: no audit was performed
+    failed to apply fixes
+
+    Caused by:
+        refusing to fix files with uncommitted changes (use --allow-dirty to override): @@INPUT@@
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:13:9
+       |
+    13 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:19:9
+       |
+    19 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    warning[artipacked]: credential persistence through GitHub Actions artifacts
+      --> @@INPUT@@:25:9
+       |
+    25 |       - uses: actions/checkout@11bd71901bbe5b1630ceea73d27597364c9af683 # tag=v4.2.2
+       |         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ does not set persist-credentials: false
+       |
+       = note: audit confidence → Low
+       = note: this finding has an auto-fix
+
+    7 findings (4 suppressed, 3 unsafe fixes): 0 informational, 0 low, 3 medium, 0 high
+    "
+    );
+    assert_eq!(std::fs::read_to_string(&input)?, dirty);
+
+    // `--allow-dirty` overrides the check.
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--fix=all", "--allow-dirty"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Successfully applied fixes to 1 files:
+      @@INPUT@@: 3 fixes
+    "
+    );
+    assert_ne!(std::fs::read_to_string(&input)?, dirty);
+
+    // Once the working tree is clean again, fixes apply as normal.
+    git(repo, &["checkout", "-q", "fixable.yml"])?;
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--fix=all"])
+            .input(input.clone())
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@
+
+    Fix Summary
+    Successfully applied fixes to 1 files:
+      @@INPUT@@: 3 fixes
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn max_depth() -> Result<()> {
+    // Without --max-depth, actions at every depth are collected.
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .input(input_under_test("max-depth"))
+            .run()?,
+        @"
+    INFO zizmor: 🌈 zizmor v@@VERSION@@
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/ci.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/a/action.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/a/b/action.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/a/b/c/action.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/action.yml
+    "
+    );
+
+    // With --max-depth, deeper actions are skipped, but workflows
+    // under .github/ are still collected.
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--max-depth=1"])
+            .input(input_under_test("max-depth"))
+            .run()?,
+        @"
+    INFO zizmor: 🌈 zizmor v@@VERSION@@
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/ci.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/a/action.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/action.yml
+    "
+    );
+
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--max-depth=0"])
+            .input(input_under_test("max-depth"))
+            .run()?,
+        @"
+    INFO zizmor: 🌈 zizmor v@@VERSION@@
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/ci.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/action.yml
+    "
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn follow_symlinks() -> Result<()> {
+    // A repository whose workflows directory contains a symlink to a
+    // workflow outside of the repository.
+    let tempdir = tempfile::tempdir()?;
+    let tempdir = camino::Utf8Path::from_path(tempdir.path()).expect("tempdir is not UTF-8");
+    let repo = tempdir.join("repo");
+    let workflows = repo.join(".github/workflows");
+    std::fs::create_dir_all(&workflows)?;
+
+    let workflow = "on: push\npermissions: {}\njobs: {}\n";
+    std::fs::write(workflows.join("real.yml"), workflow)?;
+    std::fs::write(tempdir.join("outside.yml"), workflow)?;
+    std::os::unix::fs::symlink(tempdir.join("outside.yml"), workflows.join("linked.yml"))?;
+    // Not an input, so skipping it isn't worth a warning.
+    std::os::unix::fs::symlink(tempdir.join("outside.yml"), repo.join("notes.yml"))?;
+
+    // By default, the symlinks are skipped, with a warning for the workflow.
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .input(repo.clone())
+            .run()?,
+        @"
+    INFO zizmor: 🌈 zizmor v@@VERSION@@
+    WARN collect_inputs: zizmor::registry::input: skipping symlink @@INPUT@@/.github/workflows/linked.yml (use --follow-symlinks to follow it)
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/real.yml
+    "
+    );
+
+    // With --follow-symlinks, the symlinked workflow is collected.
+    insta::assert_snapshot!(
+        zizmor()
+            .output(OutputMode::Stderr)
+            .args(["--follow-symlinks"])
+            .input(repo)
+            .run()?,
+        @"
+    INFO zizmor: 🌈 zizmor v@@VERSION@@
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/linked.yml
+    INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/real.yml
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn interleaved_inputs_deterministic() -> Result<()> {
+    let run = |args: &[&str], output: OutputMode, exit: i32| {
+        [
+            "artipacked.yml",
+            "bot-conditions.yml",
+            "several-vulnerabilities.yml",
+            "template-injection.yml",
+            "unpinned-uses.yml",
+        ]
+        .into_iter()
+        .fold(zizmor(), |zizmor, input| {
+            zizmor.input(input_under_test(input))
+        })
+        .output(output)
+        .expects_exit(exit)
+        .args(args.iter().copied())
+        .run()
+    };
+
+    for (format, exit) in [
+        ("--format=plain", 14),
+        ("--format=json-v1", 14),
+        ("--format=sarif", 0),
+    ] {
+        assert_eq!(
+            run(&[format], OutputMode::Stdout, exit)?,
+            run(&["--no-parallel-inputs", format], OutputMode::Stdout, exit)?,
+        );
+    }
+
+    // The log is identical too, since results are consumed in input order.
+    assert_eq!(
+        run(&["--format=plain"], OutputMode::Stderr, 14)?,
+        run(
+            &["--no-parallel-inputs", "--format=plain"],
+            OutputMode::Stderr,
+            14
+        )?,
+    );
+
+    Ok(())
+}
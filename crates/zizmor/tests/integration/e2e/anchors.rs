@@ -124,7 +124,7 @@ fn test_with_mapping_alias() -> Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    4 findings (2 suppressed, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
+    6 findings (4 suppressed, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
     "
     );
 
@@ -141,18 +141,20 @@ fn test_trigger_paths_anchor() -> Result<()> {
     error[dangerous-triggers]: use of fundamentally insecure workflow trigger
      --> @@INPUT@@:2:1
       |
-    2 | / on:
-    3 | |   pull_request_target:
-    4 | |     paths-ignore: &ignore
-    5 | |       - "docs/**"
-    6 | |       - "**.md"
-    7 | |   push:
-    8 | |     paths-ignore: *ignore
-      | |_________________________^ pull_request_target is almost always used insecurely
+    2 | /  on:
+    3 | |    pull_request_target:
+    4 | |/     paths-ignore: &ignore
+    5 | ||       - "docs/**"
+    6 | ||       - "**.md"
+      | ||_______________- path filters don't prevent forks from triggering this
+    7 | |    push:
+    8 | |      paths-ignore: *ignore
+      | |__________________________^ pull_request_target is almost always used insecurely
       |
       = note: audit confidence → Medium
+      = note: this finding has an auto-fix
 
-    3 findings (2 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -169,17 +171,19 @@ fn test_trigger_block_alias() -> Result<()> {
     error[dangerous-triggers]: use of fundamentally insecure workflow trigger
      --> @@INPUT@@:2:1
       |
-    2 | / on:
-    3 | |   push: &trigger
-    4 | |     branches: [main]
-    5 | |     paths-ignore:
-    6 | |       - "**.md"
-    7 | |   pull_request_target: *trigger
-      | |_______________________________^ pull_request_target is almost always used insecurely
+    2 | /  on:
+    3 | |    push: &trigger
+    4 | |      branches: [main]
+    5 | |/     paths-ignore:
+    6 | ||       - "**.md"
+      | ||_______________- path filters don't prevent forks from triggering this
+    7 | |    pull_request_target: *trigger
+      | |________________________________^ pull_request_target is almost always used insecurely
       |
       = note: audit confidence → Medium
+      = note: this finding has an auto-fix
 
-    3 findings (2 suppressed): 0 informational, 0 low, 0 medium, 1 high
+    3 findings (2 suppressed, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -204,18 +208,7 @@ fn test_steps_list_alias() -> Result<()> {
       = note: audit confidence → High
       = note: this finding has an auto-fix
 
-    error[template-injection]: code injection via template expansion
-     --> @@INPUT@@:8:24
-      |
-    8 |       - run: echo "${{ github.event.issue.title }}"
-      |         ---            ^^^^^^^^^^^^^^^^^^^^^^^^ may expand into attacker-controllable code
-      |         |
-      |         this run block
-      |
-      = note: audit confidence → High
-      = note: this finding has an auto-fix
-
-    5 findings (3 suppressed, 2 unsafe fixes): 0 informational, 0 low, 0 medium, 2 high
+    5 findings (3 suppressed, 1 deduplicated, 1 unsafe fixes): 0 informational, 0 low, 0 medium, 1 high
     "#
     );
 
@@ -354,7 +347,7 @@ fn test_dummy_job_anchors() -> Result<()> {
        = note: audit confidence → Low
        = note: this finding has an auto-fix
 
-    5 findings (3 suppressed, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
+    8 findings (6 suppressed, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 0 high
     "
     );
 
@@ -406,15 +399,7 @@ fn test_flow_mapping_step() -> Result<()> {
       |
       = note: audit confidence → High
 
-    error[unpinned-uses]: unpinned action reference
-     --> @@INPUT@@:8:28
-      |
-    8 |       - &checkout { uses: "actions/checkout@v4" }
-      |                            ^^^^^^^^^^^^^^^^^^^ action is not pinned to a hash (required by blanket policy)
-      |
-      = note: audit confidence → High
-
-    6 findings (2 suppressed, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 2 high
+    8 findings (4 suppressed, 1 deduplicated, 2 unsafe fixes): 0 informational, 0 low, 2 medium, 1 high
     "#
     );
 
@@ -17,7 +17,7 @@ fn test_fails_incompatible_modes() -> Result<()> {
      INFO zizmor: 🌈 zizmor v@@VERSION@@
     error: `workflows-only` and `actions-only` cannot be combined with other collection modes
 
-    Usage: zizmor [OPTIONS] <INPUT>...
+    Usage: zizmor [OPTIONS] [INPUT]...
 
     For more information, try '--help'.
     "
@@ -33,7 +33,7 @@ fn test_fails_incompatible_modes() -> Result<()> {
      INFO zizmor: 🌈 zizmor v@@VERSION@@
     error: `workflows-only` and `actions-only` cannot be combined with other collection modes
 
-    Usage: zizmor [OPTIONS] <INPUT>...
+    Usage: zizmor [OPTIONS] [INPUT]...
 
     For more information, try '--help'.
     "
@@ -49,7 +49,7 @@ fn test_fails_incompatible_modes() -> Result<()> {
      INFO zizmor: 🌈 zizmor v@@VERSION@@
     error: `workflows-only` and `actions-only` cannot be combined with other collection modes
 
-    Usage: zizmor [OPTIONS] <INPUT>...
+    Usage: zizmor [OPTIONS] [INPUT]...
 
     For more information, try '--help'.
     "
@@ -88,3 +88,96 @@ fn test_warn_deprecated_modes() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_input_glob() -> Result<()> {
+    // Only inputs matching the glob are audited.
+    assert_snapshot!(
+        zizmor()
+            .args(["--input-glob=*release*.yml"])
+            .output(crate::common::OutputMode::Both)
+            .input(input_under_test("input-glob"))
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/nightly-release.yml
+     INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/release.yml
+    No findings to report. Good job!
+    "
+    );
+
+    // Globs can be repeated, in which case inputs that match any of them
+    // are audited.
+    assert_snapshot!(
+        zizmor()
+            .args(["--input-glob=**/ci.yml", "--input-glob=**/release.yml"])
+            .output(crate::common::OutputMode::Both)
+            .input(input_under_test("input-glob"))
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/ci.yml
+     INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/release.yml
+    No findings to report. Good job!
+    "
+    );
+
+    // Inputs that are given explicitly are subject to the globs too.
+    assert_snapshot!(
+        zizmor()
+            .expects_failure(3)
+            .args(["--input-glob=*release*.yml"])
+            .output(crate::common::OutputMode::Both)
+            .input(input_under_test("input-glob/.github/workflows/ci.yml"))
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+    fatal: no audit was performed
+    error: no inputs collected
+      |
+      = help: collection yielded no auditable inputs
+      = help: at least one valid, auditable input must be given
+
+    Caused by:
+        no inputs collected
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_exclude() -> Result<()> {
+    // Inputs matching the exclusion are skipped, while others are kept.
+    assert_snapshot!(
+        zizmor()
+            .args(["--exclude=**/generated-*.yml"])
+            .output(crate::common::OutputMode::Both)
+            .input(input_under_test("exclude"))
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/ci.yml
+    No findings to report. Good job!
+    "
+    );
+
+    // Exclusions take precedence over `--input-glob`.
+    assert_snapshot!(
+        zizmor()
+            .args([
+                "--input-glob=**/generated-*.yml",
+                "--exclude=**/generated-docs.yml",
+            ])
+            .output(crate::common::OutputMode::Both)
+            .input(input_under_test("exclude"))
+            .run()?,
+        @"
+     INFO zizmor: 🌈 zizmor v@@VERSION@@
+     INFO audit: zizmor: 🌈 completed @@INPUT@@/.github/workflows/generated-release.yml
+    No findings to report. Good job!
+    "
+    );
+
+    Ok(())
+}
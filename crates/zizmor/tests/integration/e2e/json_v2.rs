@@ -0,0 +1,27 @@
+//! End-to-end integration tests for `--format=json-v2`.
+
+use insta::assert_snapshot;
+
+use crate::common::{input_under_test, zizmor};
+
+#[test]
+fn test_json_v2() -> anyhow::Result<()> {
+    // template-injection findings via `uses:` have multiple locations,
+    // each with a distinct role.
+    let output = zizmor()
+        .args(["--format=json-v2"])
+        .input(input_under_test("template-injection/github-script.yml"))
+        .run()?;
+
+    // The timestamp varies between runs, so we check its shape
+    // and then redact it.
+    let parsed: serde_json::Value = serde_json::from_str(&output)?;
+    let timestamp = parsed["metadata"]["timestamp"]
+        .as_str()
+        .expect("timestamp should be a string");
+    assert!(timestamp.ends_with('Z'), "{timestamp}");
+
+    assert_snapshot!(output.replace(timestamp, "@@TIMESTAMP@@"));
+
+    Ok(())
+}